@@ -0,0 +1,241 @@
+//! Performance baseline for the core matrix operations.
+//!
+//! Run with `cargo bench`, adding `--features rayon` to include the
+//! parallel back end. Running again with `--features simd` measures the
+//! same operations on the SIMD kernels.
+//! With `--features rand`, operands are drawn from a seeded generator
+//! instead of a fixed pattern. Pass a filter to only run matching
+//! benchmarks, e.g. `cargo bench -- mat_mul`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use matreex::{Matrix, Order};
+use std::hint::black_box;
+
+criterion_group!(
+    benches,
+    bench_mat_mul,
+    bench_switch_order,
+    bench_elementwise,
+    bench_scalar,
+    bench_iter,
+    bench_map,
+);
+criterion_main!(benches);
+
+fn bench_mat_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mat_mul");
+    for size in [4, 16, 64, 256] {
+        let lhs = square(size);
+        let rhs = square(size);
+        group.bench_with_input(BenchmarkId::new("naive", size), &size, |b, _| {
+            b.iter(|| black_box(lhs.clone().mat_mul(rhs.clone()).unwrap()))
+        });
+        group.bench_with_input(BenchmarkId::new("strassen", size), &size, |b, _| {
+            b.iter(|| black_box(lhs.clone().mat_mul_strassen(rhs.clone(), 64).unwrap()))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("mat_mul/128");
+    for (lhs_order, rhs_order) in ORDERS {
+        let lhs = with_order(square(128), lhs_order);
+        let rhs = with_order(square(128), rhs_order);
+        group.bench_function(format!("{lhs_order:?}x{rhs_order:?}"), |b| {
+            b.iter(|| black_box(lhs.clone().mat_mul(rhs.clone()).unwrap()))
+        });
+    }
+    group.finish();
+}
+
+fn bench_switch_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("switch_order");
+    for (nrows, ncols) in [(512, 512), (64, 4096)] {
+        let matrix = rectangle(nrows, ncols);
+        group.bench_function(format!("{nrows}x{ncols}"), |b| {
+            b.iter(|| {
+                let mut matrix = matrix.clone();
+                matrix.switch_order();
+                black_box(matrix)
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Compares elementwise operations across operand orders, against plain
+/// slice loops as a lower bound for same-order operands of `Copy`
+/// elements.
+fn bench_elementwise(c: &mut Criterion) {
+    let operands = ORDERS.map(|(lhs_order, rhs_order)| {
+        let lhs = with_order(square(512), lhs_order);
+        let rhs = with_order(square(512), rhs_order);
+        (format!("{lhs_order:?}x{rhs_order:?}"), lhs, rhs)
+    });
+    let (lhs, rhs) = (square(512), square(512));
+
+    let mut group = c.benchmark_group("elementwise_add/512");
+    for (orders, lhs, rhs) in &operands {
+        group.bench_function(orders, |b| {
+            b.iter(|| black_box(lhs.elementwise_add(rhs).unwrap()))
+        });
+    }
+    group.bench_function("slice", |b| {
+        b.iter(|| {
+            let output: Vec<f64> = lhs
+                .as_slice()
+                .iter()
+                .zip(rhs.as_slice())
+                .map(|(x, y)| x + y)
+                .collect();
+            black_box(output)
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("elementwise_mul/512");
+    for (orders, lhs, rhs) in &operands {
+        group.bench_function(orders, |b| {
+            b.iter(|| black_box(lhs.elementwise_mul(rhs).unwrap()))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("add_assign/512");
+    for (orders, lhs, rhs) in &operands {
+        group.bench_function(orders, |b| {
+            b.iter(|| {
+                let mut lhs = lhs.clone();
+                lhs += rhs;
+                black_box(lhs)
+            })
+        });
+    }
+    group.bench_function("slice", |b| {
+        b.iter(|| {
+            let mut lhs = lhs.clone();
+            lhs.as_mut_slice()
+                .iter_mut()
+                .zip(rhs.as_slice())
+                .for_each(|(x, y)| *x += y);
+            black_box(lhs)
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("add_owned/512");
+    for (orders, lhs, rhs) in &operands {
+        group.bench_function(orders, |b| b.iter(|| black_box(lhs.clone() + rhs)));
+    }
+    group.finish();
+
+    let (lhs, rhs) = (
+        lhs.map(|x| (x * 97.0) as i32),
+        rhs.map(|x| (x * 97.0) as i32),
+    );
+    let mut group = c.benchmark_group("elementwise_add_i32/512");
+    group.bench_function("matrix", |b| {
+        b.iter(|| black_box(lhs.elementwise_add(&rhs).unwrap()))
+    });
+    group.bench_function("slice", |b| {
+        b.iter(|| {
+            let output: Vec<i32> = lhs
+                .as_slice()
+                .iter()
+                .zip(rhs.as_slice())
+                .map(|(x, y)| x + y)
+                .collect();
+            black_box(output)
+        })
+    });
+    group.finish();
+}
+
+fn bench_scalar(c: &mut Criterion) {
+    let matrix = square(512);
+    let mut group = c.benchmark_group("scalar_mul/512");
+    group.bench_function("scalar_operation", |b| {
+        b.iter(|| black_box(matrix.scalar_operation(2.0, |x, y| x * y)))
+    });
+    group.bench_function("ref", |b| b.iter(|| black_box(&matrix * 2.0)));
+    group.bench_function("owned", |b| b.iter(|| black_box(matrix.clone() * 2.0)));
+    group.bench_function("assign", |b| {
+        b.iter(|| {
+            let mut matrix = matrix.clone();
+            matrix *= 2.0;
+            black_box(matrix)
+        })
+    });
+    group.finish();
+
+    c.bench_function("scalar_sub_lhs/512", |b| {
+        b.iter(|| black_box(2.0 - &matrix))
+    });
+}
+
+fn bench_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iter/512");
+    for order in [Order::RowMajor, Order::ColMajor] {
+        let matrix = with_order(square(512), order);
+        group.bench_function(format!("rows/{order:?}"), |b| {
+            b.iter(|| black_box(matrix.iter_rows().flatten().sum::<f64>()))
+        });
+        group.bench_function(format!("cols/{order:?}"), |b| {
+            b.iter(|| black_box(matrix.iter_cols().flatten().sum::<f64>()))
+        });
+        group.bench_function(format!("elements/{order:?}"), |b| {
+            b.iter(|| black_box(matrix.iter_elements().sum::<f64>()))
+        });
+    }
+    group.finish();
+}
+
+fn bench_map(c: &mut Criterion) {
+    let matrix = square(512);
+    let mut group = c.benchmark_group("map/512");
+    group.bench_function("map", |b| {
+        b.iter(|| black_box(matrix.clone().map(|x| x * 2.0)))
+    });
+    #[cfg(feature = "rayon")]
+    group.bench_function("par_map", |b| {
+        b.iter(|| black_box(matrix.clone().par_map(|x| x * 2.0)))
+    });
+    group.finish();
+}
+
+/// Every combination of operand orders.
+const ORDERS: [(Order, Order); 4] = [
+    (Order::RowMajor, Order::RowMajor),
+    (Order::RowMajor, Order::ColMajor),
+    (Order::ColMajor, Order::RowMajor),
+    (Order::ColMajor, Order::ColMajor),
+];
+
+/// Returns a `size x size` matrix of deterministic, non-trivial values.
+fn square(size: usize) -> Matrix<f64> {
+    rectangle(size, size)
+}
+
+/// Returns a `nrows x ncols` matrix of values drawn from a fixed seed.
+#[cfg(feature = "rand")]
+fn rectangle(nrows: usize, ncols: usize) -> Matrix<f64> {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    Matrix::random((nrows, ncols), &mut rng).unwrap()
+}
+
+/// Returns a `nrows x ncols` matrix of deterministic, non-trivial values.
+#[cfg(not(feature = "rand"))]
+fn rectangle(nrows: usize, ncols: usize) -> Matrix<f64> {
+    let mut matrix = Matrix::new((nrows, ncols));
+    for (index, element) in matrix.iter_elements_mut_with_index() {
+        *element = ((index.row * 31 + index.col * 17) % 97) as f64 / 97.0;
+    }
+    matrix
+}
+
+fn with_order(mut matrix: Matrix<f64>, order: Order) -> Matrix<f64> {
+    matrix.set_order(order);
+    matrix
+}
@@ -0,0 +1,69 @@
+/* C interface for matreex, available with the `capi` feature.
+ *
+ * Generated by cbindgen from `src/capi.rs`. Do not edit. */
+
+#ifndef MATREEX_H
+#define MATREEX_H
+
+#include <stddef.h>
+
+/* An opaque handle to a matrix of `double`. */
+typedef struct MatreexMatrix MatreexMatrix;
+
+typedef enum {
+  MATREEX_STATUS_OK = 0,
+  MATREEX_STATUS_NULL_POINTER = 1,
+  MATREEX_STATUS_SIZE_OVERFLOW = 2,
+  MATREEX_STATUS_SIZE_MISMATCH = 3,
+  MATREEX_STATUS_CAPACITY_EXCEEDED = 4,
+  MATREEX_STATUS_LENGTH_INCONSISTENT = 5,
+  MATREEX_STATUS_INDEX_OUT_OF_BOUNDS = 6,
+  MATREEX_STATUS_NOT_CONFORMABLE = 7,
+  MATREEX_STATUS_ARITHMETIC_OVERFLOW = 8,
+  MATREEX_STATUS_SINGULAR = 9,
+  MATREEX_STATUS_CANCELLED = 10,
+  MATREEX_STATUS_INVALID_PERMUTATION = 11,
+  MATREEX_STATUS_NOT_CONTIGUOUS = 12,
+  MATREEX_STATUS_NOT_SQUARE = 13,
+  MATREEX_STATUS_PANICKED = 14,
+} MatreexStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+MatreexStatus matreex_matrix_new(size_t nrows, size_t ncols, MatreexMatrix **out);
+
+MatreexStatus matreex_matrix_from_data(size_t nrows,
+                                       size_t ncols,
+                                       const double *data,
+                                       MatreexMatrix **out);
+
+void matreex_matrix_free(MatreexMatrix *matrix);
+
+size_t matreex_matrix_nrows(const MatreexMatrix *matrix);
+
+size_t matreex_matrix_ncols(const MatreexMatrix *matrix);
+
+MatreexStatus matreex_matrix_copy_data(const MatreexMatrix *matrix, double *data);
+
+MatreexStatus matreex_matrix_get(const MatreexMatrix *matrix,
+                                 size_t row,
+                                 size_t col,
+                                 double *value);
+
+MatreexStatus matreex_matrix_set(MatreexMatrix *matrix, size_t row, size_t col, double value);
+
+MatreexStatus matreex_matrix_matmul(const MatreexMatrix *lhs,
+                                    const MatreexMatrix *rhs,
+                                    MatreexMatrix **out);
+
+MatreexStatus matreex_matrix_solve(const MatreexMatrix *lhs,
+                                   const MatreexMatrix *rhs,
+                                   MatreexMatrix **out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MATREEX_H */
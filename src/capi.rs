@@ -0,0 +1,490 @@
+//! This module provides a C interface for `f64` matrices.
+//!
+//! Matrices are handed out as opaque [`MatreexMatrix`] pointers, which
+//! must be released with [`matreex_matrix_free`]. Fallible functions
+//! return a [`MatreexStatus`] and write their result through an out
+//! pointer, which is left untouched on failure. A panic is reported as
+//! [`MatreexStatus::Panicked`] rather than unwinding into the caller.
+//!
+//! The matching declarations live in `include/matreex.h`, which is
+//! generated by cbindgen with the settings in `cbindgen.toml`:
+//!
+//! ```text
+//! cbindgen --config cbindgen.toml --output include/matreex.h src/capi.rs
+//! ```
+//!
+//! To link against this crate from C, build it as a library with this
+//! feature enabled, e.g.:
+//!
+//! ```text
+//! cargo rustc --release --features capi --crate-type cdylib
+//! ```
+
+use crate::error::Error;
+use crate::matrix::Matrix;
+use std::panic::AssertUnwindSafe;
+
+/// An opaque handle to a matrix of `f64`.
+pub type MatreexMatrix = Matrix<f64>;
+
+/// Status codes returned by fallible functions.
+///
+/// Every variant other than [`MatreexStatus::Ok`],
+/// [`MatreexStatus::NullPointer`] and [`MatreexStatus::Panicked`]
+/// mirrors a variant of [`Error`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatreexStatus {
+    /// The call succeeded.
+    Ok = 0,
+
+    /// A required pointer argument was null.
+    NullPointer = 1,
+
+    /// See [`Error::SizeOverflow`].
+    SizeOverflow = 2,
+
+    /// See [`Error::SizeMismatch`].
+    SizeMismatch = 3,
+
+    /// See [`Error::CapacityExceeded`].
+    CapacityExceeded = 4,
+
+    /// See [`Error::LengthInconsistent`].
+    LengthInconsistent = 5,
+
+    /// See [`Error::IndexOutOfBounds`].
+    IndexOutOfBounds = 6,
+
+    /// See [`Error::NotConformable`].
+    NotConformable = 7,
+
+    /// See [`Error::ArithmeticOverflow`].
+    ArithmeticOverflow = 8,
+
+    /// See [`Error::Singular`].
+    Singular = 9,
+
+    /// See [`Error::Cancelled`].
+    Cancelled = 10,
+
+    /// See [`Error::InvalidPermutation`].
+    InvalidPermutation = 11,
+
+    /// See [`Error::NotContiguous`].
+    NotContiguous = 12,
+
+    /// See [`Error::NotSquare`].
+    NotSquare = 13,
+
+    /// The call panicked. The panic is caught at the boundary instead of
+    /// unwinding into the caller.
+    Panicked = 14,
+}
+
+impl From<Error> for MatreexStatus {
+    fn from(value: Error) -> Self {
+        match value {
+            Error::SizeOverflow => Self::SizeOverflow,
+            Error::SizeMismatch => Self::SizeMismatch,
+            Error::CapacityExceeded => Self::CapacityExceeded,
+            Error::LengthInconsistent => Self::LengthInconsistent,
+            Error::IndexOutOfBounds { .. } => Self::IndexOutOfBounds,
+            Error::NotConformable { .. } => Self::NotConformable,
+            Error::ArithmeticOverflow => Self::ArithmeticOverflow,
+            Error::Singular => Self::Singular,
+            Error::InvalidPermutation => Self::InvalidPermutation,
+            Error::Cancelled { .. } => Self::Cancelled,
+            Error::NotContiguous { .. } => Self::NotContiguous,
+            Error::NotSquare(..) => Self::NotSquare,
+        }
+    }
+}
+
+/// Runs `f`, returning `fallback` if it panics, since unwinding across
+/// the C boundary is undefined behavior.
+fn catch<R, F: FnOnce() -> R>(fallback: R, f: F) -> R {
+    std::panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(fallback)
+}
+
+/// Moves `result` behind `out`, or returns the status of its error.
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+unsafe fn emit(
+    result: crate::error::Result<MatreexMatrix>,
+    out: *mut *mut MatreexMatrix,
+) -> MatreexStatus {
+    match result {
+        Err(error) => error.into(),
+        Ok(matrix) => {
+            *out = Box::into_raw(Box::new(matrix));
+            MatreexStatus::Ok
+        }
+    }
+}
+
+/// Creates a new `nrows x ncols` matrix filled with zeros.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_new(
+    nrows: usize,
+    ncols: usize,
+    out: *mut *mut MatreexMatrix,
+) -> MatreexStatus {
+    catch(MatreexStatus::Panicked, || {
+        if out.is_null() {
+            return MatreexStatus::NullPointer;
+        }
+        emit(Matrix::build((nrows, ncols)), out)
+    })
+}
+
+/// Creates a new `nrows x ncols` matrix, copying its elements in
+/// row-major order from `data`.
+///
+/// # Safety
+///
+/// `data` must be null or valid for reads of `nrows * ncols` elements,
+/// and `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_from_data(
+    nrows: usize,
+    ncols: usize,
+    data: *const f64,
+    out: *mut *mut MatreexMatrix,
+) -> MatreexStatus {
+    catch(MatreexStatus::Panicked, || {
+        if data.is_null() || out.is_null() {
+            return MatreexStatus::NullPointer;
+        }
+        let Some(size) = nrows.checked_mul(ncols) else {
+            return MatreexStatus::SizeOverflow;
+        };
+        let data = core::slice::from_raw_parts(data, size);
+        let mut matrix = Matrix::from_slice(data);
+        if let Err(error) = matrix.reshape((nrows, ncols)) {
+            return error.into();
+        }
+        emit(Ok(matrix), out)
+    })
+}
+
+/// Releases a matrix. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `matrix` must be null or a pointer obtained from this interface that
+/// has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_free(matrix: *mut MatreexMatrix) {
+    catch((), || {
+        if !matrix.is_null() {
+            drop(Box::from_raw(matrix));
+        }
+    })
+}
+
+/// Returns the number of rows, or `0` if `matrix` is null.
+///
+/// # Safety
+///
+/// `matrix` must be null or a live pointer obtained from this interface.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_nrows(matrix: *const MatreexMatrix) -> usize {
+    catch(0, || matrix.as_ref().map_or(0, Matrix::nrows))
+}
+
+/// Returns the number of columns, or `0` if `matrix` is null.
+///
+/// # Safety
+///
+/// `matrix` must be null or a live pointer obtained from this interface.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_ncols(matrix: *const MatreexMatrix) -> usize {
+    catch(0, || matrix.as_ref().map_or(0, Matrix::ncols))
+}
+
+/// Copies the elements in row-major order into `data`.
+///
+/// # Safety
+///
+/// `matrix` must be null or a live pointer obtained from this interface,
+/// and `data` must be null or valid for writes of `nrows * ncols`
+/// elements.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_copy_data(
+    matrix: *const MatreexMatrix,
+    data: *mut f64,
+) -> MatreexStatus {
+    catch(MatreexStatus::Panicked, || {
+        let Some(matrix) = matrix.as_ref() else {
+            return MatreexStatus::NullPointer;
+        };
+        if data.is_null() {
+            return MatreexStatus::NullPointer;
+        }
+        let data = core::slice::from_raw_parts_mut(data, matrix.size());
+        for (dst, src) in data.iter_mut().zip(matrix.iter_rows().flatten()) {
+            *dst = *src;
+        }
+        MatreexStatus::Ok
+    })
+}
+
+/// Reads the element at `(row, col)` into `value`.
+///
+/// # Safety
+///
+/// `matrix` must be null or a live pointer obtained from this interface,
+/// and `value` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_get(
+    matrix: *const MatreexMatrix,
+    row: usize,
+    col: usize,
+    value: *mut f64,
+) -> MatreexStatus {
+    catch(MatreexStatus::Panicked, || {
+        let Some(matrix) = matrix.as_ref() else {
+            return MatreexStatus::NullPointer;
+        };
+        if value.is_null() {
+            return MatreexStatus::NullPointer;
+        }
+        match matrix.get((row, col)) {
+            Err(error) => error.into(),
+            Ok(element) => {
+                *value = *element;
+                MatreexStatus::Ok
+            }
+        }
+    })
+}
+
+/// Writes `value` to the element at `(row, col)`.
+///
+/// # Safety
+///
+/// `matrix` must be null or a live pointer obtained from this interface.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_set(
+    matrix: *mut MatreexMatrix,
+    row: usize,
+    col: usize,
+    value: f64,
+) -> MatreexStatus {
+    catch(MatreexStatus::Panicked, || {
+        let Some(matrix) = matrix.as_mut() else {
+            return MatreexStatus::NullPointer;
+        };
+        match matrix.get_mut((row, col)) {
+            Err(error) => error.into(),
+            Ok(element) => {
+                *element = value;
+                MatreexStatus::Ok
+            }
+        }
+    })
+}
+
+/// Multiplies `lhs` by `rhs`, storing a new matrix behind `out`.
+///
+/// # Safety
+///
+/// `lhs` and `rhs` must be null or live pointers obtained from this
+/// interface, and `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_matmul(
+    lhs: *const MatreexMatrix,
+    rhs: *const MatreexMatrix,
+    out: *mut *mut MatreexMatrix,
+) -> MatreexStatus {
+    catch(MatreexStatus::Panicked, || {
+        let (Some(lhs), Some(rhs)) = (lhs.as_ref(), rhs.as_ref()) else {
+            return MatreexStatus::NullPointer;
+        };
+        if out.is_null() {
+            return MatreexStatus::NullPointer;
+        }
+        emit(lhs.clone().mat_mul(rhs.clone()), out)
+    })
+}
+
+/// Solves `lhs * x = rhs` for `x`, storing a new matrix behind `out`.
+///
+/// # Safety
+///
+/// `lhs` and `rhs` must be null or live pointers obtained from this
+/// interface, and `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn matreex_matrix_solve(
+    lhs: *const MatreexMatrix,
+    rhs: *const MatreexMatrix,
+    out: *mut *mut MatreexMatrix,
+) -> MatreexStatus {
+    catch(MatreexStatus::Panicked, || {
+        let (Some(lhs), Some(rhs)) = (lhs.as_ref(), rhs.as_ref()) else {
+            return MatreexStatus::NullPointer;
+        };
+        if out.is_null() {
+            return MatreexStatus::NullPointer;
+        }
+        emit(lhs.lu().and_then(|lu| lu.solve(rhs)), out)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use core::ptr;
+
+    #[test]
+    fn test_lifecycle() {
+        unsafe {
+            let mut matrix = ptr::null_mut();
+            assert_eq!(matreex_matrix_new(2, 3, &mut matrix), MatreexStatus::Ok);
+            assert_eq!(matreex_matrix_nrows(matrix), 2);
+            assert_eq!(matreex_matrix_ncols(matrix), 3);
+
+            assert_eq!(matreex_matrix_set(matrix, 1, 2, 5.0), MatreexStatus::Ok);
+            assert_eq!(
+                matreex_matrix_set(matrix, 2, 0, 5.0),
+                MatreexStatus::IndexOutOfBounds
+            );
+
+            let mut value = 0.0;
+            assert_eq!(
+                matreex_matrix_get(matrix, 1, 2, &mut value),
+                MatreexStatus::Ok
+            );
+            assert_eq!(value, 5.0);
+
+            let mut data = [1.0; 6];
+            assert_eq!(
+                matreex_matrix_copy_data(matrix, data.as_mut_ptr()),
+                MatreexStatus::Ok
+            );
+            assert_eq!(data, [0.0, 0.0, 0.0, 0.0, 0.0, 5.0]);
+
+            matreex_matrix_free(matrix);
+            matreex_matrix_free(ptr::null_mut());
+
+            assert_eq!(matreex_matrix_nrows(ptr::null()), 0);
+            assert_eq!(
+                matreex_matrix_new(2, 3, ptr::null_mut()),
+                MatreexStatus::NullPointer
+            );
+            assert_eq!(
+                matreex_matrix_get(ptr::null(), 0, 0, &mut value),
+                MatreexStatus::NullPointer
+            );
+        }
+    }
+
+    #[test]
+    fn test_matmul_and_solve() {
+        unsafe {
+            let mut lhs = ptr::null_mut();
+            let data = [2.0, 1.0, 1.0, 3.0];
+            let status = matreex_matrix_from_data(2, 2, data.as_ptr(), &mut lhs);
+            assert_eq!(status, MatreexStatus::Ok);
+
+            let mut rhs = ptr::null_mut();
+            let data = [3.0, 5.0];
+            let status = matreex_matrix_from_data(2, 1, data.as_ptr(), &mut rhs);
+            assert_eq!(status, MatreexStatus::Ok);
+
+            let mut x = ptr::null_mut();
+            assert_eq!(matreex_matrix_solve(lhs, rhs, &mut x), MatreexStatus::Ok);
+            let mut product = ptr::null_mut();
+            assert_eq!(
+                matreex_matrix_matmul(lhs, x, &mut product),
+                MatreexStatus::Ok
+            );
+            assert_eq!(*product, crate::Approx(matrix![[3.0], [5.0]], 1e-12));
+
+            let mut untouched = ptr::null_mut();
+            assert_eq!(
+                matreex_matrix_matmul(rhs, rhs, &mut untouched),
+                MatreexStatus::NotConformable
+            );
+            assert!(untouched.is_null());
+
+            for matrix in [lhs, rhs, x, product] {
+                matreex_matrix_free(matrix);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_badly_scaled() {
+        unsafe {
+            let mut lhs = ptr::null_mut();
+            let data = [1e20, 0.0, 0.0, 1.0];
+            let status = matreex_matrix_from_data(2, 2, data.as_ptr(), &mut lhs);
+            assert_eq!(status, MatreexStatus::Ok);
+
+            let mut rhs = ptr::null_mut();
+            let data = [1e20, 2.0];
+            let status = matreex_matrix_from_data(2, 1, data.as_ptr(), &mut rhs);
+            assert_eq!(status, MatreexStatus::Ok);
+
+            let mut x = ptr::null_mut();
+            assert_eq!(matreex_matrix_solve(lhs, rhs, &mut x), MatreexStatus::Ok);
+            assert_eq!(*x, matrix![[1.0], [2.0]]);
+
+            let mut singular = ptr::null_mut();
+            let data = [1.0, 2.0, 2.0, 4.0];
+            let status = matreex_matrix_from_data(2, 2, data.as_ptr(), &mut singular);
+            assert_eq!(status, MatreexStatus::Ok);
+            let mut untouched = ptr::null_mut();
+            assert_eq!(
+                matreex_matrix_solve(singular, rhs, &mut untouched),
+                MatreexStatus::Singular
+            );
+            assert!(untouched.is_null());
+
+            for matrix in [lhs, rhs, x, singular] {
+                matreex_matrix_free(matrix);
+            }
+        }
+    }
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(
+            catch(MatreexStatus::Panicked, || MatreexStatus::Ok),
+            MatreexStatus::Ok
+        );
+        assert_eq!(
+            catch(MatreexStatus::Panicked, || panic!("unwinding")),
+            MatreexStatus::Panicked
+        );
+        assert_eq!(catch(0, || -> usize { panic!("unwinding") }), 0);
+    }
+
+    #[test]
+    fn test_header() {
+        let root = env!("CARGO_MANIFEST_DIR");
+        let config = cbindgen::Config::from_file(format!("{root}/cbindgen.toml")).unwrap();
+        let mut expected = Vec::new();
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_src(format!("{root}/src/capi.rs"))
+            .generate()
+            .unwrap()
+            .write(&mut expected);
+        let expected = String::from_utf8(expected).unwrap();
+        let header = std::fs::read_to_string(format!("{root}/include/matreex.h")).unwrap();
+        assert!(
+            header == expected,
+            "`include/matreex.h` is out of date, expected:\n{expected}"
+        );
+    }
+}
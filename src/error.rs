@@ -1,7 +1,35 @@
 //! This module serves for error handling.
 
+use crate::matrix::axis::Axis;
+use crate::matrix::index::Index;
+use crate::matrix::shape::Shape;
+
 /// An enum for error types.
+///
+/// Every variant falls into exactly one of the categories reported by
+/// [`Error::is_capacity_error`], [`Error::is_shape_error`],
+/// [`Error::is_index_error`], [`Error::is_numerical_error`] and
+/// [`Error::is_cancelled`], which stay meaningful as variants are added.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use matreex::{matrix, Matrix};
+///
+/// fn run() -> Result<f64, Box<dyn std::error::Error>> {
+///     let matrix: Matrix<f64> = matrix![[1.0, 2.0], [2.0, 4.0]];
+///     let inverse = matrix.inverse()?;
+///     Ok(inverse[(0, 0)])
+/// }
+///
+/// let error = run().unwrap_err();
+/// assert_eq!(error.to_string(), "matrix is singular: it has no inverse");
+/// # }
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// Error when matrix size exceeds [`usize::MAX`], which is, in fact
     /// pointless, since a matrix can store at most [`isize::MAX`] bytes.
@@ -17,28 +45,325 @@ pub enum Error {
     /// Error for inconsistent row or column length.
     LengthInconsistent,
 
-    /// Error for accessing an index out of bounds.
-    IndexOutOfBounds,
+    /// Error for accessing an index out of bounds, with the offending
+    /// `index` and the `shape` it was checked against.
+    ///
+    /// For operations on a whole row or column, `index` is the first
+    /// element of the offending vector, e.g., `(i, 0)` for row `i`.
+    IndexOutOfBounds { index: Index, shape: Shape },
+
+    /// Error when shape is not conformable, with the shapes of both
+    /// operands.
+    ///
+    /// Slices are reported as column vectors.
+    NotConformable { lhs: Shape, rhs: Shape },
+
+    /// Error when an operation requires a square matrix, with the shape
+    /// of the offending one.
+    NotSquare(Shape),
+
+    /// Error when an arithmetic operation overflows.
+    ArithmeticOverflow,
+
+    /// Error when a matrix is singular, i.e., has no inverse.
+    Singular,
+
+    /// Error when a sequence of indices is not a permutation.
+    InvalidPermutation,
+
+    /// Error when an operation is cancelled before completion, with
+    /// `done` out of `total` units of work finished.
+    Cancelled { done: usize, total: usize },
 
-    /// Error when shape is not conformable.
-    NotConformable,
+    /// Error when `strides`, given as `(row_stride, col_stride)` in
+    /// elements, do not describe a contiguous row-major or column-major
+    /// layout of `shape`.
+    NotContiguous {
+        strides: (usize, usize),
+        shape: Shape,
+    },
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let content = match self {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let summary = self.summary();
+        match self {
+            Self::SizeOverflow => {
+                write!(f, "{summary}: the number of elements exceeds usize::MAX")
+            }
+            Self::SizeMismatch => write!(
+                f,
+                "{summary}: the target shape must hold as many elements as the source"
+            ),
+            Self::CapacityExceeded => {
+                write!(
+                    f,
+                    "{summary}: the matrix would occupy more than isize::MAX bytes"
+                )
+            }
+            Self::LengthInconsistent => write!(
+                f,
+                "{summary}: rows, columns or buffers that must match in length do not"
+            ),
+            Self::IndexOutOfBounds { index, shape } => {
+                write!(f, "{summary}: {index} is outside shape {shape}")
+            }
+            Self::NotConformable { lhs, rhs } => write!(f, "{summary}: {lhs} and {rhs}"),
+            Self::NotSquare(shape) => {
+                let longer = if shape.nrows > shape.ncols {
+                    "rows than columns"
+                } else {
+                    "columns than rows"
+                };
+                write!(f, "{summary}: {shape} has more {longer}")
+            }
+            Self::ArithmeticOverflow => write!(
+                f,
+                "{summary}: use a wider element type, or a saturating or wrapping operation"
+            ),
+            Self::Singular => write!(f, "{summary}: it has no inverse"),
+            Self::InvalidPermutation => {
+                write!(f, "{summary}: every index must appear exactly once")
+            }
+            Self::Cancelled { done, total } => {
+                write!(f, "{summary} after {done} of {total} steps")
+            }
+            Self::NotContiguous {
+                strides: (row_stride, col_stride),
+                shape,
+            } => write!(
+                f,
+                "{summary}: ({row_stride}, {col_stride}) for shape {shape}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl Error {
+    /// Returns `true` if the error is caused by a matrix being too large,
+    /// i.e., [`Error::SizeOverflow`] or [`Error::CapacityExceeded`].
+    pub fn is_capacity_error(&self) -> bool {
+        matches!(self, Self::SizeOverflow | Self::CapacityExceeded)
+    }
+
+    /// Returns `true` if the error is caused by shapes or lengths that do
+    /// not fit together, i.e., [`Error::SizeMismatch`],
+    /// [`Error::LengthInconsistent`], [`Error::NotConformable`],
+    /// [`Error::NotSquare`] or [`Error::NotContiguous`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = Matrix::<i32>::new((3, 2));
+    ///
+    /// let error = lhs.checked_add(&rhs).unwrap_err();
+    /// assert!(error.is_shape_error());
+    /// assert!(!error.is_index_error());
+    /// ```
+    pub fn is_shape_error(&self) -> bool {
+        matches!(
+            self,
+            Self::SizeMismatch
+                | Self::LengthInconsistent
+                | Self::NotConformable { .. }
+                | Self::NotSquare(..)
+                | Self::NotContiguous { .. }
+        )
+    }
+
+    /// Returns `true` if the error is caused by invalid indices, i.e.,
+    /// [`Error::IndexOutOfBounds`] or [`Error::InvalidPermutation`].
+    pub fn is_index_error(&self) -> bool {
+        matches!(
+            self,
+            Self::IndexOutOfBounds { .. } | Self::InvalidPermutation
+        )
+    }
+
+    /// Returns `true` if the error is caused by the values involved
+    /// rather than their layout, i.e., [`Error::ArithmeticOverflow`] or
+    /// [`Error::Singular`].
+    pub fn is_numerical_error(&self) -> bool {
+        matches!(self, Self::ArithmeticOverflow | Self::Singular)
+    }
+
+    /// Returns `true` if the error is [`Error::Cancelled`].
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Self::Cancelled { .. })
+    }
+}
+
+impl Error {
+    /// Creates an [`Error::IndexOutOfBounds`] for the `n`-th vector along
+    /// `axis` of a matrix of the given shape.
+    pub(crate) fn vector_out_of_bounds(axis: Axis, n: usize, shape: Shape) -> Self {
+        let index = match axis {
+            Axis::Row => Index::new(n, 0),
+            Axis::Col => Index::new(0, n),
+        };
+        Self::IndexOutOfBounds { index, shape }
+    }
+
+    /// Creates an [`Error::NotConformable`] for operands of the given
+    /// shapes.
+    pub(crate) fn not_conformable(lhs: Shape, rhs: Shape) -> Self {
+        Self::NotConformable { lhs, rhs }
+    }
+
+    /// Creates an [`Error::NotSquare`] for a matrix of the given shape.
+    pub(crate) fn not_square(shape: Shape) -> Self {
+        Self::NotSquare(shape)
+    }
+
+    /// Creates an [`Error::NotConformable`] for a matrix of the given
+    /// shape and a slice of length `len`.
+    pub(crate) fn not_conformable_with_slice(shape: Shape, len: usize) -> Self {
+        Self::not_conformable(shape, Shape::new(len, 1))
+    }
+
+    /// Returns the message of the error without its context.
+    pub(crate) fn summary(&self) -> &'static str {
+        match self {
             Self::SizeOverflow => "size overflows",
             Self::SizeMismatch => "size does not match",
             Self::CapacityExceeded => "capacity exceeds",
             Self::LengthInconsistent => "length inconsistent",
-            Self::IndexOutOfBounds => "index out of bounds",
-            Self::NotConformable => "shape not conformable",
-        };
-        write!(f, "{content}")
+            Self::IndexOutOfBounds { .. } => "index out of bounds",
+            Self::NotConformable { .. } => "shape not conformable",
+            Self::NotSquare(..) => "matrix not square",
+            Self::ArithmeticOverflow => "arithmetic overflow",
+            Self::Singular => "matrix is singular",
+            Self::InvalidPermutation => "invalid permutation",
+            Self::Cancelled { .. } => "operation cancelled",
+            Self::NotContiguous { .. } => "strides not contiguous",
+        }
     }
 }
 
-impl std::error::Error for Error {}
-
 /// An alias for [`core::result::Result`].
 pub type Result<T> = core::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_display() {
+        let error = Error::IndexOutOfBounds {
+            index: Index::new(2, 0),
+            shape: Shape::new(2, 3),
+        };
+        assert_eq!(
+            error.to_string(),
+            "index out of bounds: (2, 0) is outside shape (2, 3)"
+        );
+        assert_eq!(error.summary(), "index out of bounds");
+
+        let error = Error::not_conformable(Shape::new(2, 3), Shape::new(2, 2));
+        assert_eq!(
+            error.to_string(),
+            "shape not conformable: (2, 3) and (2, 2)"
+        );
+        assert_eq!(error.summary(), "shape not conformable");
+
+        let error = Error::not_square(Shape::new(2, 3));
+        assert_eq!(
+            error.to_string(),
+            "matrix not square: (2, 3) has more columns than rows"
+        );
+        let error = Error::not_square(Shape::new(3, 1));
+        assert_eq!(
+            error.to_string(),
+            "matrix not square: (3, 1) has more rows than columns"
+        );
+
+        let error = Error::Cancelled { done: 1, total: 4 };
+        assert_eq!(error.to_string(), "operation cancelled after 1 of 4 steps");
+
+        let error = Error::NotContiguous {
+            strides: (6, 2),
+            shape: Shape::new(2, 3),
+        };
+        assert_eq!(
+            error.to_string(),
+            "strides not contiguous: (6, 2) for shape (2, 3)"
+        );
+
+        for error in all_errors() {
+            assert!(error.to_string().starts_with(error.summary()));
+        }
+    }
+
+    #[test]
+    fn test_classification() {
+        for error in all_errors() {
+            let categories = [
+                error.is_capacity_error(),
+                error.is_shape_error(),
+                error.is_index_error(),
+                error.is_numerical_error(),
+                error.is_cancelled(),
+            ];
+            assert_eq!(categories.iter().filter(|&&x| x).count(), 1, "{error:?}");
+        }
+
+        assert!(Error::CapacityExceeded.is_capacity_error());
+        assert!(Error::LengthInconsistent.is_shape_error());
+        assert!(Error::InvalidPermutation.is_index_error());
+        assert!(Error::Singular.is_numerical_error());
+        assert!(!Error::Singular.is_cancelled());
+    }
+
+    fn all_errors() -> [Error; 12] {
+        let shape = Shape::new(2, 3);
+        [
+            Error::SizeOverflow,
+            Error::SizeMismatch,
+            Error::CapacityExceeded,
+            Error::LengthInconsistent,
+            Error::IndexOutOfBounds {
+                index: Index::new(2, 0),
+                shape,
+            },
+            Error::NotConformable {
+                lhs: shape,
+                rhs: shape,
+            },
+            Error::NotSquare(shape),
+            Error::ArithmeticOverflow,
+            Error::Singular,
+            Error::InvalidPermutation,
+            Error::Cancelled { done: 0, total: 1 },
+            Error::NotContiguous {
+                strides: (3, 2),
+                shape,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_vector_out_of_bounds() {
+        let shape = Shape::new(2, 3);
+        assert_eq!(
+            Error::vector_out_of_bounds(Axis::Row, 2, shape),
+            Error::IndexOutOfBounds {
+                index: Index::new(2, 0),
+                shape,
+            }
+        );
+        assert_eq!(
+            Error::vector_out_of_bounds(Axis::Col, 3, shape),
+            Error::IndexOutOfBounds {
+                index: Index::new(0, 3),
+                shape,
+            }
+        );
+    }
+}
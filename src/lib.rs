@@ -11,19 +11,84 @@
 //! assert_eq!(lhs * rhs, matrix![[10, 13], [28, 40]]);
 //! ```
 //!
+//! # Features
+//!
+//! The `std` feature is enabled by default. Without it, the crate is
+//! `no_std` and only depends on `alloc`, leaving out file and stream
+//! I/O as well as everything built on floating-point functions such as
+//! [`f64::sqrt`], which `core` does not provide.
+//!
+//! The `simd` feature runs elementwise, scalar and matrix multiplication
+//! operations on primitive numeric types through kernels dispatched to
+//! AVX2 at runtime where the CPU supports them. Matrix multiplication
+//! then sums the products in a different order, so float results may
+//! differ in the last bits.
+//!
+//! The `nalgebra` and `ndarray` features add conversions between
+//! [`Matrix`] and `nalgebra::DMatrix` or `ndarray::Array2`, moving the
+//! data without copying wherever the memory order allows it.
+//!
+//! The `pyo3` feature lets a [`Matrix`] cross into Python as a
+//! two-dimensional NumPy array without copying, and be extracted back
+//! from one, for use in `pyo3` bindings.
+//!
 //! # FAQs
 //!
 //! ## Why `matreex` instead of `matrix`?
 //!
 //! Hmm ... Who knows? Could be a name conflict.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub use self::error::{Error, Result};
+pub use self::matrix::any::{AnyMatrix, DType};
+pub use self::matrix::approx::Approx;
+pub use self::matrix::axis::Axis;
+pub use self::matrix::banded::BandedMatrix;
+#[cfg(feature = "std")]
+pub use self::matrix::binary::Endianness;
+#[cfg(feature = "rand")]
+pub use self::matrix::cluster::KMeans;
+#[cfg(feature = "std")]
+pub use self::matrix::csv::CsvOptions;
+pub use self::matrix::dynamic::DynMatrix;
+#[cfg(feature = "std")]
+pub use self::matrix::game::ZeroSumGame;
 pub use self::matrix::index::Index;
+pub use self::matrix::iter::{
+    AxisIter, AxisIterMut, LendingIterator, RowsLending, StrideIter, StrideIterMut,
+};
+#[allow(deprecated)]
 pub use self::matrix::iter::{MatrixIter, VectorIter};
+#[cfg(feature = "std")]
+pub use self::matrix::linalg::{LuDecomposition, NormKind};
+#[cfg(feature = "std")]
+pub use self::matrix::markov::ZeroRowPolicy;
+#[cfg(feature = "npy")]
+pub use self::matrix::npy::NpyElement;
+#[cfg(feature = "std")]
+pub use self::matrix::ooc::TiledMatrix;
 pub use self::matrix::order::Order;
+pub use self::matrix::permutation::PermutationMatrix;
+#[cfg(feature = "std")]
+pub use self::matrix::preprocessing::{NormalizeKind, Scaler, ScalerKind};
+pub use self::matrix::progress::ProgressSink;
+#[cfg(feature = "rand")]
+pub use self::matrix::randomized::Svd;
+pub use self::matrix::scalar::ScalarLike;
 pub use self::matrix::shape::Shape;
+pub use self::matrix::shared::{SharedMatrix, SharedVector, SharedVectors};
+pub use self::matrix::sparse::{CooMatrix, CsrMatrix};
+#[cfg(feature = "std")]
+pub use self::matrix::stream::RowStream;
+pub use self::matrix::typed::Typed;
+pub use self::matrix::window::{Chunks, Windows};
 pub use self::matrix::Matrix;
 
+#[cfg(feature = "capi")]
+pub mod capi;
 pub mod error;
 pub mod matrix;
 
@@ -109,7 +109,7 @@ macro_rules! col_vec {
 macro_rules! impl_scalar_add {
     ($($t:ty)*) => {
         $(
-            impl std::ops::Add<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Add<$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: $t) -> Self::Output {
@@ -117,7 +117,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Add<&$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: &$t) -> Self::Output {
@@ -125,7 +125,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Add<$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: $t) -> Self::Output {
@@ -133,7 +133,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<&$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Add<&$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: &$t) -> Self::Output {
@@ -141,7 +141,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Add<$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: $t) -> Self::Output {
@@ -149,7 +149,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<&$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Add<&$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: &$t) -> Self::Output {
@@ -157,7 +157,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Add<$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: $t) -> Self::Output {
@@ -165,7 +165,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<&$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Add<&$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: &$t) -> Self::Output {
@@ -173,7 +173,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Add<$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -181,7 +181,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<&$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Add<&$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -189,7 +189,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Add<$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -197,7 +197,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<&$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Add<&$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -205,7 +205,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Add<$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -213,7 +213,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<&$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Add<&$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -221,7 +221,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Add<$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -229,7 +229,7 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::Add<&$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Add<&$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn add(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -237,13 +237,13 @@ macro_rules! impl_scalar_add {
                 }
             }
 
-            impl std::ops::AddAssign<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::AddAssign<$t> for $crate::matrix::Matrix<$t> {
                 fn add_assign(&mut self, rhs: $t) {
                     self.scalar_operation_assign(&rhs, |element, scalar| *element += scalar.clone());
                 }
             }
 
-            impl std::ops::AddAssign<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::AddAssign<&$t> for $crate::matrix::Matrix<$t> {
                 fn add_assign(&mut self, rhs: &$t) {
                     self.scalar_operation_assign(rhs, |element, scalar| *element += scalar.clone());
                 }
@@ -263,7 +263,7 @@ macro_rules! impl_scalar_add {
 macro_rules! impl_scalar_sub {
     ($($t:ty)*) => {
         $(
-            impl std::ops::Sub<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Sub<$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: $t) -> Self::Output {
@@ -271,7 +271,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Sub<&$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: &$t) -> Self::Output {
@@ -279,7 +279,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Sub<$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: $t) -> Self::Output {
@@ -287,7 +287,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<&$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Sub<&$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: &$t) -> Self::Output {
@@ -295,7 +295,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Sub<$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: $t) -> Self::Output {
@@ -303,7 +303,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<&$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Sub<&$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: &$t) -> Self::Output {
@@ -311,7 +311,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Sub<$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: $t) -> Self::Output {
@@ -319,7 +319,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<&$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Sub<&$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: &$t) -> Self::Output {
@@ -327,7 +327,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Sub<$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -335,7 +335,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<&$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Sub<&$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -343,7 +343,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Sub<$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -351,7 +351,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<&$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Sub<&$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -359,7 +359,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Sub<$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -367,7 +367,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<&$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Sub<&$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -375,7 +375,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Sub<$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -383,7 +383,7 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::Sub<&$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Sub<&$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn sub(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -391,13 +391,13 @@ macro_rules! impl_scalar_sub {
                 }
             }
 
-            impl std::ops::SubAssign<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::SubAssign<$t> for $crate::matrix::Matrix<$t> {
                 fn sub_assign(&mut self, rhs: $t) {
                     self.scalar_operation_assign(&rhs, |element, scalar| *element -= scalar.clone());
                 }
             }
 
-            impl std::ops::SubAssign<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::SubAssign<&$t> for $crate::matrix::Matrix<$t> {
                 fn sub_assign(&mut self, rhs: &$t) {
                     self.scalar_operation_assign(rhs, |element, scalar| *element -= scalar.clone());
                 }
@@ -417,7 +417,7 @@ macro_rules! impl_scalar_sub {
 macro_rules! impl_scalar_mul {
     ($($t:ty)*) => {
         $(
-            impl std::ops::Mul<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Mul<$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: $t) -> Self::Output {
@@ -425,7 +425,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Mul<&$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: &$t) -> Self::Output {
@@ -433,7 +433,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Mul<$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: $t) -> Self::Output {
@@ -441,7 +441,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<&$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Mul<&$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: &$t) -> Self::Output {
@@ -449,7 +449,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Mul<$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: $t) -> Self::Output {
@@ -457,7 +457,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<&$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Mul<&$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: &$t) -> Self::Output {
@@ -465,7 +465,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Mul<$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: $t) -> Self::Output {
@@ -473,7 +473,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<&$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Mul<&$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: &$t) -> Self::Output {
@@ -481,7 +481,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Mul<$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -489,7 +489,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<&$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Mul<&$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -497,7 +497,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Mul<$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -505,7 +505,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<&$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Mul<&$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -513,7 +513,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Mul<$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -521,7 +521,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<&$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Mul<&$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -529,7 +529,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Mul<$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -537,7 +537,7 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::Mul<&$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Mul<&$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn mul(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -545,13 +545,13 @@ macro_rules! impl_scalar_mul {
                 }
             }
 
-            impl std::ops::MulAssign<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::MulAssign<$t> for $crate::matrix::Matrix<$t> {
                 fn mul_assign(&mut self, rhs: $t) {
                     self.scalar_operation_assign(&rhs, |element, scalar| *element *= scalar.clone());
                 }
             }
 
-            impl std::ops::MulAssign<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::MulAssign<&$t> for $crate::matrix::Matrix<$t> {
                 fn mul_assign(&mut self, rhs: &$t) {
                     self.scalar_operation_assign(rhs, |element, scalar| *element *= scalar.clone());
                 }
@@ -571,7 +571,7 @@ macro_rules! impl_scalar_mul {
 macro_rules! impl_scalar_div {
     ($($t:ty)*) => {
         $(
-            impl std::ops::Div<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Div<$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: $t) -> Self::Output {
@@ -579,7 +579,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Div<&$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: &$t) -> Self::Output {
@@ -587,7 +587,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Div<$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: $t) -> Self::Output {
@@ -595,7 +595,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<&$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Div<&$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: &$t) -> Self::Output {
@@ -603,7 +603,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Div<$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: $t) -> Self::Output {
@@ -611,7 +611,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<&$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Div<&$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: &$t) -> Self::Output {
@@ -619,7 +619,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Div<$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: $t) -> Self::Output {
@@ -627,7 +627,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<&$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Div<&$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: &$t) -> Self::Output {
@@ -635,7 +635,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Div<$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -643,7 +643,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<&$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Div<&$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -651,7 +651,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Div<$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -659,7 +659,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<&$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Div<&$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -667,7 +667,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Div<$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -675,7 +675,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<&$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Div<&$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -683,7 +683,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Div<$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -691,7 +691,7 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::Div<&$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Div<&$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn div(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -699,13 +699,13 @@ macro_rules! impl_scalar_div {
                 }
             }
 
-            impl std::ops::DivAssign<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::DivAssign<$t> for $crate::matrix::Matrix<$t> {
                 fn div_assign(&mut self, rhs: $t) {
                     self.scalar_operation_assign(&rhs, |element, scalar| *element /= scalar.clone());
                 }
             }
 
-            impl std::ops::DivAssign<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::DivAssign<&$t> for $crate::matrix::Matrix<$t> {
                 fn div_assign(&mut self, rhs: &$t) {
                     self.scalar_operation_assign(rhs, |element, scalar| *element /= scalar.clone());
                 }
@@ -725,7 +725,7 @@ macro_rules! impl_scalar_div {
 macro_rules! impl_scalar_rem {
     ($($t:ty)*) => {
         $(
-            impl std::ops::Rem<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Rem<$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: $t) -> Self::Output {
@@ -733,7 +733,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::Rem<&$t> for $crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: &$t) -> Self::Output {
@@ -741,7 +741,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Rem<$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: $t) -> Self::Output {
@@ -749,7 +749,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<&$t> for &$crate::matrix::Matrix<$t> {
+            impl core::ops::Rem<&$t> for &$crate::matrix::Matrix<$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: &$t) -> Self::Output {
@@ -757,7 +757,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Rem<$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: $t) -> Self::Output {
@@ -765,7 +765,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<&$t> for $crate::matrix::Matrix<&$t> {
+            impl core::ops::Rem<&$t> for $crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: &$t) -> Self::Output {
@@ -773,7 +773,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Rem<$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: $t) -> Self::Output {
@@ -781,7 +781,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<&$t> for &$crate::matrix::Matrix<&$t> {
+            impl core::ops::Rem<&$t> for &$crate::matrix::Matrix<&$t> {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: &$t) -> Self::Output {
@@ -789,7 +789,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Rem<$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -797,7 +797,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<&$crate::matrix::Matrix<$t>> for $t {
+            impl core::ops::Rem<&$crate::matrix::Matrix<$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -805,7 +805,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Rem<$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -813,7 +813,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<&$crate::matrix::Matrix<&$t>> for $t {
+            impl core::ops::Rem<&$crate::matrix::Matrix<&$t>> for $t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -821,7 +821,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Rem<$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
@@ -829,7 +829,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<&$crate::matrix::Matrix<$t>> for &$t {
+            impl core::ops::Rem<&$crate::matrix::Matrix<$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
@@ -837,7 +837,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Rem<$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -845,7 +845,7 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::Rem<&$crate::matrix::Matrix<&$t>> for &$t {
+            impl core::ops::Rem<&$crate::matrix::Matrix<&$t>> for &$t {
                 type Output = $crate::matrix::Matrix<$t>;
 
                 fn rem(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
@@ -853,13 +853,13 @@ macro_rules! impl_scalar_rem {
                 }
             }
 
-            impl std::ops::RemAssign<$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::RemAssign<$t> for $crate::matrix::Matrix<$t> {
                 fn rem_assign(&mut self, rhs: $t) {
                     self.scalar_operation_assign(&rhs, |element, scalar| *element %= scalar.clone());
                 }
             }
 
-            impl std::ops::RemAssign<&$t> for $crate::matrix::Matrix<$t> {
+            impl core::ops::RemAssign<&$t> for $crate::matrix::Matrix<$t> {
                 fn rem_assign(&mut self, rhs: &$t) {
                     self.scalar_operation_assign(rhs, |element, scalar| *element %= scalar.clone());
                 }
@@ -1,19 +1,79 @@
 //! This module defines [`Matrix`] and all its related components.
 
-use self::iter::VectorIter;
+use self::axis::Axis;
+use self::index::Index;
+use self::iter::StrideIter;
 use self::order::Order;
+use self::scalar::ScalarLike;
 use self::shape::{AxisShape, Shape, ShapeLike};
 use crate::error::{Error, Result};
-
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+pub mod any;
+pub mod approx;
+pub mod axis;
+pub mod banded;
+pub mod dynamic;
 pub mod index;
 pub mod iter;
+pub mod linalg;
 pub mod order;
+pub mod permutation;
+pub mod progress;
+pub mod scalar;
 pub mod shape;
+pub mod shared;
+pub mod sparse;
+pub mod typed;
+pub mod window;
+
+#[cfg(feature = "std")]
+pub mod binary;
+#[cfg(feature = "rand")]
+pub mod cluster;
+#[cfg(feature = "std")]
+pub mod csv;
+#[cfg(feature = "std")]
+pub mod game;
+#[cfg(feature = "std")]
+pub mod markov;
+#[cfg(feature = "npy")]
+pub mod npy;
+#[cfg(feature = "std")]
+pub mod ooc;
+#[cfg(feature = "std")]
+pub mod preprocessing;
+#[cfg(feature = "rand")]
+pub mod randomized;
+#[cfg(feature = "std")]
+pub mod stream;
 
 mod arithmetic;
+mod cmp;
 mod conversion;
 mod default;
+mod elementary;
 mod fmt;
+mod graph;
+mod logical;
+mod math;
+mod sort;
+mod statistics;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "num")]
+mod num;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "std")]
+mod real;
 
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
@@ -100,13 +160,166 @@ impl<T> Matrix<T> {
     where
         T: Default,
     {
-        let order = Order::default();
+        Self::build_with_order(Order::default(), shape)
+    }
+
+    /// Creates a new [`Matrix`] instance with default values, stored in
+    /// the given `order`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if size exceeds [`usize::MAX`], or total bytes stored
+    /// exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix, Order};
+    ///
+    /// let matrix = Matrix::with_order(Order::ColMajor, (2, 3));
+    /// assert_eq!(matrix.order(), Order::ColMajor);
+    ///
+    /// let mut expected = matrix![[0, 0, 0], [0, 0, 0]];
+    /// expected.set_order(Order::ColMajor);
+    /// assert_eq!(matrix, expected);
+    /// ```
+    pub fn with_order<S: ShapeLike>(order: Order, shape: S) -> Self
+    where
+        T: Default,
+    {
+        match Self::build_with_order(order, shape) {
+            Err(error) => panic!("{error}"),
+            Ok(matrix) => matrix,
+        }
+    }
+
+    fn build_with_order<S: ShapeLike>(order: Order, shape: S) -> Result<Self>
+    where
+        T: Default,
+    {
         let shape = AxisShape::try_from_shape(shape, order)?;
         let size = Self::check_size(shape.size())?;
-        let data = std::iter::repeat_with(T::default).take(size).collect();
+        let data = core::iter::repeat_with(T::default).take(size).collect();
         Ok(Self { order, shape, data })
     }
 
+    /// Creates a new [`Matrix`] instance with every element set to `value`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if size exceeds [`usize::MAX`].
+    /// - Panics if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let matrix = Matrix::full((2, 3), 7);
+    /// assert_eq!(matrix, matrix![[7, 7, 7], [7, 7, 7]]);
+    /// ```
+    pub fn full<S: ShapeLike>(shape: S, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let order = Order::default();
+        let shape = match AxisShape::try_from_shape(shape, order) {
+            Err(error) => panic!("{error}"),
+            Ok(shape) => shape,
+        };
+        let size = match Self::check_size(shape.size()) {
+            Err(error) => panic!("{error}"),
+            Ok(size) => size,
+        };
+        let data = vec![value; size];
+        Self { order, shape, data }
+    }
+
+    /// Creates a new [`Matrix`] instance with each element computed by
+    /// calling `f` with its index.
+    ///
+    /// Each element is written exactly once, unlike [`Matrix::new`]
+    /// followed by an assignment pass.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if size exceeds [`usize::MAX`].
+    /// - Panics if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// The order in which elements are visited is not guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let matrix = Matrix::from_fn((2, 3), |index| index.row * 3 + index.col);
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    /// ```
+    pub fn from_fn<S, F>(shape: S, mut f: F) -> Self
+    where
+        S: ShapeLike,
+        F: FnMut(Index) -> T,
+    {
+        let order = Order::default();
+        let shape = match AxisShape::try_from_shape(shape, order) {
+            Err(error) => panic!("{error}"),
+            Ok(shape) => shape,
+        };
+        let size = match Self::check_size(shape.size()) {
+            Err(error) => panic!("{error}"),
+            Ok(size) => size,
+        };
+        let data = (0..size)
+            .map(|index| f(Self::unflatten_index(index, order, shape)))
+            .collect();
+        Self { order, shape, data }
+    }
+
+    /// Creates a new [`Matrix`] instance with uninitialized elements.
+    ///
+    /// Once every element has been written, call
+    /// [`Matrix::assume_init`] to obtain the initialized matrix. This
+    /// skips the pass that [`Matrix::new`] spends on default values.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if size exceeds [`usize::MAX`].
+    /// - Panics if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let mut matrix = Matrix::<i32>::new_uninit((2, 3));
+    /// for row in 0..2 {
+    ///     for col in 0..3 {
+    ///         matrix[(row, col)].write((row * 3 + col) as i32);
+    ///     }
+    /// }
+    ///
+    /// let matrix = unsafe { matrix.assume_init() };
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    /// ```
+    pub fn new_uninit<S: ShapeLike>(shape: S) -> Matrix<MaybeUninit<T>> {
+        let order = Order::default();
+        let shape = match AxisShape::try_from_shape(shape, order) {
+            Err(error) => panic!("{error}"),
+            Ok(shape) => shape,
+        };
+        let size = match Matrix::<MaybeUninit<T>>::check_size(shape.size()) {
+            Err(error) => panic!("{error}"),
+            Ok(size) => size,
+        };
+        let mut data = Vec::with_capacity(size);
+        // `MaybeUninit<T>` requires no initialization.
+        unsafe { data.set_len(size) };
+        Matrix { order, shape, data }
+    }
+
     /// Creates an empty [`Matrix`] instance.
     ///
     /// # Examples
@@ -145,6 +358,27 @@ impl<T> Matrix<T> {
         self.shape.interpret_ncols(self.order)
     }
 
+    /// Returns the length of the matrix along the given axis, that is,
+    /// the number of rows for [`Axis::Row`] and the number of columns
+    /// for [`Axis::Col`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// assert_eq!(matrix.len_of(Axis::Row), 2);
+    /// assert_eq!(matrix.len_of(Axis::Col), 3);
+    /// ```
+    pub fn len_of(&self, axis: Axis) -> usize {
+        if axis.is_major(self.order) {
+            self.major()
+        } else {
+            self.minor()
+        }
+    }
+
     /// Returns the total number of elements in the matrix.
     pub fn size(&self) -> usize {
         self.data.len()
@@ -160,6 +394,70 @@ impl<T> Matrix<T> {
         self.data.capacity()
     }
 
+    /// Returns the strides of the rows and columns of the matrix, in
+    /// number of elements.
+    ///
+    /// The element at `(row, col)` is stored at position
+    /// `row * row_stride + col * col_stride` of [`Matrix::as_slice`].
+    /// Multiplied by `size_of::<T>()`, these are the strides expected by
+    /// buffer protocols such as NumPy's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// assert_eq!(matrix.strides(), (3, 1));
+    ///
+    /// matrix.switch_order();
+    /// assert_eq!(matrix.strides(), (1, 2));
+    /// ```
+    pub fn strides(&self) -> (usize, usize) {
+        match self.order {
+            Order::RowMajor => (self.major_stride(), 1),
+            Order::ColMajor => (1, self.major_stride()),
+        }
+    }
+
+    /// Returns the underlying data as a slice, in memory order as
+    /// described by [`Matrix::order`] and [`Matrix::strides`].
+    ///
+    /// Unlike indexing, this view does not hide the internal order, so
+    /// the same matrix yields different slices after
+    /// [`Matrix::switch_order`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// assert_eq!(matrix.as_slice(), &[0, 1, 2, 3, 4, 5]);
+    ///
+    /// matrix.switch_order();
+    /// assert_eq!(matrix.as_slice(), &[0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the underlying data as a mutable slice, in memory order as
+    /// described by [`Matrix::order`] and [`Matrix::strides`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.as_mut_slice()[1] = -1;
+    /// assert_eq!(matrix, matrix![[0, -1, 2], [3, 4, 5]]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
     /// Returns the length of the major axis.
     fn major(&self) -> usize {
         self.shape.major()
@@ -212,7 +510,15 @@ impl<T> Matrix<T> {
         self
     }
 
-    /// Switches the order of the matrix.
+    /// Rotates the matrix by `k` quarter turns, counterclockwise for
+    /// positive `k` and clockwise for negative `k`, like NumPy's `rot90`.
+    ///
+    /// # Notes
+    ///
+    /// Like [`Matrix::transpose`], this may change the order of the
+    /// matrix. Odd turns reverse the data along one axis and then
+    /// transpose, while half turns reverse the data as a whole, so no
+    /// element is copied more than once.
     ///
     /// # Examples
     ///
@@ -220,37 +526,34 @@ impl<T> Matrix<T> {
     /// use matreex::{matrix, Order};
     ///
     /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
-    /// assert_eq!(matrix.order(), Order::default());
     ///
-    /// matrix.switch_order();
-    /// assert_eq!(matrix.order(), Order::default().switch());
+    /// matrix.rot90(1).set_order(Order::RowMajor);
+    /// assert_eq!(matrix, matrix![[2, 5], [1, 4], [0, 3]]);
     ///
-    /// matrix.switch_order();
-    /// assert_eq!(matrix.order(), Order::default());
+    /// matrix.rot90(-3).set_order(Order::RowMajor);
+    /// assert_eq!(matrix, matrix![[5, 4, 3], [2, 1, 0]]);
     /// ```
-    pub fn switch_order(&mut self) -> &mut Self {
-        let size = self.size();
-        let mut visited = vec![false; size];
-
-        for index in 0..size {
-            if visited[index] {
-                continue;
+    pub fn rot90(&mut self, k: i32) -> &mut Self {
+        match k.rem_euclid(4) {
+            1 => {
+                self.reverse_vectors(Axis::Row);
+                self.transpose()
             }
-            let mut current = index;
-            while !visited[current] {
-                visited[current] = true;
-                let next = Self::reindex_to_different_order_unchecked(current, self.shape);
-                self.data.swap(index, next);
-                current = next;
+            2 => {
+                self.data.reverse();
+                self
+            }
+            3 => {
+                self.reverse_vectors(Axis::Col);
+                self.transpose()
             }
+            _ => self,
         }
-
-        self.order = self.order.switch();
-        self.shape.transpose();
-        self
     }
 
-    /// Sets the order of the matrix.
+    /// Rotates the matrix a quarter turn clockwise.
+    ///
+    /// This is equivalent to `rot90(-1)`.
     ///
     /// # Examples
     ///
@@ -258,29 +561,34 @@ impl<T> Matrix<T> {
     /// use matreex::{matrix, Order};
     ///
     /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
-    /// assert_eq!(matrix.order(), Order::default());
+    /// matrix.rotate_cw().set_order(Order::RowMajor);
+    /// assert_eq!(matrix, matrix![[3, 0], [4, 1], [5, 2]]);
+    /// ```
+    pub fn rotate_cw(&mut self) -> &mut Self {
+        self.rot90(-1)
+    }
+
+    /// Rotates the matrix a quarter turn counterclockwise.
     ///
-    /// matrix.set_order(Order::RowMajor);
-    /// assert_eq!(matrix.order(), Order::RowMajor);
+    /// This is equivalent to `rot90(1)`.
+    ///
+    /// # Examples
     ///
-    /// matrix.set_order(Order::ColMajor);
-    /// assert_eq!(matrix.order(), Order::ColMajor);
     /// ```
-    pub fn set_order(&mut self, order: Order) -> &mut Self {
-        if order != self.order {
-            self.switch_order();
-        }
-        self
+    /// use matreex::{matrix, Order};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.rotate_ccw().set_order(Order::RowMajor);
+    /// assert_eq!(matrix, matrix![[2, 5], [1, 4], [0, 3]]);
+    /// ```
+    pub fn rotate_ccw(&mut self) -> &mut Self {
+        self.rot90(1)
     }
 
-    /// Resizes the matrix to the specified shape.
-    ///
-    /// # Notes
+    /// Rotates the matrix a half turn.
     ///
-    /// Reducing the size does not automatically shrink the capacity.
-    /// This choice is made to avoid potential reallocation.
-    /// Consider explicitly calling [`Matrix::shrink_capacity_to_fit`]
-    /// if needed.
+    /// This is equivalent to `rot90(2)`, and never changes the order of
+    /// the matrix.
     ///
     /// # Examples
     ///
@@ -288,119 +596,1308 @@ impl<T> Matrix<T> {
     /// use matreex::matrix;
     ///
     /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.rotate_180();
+    /// assert_eq!(matrix, matrix![[5, 4, 3], [2, 1, 0]]);
+    /// ```
+    pub fn rotate_180(&mut self) -> &mut Self {
+        self.rot90(2)
+    }
+
+    /// Flips the matrix horizontally, i.e., reverses the order of the
+    /// columns, in place.
     ///
-    /// matrix.resize((2, 2)).unwrap();
-    /// assert_eq!(matrix, matrix![[0, 1], [2, 3]]);
+    /// # Examples
     ///
-    /// matrix.resize((2, 3)).unwrap();
-    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 0, 0]]);
     /// ```
-    pub fn resize<S: ShapeLike>(&mut self, shape: S) -> Result<&mut Self>
-    where
-        T: Default,
-    {
-        let shape = AxisShape::try_from_shape(shape, self.order)?;
-        let size = Self::check_size(shape.size())?;
-        self.shape = shape;
-        self.data.resize_with(size, T::default);
-        Ok(self)
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.flip_horizontal();
+    /// assert_eq!(matrix, matrix![[2, 1, 0], [5, 4, 3]]);
+    /// ```
+    pub fn flip_horizontal(&mut self) -> &mut Self {
+        self.reverse_vectors(Axis::Row);
+        self
     }
 
-    /// Reshapes the matrix to the specified shape.
+    /// Flips the matrix vertically, i.e., reverses the order of the rows,
+    /// in place.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// - [`Error::SizeMismatch`] if the size of the new shape does not
-    /// match the current size of the matrix.
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.flip_vertical();
+    /// assert_eq!(matrix, matrix![[3, 4, 5], [0, 1, 2]]);
+    /// ```
+    pub fn flip_vertical(&mut self) -> &mut Self {
+        self.reverse_vectors(Axis::Col);
+        self
+    }
+
+    /// Cyclically shifts the rows of the matrix by `n` positions, down
+    /// for positive `n` and up for negative `n`.
+    ///
+    /// This is equivalent to `numpy.roll(a, n, axis=0)`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use matreex::{matrix, Error};
+    /// use matreex::matrix;
     ///
-    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let mut matrix = matrix![[0, 1], [2, 3], [4, 5]];
     ///
-    /// matrix.reshape((3, 2)).unwrap();
-    /// assert_eq!(matrix, matrix![[0, 1], [2, 3], [4, 5]]);
+    /// matrix.roll_rows(1);
+    /// assert_eq!(matrix, matrix![[4, 5], [0, 1], [2, 3]]);
     ///
-    /// let result = matrix.reshape((2, 2));
-    /// assert_eq!(result, Err(Error::SizeMismatch));
+    /// matrix.roll_rows(-4);
+    /// assert_eq!(matrix, matrix![[0, 1], [2, 3], [4, 5]]);
     /// ```
-    pub fn reshape<S: ShapeLike>(&mut self, shape: S) -> Result<&mut Self> {
-        match shape.size() {
-            Ok(size) if (self.size() == size) => {
-                self.shape = AxisShape::from_shape_unchecked(shape, self.order);
-                Ok(self)
-            }
-            _ => Err(Error::SizeMismatch),
-        }
-    }
-
-    /// Shrinks the capacity of the matrix as much as possible.
-    pub fn shrink_capacity_to_fit(&mut self) -> &mut Self {
-        self.data.shrink_to_fit();
+    pub fn roll_rows(&mut self, n: isize) -> &mut Self {
+        self.roll_vectors(Axis::Row, n);
         self
     }
 
-    /// Shrinks the capacity of the matrix with a lower bound.
+    /// Cyclically shifts the columns of the matrix by `n` positions,
+    /// right for positive `n` and left for negative `n`.
     ///
-    /// The capacity will remain at least as large as both the size
-    /// and the supplied value.
+    /// This is equivalent to `numpy.roll(a, n, axis=1)`.
     ///
-    /// If the current capacity is less than the lower limit,
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.roll_cols(1);
+    /// assert_eq!(matrix, matrix![[2, 0, 1], [5, 3, 4]]);
+    ///
+    /// matrix.roll_cols(-2);
+    /// assert_eq!(matrix, matrix![[1, 2, 0], [4, 5, 3]]);
+    /// ```
+    pub fn roll_cols(&mut self, n: isize) -> &mut Self {
+        self.roll_vectors(Axis::Col, n);
+        self
+    }
+
+    /// Shifts the rows of the matrix by `n` positions, down for positive
+    /// `n` and up for negative `n`, filling the vacated rows with `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1], [2, 3], [4, 5]];
+    ///
+    /// matrix.shift_rows(1, -1);
+    /// assert_eq!(matrix, matrix![[-1, -1], [0, 1], [2, 3]]);
+    ///
+    /// matrix.shift_rows(-2, 9);
+    /// assert_eq!(matrix, matrix![[2, 3], [9, 9], [9, 9]]);
+    /// ```
+    pub fn shift_rows(&mut self, n: isize, value: T) -> &mut Self
+    where
+        T: Clone,
+    {
+        self.shift_vectors(Axis::Row, n, value);
+        self
+    }
+
+    /// Shifts the columns of the matrix by `n` positions, right for
+    /// positive `n` and left for negative `n`, filling the vacated
+    /// columns with `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.shift_cols(-1, 0);
+    /// assert_eq!(matrix, matrix![[1, 2, 0], [4, 5, 0]]);
+    /// ```
+    pub fn shift_cols(&mut self, n: isize, value: T) -> &mut Self
+    where
+        T: Clone,
+    {
+        self.shift_vectors(Axis::Col, n, value);
+        self
+    }
+
+    /// Cyclically shifts the vectors along `axis` by `n` positions.
+    fn roll_vectors(&mut self, axis: Axis, n: isize) {
+        let count = self.len_of(axis);
+        if count == 0 || self.is_empty() {
+            return;
+        }
+        let k = n.rem_euclid(count as isize) as usize;
+        let stride = self.major_stride();
+        if axis.is_major(self.order) {
+            self.data.rotate_right(k * stride);
+        } else {
+            self.data
+                .chunks_exact_mut(stride)
+                .for_each(|vector| vector.rotate_right(k));
+        }
+    }
+
+    /// Shifts the vectors along `axis` by `n` positions, filling the
+    /// vacated vectors with `value`.
+    fn shift_vectors(&mut self, axis: Axis, n: isize, value: T)
+    where
+        T: Clone,
+    {
+        let count = self.len_of(axis);
+        let vacated = n.unsigned_abs().min(count);
+        if vacated < count {
+            self.roll_vectors(axis, n);
+        }
+        let range = if n >= 0 {
+            0..vacated
+        } else {
+            count - vacated..count
+        };
+        for i in range {
+            let _ = self.fill_vector(axis, i, value.clone());
+        }
+    }
+
+    /// Reverses the matrix along `axis`, i.e., reverses each vector
+    /// along `axis` in place.
+    fn reverse_vectors(&mut self, axis: Axis) {
+        let stride = self.major_stride();
+        if stride == 0 {
+            return;
+        }
+        let along_major = matches!(
+            (axis, self.order),
+            (Axis::Row, Order::RowMajor) | (Axis::Col, Order::ColMajor)
+        );
+        if along_major {
+            self.data
+                .chunks_exact_mut(stride)
+                .for_each(|vector| vector.reverse());
+        } else {
+            let major = self.major();
+            for i in 0..major / 2 {
+                let (head, tail) = self.data.split_at_mut((major - 1 - i) * stride);
+                head[i * stride..(i + 1) * stride].swap_with_slice(&mut tail[..stride]);
+            }
+        }
+    }
+
+    /// Switches the order of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Order};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// assert_eq!(matrix.order(), Order::default());
+    ///
+    /// matrix.switch_order();
+    /// assert_eq!(matrix.order(), Order::default().switch());
+    ///
+    /// matrix.switch_order();
+    /// assert_eq!(matrix.order(), Order::default());
+    /// ```
+    pub fn switch_order(&mut self) -> &mut Self {
+        let size = self.size();
+        let mut visited = vec![false; size];
+
+        for index in 0..size {
+            if visited[index] {
+                continue;
+            }
+            let mut current = index;
+            while !visited[current] {
+                visited[current] = true;
+                let next = Self::reindex_to_different_order_unchecked(current, self.shape);
+                self.data.swap(index, next);
+                current = next;
+            }
+        }
+
+        self.order = self.order.switch();
+        self.shape.transpose();
+        self
+    }
+
+    /// Sets the order of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Order};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// assert_eq!(matrix.order(), Order::default());
+    ///
+    /// matrix.set_order(Order::RowMajor);
+    /// assert_eq!(matrix.order(), Order::RowMajor);
+    ///
+    /// matrix.set_order(Order::ColMajor);
+    /// assert_eq!(matrix.order(), Order::ColMajor);
+    /// ```
+    pub fn set_order(&mut self, order: Order) -> &mut Self {
+        if order != self.order {
+            self.switch_order();
+        }
+        self
+    }
+
+    /// Resizes the matrix to the specified shape.
+    ///
+    /// # Notes
+    ///
+    /// Reducing the size does not automatically shrink the capacity.
+    /// This choice is made to avoid potential reallocation.
+    /// Consider explicitly calling [`Matrix::shrink_capacity_to_fit`]
+    /// if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.resize((2, 2)).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1], [2, 3]]);
+    ///
+    /// matrix.resize((2, 3)).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 0, 0]]);
+    /// ```
+    pub fn resize<S: ShapeLike>(&mut self, shape: S) -> Result<&mut Self>
+    where
+        T: Default,
+    {
+        let shape = AxisShape::try_from_shape(shape, self.order)?;
+        let size = Self::check_size(shape.size())?;
+        self.shape = shape;
+        self.data.resize_with(size, T::default);
+        Ok(self)
+    }
+
+    /// Resizes the matrix to the specified shape, keeping each element
+    /// at the same row and column.
+    ///
+    /// Elements that fall outside the new shape are dropped, and newly
+    /// added elements are set to `fill`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// Unlike [`Matrix::resize`], this always reallocates, since
+    /// elements may need to move within the underlying storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.resize_preserving((3, 2), -1).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1], [3, 4], [-1, -1]]);
+    ///
+    /// matrix.resize_preserving((2, 3), 9).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1, 9], [3, 4, 9]]);
+    /// ```
+    pub fn resize_preserving<S: ShapeLike>(&mut self, shape: S, fill: T) -> Result<&mut Self>
+    where
+        T: Clone,
+    {
+        let shape = AxisShape::try_from_shape(shape, self.order)?;
+        let size = Self::check_size(shape.size())?;
+        let (old_major, old_minor) = (self.major(), self.minor());
+        let (new_major, new_minor) = (shape.major(), shape.minor());
+        let kept = old_minor.min(new_minor);
+
+        let mut data = Vec::with_capacity(size);
+        let mut old = core::mem::take(&mut self.data).into_iter();
+        for major in 0..new_major {
+            if major < old_major {
+                data.extend(old.by_ref().take(kept));
+                old.by_ref().take(old_minor - kept).for_each(drop);
+                data.resize(data.len() + new_minor - kept, fill.clone());
+            } else {
+                data.resize(data.len() + new_minor, fill.clone());
+            }
+        }
+
+        self.shape = shape;
+        self.data = data;
+        Ok(self)
+    }
+
+    /// Reshapes the matrix to the specified shape.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeMismatch`] if the size of the new shape does not
+    ///   match the current size of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.reshape((3, 2)).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1], [2, 3], [4, 5]]);
+    ///
+    /// let result = matrix.reshape((2, 2));
+    /// assert_eq!(result, Err(Error::SizeMismatch));
+    /// ```
+    pub fn reshape<S: ShapeLike>(&mut self, shape: S) -> Result<&mut Self> {
+        match shape.size() {
+            Ok(size) if (self.size() == size) => {
+                self.shape = AxisShape::from_shape_unchecked(shape, self.order);
+                Ok(self)
+            }
+            _ => Err(Error::SizeMismatch),
+        }
+    }
+
+    /// Shortens the matrix, keeping the first `n` rows and dropping the
+    /// rest.
+    ///
+    /// If `n` is greater than or equal to the number of rows, this is a
+    /// no-op.
+    ///
+    /// # Notes
+    ///
+    /// For [`Order::RowMajor`] no element is moved, while for
+    /// [`Order::ColMajor`] the kept elements are compacted in place.
+    /// Neither shrinks the capacity; consider explicitly calling
+    /// [`Matrix::shrink_capacity_to_fit`] if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+    ///
+    /// matrix.truncate_rows(2);
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    /// ```
+    pub fn truncate_rows(&mut self, n: usize) -> &mut Self {
+        self.truncate_axis(Axis::Row, n)
+    }
+
+    /// Shortens the matrix, keeping the first `n` columns and dropping
+    /// the rest.
+    ///
+    /// If `n` is greater than or equal to the number of columns, this is
+    /// a no-op.
+    ///
+    /// # Notes
+    ///
+    /// For [`Order::ColMajor`] no element is moved, while for
+    /// [`Order::RowMajor`] the kept elements are compacted in place.
+    /// Neither shrinks the capacity; consider explicitly calling
+    /// [`Matrix::shrink_capacity_to_fit`] if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.truncate_cols(1);
+    /// assert_eq!(matrix, matrix![[0], [3]]);
+    /// ```
+    pub fn truncate_cols(&mut self, n: usize) -> &mut Self {
+        self.truncate_axis(Axis::Col, n)
+    }
+
+    /// Shortens the matrix, keeping the first `n` vectors along the given
+    /// axis and dropping the rest.
+    ///
+    /// If `n` is greater than or equal to the length of the matrix along
+    /// `axis`, this is a no-op.
+    ///
+    /// # Notes
+    ///
+    /// If `axis` is the major axis of the matrix, no element is moved.
+    /// Otherwise the kept elements are compacted in place. Neither shrinks
+    /// the capacity; consider explicitly calling
+    /// [`Matrix::shrink_capacity_to_fit`] if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.truncate_axis(Axis::Col, 2);
+    /// assert_eq!(matrix, matrix![[0, 1], [3, 4]]);
+    ///
+    /// matrix.truncate_axis(Axis::Row, 1);
+    /// assert_eq!(matrix, matrix![[0, 1]]);
+    /// ```
+    pub fn truncate_axis(&mut self, axis: Axis, n: usize) -> &mut Self {
+        if n >= self.len_of(axis) {
+            return self;
+        }
+        let shape = match axis {
+            Axis::Row => Shape::new(n, self.ncols()),
+            Axis::Col => Shape::new(self.nrows(), n),
+        };
+        if axis.is_major(self.order) {
+            self.data.truncate(n * self.major_stride());
+        } else {
+            let minor = self.minor();
+            let mut index = 0;
+            self.data.retain(|_| {
+                let keep = index % minor < n;
+                index += 1;
+                keep
+            });
+        }
+        self.shape = AxisShape::from_shape_unchecked(shape, self.order);
+        self
+    }
+
+    /// Removes all elements, leaving an empty matrix with the same order.
+    ///
+    /// This does not shrink the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Shape};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.clear();
+    /// assert!(matrix.is_empty());
+    /// assert_eq!(matrix.shape(), Shape::new(0, 0));
+    /// assert!(matrix.capacity() >= 6);
+    /// ```
+    pub fn clear(&mut self) -> &mut Self {
+        self.data.clear();
+        self.shape = AxisShape::default();
+        self
+    }
+
+    /// Splits the matrix into two at row `n`, moving rows `0..n` into
+    /// the first matrix and rows `n..` into the second.
+    ///
+    /// Both matrices keep the order of the original.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is greater than the number of
+    ///   rows.
+    ///
+    /// # Notes
+    ///
+    /// Elements are moved, never cloned. For [`Order::RowMajor`] the
+    /// first matrix also reuses the original allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+    ///
+    /// let (top, bottom) = matrix.split_at_row(1).unwrap();
+    /// assert_eq!(top, matrix![[0, 1, 2]]);
+    /// assert_eq!(bottom, matrix![[3, 4, 5], [6, 7, 8]]);
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn split_at_row(self, n: usize) -> Result<(Self, Self)> {
+        self.split_at_axis(Axis::Row, n)
+    }
+
+    /// Splits the matrix into two at column `n`, moving columns `0..n`
+    /// into the first matrix and columns `n..` into the second.
+    ///
+    /// Both matrices keep the order of the original.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is greater than the number of
+    ///   columns.
+    ///
+    /// # Notes
+    ///
+    /// Elements are moved, never cloned. For [`Order::ColMajor`] the
+    /// first matrix also reuses the original allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let (left, right) = matrix.split_at_col(2).unwrap();
+    /// assert_eq!(left, matrix![[0, 1], [3, 4]]);
+    /// assert_eq!(right, matrix![[2], [5]]);
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn split_at_col(self, n: usize) -> Result<(Self, Self)> {
+        self.split_at_axis(Axis::Col, n)
+    }
+
+    /// Splits the matrix into two at the `n`-th vector along the given
+    /// axis, moving vectors `0..n` into the first matrix and vectors
+    /// `n..` into the second.
+    ///
+    /// Both matrices keep the order of the original.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is greater than the length of
+    ///   the matrix along `axis`.
+    ///
+    /// # Notes
+    ///
+    /// Elements are moved, never cloned. If `axis` is the major axis of
+    /// the matrix, the first matrix also reuses the original allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let (left, right) = matrix.split_at_axis(Axis::Col, 1).unwrap();
+    /// assert_eq!(left, matrix![[0], [3]]);
+    /// assert_eq!(right, matrix![[1, 2], [4, 5]]);
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn split_at_axis(mut self, axis: Axis, n: usize) -> Result<(Self, Self)> {
+        let count = self.len_of(axis);
+        if n > count {
+            return Err(Error::vector_out_of_bounds(axis, n, self.shape()));
+        }
+        let (first, second) = match axis {
+            Axis::Row => (
+                Shape::new(n, self.ncols()),
+                Shape::new(count - n, self.ncols()),
+            ),
+            Axis::Col => (
+                Shape::new(self.nrows(), n),
+                Shape::new(self.nrows(), count - n),
+            ),
+        };
+        let order = self.order;
+        let (first, second) = (
+            AxisShape::from_shape_unchecked(first, order),
+            AxisShape::from_shape_unchecked(second, order),
+        );
+
+        let (lhs, rhs) = if axis.is_major(order) {
+            let rhs = self.data.split_off(n * self.major_stride());
+            (self.data, rhs)
+        } else {
+            let minor = self.minor();
+            let mut lhs = Vec::with_capacity(first.size());
+            let mut rhs = Vec::with_capacity(second.size());
+            let mut data = self.data.into_iter();
+            for _ in 0..self.shape.major() {
+                lhs.extend(data.by_ref().take(n));
+                rhs.extend(data.by_ref().take(minor - n));
+            }
+            (lhs, rhs)
+        };
+
+        Ok((
+            Self {
+                order,
+                shape: first,
+                data: lhs,
+            },
+            Self {
+                order,
+                shape: second,
+                data: rhs,
+            },
+        ))
+    }
+
+    /// Shrinks the capacity of the matrix as much as possible.
+    pub fn shrink_capacity_to_fit(&mut self) -> &mut Self {
+        self.data.shrink_to_fit();
+        self
+    }
+
+    /// Shrinks the capacity of the matrix with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the size
+    /// and the supplied value.
+    ///
+    /// If the current capacity is less than the lower limit,
     /// this is a no-op.
     pub fn shrink_capacity_to(&mut self, min_capacity: usize) -> &mut Self {
         self.data.shrink_to(min_capacity);
         self
     }
 
-    /// Overwrites the overlapping part of this matrix with another one,
-    /// leaving the non-overlapping part unchanged.
+    /// Overwrites the overlapping part of this matrix with another one,
+    /// leaving the non-overlapping part unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Order};
+    ///
+    /// let mut matrix = matrix![[0, 0, 0], [0, 0, 0]];
+    /// let other = matrix![[1, 1], [1, 1], [1, 1]];
+    ///
+    /// matrix.overwrite_with(&other);
+    /// assert_eq!(matrix, matrix![[1, 1, 0], [1, 1, 0]]);
+    /// ```
+    pub fn overwrite_with(&mut self, other: &Self) -> &mut Self
+    where
+        T: Clone,
+    {
+        if self.order == other.order {
+            let major = core::cmp::min(self.major(), other.major());
+            let minor = core::cmp::min(self.minor(), other.minor());
+            for i in 0..major {
+                let self_lower = i * self.major_stride();
+                let self_upper = self_lower + minor;
+                let other_lower = i * other.major_stride();
+                let other_upper = other_lower + minor;
+                self.data[self_lower..self_upper]
+                    .clone_from_slice(&other.data[other_lower..other_upper]);
+            }
+        } else {
+            let major = core::cmp::min(self.major(), other.minor());
+            let minor = core::cmp::min(self.minor(), other.major());
+            for i in 0..major {
+                let self_lower = i * self.major_stride();
+                let self_upper = self_lower + minor;
+                self.data[self_lower..self_upper]
+                    .iter_mut()
+                    .zip(unsafe { other.iter_nth_minor_axis_vector_unchecked(i) })
+                    .for_each(|(x, y)| *x = y.clone());
+            }
+        }
+        self
+    }
+
+    /// Sets every element of the matrix to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.fill(7);
+    /// assert_eq!(matrix, matrix![[7, 7, 7], [7, 7, 7]]);
+    /// ```
+    pub fn fill(&mut self, value: T) -> &mut Self
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+        self
+    }
+
+    /// Sets every element of the matrix to the value returned by calling
+    /// `f` repeatedly.
+    ///
+    /// # Notes
+    ///
+    /// The order in which elements are filled is not guaranteed. In the
+    /// current implementation, elements are filled in memory order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let mut count = 0;
+    /// matrix.fill_with(|| {
+    ///     count += 1;
+    ///     count
+    /// });
+    /// assert_eq!(count, 6);
+    /// ```
+    pub fn fill_with<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnMut() -> T,
+    {
+        self.data.fill_with(f);
+        self
+    }
+
+    /// Sets every element of row `n` to `value`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Index, Shape};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.fill_row(1, 0).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [0, 0, 0]]);
+    ///
+    /// let result = matrix.fill_row(2, 0);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(2, 0),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn fill_row(&mut self, n: usize, value: T) -> Result<&mut Self>
+    where
+        T: Clone,
+    {
+        self.fill_vector(Axis::Row, n, value)
+    }
+
+    /// Sets every element of column `n` to `value`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Index, Shape};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.fill_col(1, 0).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 0, 2], [3, 0, 5]]);
+    ///
+    /// let result = matrix.fill_col(3, 0);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(0, 3),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn fill_col(&mut self, n: usize, value: T) -> Result<&mut Self>
+    where
+        T: Clone,
+    {
+        self.fill_vector(Axis::Col, n, value)
+    }
+
+    fn fill_vector(&mut self, axis: Axis, n: usize, value: T) -> Result<&mut Self>
+    where
+        T: Clone,
+    {
+        let layout = self.vector_layout(axis);
+        if n >= layout.count {
+            return Err(Error::vector_out_of_bounds(axis, n, self.shape()));
+        }
+        let start = n * layout.offset;
+        for k in 0..layout.len {
+            self.data[start + k * layout.step] = value.clone();
+        }
+        Ok(self)
+    }
+
+    /// Appends a row to the bottom of the matrix.
+    ///
+    /// If the matrix has neither rows nor columns, the row also sets the
+    /// number of columns.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::LengthInconsistent`] if the length of `row` does not
+    ///   match the number of columns.
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// On error, the matrix is left unchanged.
+    ///
+    /// For [`Order::RowMajor`] this appends in place, while for
+    /// [`Order::ColMajor`] the elements have to be interleaved, which
+    /// reallocates. Grow along the major axis when streaming.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Matrix};
+    ///
+    /// let mut matrix = Matrix::empty();
+    /// matrix.push_row([0, 1, 2]).unwrap();
+    /// matrix.push_row(3..6).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    ///
+    /// let result = matrix.push_row([6, 7]);
+    /// assert_eq!(result, Err(Error::LengthInconsistent));
+    /// ```
+    ///
+    /// [`Error::LengthInconsistent`]: crate::error::Error::LengthInconsistent
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn push_row<I>(&mut self, row: I) -> Result<&mut Self>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.push_vector(Axis::Row, row)
+    }
+
+    /// Appends a column to the right of the matrix.
+    ///
+    /// If the matrix has neither rows nor columns, the column also sets
+    /// the number of rows.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::LengthInconsistent`] if the length of `col` does not
+    ///   match the number of rows.
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// On error, the matrix is left unchanged.
+    ///
+    /// For [`Order::ColMajor`] this appends in place, while for
+    /// [`Order::RowMajor`] the elements have to be interleaved, which
+    /// reallocates. Grow along the major axis when streaming.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Matrix};
+    ///
+    /// let mut matrix = Matrix::empty();
+    /// matrix.push_col([0, 3]).unwrap();
+    /// matrix.push_col([1, 4]).unwrap();
+    /// matrix.push_col([2, 5]).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    ///
+    /// let result = matrix.push_col([6, 7, 8]);
+    /// assert_eq!(result, Err(Error::LengthInconsistent));
+    /// ```
+    ///
+    /// [`Error::LengthInconsistent`]: crate::error::Error::LengthInconsistent
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn push_col<I>(&mut self, col: I) -> Result<&mut Self>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.push_vector(Axis::Col, col)
+    }
+
+    /// Appends rows from an iterator to the bottom of the matrix.
+    ///
+    /// If the matrix has neither rows nor columns, the first row also
+    /// sets the number of columns.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::LengthInconsistent`] if any row differs in length from
+    ///   the others or from the existing rows.
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// On error, the matrix is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2]];
+    ///
+    /// matrix.extend_rows([[3, 4, 5], [6, 7, 8]]).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]]);
+    ///
+    /// let result = matrix.extend_rows([vec![9, 10, 11], vec![12]]);
+    /// assert_eq!(result, Err(Error::LengthInconsistent));
+    /// ```
+    ///
+    /// [`Error::LengthInconsistent`]: crate::error::Error::LengthInconsistent
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn extend_rows<I, V>(&mut self, rows: I) -> Result<&mut Self>
+    where
+        I: IntoIterator<Item = V>,
+        V: IntoIterator<Item = T>,
+    {
+        let mut other = Self::from_rows(rows)?;
+        if other.nrows() == 0 {
+            return Ok(self);
+        }
+        if self.nrows() == 0 && self.ncols() == 0 {
+            other.set_order(self.order);
+            *self = other;
+            return Ok(self);
+        }
+        if other.ncols() != self.ncols() {
+            return Err(Error::LengthInconsistent);
+        }
+
+        let (old_nrows, new_nrows) = (self.nrows(), other.nrows());
+        let nrows = old_nrows
+            .checked_add(new_nrows)
+            .ok_or(Error::SizeOverflow)?;
+        let shape = AxisShape::try_from_shape(Shape::new(nrows, self.ncols()), self.order)?;
+        let size = Self::check_size(shape.size())?;
+
+        match self.order {
+            Order::RowMajor => self.data.append(&mut other.data),
+            Order::ColMajor => {
+                other.set_order(Order::ColMajor);
+                let mut data = Vec::with_capacity(size);
+                let mut old = core::mem::take(&mut self.data).into_iter();
+                let mut new = other.data.into_iter();
+                for _ in 0..shape.major() {
+                    data.extend(old.by_ref().take(old_nrows));
+                    data.extend(new.by_ref().take(new_nrows));
+                }
+                self.data = data;
+            }
+        }
+
+        self.shape = shape;
+        Ok(self)
+    }
+
+    /// Appends rows from an iterator to the bottom of the matrix,
+    /// truncating longer rows and padding shorter ones with `fill` to
+    /// the number of columns.
+    ///
+    /// If the matrix has neither rows nor columns, the first row sets the
+    /// number of columns.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// On error, the matrix is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2]];
+    ///
+    /// matrix.extend_rows_saturating([vec![3, 4], vec![5, 6, 7, 8]], -1).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, -1], [5, 6, 7]]);
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn extend_rows_saturating<I, V>(&mut self, rows: I, fill: T) -> Result<&mut Self>
+    where
+        I: IntoIterator<Item = V>,
+        V: IntoIterator<Item = T>,
+        T: Clone,
+    {
+        let mut rows = rows.into_iter();
+        if self.nrows() == 0 && self.ncols() == 0 {
+            let Some(row) = rows.next() else {
+                return Ok(self);
+            };
+            // build the result aside, so that an error leaves `self` as is
+            let mut other = Self::from_rows([row])?;
+            other.extend_rows_saturating(rows, fill)?;
+            other.set_order(self.order);
+            *self = other;
+            return Ok(self);
+        }
+        let ncols = self.ncols();
+        self.extend_rows(rows.map(|row| {
+            row.into_iter()
+                .chain(core::iter::repeat(fill.clone()))
+                .take(ncols)
+        }))
+    }
+
+    fn push_vector<I>(&mut self, axis: Axis, vector: I) -> Result<&mut Self>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let layout = self.vector_layout(axis);
+        let mut vector = vector.into_iter();
+
+        if layout.count == 0 && layout.len == 0 {
+            let data: Vec<T> = vector.collect();
+            let shape = match axis {
+                Axis::Row => Shape::new(1, data.len()),
+                Axis::Col => Shape::new(data.len(), 1),
+            };
+            self.shape = AxisShape::from_shape_unchecked(shape, self.order);
+            self.data = data;
+            return Ok(self);
+        }
+
+        let count = layout.count.checked_add(1).ok_or(Error::SizeOverflow)?;
+        let shape = match axis {
+            Axis::Row => Shape::new(count, layout.len),
+            Axis::Col => Shape::new(layout.len, count),
+        };
+        let shape = AxisShape::try_from_shape(shape, self.order)?;
+        let size = Self::check_size(shape.size())?;
+
+        if axis.is_major(self.order) {
+            let len = self.data.len();
+            self.data.extend(vector.by_ref().take(layout.len));
+            if self.data.len() != size || vector.next().is_some() {
+                self.data.truncate(len);
+                return Err(Error::LengthInconsistent);
+            }
+        } else {
+            let vector: Vec<T> = vector.take(layout.len.saturating_add(1)).collect();
+            if vector.len() != layout.len {
+                return Err(Error::LengthInconsistent);
+            }
+            let minor = self.minor();
+            let mut data = Vec::with_capacity(size);
+            let mut old = core::mem::take(&mut self.data).into_iter();
+            for element in vector {
+                data.extend(old.by_ref().take(minor));
+                data.push(element);
+            }
+            self.data = data;
+        }
+
+        self.shape = shape;
+        Ok(self)
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Applies a closure to each element of the matrix,
+    /// modifying the matrix in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.apply(|x| *x += 1);
+    /// assert_eq!(matrix, matrix![[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    pub fn apply<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnMut(&mut T),
+    {
+        self.data.iter_mut().for_each(f);
+        self
+    }
+
+    /// Applies a closure to each element of the matrix,
+    /// returning a new matrix with the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix_i32 = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let matrix_f64 = matrix_i32.map(|x| x as f64);
+    /// assert_eq!(matrix_f64, matrix![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Matrix<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        let order = self.order;
+        let shape = self.shape;
+        let data = self.data.into_iter().map(f).collect();
+        Matrix { order, shape, data }
+    }
+
+    /// Applies a closure to each element of the matrix along with its
+    /// index, modifying the matrix in place.
+    ///
+    /// # Notes
+    ///
+    /// The order in which elements are visited is not guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.apply_with_index(|index, x| *x *= index.col as i32);
+    /// assert_eq!(matrix, matrix![[0, 1, 4], [0, 4, 10]]);
+    /// ```
+    pub fn apply_with_index<F>(&mut self, mut f: F) -> &mut Self
+    where
+        F: FnMut(Index, &mut T),
+    {
+        let (order, shape) = (self.order, self.shape);
+        self.data
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, element)| f(Self::unflatten_index(index, order, shape), element));
+        self
+    }
+
+    /// Applies a closure to each element of the matrix along with its
+    /// index, returning a new matrix with the results.
+    ///
+    /// # Notes
+    ///
+    /// The order in which elements are visited is not guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let output = matrix.map_with_index(|index, x| (index.row, x));
+    /// assert_eq!(output, matrix![[(0, 0), (0, 1), (0, 2)], [(1, 3), (1, 4), (1, 5)]]);
+    /// ```
+    pub fn map_with_index<U, F>(self, mut f: F) -> Matrix<U>
+    where
+        F: FnMut(Index, T) -> U,
+    {
+        let order = self.order;
+        let shape = self.shape;
+        let data = self
+            .data
+            .into_iter()
+            .enumerate()
+            .map(|(index, element)| f(Self::unflatten_index(index, order, shape), element))
+            .collect();
+        Matrix { order, shape, data }
+    }
+
+    /// Applies a closure to each row of the matrix, returning a new matrix
+    /// whose rows are the outputs of the closure.
+    ///
+    /// The closure may change the number of columns, but it must yield
+    /// the same number of elements for every row.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::LengthInconsistent`] if the closure yields rows of
+    ///   different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error};
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let result = matrix.map_rows(|row| {
+    ///     let row: Vec<i32> = row.copied().collect();
+    ///     [row.iter().sum::<i32>(), *row.iter().max().unwrap()]
+    /// });
+    /// assert_eq!(result, Ok(matrix![[3, 2], [12, 5]]));
+    ///
+    /// let result = matrix.map_rows(|row| row.filter(|x| **x % 2 == 0).copied());
+    /// assert_eq!(result, Err(Error::LengthInconsistent));
+    /// ```
+    pub fn map_rows<'a, U, V, F>(&'a self, mut f: F) -> Result<Matrix<U>>
+    where
+        V: IntoIterator<Item = U>,
+        F: FnMut(StrideIter<'a, T>) -> V,
+    {
+        let nrows = self.nrows();
+        let mut ncols = None;
+        let mut data = Vec::new();
+        for row in self.iter_rows() {
+            let data_len = data.len();
+            data.extend(f(row));
+            let row_len = data.len() - data_len;
+            match ncols {
+                None => ncols = Some(row_len),
+                Some(ncols) if ncols != row_len => return Err(Error::LengthInconsistent),
+                Some(_) => (),
+            }
+        }
+        let order = Order::default();
+        let shape = AxisShape::from_shape_unchecked(Shape::new(nrows, ncols.unwrap_or(0)), order);
+        Ok(Matrix { order, shape, data })
+    }
+
+    /// Reduces each vector along the given axis to a single value with a
+    /// closure.
+    ///
+    /// The result is an `m x 1` matrix for [`Axis::Row`] and a `1 x n`
+    /// matrix for [`Axis::Col`], with the same order as `self`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use matreex::{matrix, Order};
+    /// use matreex::{matrix, Axis};
     ///
-    /// let mut matrix = matrix![[0, 0, 0], [0, 0, 0]];
-    /// let other = matrix![[1, 1], [1, 1], [1, 1]];
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
     ///
-    /// matrix.overwrite_with(&other);
-    /// assert_eq!(matrix, matrix![[1, 1, 0], [1, 1, 0]]);
+    /// let sums = matrix.map_axis(Axis::Row, |row| row.sum::<i32>());
+    /// assert_eq!(sums, matrix![[3], [12]]);
+    ///
+    /// let maxima = matrix.map_axis(Axis::Col, |col| *col.max().unwrap());
+    /// assert_eq!(maxima, matrix![[3, 4, 5]]);
     /// ```
-    pub fn overwrite_with(&mut self, other: &Self) -> &mut Self
+    pub fn map_axis<'a, U, F>(&'a self, axis: Axis, f: F) -> Matrix<U>
     where
-        T: Clone,
+        F: FnMut(StrideIter<'a, T>) -> U,
     {
-        if self.order == other.order {
-            let major = std::cmp::min(self.major(), other.major());
-            let minor = std::cmp::min(self.minor(), other.minor());
-            for i in 0..major {
-                let self_lower = i * self.major_stride();
-                let self_upper = self_lower + minor;
-                let other_lower = i * other.major_stride();
-                let other_upper = other_lower + minor;
-                self.data[self_lower..self_upper]
-                    .clone_from_slice(&other.data[other_lower..other_upper]);
-            }
-        } else {
-            let major = std::cmp::min(self.major(), other.minor());
-            let minor = std::cmp::min(self.minor(), other.major());
-            for i in 0..major {
-                let self_lower = i * self.major_stride();
-                let self_upper = self_lower + minor;
-                self.data[self_lower..self_upper]
-                    .iter_mut()
-                    .zip(unsafe { other.iter_nth_minor_axis_vector_unchecked(i) })
-                    .for_each(|(x, y)| *x = y.clone());
-            }
-        }
-        self
+        let order = self.order;
+        let shape = match axis {
+            Axis::Row => Shape::new(self.nrows(), 1),
+            Axis::Col => Shape::new(1, self.ncols()),
+        };
+        let shape = AxisShape::from_shape_unchecked(shape, order);
+        let data = self.iter_axis(axis).map(f).collect();
+        Matrix { order, shape, data }
     }
 }
 
-impl<T> Matrix<T> {
-    /// Applies a closure to each element of the matrix,
+#[cfg(feature = "rayon")]
+impl<T> Matrix<T>
+where
+    T: Sync + Send,
+{
+    /// Applies a closure to each element of the matrix in parallel,
     /// modifying the matrix in place.
     ///
     /// # Examples
@@ -410,18 +1907,18 @@ impl<T> Matrix<T> {
     ///
     /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
     ///
-    /// matrix.apply(|x| *x += 1);
+    /// matrix.par_apply(|x| *x += 1);
     /// assert_eq!(matrix, matrix![[1, 2, 3], [4, 5, 6]]);
     /// ```
-    pub fn apply<F>(&mut self, f: F) -> &mut Self
+    pub fn par_apply<F>(&mut self, f: F) -> &mut Self
     where
-        F: FnMut(&mut T),
+        F: Fn(&mut T) + Sync + Send,
     {
-        self.data.iter_mut().for_each(f);
+        self.data.par_iter_mut().for_each(f);
         self
     }
 
-    /// Applies a closure to each element of the matrix,
+    /// Applies a closure to each element of the matrix in parallel,
     /// returning a new matrix with the results.
     ///
     /// # Examples
@@ -431,27 +1928,22 @@ impl<T> Matrix<T> {
     ///
     /// let matrix_i32 = matrix![[0, 1, 2], [3, 4, 5]];
     ///
-    /// let matrix_f64 = matrix_i32.map(|x| x as f64);
+    /// let matrix_f64 = matrix_i32.par_map(|x| x as f64);
     /// assert_eq!(matrix_f64, matrix![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
     /// ```
-    pub fn map<U, F>(self, f: F) -> Matrix<U>
+    pub fn par_map<U, F>(self, f: F) -> Matrix<U>
     where
-        F: FnMut(T) -> U,
+        U: Send,
+        F: Fn(T) -> U + Sync + Send,
     {
         let order = self.order;
         let shape = self.shape;
-        let data = self.data.into_iter().map(f).collect();
+        let data = self.data.into_par_iter().map(f).collect();
         Matrix { order, shape, data }
     }
-}
 
-#[cfg(feature = "rayon")]
-impl<T> Matrix<T>
-where
-    T: Sync + Send,
-{
-    /// Applies a closure to each element of the matrix in parallel,
-    /// modifying the matrix in place.
+    /// Applies a closure to each element of the matrix along with its
+    /// index in parallel, modifying the matrix in place.
     ///
     /// # Examples
     ///
@@ -460,40 +1952,67 @@ where
     ///
     /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
     ///
-    /// matrix.par_apply(|x| *x += 1);
-    /// assert_eq!(matrix, matrix![[1, 2, 3], [4, 5, 6]]);
+    /// matrix.par_apply_with_index(|index, x| *x *= index.col as i32);
+    /// assert_eq!(matrix, matrix![[0, 1, 4], [0, 4, 10]]);
     /// ```
-    pub fn par_apply<F>(&mut self, f: F) -> &mut Self
+    pub fn par_apply_with_index<F>(&mut self, f: F) -> &mut Self
     where
-        F: Fn(&mut T) + Sync + Send,
+        F: Fn(Index, &mut T) + Sync + Send,
     {
-        self.data.par_iter_mut().for_each(f);
+        let (order, shape) = (self.order, self.shape);
+        self.data
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(index, element)| f(Self::unflatten_index(index, order, shape), element));
         self
     }
 
-    /// Applies a closure to each element of the matrix in parallel,
-    /// returning a new matrix with the results.
+    /// Applies a closure to each element of the matrix along with its
+    /// index in parallel, returning a new matrix with the results.
     ///
     /// # Examples
     ///
     /// ```
     /// use matreex::matrix;
     ///
-    /// let matrix_i32 = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
     ///
-    /// let matrix_f64 = matrix_i32.par_map(|x| x as f64);
-    /// assert_eq!(matrix_f64, matrix![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    /// let output = matrix.par_map_with_index(|index, x| (index.row, x));
+    /// assert_eq!(output, matrix![[(0, 0), (0, 1), (0, 2)], [(1, 3), (1, 4), (1, 5)]]);
     /// ```
-    pub fn par_map<U, F>(self, f: F) -> Matrix<U>
+    pub fn par_map_with_index<U, F>(self, f: F) -> Matrix<U>
     where
         U: Send,
-        F: Fn(T) -> U + Sync + Send,
+        F: Fn(Index, T) -> U + Sync + Send,
     {
         let order = self.order;
         let shape = self.shape;
-        let data = self.data.into_par_iter().map(f).collect();
+        let data = self
+            .data
+            .into_par_iter()
+            .enumerate()
+            .map(|(index, element)| f(Self::unflatten_index(index, order, shape), element))
+            .collect();
         Matrix { order, shape, data }
     }
+
+    /// Drops the elements of the matrix in parallel.
+    ///
+    /// This is useful for huge matrices whose elements have non-trivial
+    /// drop (e.g., [`String`]), where dropping sequentially would block
+    /// the current thread noticeably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]].map(|x| x.to_string());
+    /// matrix.par_drop();
+    /// ```
+    pub fn par_drop(self) {
+        self.data.into_par_iter().for_each(drop);
+    }
 }
 
 impl<L> Matrix<L> {
@@ -506,7 +2025,7 @@ impl<L> Matrix<L> {
     /// # Examples
     ///
     /// ```
-    /// use matreex::{Error, Matrix};
+    /// use matreex::{Error, Matrix, Shape};
     ///
     /// let lhs = Matrix::<i32>::new((2, 3));
     ///
@@ -516,11 +2035,15 @@ impl<L> Matrix<L> {
     ///
     /// let rhs = Matrix::<i32>::new((3, 2));
     /// let result = lhs.ensure_elementwise_operation_conformable(&rhs);
-    /// assert_eq!(result, Err(Error::NotConformable));
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(2, 3),
+    ///     rhs: Shape::new(3, 2),
+    /// };
+    /// assert_eq!(result, Err(error));
     /// ```
     pub fn ensure_elementwise_operation_conformable<R>(&self, rhs: &Matrix<R>) -> Result<&Self> {
         if self.shape() != rhs.shape() {
-            Err(Error::NotConformable)
+            Err(Error::not_conformable(self.shape(), rhs.shape()))
         } else {
             Ok(self)
         }
@@ -553,6 +2076,10 @@ impl<L> Matrix<L> {
     {
         self.ensure_elementwise_operation_conformable(rhs)?;
 
+        if let Some(output) = arithmetic::copy::elementwise(self, rhs, &mut op) {
+            return Ok(output);
+        }
+
         let order = self.order;
         let shape = self.shape;
         let data = if self.order == rhs.order {
@@ -560,9 +2087,11 @@ impl<L> Matrix<L> {
         } else {
             self.data
                 .iter()
-                .enumerate()
-                .map(|(index, left)| {
-                    let index = Self::reindex_to_different_order_unchecked(index, self.shape);
+                .zip(Self::reindices_to_different_order(self.shape))
+                .map(|(left, index)| {
+                    debug_assert!(index < rhs.data.len());
+                    // SAFETY: `index` is a position in a matrix of the same
+                    // shape as `rhs`, so it is within `rhs.data`.
                     let right = unsafe { rhs.data.get_unchecked(index) };
                     op((left, right))
                 })
@@ -603,16 +2132,23 @@ impl<L> Matrix<L> {
     {
         self.ensure_elementwise_operation_conformable(rhs)?;
 
-        let order = self.order;
-        let shape = self.shape;
-        let data = if self.order == rhs.order {
-            self.data.into_iter().zip(rhs.data.iter()).map(op).collect()
+        let lhs = match arithmetic::copy::elementwise_consume_self(self, rhs, &mut op) {
+            Ok(output) => return Ok(output),
+            Err(lhs) => lhs,
+        };
+
+        let order = lhs.order;
+        let shape = lhs.shape;
+        let data = if lhs.order == rhs.order {
+            lhs.data.into_iter().zip(rhs.data.iter()).map(op).collect()
         } else {
-            self.data
+            lhs.data
                 .into_iter()
-                .enumerate()
-                .map(|(index, left)| {
-                    let index = Self::reindex_to_different_order_unchecked(index, self.shape);
+                .zip(Self::reindices_to_different_order(lhs.shape))
+                .map(|(left, index)| {
+                    debug_assert!(index < rhs.data.len());
+                    // SAFETY: `index` is a position in a matrix of the same
+                    // shape as `rhs`, so it is within `rhs.data`.
                     let right = unsafe { rhs.data.get_unchecked(index) };
                     op((left, right))
                 })
@@ -650,11 +2186,18 @@ impl<L> Matrix<L> {
     {
         self.ensure_elementwise_operation_conformable(rhs)?;
 
+        if arithmetic::copy::elementwise_assign(self, rhs, &mut op) {
+            return Ok(self);
+        }
+
         if self.order == rhs.order {
             self.data.iter_mut().zip(rhs.data.iter()).for_each(op);
         } else {
-            self.data.iter_mut().enumerate().for_each(|(index, left)| {
-                let index = Self::reindex_to_different_order_unchecked(index, self.shape);
+            let indices = Self::reindices_to_different_order(self.shape);
+            self.data.iter_mut().zip(indices).for_each(|(left, index)| {
+                debug_assert!(index < rhs.data.len());
+                // SAFETY: `index` is a position in a matrix of the same
+                // shape as `rhs`, so it is within `rhs.data`.
                 let right = unsafe { rhs.data.get_unchecked(index) };
                 op((left, right))
             });
@@ -662,6 +2205,133 @@ impl<L> Matrix<L> {
 
         Ok(self)
     }
+
+    /// Combines two matrices elementwise with a closure taking both
+    /// elements as separate arguments.
+    ///
+    /// This is equivalent to [`Matrix::elementwise_operation`] with the
+    /// closure arguments untupled.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[2, 2, 2], [2, 2, 2]];
+    ///
+    /// let result = lhs.zip_map(&rhs, |x, y| x * 10 + y);
+    /// assert_eq!(result, Ok(matrix![[2, 12, 22], [32, 42, 52]]));
+    /// ```
+    pub fn zip_map<R, F, U>(&self, rhs: &Matrix<R>, mut f: F) -> Result<Matrix<U>>
+    where
+        F: FnMut(&L, &R) -> U,
+    {
+        self.elementwise_operation(rhs, |(left, right)| f(left, right))
+    }
+
+    /// Combines two matrices elementwise with a closure taking both
+    /// elements as separate arguments, consuming `self`.
+    ///
+    /// This is equivalent to [`Matrix::elementwise_operation_consume_self`]
+    /// with the closure arguments untupled.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![["a", "b"], ["c", "d"]].map(String::from);
+    /// let rhs = matrix![[1, 2], [3, 4]];
+    ///
+    /// let result = lhs.zip_map_consume(&rhs, |x, y| x.repeat(*y));
+    /// let expected = matrix![["a", "bb"], ["ccc", "dddd"]].map(String::from);
+    /// assert_eq!(result, Ok(expected));
+    /// ```
+    pub fn zip_map_consume<R, F, U>(self, rhs: &Matrix<R>, mut f: F) -> Result<Matrix<U>>
+    where
+        F: FnMut(L, &R) -> U,
+    {
+        self.elementwise_operation_consume_self(rhs, |(left, right)| f(left, right))
+    }
+
+    /// Combines three matrices elementwise with a closure taking all
+    /// three elements as separate arguments, e.g., for fused
+    /// multiply-add style updates.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let a = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let b = matrix![[2, 2, 2], [2, 2, 2]];
+    /// let c = matrix![[1, 1, 1], [1, 1, 1]];
+    ///
+    /// let result = a.zip_map3(&b, &c, |x, y, z| x * y + z);
+    /// assert_eq!(result, Ok(matrix![[1, 3, 5], [7, 9, 11]]));
+    /// ```
+    pub fn zip_map3<R, S, F, U>(
+        &self,
+        rhs: &Matrix<R>,
+        third: &Matrix<S>,
+        mut f: F,
+    ) -> Result<Matrix<U>>
+    where
+        F: FnMut(&L, &R, &S) -> U,
+    {
+        self.ensure_elementwise_operation_conformable(rhs)?;
+        self.ensure_elementwise_operation_conformable(third)?;
+
+        let order = self.order;
+        let shape = self.shape;
+        let locate = |other: Order, index: usize| {
+            if other == order {
+                index
+            } else {
+                Self::reindex_to_different_order_unchecked(index, shape)
+            }
+        };
+        let data = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(index, left)| {
+                let right = locate(rhs.order, index);
+                let last = locate(third.order, index);
+                debug_assert!(right < rhs.data.len() && last < third.data.len());
+                let right = unsafe { rhs.data.get_unchecked(right) };
+                let last = unsafe { third.data.get_unchecked(last) };
+                f(left, right, last)
+            })
+            .collect();
+
+        Ok(Matrix { order, shape, data })
+    }
 }
 
 impl<L> Matrix<L> {
@@ -675,7 +2345,7 @@ impl<L> Matrix<L> {
     /// # Examples
     ///
     /// ```
-    /// use matreex::{Error, Matrix};
+    /// use matreex::{Error, Matrix, Shape};
     ///
     /// let lhs = Matrix::<i32>::new((2, 3));
     ///
@@ -685,14 +2355,18 @@ impl<L> Matrix<L> {
     ///
     /// let rhs = Matrix::<i32>::new((2, 3));
     /// let result = lhs.ensure_multiplication_like_operation_conformable(&rhs);
-    /// assert_eq!(result, Err(Error::NotConformable));
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(2, 3),
+    ///     rhs: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(result, Err(error));
     /// ```
     pub fn ensure_multiplication_like_operation_conformable<R>(
         &self,
         rhs: &Matrix<R>,
     ) -> Result<&Self> {
         if self.ncols() != rhs.nrows() {
-            Err(Error::NotConformable)
+            Err(Error::not_conformable(self.shape(), rhs.shape()))
         } else {
             Ok(self)
         }
@@ -716,11 +2390,11 @@ impl<L> Matrix<L> {
     /// # Examples
     ///
     /// ```
-    /// use matreex::{matrix, VectorIter};
+    /// use matreex::{matrix, StrideIter};
     ///
     /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
     /// let rhs = matrix![[0, 1], [2, 3], [4, 5]];
-    /// let op = |vl: VectorIter<&i32>, vr: VectorIter<&i32>| {
+    /// let op = |vl: StrideIter<i32>, vr: StrideIter<i32>| {
     ///     vl.zip(vr).map(|(x, y)| x * y).reduce(|acc, p| acc + p).unwrap()
     /// };
     ///
@@ -733,7 +2407,7 @@ impl<L> Matrix<L> {
         mut op: F,
     ) -> Result<Matrix<U>>
     where
-        F: FnMut(VectorIter<&L>, VectorIter<&R>) -> U,
+        F: FnMut(StrideIter<L>, StrideIter<R>) -> U,
         U: Default,
     {
         self.ensure_multiplication_like_operation_conformable(&rhs)?;
@@ -758,8 +2432,8 @@ impl<L> Matrix<L> {
                 for row in 0..nrows {
                     for col in 0..ncols {
                         let element = op(
-                            unsafe { Box::new(self.iter_nth_major_axis_vector_unchecked(row)) },
-                            unsafe { Box::new(rhs.iter_nth_major_axis_vector_unchecked(col)) },
+                            unsafe { self.iter_nth_major_axis_vector_unchecked(row) },
+                            unsafe { rhs.iter_nth_major_axis_vector_unchecked(col) },
                         );
                         data.push(element);
                     }
@@ -770,8 +2444,8 @@ impl<L> Matrix<L> {
                 for col in 0..ncols {
                     for row in 0..nrows {
                         let element = op(
-                            unsafe { Box::new(self.iter_nth_major_axis_vector_unchecked(row)) },
-                            unsafe { Box::new(rhs.iter_nth_major_axis_vector_unchecked(col)) },
+                            unsafe { self.iter_nth_major_axis_vector_unchecked(row) },
+                            unsafe { rhs.iter_nth_major_axis_vector_unchecked(col) },
                         );
                         data.push(element);
                     }
@@ -786,6 +2460,9 @@ impl<L> Matrix<L> {
 impl<T> Matrix<T> {
     /// Performs scalar operation on the matrix.
     ///
+    /// `scalar` may be passed by reference, or by value for primitive
+    /// types. Refer to [`ScalarLike`] for more information.
+    ///
     /// # Examples
     ///
     /// ```
@@ -796,11 +2473,21 @@ impl<T> Matrix<T> {
     ///
     /// let result = matrix.scalar_operation(&scalar, |x, y| x + y);
     /// assert_eq!(result, matrix![[2, 3, 4], [5, 6, 7]]);
+    ///
+    /// let result = matrix.scalar_operation(2, |x, y| x + y);
+    /// assert_eq!(result, matrix![[2, 3, 4], [5, 6, 7]]);
     /// ```
-    pub fn scalar_operation<S, F, U>(&self, scalar: &S, mut op: F) -> Matrix<U>
+    pub fn scalar_operation<S, F, U>(&self, scalar: S, mut op: F) -> Matrix<U>
     where
-        F: FnMut(&T, &S) -> U,
+        S: ScalarLike,
+        F: FnMut(&T, &S::Scalar) -> U,
     {
+        let scalar = scalar.as_scalar();
+
+        if let Some(output) = arithmetic::copy::scalar(self, scalar, &mut op) {
+            return output;
+        }
+
         let order = self.order;
         let shape = self.shape;
         let data = self
@@ -813,6 +2500,9 @@ impl<T> Matrix<T> {
 
     /// Performs scalar operation on the matrix, consuming `self`.
     ///
+    /// `scalar` may be passed by reference, or by value for primitive
+    /// types. Refer to [`ScalarLike`] for more information.
+    ///
     /// # Examples
     ///
     /// ```
@@ -821,16 +2511,27 @@ impl<T> Matrix<T> {
     /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
     /// let scalar = 2;
     ///
-    /// let result = matrix.scalar_operation_consume_self(&scalar, |x, y| x + y);
+    /// let result = matrix.clone().scalar_operation_consume_self(&scalar, |x, y| x + y);
+    /// assert_eq!(result, matrix![[2, 3, 4], [5, 6, 7]]);
+    ///
+    /// let result = matrix.scalar_operation_consume_self(2, |x, y| x + y);
     /// assert_eq!(result, matrix![[2, 3, 4], [5, 6, 7]]);
     /// ```
-    pub fn scalar_operation_consume_self<S, F, U>(self, scalar: &S, mut op: F) -> Matrix<U>
+    pub fn scalar_operation_consume_self<S, F, U>(self, scalar: S, mut op: F) -> Matrix<U>
     where
-        F: FnMut(T, &S) -> U,
+        S: ScalarLike,
+        F: FnMut(T, &S::Scalar) -> U,
     {
-        let order = self.order;
-        let shape = self.shape;
-        let data = self
+        let scalar = scalar.as_scalar();
+
+        let matrix = match arithmetic::copy::scalar_consume_self(self, scalar, &mut op) {
+            Ok(output) => return output,
+            Err(matrix) => matrix,
+        };
+
+        let order = matrix.order;
+        let shape = matrix.shape;
+        let data = matrix
             .data
             .into_iter()
             .map(|element| op(element, scalar))
@@ -841,6 +2542,9 @@ impl<T> Matrix<T> {
     /// Performs scalar operation on the matrix, assigning the result
     /// to `self`.
     ///
+    /// `scalar` may be passed by reference, or by value for primitive
+    /// types. Refer to [`ScalarLike`] for more information.
+    ///
     /// # Examples
     ///
     /// ```
@@ -851,11 +2555,21 @@ impl<T> Matrix<T> {
     ///
     /// matrix.scalar_operation_assign(&scalar, |x, y| *x += y);
     /// assert_eq!(matrix, matrix![[2, 3, 4], [5, 6, 7]]);
+    ///
+    /// matrix.scalar_operation_assign(2, |x, y| *x -= y);
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
     /// ```
-    pub fn scalar_operation_assign<S, F>(&mut self, scalar: &S, mut op: F) -> &mut Self
+    pub fn scalar_operation_assign<S, F>(&mut self, scalar: S, mut op: F) -> &mut Self
     where
-        F: FnMut(&mut T, &S),
+        S: ScalarLike,
+        F: FnMut(&mut T, &S::Scalar),
     {
+        let scalar = scalar.as_scalar();
+
+        if arithmetic::copy::scalar_assign(self, scalar, &mut op) {
+            return self;
+        }
+
         self.data.iter_mut().for_each(|element| op(element, scalar));
         self
     }
@@ -865,17 +2579,55 @@ impl<T> Matrix<T> {
     fn check_size(size: usize) -> Result<usize> {
         // see more info at https://doc.rust-lang.org/stable/std/vec/struct.Vec.html#method.with_capacity
         const MAX: usize = isize::MAX as usize;
-        match std::mem::size_of::<T>().checked_mul(size) {
+        match core::mem::size_of::<T>().checked_mul(size) {
             Some(0..=MAX) => Ok(size),
             _ => Err(Error::CapacityExceeded),
         }
     }
 }
 
+impl<T> Matrix<MaybeUninit<T>> {
+    /// Converts to [`Matrix<T>`], without copying.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method when any element is not yet initialized is
+    /// *[undefined behavior]*.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let mut matrix = Matrix::<i32>::new_uninit((2, 2));
+    /// matrix.apply(|element| {
+    ///     element.write(1);
+    /// });
+    ///
+    /// let matrix = unsafe { matrix.assume_init() };
+    /// assert_eq!(matrix, matrix![[1, 1], [1, 1]]);
+    /// ```
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub unsafe fn assume_init(self) -> Matrix<T> {
+        let mut data = ManuallyDrop::new(self.data);
+        let (pointer, len, capacity) = (data.as_mut_ptr(), data.len(), data.capacity());
+        let data = unsafe { Vec::from_raw_parts(pointer.cast::<T>(), len, capacity) };
+        Matrix {
+            order: self.order,
+            shape: self.shape,
+            data,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::matrix;
+    use crate::matrix::index::Index;
+    use alloc::format;
+    use alloc::string::{String, ToString};
 
     // All tests are based on the assumption that
     // the default order is `RowMajor`.
@@ -885,6 +2637,11 @@ mod tests {
         let expected = matrix![[0, 0, 0], [0, 0, 0]];
         assert_eq!(Matrix::new((2, 3)), expected);
         assert_ne!(Matrix::new((3, 2)), expected);
+
+        let expected = matrix![[0, 0], [0, 0]];
+        assert_eq!(Matrix::new(2), expected);
+        assert_eq!(Matrix::new((2,)), expected);
+        assert_eq!(Matrix::new([2]), expected);
     }
 
     #[test]
@@ -907,9 +2664,89 @@ mod tests {
             Error::SizeOverflow
         );
         assert_eq!(
-            Matrix::<i32>::build((isize::MAX as usize / 4 + 1, 1)).unwrap_err(),
-            Error::CapacityExceeded
+            Matrix::<i32>::build((isize::MAX as usize / 4 + 1, 1)).unwrap_err(),
+            Error::CapacityExceeded
+        );
+    }
+
+    #[test]
+    fn test_with_order() {
+        for order in [Order::RowMajor, Order::ColMajor] {
+            let matrix = Matrix::<i32>::with_order(order, (2, 3));
+            assert_eq!(matrix.order, order);
+            assert_eq!(matrix.nrows(), 2);
+            assert_eq!(matrix.ncols(), 3);
+            assert_eq!(matrix.data, vec![0; 6]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_with_order_fails() {
+        Matrix::<u8>::with_order(Order::ColMajor, (usize::MAX, 2));
+    }
+
+    #[test]
+    fn test_full() {
+        let expected = matrix![[7, 7, 7], [7, 7, 7]];
+        assert_eq!(Matrix::full((2, 3), 7), expected);
+        assert_ne!(Matrix::full((3, 2), 7), expected);
+        assert_eq!(Matrix::full((0, 3), 7), Matrix::new((0, 3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_full_fails() {
+        Matrix::full((usize::MAX, 2), 0u8);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let matrix = Matrix::from_fn((2, 3), |index| index.row * 3 + index.col);
+        assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+
+        let mut calls = 0;
+        let matrix = Matrix::from_fn((3, 2), |index| {
+            calls += 1;
+            index
+        });
+        assert_eq!(calls, 6);
+        matrix.map_with_index(|index, element| assert_eq!(element, index));
+
+        assert_eq!(Matrix::from_fn((0, 3), |_| 7), Matrix::new((0, 3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_fn_fails() {
+        Matrix::from_fn((usize::MAX, 2), |_| 0u8);
+    }
+
+    #[test]
+    fn test_new_uninit() {
+        let mut matrix = Matrix::<String>::new_uninit((2, 3));
+        assert_eq!(matrix.order(), Order::RowMajor);
+        assert_eq!(matrix.shape(), Shape::new(2, 3));
+        matrix.apply_with_index(|index, element| {
+            element.write(format!("{}{}", index.row, index.col));
+        });
+        let matrix = unsafe { matrix.assume_init() };
+        assert_eq!(
+            matrix,
+            matrix![
+                ["00".to_string(), "01".to_string(), "02".to_string()],
+                ["10".to_string(), "11".to_string(), "12".to_string()]
+            ]
         );
+
+        let matrix = unsafe { Matrix::<i32>::new_uninit((0, 3)).assume_init() };
+        assert_eq!(matrix, Matrix::new((0, 3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_uninit_fails() {
+        Matrix::<u8>::new_uninit((isize::MAX as usize + 1, 1));
     }
 
     #[test]
@@ -921,6 +2758,47 @@ mod tests {
         assert!(matrix.is_empty());
     }
 
+    #[test]
+    fn test_len_of() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        assert_eq!(matrix.len_of(Axis::Row), 2);
+        assert_eq!(matrix.len_of(Axis::Col), 3);
+
+        matrix.switch_order();
+        assert_eq!(matrix.len_of(Axis::Row), 2);
+        assert_eq!(matrix.len_of(Axis::Col), 3);
+
+        matrix.transpose();
+        assert_eq!(matrix.len_of(Axis::Row), 3);
+        assert_eq!(matrix.len_of(Axis::Col), 2);
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        assert_eq!(matrix.strides(), (3, 1));
+        assert_eq!(matrix.as_slice(), &[0, 1, 2, 3, 4, 5]);
+
+        matrix.switch_order();
+        assert_eq!(matrix.strides(), (1, 2));
+        assert_eq!(matrix.as_slice(), &[0, 3, 1, 4, 2, 5]);
+
+        for row in 0..2 {
+            for col in 0..3 {
+                let (row_stride, col_stride) = matrix.strides();
+                let index = row * row_stride + col * col_stride;
+                assert_eq!(matrix.as_slice()[index], matrix[(row, col)]);
+            }
+        }
+
+        matrix.as_mut_slice()[1] = -1;
+        assert_eq!(matrix[(1, 0)], -1);
+
+        matrix.transpose();
+        assert_eq!(matrix.strides(), (2, 1));
+        assert_eq!(matrix.as_slice(), &[0, -1, 1, 4, 2, 5]);
+    }
+
     #[test]
     fn test_transpose() {
         let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
@@ -948,6 +2826,184 @@ mod tests {
         assert_eq!(matrix[(1, 2)], 5);
     }
 
+    #[test]
+    fn test_rot90() {
+        let expected = [
+            matrix![[0, 1, 2], [3, 4, 5]],
+            matrix![[2, 5], [1, 4], [0, 3]],
+            matrix![[5, 4, 3], [2, 1, 0]],
+            matrix![[3, 0], [4, 1], [5, 2]],
+        ];
+
+        for order in [Order::RowMajor, Order::ColMajor] {
+            for k in -9..=9 {
+                let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+                matrix.set_order(order);
+                matrix.rot90(k).set_order(Order::RowMajor);
+                assert_eq!(matrix, expected[k.rem_euclid(4) as usize], "k = {k}");
+            }
+        }
+
+        let mut matrix = matrix![[0, 1], [2, 3], [4, 5], [6, 7]];
+        matrix
+            .rot90(1)
+            .rot90(1)
+            .rot90(-1)
+            .set_order(Order::RowMajor);
+        assert_eq!(matrix, matrix![[1, 3, 5, 7], [0, 2, 4, 6]]);
+
+        let mut matrix = Matrix::<i32>::new((2, 0));
+        matrix.rot90(1);
+        assert_eq!(matrix.shape(), Shape::new(0, 2));
+        matrix.rot90(-1);
+        assert_eq!(matrix.shape(), Shape::new(2, 0));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let order = matrix.order;
+
+            let mut output = matrix.clone();
+            output.rotate_cw().set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[3, 0], [4, 1], [5, 2]]);
+
+            let mut output = matrix.clone();
+            output.rotate_ccw().set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[2, 5], [1, 4], [0, 3]]);
+
+            let mut output = matrix.clone();
+            output.rotate_180();
+            assert_eq!(output.order, order);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[5, 4, 3], [2, 1, 0]]);
+
+            let mut output = matrix.clone();
+            output.rotate_cw().rotate_ccw();
+            assert_eq!(output, matrix);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_flip() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+
+        for _ in 0..2 {
+            let order = matrix.order;
+
+            let mut output = matrix.clone();
+            output.flip_horizontal();
+            assert_eq!(output.order, order);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[2, 1, 0], [5, 4, 3], [8, 7, 6]]);
+
+            let mut output = matrix.clone();
+            output.flip_vertical();
+            assert_eq!(output.order, order);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[6, 7, 8], [3, 4, 5], [0, 1, 2]]);
+
+            let mut output = matrix.clone();
+            output.flip_horizontal().flip_vertical();
+            let mut expected = matrix.clone();
+            expected.rotate_180();
+            assert_eq!(output, expected);
+
+            matrix.switch_order();
+        }
+
+        let mut matrix = Matrix::<i32>::new((0, 3));
+        matrix.flip_horizontal().flip_vertical();
+        assert_eq!(matrix.shape(), Shape::new(0, 3));
+    }
+
+    #[test]
+    fn test_roll() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8], [9, 10, 11]];
+
+        for _ in 0..2 {
+            let order = matrix.order;
+
+            for (n, expected) in [
+                (0, matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8], [9, 10, 11]]),
+                (1, matrix![[9, 10, 11], [0, 1, 2], [3, 4, 5], [6, 7, 8]]),
+                (-1, matrix![[3, 4, 5], [6, 7, 8], [9, 10, 11], [0, 1, 2]]),
+                (6, matrix![[6, 7, 8], [9, 10, 11], [0, 1, 2], [3, 4, 5]]),
+            ] {
+                let mut output = matrix.clone();
+                output.roll_rows(n);
+                assert_eq!(output.order, order);
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, expected, "n = {n}");
+            }
+
+            for (n, expected) in [
+                (1, matrix![[2, 0, 1], [5, 3, 4], [8, 6, 7], [11, 9, 10]]),
+                (-1, matrix![[1, 2, 0], [4, 5, 3], [7, 8, 6], [10, 11, 9]]),
+                (-3, matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8], [9, 10, 11]]),
+            ] {
+                let mut output = matrix.clone();
+                output.roll_cols(n);
+                assert_eq!(output.order, order);
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, expected, "n = {n}");
+            }
+
+            matrix.switch_order();
+        }
+
+        let mut matrix = Matrix::<i32>::new((3, 0));
+        matrix.roll_rows(1).roll_cols(-1);
+        assert_eq!(matrix.shape(), Shape::new(3, 0));
+    }
+
+    #[test]
+    fn test_shift() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+
+        for _ in 0..2 {
+            let order = matrix.order;
+
+            for (n, expected) in [
+                (0, matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]]),
+                (1, matrix![[-1, -1, -1], [0, 1, 2], [3, 4, 5]]),
+                (-2, matrix![[6, 7, 8], [-1, -1, -1], [-1, -1, -1]]),
+                (3, matrix![[-1, -1, -1], [-1, -1, -1], [-1, -1, -1]]),
+                (
+                    isize::MIN,
+                    matrix![[-1, -1, -1], [-1, -1, -1], [-1, -1, -1]],
+                ),
+            ] {
+                let mut output = matrix.clone();
+                output.shift_rows(n, -1);
+                assert_eq!(output.order, order);
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, expected, "n = {n}");
+            }
+
+            for (n, expected) in [
+                (2, matrix![[-1, -1, 0], [-1, -1, 3], [-1, -1, 6]]),
+                (-1, matrix![[1, 2, -1], [4, 5, -1], [7, 8, -1]]),
+            ] {
+                let mut output = matrix.clone();
+                output.shift_cols(n, -1);
+                assert_eq!(output.order, order);
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, expected, "n = {n}");
+            }
+
+            matrix.switch_order();
+        }
+
+        let mut matrix = Matrix::<i32>::new((0, 2));
+        matrix.shift_rows(1, 1).shift_cols(-1, 1);
+        assert_eq!(matrix.shape(), Shape::new(0, 2));
+    }
+
     #[test]
     fn test_switch_order() {
         let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
@@ -1072,6 +3128,40 @@ mod tests {
         assert_eq!(matrix, unchanged);
     }
 
+    #[test]
+    fn test_resize_preserving() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let order = matrix.order();
+            let mut resized = matrix.clone();
+
+            resized.resize_preserving((3, 3), -1).unwrap();
+            assert_eq!(resized.order(), order);
+            assert_eq!(resized.shape(), Shape::new(3, 3));
+            for (row, col) in [(0, 0), (0, 2), (1, 1), (1, 2)] {
+                assert_eq!(resized[(row, col)], matrix[(row, col)]);
+            }
+            for col in 0..3 {
+                assert_eq!(resized[(2, col)], -1);
+            }
+
+            resized.resize_preserving((1, 2), -1).unwrap();
+            resized.set_order(Order::RowMajor);
+            assert_eq!(resized, matrix![[0, 1]]);
+
+            resized.resize_preserving((2, 0), -1).unwrap();
+            assert_eq!(resized.shape(), Shape::new(2, 0));
+
+            let unchanged = resized.clone();
+            let error = resized.resize_preserving((usize::MAX, 2), 0).unwrap_err();
+            assert_eq!(error, Error::SizeOverflow);
+            assert_eq!(resized, unchanged);
+
+            matrix.switch_order();
+        }
+    }
+
     #[test]
     fn test_reshape() {
         let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
@@ -1106,6 +3196,105 @@ mod tests {
         assert_eq!(matrix, unchanged);
     }
 
+    #[test]
+    fn test_truncate() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.truncate_rows(2);
+            assert_eq!(output.order(), matrix.order());
+            assert_eq!(output.capacity(), matrix.capacity());
+            assert_eq!(output, {
+                let mut expected = matrix![[0, 1, 2], [3, 4, 5]];
+                expected.set_order(matrix.order());
+                expected
+            });
+
+            output.truncate_cols(1);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0], [3]]);
+
+            let mut output = matrix.clone();
+            output.truncate_cols(2).truncate_rows(0);
+            assert_eq!(output.shape(), Shape::new(0, 2));
+            assert!(output.is_empty());
+
+            let mut output = matrix.clone();
+            output.truncate_rows(3).truncate_cols(5);
+            assert_eq!(output, matrix);
+
+            let mut output = matrix.clone();
+            output
+                .truncate_axis(Axis::Col, 2)
+                .truncate_axis(Axis::Row, 1);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 1]]);
+
+            let mut output = matrix.clone();
+            output.clear();
+            assert_eq!(output.order(), matrix.order());
+            assert_eq!(output.shape(), Shape::new(0, 0));
+            assert!(output.is_empty());
+            assert_eq!(output.capacity(), matrix.capacity());
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_split_at() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+
+        for _ in 0..2 {
+            let (mut top, mut bottom) = matrix.clone().split_at_row(1).unwrap();
+            assert_eq!(top.order(), matrix.order());
+            assert_eq!(bottom.order(), matrix.order());
+            top.set_order(Order::RowMajor);
+            bottom.set_order(Order::RowMajor);
+            assert_eq!(top, matrix![[0, 1, 2]]);
+            assert_eq!(bottom, matrix![[3, 4, 5], [6, 7, 8]]);
+
+            let (mut left, mut right) = matrix.clone().split_at_col(2).unwrap();
+            assert_eq!(left.order(), matrix.order());
+            assert_eq!(right.order(), matrix.order());
+            left.set_order(Order::RowMajor);
+            right.set_order(Order::RowMajor);
+            assert_eq!(left, matrix![[0, 1], [3, 4], [6, 7]]);
+            assert_eq!(right, matrix![[2], [5], [8]]);
+
+            let (mut top, mut bottom) = matrix.clone().split_at_axis(Axis::Row, 2).unwrap();
+            top.set_order(Order::RowMajor);
+            bottom.set_order(Order::RowMajor);
+            assert_eq!(top, matrix![[0, 1, 2], [3, 4, 5]]);
+            assert_eq!(bottom, matrix![[6, 7, 8]]);
+
+            let (top, bottom) = matrix.clone().split_at_row(0).unwrap();
+            assert_eq!(top.shape(), Shape::new(0, 3));
+            assert_eq!(bottom, matrix);
+            let (left, right) = matrix.clone().split_at_col(3).unwrap();
+            assert_eq!(left, matrix);
+            assert_eq!(right.shape(), Shape::new(3, 0));
+
+            assert_eq!(
+                matrix.clone().split_at_row(4),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(4, 0),
+                    shape: Shape::new(3, 3)
+                })
+            );
+            assert_eq!(
+                matrix.clone().split_at_col(4),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(0, 4),
+                    shape: Shape::new(3, 3)
+                })
+            );
+
+            matrix.switch_order();
+        }
+    }
+
     #[test]
     fn test_overwrite_with() {
         let blank = matrix![[0, 0, 0], [0, 0, 0]];
@@ -1181,6 +3370,178 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fill() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut filled = matrix.clone();
+            filled.fill(9);
+            assert_eq!(filled.shape(), matrix.shape());
+            assert!(filled.iter_elements().all(|&x| x == 9));
+
+            let mut next = 0;
+            filled.fill_with(|| {
+                next += 1;
+                next
+            });
+            assert_eq!(next, 6);
+            let mut elements: Vec<_> = filled.iter_elements().copied().collect();
+            elements.sort();
+            assert_eq!(elements, [1, 2, 3, 4, 5, 6]);
+
+            let mut filled = matrix.clone();
+            filled.fill_row(0, -1).unwrap().fill_col(2, -2).unwrap();
+            filled.set_order(Order::RowMajor);
+            assert_eq!(filled, matrix![[-1, -1, -2], [3, 4, -2]]);
+            assert_eq!(
+                filled.fill_row(2, 0),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
+            assert_eq!(
+                filled.fill_col(3, 0),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                })
+            );
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_push_row_and_col() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.push_row([6, 7, 8]).unwrap().push_col(9..12).unwrap();
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 1, 2, 9], [3, 4, 5, 10], [6, 7, 8, 11]]);
+
+            let mut output = matrix.clone();
+            assert_eq!(output.push_row([6, 7]), Err(Error::LengthInconsistent));
+            assert_eq!(output.push_row(6..10), Err(Error::LengthInconsistent));
+            assert_eq!(output.push_col([6]), Err(Error::LengthInconsistent));
+            assert_eq!(output.push_col(6..9), Err(Error::LengthInconsistent));
+            assert_eq!(output, matrix);
+
+            for order in [Order::RowMajor, Order::ColMajor] {
+                let mut output = Matrix::with_order(order, (0, 0));
+                output
+                    .push_row([0, 1, 2])
+                    .unwrap()
+                    .push_row([3, 4, 5])
+                    .unwrap();
+                assert_eq!(output.order(), order);
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, matrix![[0, 1, 2], [3, 4, 5]]);
+
+                let mut output = Matrix::with_order(order, (2, 0));
+                assert_eq!(output.push_col([0, 3, 6]), Err(Error::LengthInconsistent));
+                assert_eq!(output.shape(), Shape::new(2, 0));
+                output.push_col([0, 3]).unwrap();
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, matrix![[0], [3]]);
+
+                let mut output = Matrix::with_order(order, (0, 3));
+                assert_eq!(output.push_row([0, 1]), Err(Error::LengthInconsistent));
+                output.push_row([0, 1, 2]).unwrap();
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, matrix![[0, 1, 2]]);
+
+                let mut output = Matrix::with_order(order, (3, 0));
+                assert_eq!(output.push_row([0]), Err(Error::LengthInconsistent));
+                output.push_row([]).unwrap();
+                assert_eq!(output.shape(), Shape::new(4, 0));
+            }
+
+            matrix.switch_order();
+        }
+
+        let mut matrix = Matrix::<()>::new((usize::MAX, 0));
+        assert_eq!(matrix.push_row([]), Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    fn test_extend_rows() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.extend_rows([[6, 7, 8], [9, 10, 11]]).unwrap();
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(
+                output,
+                matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8], [9, 10, 11]]
+            );
+
+            let mut output = matrix.clone();
+            output.extend_rows(core::iter::empty::<[i32; 2]>()).unwrap();
+            assert_eq!(output, matrix);
+            assert_eq!(
+                output.extend_rows([[6, 7], [8, 9]]),
+                Err(Error::LengthInconsistent)
+            );
+            assert_eq!(
+                output.extend_rows([vec![6, 7, 8], vec![9]]),
+                Err(Error::LengthInconsistent)
+            );
+            assert_eq!(output, matrix);
+
+            let mut output = matrix.clone();
+            output
+                .extend_rows_saturating([vec![6], vec![7, 8, 9, 10]], -1)
+                .unwrap();
+            output.set_order(Order::RowMajor);
+            assert_eq!(
+                output,
+                matrix![[0, 1, 2], [3, 4, 5], [6, -1, -1], [7, 8, 9]]
+            );
+
+            for order in [Order::RowMajor, Order::ColMajor] {
+                let mut output = Matrix::with_order(order, (0, 3));
+                assert_eq!(
+                    output.extend_rows([[0, 1], [2, 3]]),
+                    Err(Error::LengthInconsistent)
+                );
+                assert_eq!(output.shape(), Shape::new(0, 3));
+                output.extend_rows([[0, 1, 2], [3, 4, 5]]).unwrap();
+                assert_eq!(output.order(), order);
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, matrix![[0, 1, 2], [3, 4, 5]]);
+
+                let mut output = Matrix::with_order(order, (0, 3));
+                output
+                    .extend_rows_saturating([vec![0, 1], vec![2, 3, 4, 5]], 9)
+                    .unwrap();
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, matrix![[0, 1, 9], [2, 3, 4]]);
+
+                let mut output = Matrix::with_order(order, (2, 0));
+                assert_eq!(output.extend_rows([[0]]), Err(Error::LengthInconsistent));
+                output.extend_rows([[]; 1]).unwrap();
+                assert_eq!(output.shape(), Shape::new(3, 0));
+
+                let mut output = Matrix::with_order(order, (0, 0));
+                output
+                    .extend_rows_saturating([vec![0, 1], vec![2, 3, 4], vec![]], 0)
+                    .unwrap();
+                assert_eq!(output.order(), order);
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, matrix![[0, 1], [2, 3], [0, 0]]);
+            }
+
+            matrix.switch_order();
+        }
+    }
+
     #[test]
     fn test_apply() {
         let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
@@ -1209,6 +3570,86 @@ mod tests {
         assert_eq!(matrix_i32, matrix![[0, 1, 2], [3, 4, 5]]);
     }
 
+    #[test]
+    fn test_apply_with_index() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.apply_with_index(|index, x| *x += (index.row * 10 + index.col) as i32);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 2, 4], [13, 15, 17]]);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_map_with_index() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone().map_with_index(|index, x| (index, x));
+            output.set_order(Order::RowMajor);
+            for (index, &(position, x)) in output.iter_elements_with_index() {
+                assert_eq!(position, index);
+                assert_eq!(x, matrix[index]);
+            }
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_map_rows() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        let op = |row: StrideIter<i32>| {
+            let sum: i32 = row.sum();
+            [sum, sum * 2]
+        };
+
+        let output = matrix.map_rows(op).unwrap();
+        assert_eq!(output, matrix![[3, 6], [12, 24]]);
+
+        matrix.switch_order();
+
+        let output = matrix.map_rows(op).unwrap();
+        assert_eq!(output, matrix![[3, 6], [12, 24]]);
+
+        let output = matrix.map_rows(|_| core::iter::empty::<i32>()).unwrap();
+        assert_eq!(output, matrix![[], []]);
+
+        let error = matrix
+            .map_rows(|row| row.take_while(|x| **x < 4).copied())
+            .unwrap_err();
+        assert_eq!(error, Error::LengthInconsistent);
+
+        let output = Matrix::<i32>::empty().map_rows(op).unwrap();
+        assert_eq!(output, Matrix::empty());
+    }
+
+    #[test]
+    fn test_map_axis() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut output = matrix.map_axis(Axis::Row, |row| row.sum::<i32>());
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[3], [12]]);
+
+            let mut output = matrix.map_axis(Axis::Col, |col| col.count());
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[2, 2, 2]]);
+
+            matrix.switch_order();
+        }
+
+        let output = Matrix::<i32>::new((0, 3)).map_axis(Axis::Col, |col| col.count());
+        assert_eq!(output, matrix![[0, 0, 0]]);
+    }
+
     #[cfg(feature = "rayon")]
     #[test]
     fn test_par_apply() {
@@ -1224,6 +3665,54 @@ mod tests {
         assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
     }
 
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_apply_with_index() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.par_apply_with_index(|index, x| *x += (index.row * 10 + index.col) as i32);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 2, 4], [13, 15, 17]]);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_map_with_index() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let output = matrix.clone().par_map_with_index(|index, x| (index, x));
+            assert_eq!(output, matrix.clone().map_with_index(|index, x| (index, x)));
+
+            matrix.switch_order();
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_drop() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct Mock;
+
+        impl Drop for Mock {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let matrix = matrix![[Mock, Mock, Mock], [Mock, Mock, Mock]];
+        matrix.par_drop();
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 6);
+    }
+
     #[cfg(feature = "rayon")]
     #[test]
     fn test_par_map() {
@@ -1239,6 +3728,64 @@ mod tests {
         assert_eq!(matrix_i32, matrix![[0, 1, 2], [3, 4, 5]]);
     }
 
+    #[test]
+    fn test_zip_map() {
+        let mut lhs = matrix![[0, 1, 2], [3, 4, 5]];
+        let mut rhs = matrix![[5, 4, 3], [2, 1, 0]];
+        let mut expected = matrix![[5, 14, 23], [32, 41, 50]];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                assert_eq!(lhs.zip_map(&rhs, |x, y| x * 10 + y), Ok(expected.clone()));
+                assert_eq!(
+                    lhs.clone().zip_map_consume(&rhs, |x, y| x * 10 + y),
+                    Ok(expected.clone())
+                );
+                rhs.switch_order();
+            }
+            lhs.switch_order();
+            expected.switch_order();
+        }
+
+        let rhs = Matrix::<i32>::new((3, 2));
+        let error = Error::NotConformable {
+            lhs: Shape::new(2, 3),
+            rhs: Shape::new(3, 2),
+        };
+        assert_eq!(lhs.zip_map(&rhs, |x, y| x + y), Err(error));
+        assert_eq!(lhs.zip_map_consume(&rhs, |x, y| x + y), Err(error));
+    }
+
+    #[test]
+    fn test_zip_map3() {
+        let mut a = matrix![[0, 1, 2], [3, 4, 5]];
+        let mut b = matrix![[2, 2, 2], [2, 2, 2]];
+        let mut c = matrix![[0, 1, 0], [1, 0, 1]];
+        let expected = matrix![[0, 3, 4], [7, 8, 11]];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                for _ in 0..2 {
+                    let mut output = a.zip_map3(&b, &c, |x, y, z| x * y + z).unwrap();
+                    assert_eq!(output.order(), a.order());
+                    output.set_order(Order::RowMajor);
+                    assert_eq!(output, expected);
+                    c.switch_order();
+                }
+                b.switch_order();
+            }
+            a.switch_order();
+        }
+
+        let wrong = Matrix::<i32>::new((3, 2));
+        let error = Error::NotConformable {
+            lhs: Shape::new(2, 3),
+            rhs: Shape::new(3, 2),
+        };
+        assert_eq!(a.zip_map3(&wrong, &c, |x, y, z| x + y + z), Err(error));
+        assert_eq!(a.zip_map3(&b, &wrong, |x, y, z| x + y + z), Err(error));
+    }
+
     #[test]
     fn test_ensure_elementwise_operation_conformable() {
         let mut lhs = Matrix::<i32>::new((2, 3));
@@ -1268,11 +3815,23 @@ mod tests {
 
         let rhs = Matrix::<i32>::new((2, 2));
         let result = lhs.ensure_elementwise_operation_conformable(&rhs);
-        assert_eq!(result, Err(Error::NotConformable));
+        assert_eq!(
+            result,
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(2, 2)
+            })
+        );
 
         let rhs = Matrix::<i32>::new((3, 2));
         let result = lhs.ensure_elementwise_operation_conformable(&rhs);
-        assert_eq!(result, Err(Error::NotConformable));
+        assert_eq!(
+            result,
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 2)
+            })
+        );
     }
 
     #[test]
@@ -1308,11 +3867,23 @@ mod tests {
 
         let rhs = matrix![[2, 2], [2, 2]];
         let error = lhs.elementwise_operation(&rhs, op).unwrap_err();
-        assert_eq!(error, Error::NotConformable);
+        assert_eq!(
+            error,
+            Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(2, 2)
+            }
+        );
 
         let rhs = matrix![[2, 2], [2, 2], [2, 2]];
         let error = lhs.elementwise_operation(&rhs, op).unwrap_err();
-        assert_eq!(error, Error::NotConformable);
+        assert_eq!(
+            error,
+            Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 2)
+            }
+        );
     }
 
     #[test]
@@ -1364,7 +3935,13 @@ mod tests {
             let error = lhs
                 .elementwise_operation_consume_self(&rhs, op)
                 .unwrap_err();
-            assert_eq!(error, Error::NotConformable);
+            assert_eq!(
+                error,
+                Error::NotConformable {
+                    lhs: Shape::new(2, 3),
+                    rhs: Shape::new(2, 2)
+                }
+            );
         }
 
         {
@@ -1373,7 +3950,13 @@ mod tests {
             let error = lhs
                 .elementwise_operation_consume_self(&rhs, op)
                 .unwrap_err();
-            assert_eq!(error, Error::NotConformable);
+            assert_eq!(
+                error,
+                Error::NotConformable {
+                    lhs: Shape::new(2, 3),
+                    rhs: Shape::new(3, 2)
+                }
+            );
         }
     }
 
@@ -1424,12 +4007,24 @@ mod tests {
 
         let rhs = matrix![[2, 2], [2, 2]];
         let error = lhs.elementwise_operation_assign(&rhs, op).unwrap_err();
-        assert_eq!(error, Error::NotConformable);
+        assert_eq!(
+            error,
+            Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(2, 2)
+            }
+        );
         assert_eq!(lhs, unchanged);
 
         let rhs = matrix![[2, 2], [2, 2], [2, 2]];
         let error = lhs.elementwise_operation_assign(&rhs, op).unwrap_err();
-        assert_eq!(error, Error::NotConformable);
+        assert_eq!(
+            error,
+            Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 2)
+            }
+        );
         assert_eq!(lhs, unchanged);
     }
 
@@ -1470,18 +4065,30 @@ mod tests {
 
         let rhs = Matrix::<i32>::new((2, 2));
         let result = lhs.ensure_multiplication_like_operation_conformable(&rhs);
-        assert_eq!(result, Err(Error::NotConformable));
+        assert_eq!(
+            result,
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(2, 2)
+            })
+        );
 
         let rhs = Matrix::<i32>::new((2, 3));
         let result = lhs.ensure_multiplication_like_operation_conformable(&rhs);
-        assert_eq!(result, Err(Error::NotConformable));
+        assert_eq!(
+            result,
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(2, 3)
+            })
+        );
     }
 
     #[test]
     fn test_multiplication_like_operation() {
         let mut lhs = matrix![[0, 1, 2], [3, 4, 5]];
         let mut rhs = matrix![[0, 1], [2, 3], [4, 5]];
-        let op = |vl: VectorIter<&i32>, vr: VectorIter<&i32>| {
+        let op = |vl: StrideIter<i32>, vr: StrideIter<i32>| {
             vl.zip(vr)
                 .map(|(x, y)| x * y)
                 .reduce(|acc, p| acc + p)
@@ -1549,18 +4156,31 @@ mod tests {
             let lhs = lhs.clone();
             let rhs = matrix![[0, 1], [2, 3]];
             let error = lhs.multiplication_like_operation(rhs, op).unwrap_err();
-            assert_eq!(error, Error::NotConformable);
+            assert_eq!(
+                error,
+                Error::NotConformable {
+                    lhs: Shape::new(2, 3),
+                    rhs: Shape::new(2, 2)
+                }
+            );
         }
 
         {
             let lhs = lhs.clone();
             let rhs = matrix![[0, 1, 3], [4, 5, 6]];
             let error = lhs.multiplication_like_operation(rhs, op).unwrap_err();
-            assert_eq!(error, Error::NotConformable);
+            assert_eq!(
+                error,
+                Error::NotConformable {
+                    lhs: Shape::new(2, 3),
+                    rhs: Shape::new(2, 3)
+                }
+            );
         }
     }
 
     #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
     fn test_scalar_operation() {
         let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
         let scalar = 2;
@@ -1577,9 +4197,15 @@ mod tests {
         let mut output = matrix.scalar_operation(&scalar, op);
         output.switch_order();
         assert_eq!(output, expected);
+
+        // by value
+        let mut output = matrix.scalar_operation(scalar, op);
+        output.switch_order();
+        assert_eq!(output, expected);
     }
 
     #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
     fn test_scalar_operation_consume_self() {
         let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
         let scalar = 2;
@@ -1602,9 +4228,17 @@ mod tests {
             output.switch_order();
             assert_eq!(output, expected);
         }
+
+        // by value
+        {
+            let mut output = matrix.scalar_operation_consume_self(scalar, op);
+            output.switch_order();
+            assert_eq!(output, expected);
+        }
     }
 
     #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
     fn test_scalar_operation_assign() {
         let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
         let scalar = 2;
@@ -1627,5 +4261,12 @@ mod tests {
             matrix.switch_order();
             assert_eq!(matrix, expected);
         }
+
+        // by value
+        {
+            matrix.scalar_operation_assign(scalar, op);
+            matrix.switch_order();
+            assert_eq!(matrix, expected);
+        }
     }
 }
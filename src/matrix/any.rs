@@ -0,0 +1,187 @@
+//! This module provides [`AnyMatrix`], a matrix whose element type is
+//! only known at runtime.
+
+use super::shape::Shape;
+use super::Matrix;
+
+/// The element type of an [`AnyMatrix`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DType {
+    /// [`u8`] elements.
+    U8,
+
+    /// [`i32`] elements.
+    I32,
+
+    /// [`f32`] elements.
+    F32,
+
+    /// [`f64`] elements.
+    F64,
+
+    /// [`bool`] elements.
+    Bool,
+}
+
+/// A matrix of one of the common element types, tagged at runtime.
+///
+/// This is useful for loaders that only learn the element type from the
+/// data they read, without forcing everything to [`f64`].
+///
+/// # Examples
+///
+/// ```
+/// use matreex::{matrix, AnyMatrix, DType, Shape};
+///
+/// let matrix = AnyMatrix::from(matrix![[0u8, 1, 2], [3, 4, 5]]);
+///
+/// assert_eq!(matrix.dtype(), DType::U8);
+/// assert_eq!(matrix.shape(), Shape::new(2, 3));
+/// assert_eq!(matrix.cast_to_f64(), matrix![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnyMatrix {
+    /// A matrix of [`u8`].
+    U8(Matrix<u8>),
+
+    /// A matrix of [`i32`].
+    I32(Matrix<i32>),
+
+    /// A matrix of [`f32`].
+    F32(Matrix<f32>),
+
+    /// A matrix of [`f64`].
+    F64(Matrix<f64>),
+
+    /// A matrix of [`bool`].
+    Bool(Matrix<bool>),
+}
+
+macro_rules! dispatch {
+    ($self:expr, $matrix:ident => $body:expr) => {
+        match $self {
+            AnyMatrix::U8($matrix) => $body,
+            AnyMatrix::I32($matrix) => $body,
+            AnyMatrix::F32($matrix) => $body,
+            AnyMatrix::F64($matrix) => $body,
+            AnyMatrix::Bool($matrix) => $body,
+        }
+    };
+}
+
+impl AnyMatrix {
+    /// Returns the element type of the matrix.
+    pub fn dtype(&self) -> DType {
+        match self {
+            Self::U8(_) => DType::U8,
+            Self::I32(_) => DType::I32,
+            Self::F32(_) => DType::F32,
+            Self::F64(_) => DType::F64,
+            Self::Bool(_) => DType::Bool,
+        }
+    }
+
+    /// Returns the shape of the matrix.
+    pub fn shape(&self) -> Shape {
+        dispatch!(self, matrix => matrix.shape())
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn nrows(&self) -> usize {
+        dispatch!(self, matrix => matrix.nrows())
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn ncols(&self) -> usize {
+        dispatch!(self, matrix => matrix.ncols())
+    }
+
+    /// Returns the total number of elements in the matrix.
+    pub fn size(&self) -> usize {
+        dispatch!(self, matrix => matrix.size())
+    }
+
+    /// Returns a copy of the matrix with its elements converted to [`f64`].
+    ///
+    /// # Notes
+    ///
+    /// All conversions are lossless, with `true` and `false` mapped to
+    /// `1.0` and `0.0`. The resulting matrix will always have the same
+    /// order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, AnyMatrix};
+    ///
+    /// let matrix = AnyMatrix::from(matrix![[true, false]]);
+    /// assert_eq!(matrix.cast_to_f64(), matrix![[1.0, 0.0]]);
+    /// ```
+    pub fn cast_to_f64(&self) -> Matrix<f64> {
+        match self {
+            Self::U8(matrix) => matrix.clone().map(f64::from),
+            Self::I32(matrix) => matrix.clone().map(f64::from),
+            Self::F32(matrix) => matrix.clone().map(f64::from),
+            Self::F64(matrix) => matrix.clone(),
+            Self::Bool(matrix) => matrix.clone().map(|x| f64::from(u8::from(x))),
+        }
+    }
+}
+
+macro_rules! impl_from_matrix {
+    ($($t:ty => $variant:ident)*) => {
+        $(
+            impl From<Matrix<$t>> for AnyMatrix {
+                fn from(value: Matrix<$t>) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_matrix! {u8 => U8 i32 => I32 f32 => F32 f64 => F64 bool => Bool}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_any_matrix() {
+        let mut matrices = [
+            AnyMatrix::from(matrix![[0u8, 1, 2], [3, 4, 5]]),
+            AnyMatrix::from(matrix![[0i32, 1, 2], [3, 4, 5]]),
+            AnyMatrix::from(matrix![[0.0f32, 1.0, 2.0], [3.0, 4.0, 5.0]]),
+            AnyMatrix::from(matrix![[0.0f64, 1.0, 2.0], [3.0, 4.0, 5.0]]),
+        ];
+        let dtypes = [DType::U8, DType::I32, DType::F32, DType::F64];
+        let expected: Matrix<f64> = matrix![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]];
+
+        for _ in 0..2 {
+            for (matrix, dtype) in matrices.iter_mut().zip(dtypes) {
+                assert_eq!(matrix.dtype(), dtype);
+                assert_eq!(matrix.shape(), Shape::new(2, 3));
+                assert_eq!(matrix.nrows(), 2);
+                assert_eq!(matrix.ncols(), 3);
+                assert_eq!(matrix.size(), 6);
+
+                let cast = matrix.cast_to_f64();
+                for row in 0..2 {
+                    for col in 0..3 {
+                        assert_eq!(cast[(row, col)], expected[(row, col)]);
+                    }
+                }
+
+                dispatch!(matrix, matrix => {
+                    matrix.switch_order();
+                });
+            }
+        }
+
+        let matrix = AnyMatrix::from(matrix![[true], [false]]);
+        assert_eq!(matrix.dtype(), DType::Bool);
+        assert_eq!(matrix.shape(), Shape::new(2, 1));
+        assert_eq!(matrix.cast_to_f64(), matrix![[1.0], [0.0]]);
+    }
+}
@@ -0,0 +1,81 @@
+//! This module provides [`Approx`], a wrapper for comparing float
+//! matrices within a tolerance.
+
+use super::Matrix;
+
+/// A float matrix that compares equal to any matrix of the same shape
+/// whose elements all lie within the given absolute tolerance.
+///
+/// This is mostly useful in tests, where `assert_eq!` prints both
+/// matrices when the comparison fails.
+///
+/// # Notes
+///
+/// The comparison is independent of the order of either matrix. `NaN`
+/// never compares equal to anything.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::{matrix, Approx, Matrix};
+///
+/// let actual: Matrix<f64> = matrix![[0.1 + 0.2, 1.0], [2.0, 3.0]];
+///
+/// assert_ne!(actual, matrix![[0.3, 1.0], [2.0, 3.0]]);
+/// assert_eq!(Approx(matrix![[0.3, 1.0], [2.0, 3.0]], 1e-9), actual);
+/// assert_ne!(Approx(matrix![[0.3, 1.0], [2.0, 3.1]], 1e-9), actual);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Approx<T>(pub Matrix<T>, pub T);
+
+macro_rules! impl_approx {
+    ($($t:ty)*) => {
+        $(
+            impl PartialEq<Matrix<$t>> for Approx<$t> {
+                fn eq(&self, other: &Matrix<$t>) -> bool {
+                    let Approx(expected, tolerance) = self;
+                    expected.shape() == other.shape()
+                        && expected
+                            .iter_rows()
+                            .flatten()
+                            .zip(other.iter_rows().flatten())
+                            .all(|(x, y)| (x - y).abs() <= *tolerance)
+                }
+            }
+
+            impl PartialEq<Approx<$t>> for Matrix<$t> {
+                fn eq(&self, other: &Approx<$t>) -> bool {
+                    other == self
+                }
+            }
+        )*
+    };
+}
+
+impl_approx! {f32 f64}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_approx() {
+        let expected: Matrix<f64> = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let mut actual: Matrix<f64> = matrix![[1.0 + 1e-10, 2.0, 3.0], [4.0, 5.0, 6.0 - 1e-10]];
+
+        for _ in 0..2 {
+            assert_eq!(Approx(expected.clone(), 1e-9), actual);
+            assert_eq!(actual, Approx(expected.clone(), 1e-9));
+            assert_ne!(Approx(expected.clone(), 1e-11), actual);
+            actual.switch_order();
+        }
+
+        let mut transposed = expected.clone();
+        transposed.transpose();
+        assert_ne!(Approx(expected, 1.0), transposed);
+
+        let nan: Matrix<f32> = matrix![[f32::NAN]];
+        assert_ne!(Approx(nan.clone(), f32::INFINITY), nan);
+    }
+}
@@ -1,6 +1,368 @@
+use super::shape::Shape;
+use crate::error::Error;
+use alloc::format;
+use alloc::string::String;
+use core::any::type_name;
+
+/// Implements scalar arithmetic for primitive types, the same way the
+/// exported `impl_scalar_*!` macros do, but copying elements and scalars
+/// instead of cloning them, and updating owned matrices in place.
+macro_rules! impl_scalar_copy {
+    ($trait:ident, $method:ident, $op:tt, $assign_trait:ident, $assign_method:ident, $assign_op:tt; $($t:ty)*) => {
+        $(
+            impl core::ops::$trait<$t> for $crate::matrix::Matrix<$t> {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(mut self, rhs: $t) -> Self::Output {
+                    self $assign_op rhs;
+                    self
+                }
+            }
+
+            impl core::ops::$trait<&$t> for $crate::matrix::Matrix<$t> {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: &$t) -> Self::Output {
+                    self $op *rhs
+                }
+            }
+
+            impl core::ops::$trait<$t> for &$crate::matrix::Matrix<$t> {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: $t) -> Self::Output {
+                    self.scalar_operation(rhs, |&element, &scalar| element $op scalar)
+                }
+            }
+
+            impl core::ops::$trait<&$t> for &$crate::matrix::Matrix<$t> {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: &$t) -> Self::Output {
+                    self $op *rhs
+                }
+            }
+
+            impl core::ops::$trait<$t> for $crate::matrix::Matrix<&$t> {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: $t) -> Self::Output {
+                    self.scalar_operation_consume_self(rhs, |&element, &scalar| element $op scalar)
+                }
+            }
+
+            impl core::ops::$trait<&$t> for $crate::matrix::Matrix<&$t> {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: &$t) -> Self::Output {
+                    self $op *rhs
+                }
+            }
+
+            impl core::ops::$trait<$t> for &$crate::matrix::Matrix<&$t> {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: $t) -> Self::Output {
+                    self.scalar_operation(rhs, |&&element, &scalar| element $op scalar)
+                }
+            }
+
+            impl core::ops::$trait<&$t> for &$crate::matrix::Matrix<&$t> {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: &$t) -> Self::Output {
+                    self $op *rhs
+                }
+            }
+
+            impl core::ops::$trait<$crate::matrix::Matrix<$t>> for $t {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, mut rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
+                    rhs.scalar_operation_assign(self, |element, &scalar| {
+                        *element = core::ops::$trait::$method(scalar, *element)
+                    });
+                    rhs
+                }
+            }
+
+            impl core::ops::$trait<&$crate::matrix::Matrix<$t>> for $t {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
+                    rhs.scalar_operation(self, |&element, &scalar| scalar $op element)
+                }
+            }
+
+            impl core::ops::$trait<$crate::matrix::Matrix<&$t>> for $t {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
+                    rhs.scalar_operation_consume_self(self, |&element, &scalar| scalar $op element)
+                }
+            }
+
+            impl core::ops::$trait<&$crate::matrix::Matrix<&$t>> for $t {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
+                    rhs.scalar_operation(self, |&&element, &scalar| scalar $op element)
+                }
+            }
+
+            impl core::ops::$trait<$crate::matrix::Matrix<$t>> for &$t {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: $crate::matrix::Matrix<$t>) -> Self::Output {
+                    *self $op rhs
+                }
+            }
+
+            impl core::ops::$trait<&$crate::matrix::Matrix<$t>> for &$t {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: &$crate::matrix::Matrix<$t>) -> Self::Output {
+                    *self $op rhs
+                }
+            }
+
+            impl core::ops::$trait<$crate::matrix::Matrix<&$t>> for &$t {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: $crate::matrix::Matrix<&$t>) -> Self::Output {
+                    *self $op rhs
+                }
+            }
+
+            impl core::ops::$trait<&$crate::matrix::Matrix<&$t>> for &$t {
+                type Output = $crate::matrix::Matrix<$t>;
+
+                fn $method(self, rhs: &$crate::matrix::Matrix<&$t>) -> Self::Output {
+                    *self $op rhs
+                }
+            }
+
+            impl core::ops::$assign_trait<$t> for $crate::matrix::Matrix<$t> {
+                fn $assign_method(&mut self, rhs: $t) {
+                    self.scalar_operation_assign(rhs, |element, &scalar| *element $assign_op scalar);
+                }
+            }
+
+            impl core::ops::$assign_trait<&$t> for $crate::matrix::Matrix<$t> {
+                fn $assign_method(&mut self, rhs: &$t) {
+                    *self $assign_op *rhs;
+                }
+            }
+        )*
+    };
+}
+
 mod add;
+pub(super) mod copy;
 mod div;
+mod fused;
 mod mul;
 mod neg;
+mod overflow;
 mod rem;
+mod strassen;
 mod sub;
+
+/// Panics with `error`, describing the operation, both shapes and
+/// element types, and suggesting a fix if it is caused by
+/// non-conformable operands of an elementwise operation.
+fn elementwise_operation_panic<L, R>(op: &str, lhs: Shape, rhs: Shape, error: Error) -> ! {
+    let not_conformable = matches!(error, Error::NotConformable { .. });
+    let hint = if not_conformable && lhs == transposed(rhs) {
+        "; did you mean to transpose rhs?"
+    } else {
+        ""
+    };
+    let operands = describe_operands::<L, R>(lhs, rhs);
+    let error = error.summary();
+    panic!("{error} for elementwise {op}: {operands}{hint}");
+}
+
+/// Panics with `error`, describing both shapes and element types, and
+/// suggesting a fix if it is caused by non-conformable operands of
+/// matrix multiplication.
+fn multiplication_panic<L, R>(lhs: Shape, rhs: Shape, error: Error) -> ! {
+    let hint = if !matches!(error, Error::NotConformable { .. }) {
+        ""
+    } else if lhs.ncols == rhs.ncols {
+        "; did you mean to transpose rhs?"
+    } else if lhs.nrows == rhs.nrows {
+        "; did you mean to transpose lhs?"
+    } else if lhs.nrows == rhs.ncols {
+        "; did you mean to swap lhs and rhs?"
+    } else {
+        ""
+    };
+    let operands = describe_operands::<L, R>(lhs, rhs);
+    let error = error.summary();
+    panic!("{error} for matrix multiplication: {operands}{hint}");
+}
+
+fn describe_operands<L, R>(lhs: Shape, rhs: Shape) -> String {
+    let (lhs_type, rhs_type) = (type_name::<L>(), type_name::<R>());
+    format!("lhs is {lhs} Matrix<{lhs_type}>, rhs is {rhs} Matrix<{rhs_type}>")
+}
+
+fn transposed(shape: Shape) -> Shape {
+    Shape::new(shape.ncols, shape.nrows)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix;
+    use crate::matrix::order::Order;
+    use crate::matrix::Matrix;
+
+    #[test]
+    #[should_panic(expected = "shape not conformable for elementwise addition: \
+                               lhs is (2, 3) Matrix<i32>, rhs is (3, 2) Matrix<i32>; \
+                               did you mean to transpose rhs?")]
+    fn test_add_panic_message() {
+        let _ = matrix![[0, 1, 2], [3, 4, 5]] + matrix![[0, 1], [2, 3], [4, 5]];
+    }
+
+    #[test]
+    #[should_panic(expected = "shape not conformable for elementwise subtraction: \
+                               lhs is (1, 3) Matrix<i32>, rhs is (2, 3) Matrix<i32>")]
+    fn test_sub_assign_panic_message() {
+        let mut lhs = matrix![[0, 1, 2]];
+        lhs -= matrix![[0, 1, 2], [3, 4, 5]];
+    }
+
+    #[test]
+    #[should_panic(expected = "shape not conformable for elementwise division: \
+                               lhs is (2, 3) Matrix<i32>, rhs is (3, 2) Matrix<i32>; \
+                               did you mean to transpose rhs?")]
+    fn test_div_panic_message() {
+        let _ = matrix![[0, 1, 2], [3, 4, 5]] / matrix![[1, 1], [1, 1], [1, 1]];
+    }
+
+    #[test]
+    #[should_panic(expected = "shape not conformable for elementwise remainder: \
+                               lhs is (1, 3) Matrix<i32>, rhs is (2, 3) Matrix<i32>")]
+    fn test_rem_assign_panic_message() {
+        let mut lhs = matrix![[0, 1, 2]];
+        lhs %= matrix![[1, 1, 1], [1, 1, 1]];
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let mut lhs = matrix![[0, 7, -7], [9, 10, 11]];
+        let mut rhs = matrix![[1, 2, 2], [4, 5, 3]];
+        let quotient = matrix![[0, 3, -3], [2, 2, 3]];
+        let remainder = matrix![[0, 1, -1], [1, 0, 2]];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                for (output, expected) in [
+                    (lhs.clone() / rhs.clone(), &quotient),
+                    (lhs.clone() / &rhs, &quotient),
+                    (&lhs / rhs.clone(), &quotient),
+                    (&lhs / &rhs, &quotient),
+                    (lhs.clone() % rhs.clone(), &remainder),
+                    (lhs.clone() % &rhs, &remainder),
+                    (&lhs % rhs.clone(), &remainder),
+                    (&lhs % &rhs, &remainder),
+                ] {
+                    let mut output = output;
+                    assert_eq!(output.order(), lhs.order());
+                    output.set_order(Order::RowMajor);
+                    assert_eq!(&output, expected);
+                }
+
+                let mut output = lhs.clone();
+                output /= &rhs;
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, quotient);
+
+                let mut output = lhs.clone();
+                output %= rhs.clone();
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, remainder);
+
+                rhs.switch_order();
+            }
+            lhs.switch_order();
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "shape not conformable for matrix multiplication: \
+                               lhs is (3, 2) Matrix<i32>, rhs is (3, 2) Matrix<i32>; \
+                               did you mean to transpose rhs?")]
+    fn test_mul_panic_message_transpose_rhs() {
+        let lhs = matrix![[0, 1], [2, 3], [4, 5]];
+        let _ = &lhs * &lhs;
+    }
+
+    #[test]
+    #[should_panic(expected = "shape not conformable for matrix multiplication: \
+                               lhs is (3, 2) Matrix<i32>, rhs is (3, 4) Matrix<i32>; \
+                               did you mean to transpose lhs?")]
+    fn test_mul_panic_message_transpose_lhs() {
+        let _ = matrix![[0; 2]; 3] * matrix![[0; 4]; 3];
+    }
+
+    #[test]
+    #[should_panic(expected = "shape not conformable for matrix multiplication: \
+                               lhs is (2, 4) Matrix<i32>, rhs is (3, 2) Matrix<i32>; \
+                               did you mean to swap lhs and rhs?")]
+    fn test_mul_panic_message_swap() {
+        let _ = matrix![[0; 4]; 2] * matrix![[0; 2]; 3];
+    }
+
+    #[test]
+    #[should_panic(expected = "shape not conformable for matrix multiplication: \
+                               lhs is (1, 2) Matrix<f64>, rhs is (3, 1) Matrix<f64>")]
+    fn test_mul_panic_message_element_type() {
+        let _ = matrix![[0.0, 1.0]] * matrix![[0.0], [1.0], [2.0]];
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_scalar_copy() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        let mut lhs = matrix![[-2, -1, 0], [1, 2, 3]];
+        let mut rhs = matrix![[2, 1, 0], [-1, -2, -3]];
+
+        for _ in 0..2 {
+            let view = Matrix {
+                order: matrix.order,
+                shape: matrix.shape,
+                data: matrix.data.iter().collect(),
+            };
+            assert_eq!(matrix.clone() - 2, lhs);
+            assert_eq!(matrix.clone() - &2, lhs);
+            assert_eq!(&matrix - 2, lhs);
+            assert_eq!(&matrix - &2, lhs);
+            assert_eq!(&view - 2, lhs);
+            assert_eq!(&view - &2, lhs);
+            assert_eq!(view.clone() - 2, lhs);
+            assert_eq!(view.clone() - &2, lhs);
+            assert_eq!(2 - matrix.clone(), rhs);
+            assert_eq!(2 - &matrix, rhs);
+            assert_eq!(&2 - matrix.clone(), rhs);
+            assert_eq!(&2 - &matrix, rhs);
+            assert_eq!(2 - &view, rhs);
+            assert_eq!(&2 - &view, rhs);
+            assert_eq!(2 - view.clone(), rhs);
+            assert_eq!(&2 - view, rhs);
+
+            let mut output = matrix.clone();
+            output -= 2;
+            assert_eq!(output, lhs);
+            let mut output = matrix.clone();
+            output -= &2;
+            assert_eq!(output, lhs);
+
+            matrix.switch_order();
+            lhs.switch_order();
+            rhs.switch_order();
+        }
+    }
+}
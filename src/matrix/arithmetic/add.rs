@@ -1,7 +1,7 @@
 use super::super::Matrix;
+use super::elementwise_operation_panic;
 use crate::error::Result;
-use crate::impl_scalar_add;
-use std::ops::{Add, AddAssign};
+use core::ops::{Add, AddAssign};
 
 impl<L, R, U> Add<Matrix<R>> for Matrix<L>
 where
@@ -23,8 +23,11 @@ where
     type Output = Matrix<U>;
 
     fn add(self, rhs: &Matrix<R>) -> Self::Output {
+        let shape = self.shape();
         match self.elementwise_add_consume_self(rhs) {
-            Err(error) => panic!("{error}"),
+            Err(error) => {
+                elementwise_operation_panic::<L, R>("addition", shape, rhs.shape(), error)
+            }
             Ok(output) => output,
         }
     }
@@ -51,7 +54,9 @@ where
 
     fn add(self, rhs: &Matrix<R>) -> Self::Output {
         match self.elementwise_add(rhs) {
-            Err(error) => panic!("{error}"),
+            Err(error) => {
+                elementwise_operation_panic::<L, R>("addition", self.shape(), rhs.shape(), error)
+            }
             Ok(output) => output,
         }
     }
@@ -74,7 +79,7 @@ where
 {
     fn add_assign(&mut self, rhs: &Matrix<R>) {
         if let Err(error) = self.elementwise_add_assign(rhs) {
-            panic!("{error}");
+            elementwise_operation_panic::<L, R>("addition", self.shape(), rhs.shape(), error);
         }
     }
 }
@@ -169,6 +174,73 @@ impl<L> Matrix<L> {
     {
         self.elementwise_operation_assign(rhs, |(left, right)| *left += right.clone())
     }
+
+    /// Performs addition on two matrices, returning an error instead of
+    /// panicking if they are not conformable.
+    ///
+    /// This is the non-panicking counterpart of the `+` operator.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape};
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[2, 2, 2], [2, 2, 2]];
+    ///
+    /// let result = lhs.checked_add(&rhs);
+    /// assert_eq!(result, Ok(matrix![[2, 3, 4], [5, 6, 7]]));
+    ///
+    /// let rhs = matrix![[2, 2], [2, 2]];
+    /// let result = lhs.checked_add(&rhs);
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(2, 3),
+    ///     rhs: Shape::new(2, 2),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn checked_add<R, U>(&self, rhs: &Matrix<R>) -> Result<Matrix<U>>
+    where
+        L: Add<R, Output = U> + Clone,
+        R: Clone,
+    {
+        self.elementwise_add(rhs)
+    }
+
+    /// Performs scalar addition on the matrix, returning a [`Result`] for
+    /// uniformity with [`Matrix::checked_add`].
+    ///
+    /// # Errors
+    ///
+    /// This method never fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let result = matrix.checked_add_scalar(&2);
+    /// assert_eq!(result, Ok(matrix![[2, 3, 4], [5, 6, 7]]));
+    /// ```
+    pub fn checked_add_scalar<S, U>(&self, scalar: &S) -> Result<Matrix<U>>
+    where
+        L: Add<S, Output = U> + Clone,
+        S: Clone,
+    {
+        Ok(self.scalar_operation(scalar, |element, scalar| element.clone() + scalar.clone()))
+    }
 }
 
-impl_scalar_add! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
+impl_scalar_copy! {Add, add, +, AddAssign, add_assign, +=; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
@@ -0,0 +1,533 @@
+//! Fast paths for elementwise, scalar and matrix multiplication
+//! operations on primitive numeric types.
+//!
+//! The operations themselves are generic, so each hook here checks
+//! whether the element types happen to be one and the same primitive,
+//! and the operands one and the same order, and if so runs the
+//! operation as a loop over plain slices, copying elements instead of
+//! cloning them. Otherwise it hands the operation back to the generic
+//! code.
+//!
+//! With the `simd` feature, the loops are also compiled for AVX2 and
+//! picked at runtime, and matrix multiplication sums its products in
+//! lanes.
+
+use super::super::Matrix;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use core::ops::{Add, Mul};
+use core::ptr;
+
+/// Number of partial sums in a dot product, which fills at least one
+/// 256-bit register for every supported element type.
+#[cfg(feature = "simd")]
+const LANES: usize = 8;
+
+/// A primitive numeric type with its own kernels.
+///
+/// Implemented only for types without lifetimes, so that comparing
+/// [`TypeId`]s against one is exact even for non-`'static` types.
+trait Lane: Copy + Default + Add<Output = Self> + Mul<Output = Self> + 'static {}
+
+/// Runs `$body` once for each lane type, with `$lane` naming it.
+macro_rules! for_each_lane {
+    ($lane:ident => $body:block) => {
+        for_each_lane!(@ $lane $body; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64)
+    };
+    (@ $lane:ident $body:block; $($t:ty)*) => {
+        $({
+            type $lane = $t;
+            $body
+        })*
+    };
+}
+
+macro_rules! impl_lane {
+    ($($t:ty)*) => {
+        $(
+            impl Lane for $t {}
+        )*
+    };
+}
+
+impl_lane! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
+
+/// Defines the kernels as plain loops over slices, which the compiler
+/// turns into vector instructions for whatever target features the
+/// enclosing module enables.
+macro_rules! kernels {
+    ($(#[$attr:meta])* $($unsafety:ident)?) => {
+        use super::Lane;
+        #[cfg(feature = "simd")]
+        use super::LANES;
+        use alloc::vec::Vec;
+
+        /// Applies `op` to each pair of elements of `lhs` and `rhs`.
+        $(#[$attr])*
+        pub(super) $($unsafety)? fn zip_map<T, F>(lhs: &[T], rhs: &[T], mut op: F) -> Vec<T>
+        where
+            T: Lane,
+            F: FnMut(T, T) -> T,
+        {
+            lhs.iter().zip(rhs).map(|(&x, &y)| op(x, y)).collect()
+        }
+
+        /// Applies `op` to each element of `lhs` in place, along with the
+        /// corresponding element of `rhs`.
+        $(#[$attr])*
+        pub(super) $($unsafety)? fn zip_apply<T, F>(lhs: &mut [T], rhs: &[T], mut op: F)
+        where
+            T: Lane,
+            F: FnMut(&mut T, T),
+        {
+            lhs.iter_mut().zip(rhs).for_each(|(x, &y)| op(x, y));
+        }
+
+        /// Applies `op` to each element of `data` and `scalar`.
+        $(#[$attr])*
+        pub(super) $($unsafety)? fn map<T, F>(data: &[T], scalar: T, mut op: F) -> Vec<T>
+        where
+            T: Lane,
+            F: FnMut(T, T) -> T,
+        {
+            data.iter().map(|&x| op(x, scalar)).collect()
+        }
+
+        /// Applies `op` to each element of `data` in place, along with
+        /// `scalar`.
+        $(#[$attr])*
+        pub(super) $($unsafety)? fn apply<T, F>(data: &mut [T], scalar: T, mut op: F)
+        where
+            T: Lane,
+            F: FnMut(&mut T, T),
+        {
+            data.iter_mut().for_each(|x| op(x, scalar));
+        }
+
+        /// Returns the dot product of `lhs` and `rhs`, summing each lane
+        /// separately so that the additions need not happen in order.
+        #[cfg(feature = "simd")]
+        $(#[$attr])*
+        pub(super) $($unsafety)? fn dot<T: Lane>(lhs: &[T], rhs: &[T]) -> T {
+            let mut sums = [T::default(); LANES];
+            let mut lhs = lhs.chunks_exact(LANES);
+            let mut rhs = rhs.chunks_exact(LANES);
+            for (lhs, rhs) in (&mut lhs).zip(&mut rhs) {
+                for lane in 0..LANES {
+                    sums[lane] = sums[lane] + lhs[lane] * rhs[lane];
+                }
+            }
+            let mut sum = T::default();
+            for (&x, &y) in lhs.remainder().iter().zip(rhs.remainder()) {
+                sum = sum + x * y;
+            }
+            sums.into_iter().fold(sum, |sum, x| sum + x)
+        }
+    };
+}
+
+mod portable {
+    kernels! {}
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod avx2 {
+    kernels! { #[target_feature(enable = "avx2")] unsafe }
+}
+
+/// Calls the AVX2 build of `kernel` if the `simd` feature is enabled and
+/// the CPU supports it, and the portable one otherwise.
+macro_rules! dispatch {
+    ($kernel:ident($($arg:expr),* $(,)?)) => {{
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if std::is_x86_feature_detected!("avx2") {
+            // SAFETY: AVX2 is supported by the running CPU.
+            return unsafe { avx2::$kernel($($arg),*) };
+        }
+        portable::$kernel($($arg),*)
+    }};
+}
+
+fn zip_map<T: Lane, F: FnMut(T, T) -> T>(lhs: &[T], rhs: &[T], op: F) -> Vec<T> {
+    dispatch!(zip_map(lhs, rhs, op))
+}
+
+fn zip_apply<T: Lane, F: FnMut(&mut T, T)>(lhs: &mut [T], rhs: &[T], op: F) {
+    dispatch!(zip_apply(lhs, rhs, op))
+}
+
+fn map<T: Lane, F: FnMut(T, T) -> T>(data: &[T], scalar: T, op: F) -> Vec<T> {
+    dispatch!(map(data, scalar, op))
+}
+
+fn apply<T: Lane, F: FnMut(&mut T, T)>(data: &mut [T], scalar: T, op: F) {
+    dispatch!(apply(data, scalar, op))
+}
+
+#[cfg(feature = "simd")]
+fn dot_kernel<T: Lane>(lhs: &[T], rhs: &[T]) -> T {
+    dispatch!(dot(lhs, rhs))
+}
+
+/// Object-safe access to [`TypeId`], implemented for types that need
+/// not be `'static`.
+trait NonStaticAny {
+    fn get_type_id(&self) -> TypeId
+    where
+        Self: 'static;
+}
+
+impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+    fn get_type_id(&self) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<T>()
+    }
+}
+
+/// Returns `true` if `A` is the lane type `T`.
+fn is<A, T: Lane>() -> bool {
+    let phantom = PhantomData::<A>;
+    let phantom: &dyn NonStaticAny = &phantom;
+    // SAFETY: Only lifetimes are extended, which `get_type_id` erases
+    // without touching `phantom`. Since `T` has no lifetimes, the ids
+    // are equal exactly when `A` is `T`.
+    let phantom: &(dyn NonStaticAny + 'static) = unsafe { mem::transmute(phantom) };
+    phantom.get_type_id() == TypeId::of::<T>()
+}
+
+/// Moves `value` into type `B`.
+///
+/// # Safety
+///
+/// `A` and `B` must be the same type, up to lifetimes.
+unsafe fn cast<A, B>(value: A) -> B {
+    let value = ManuallyDrop::new(value);
+    unsafe { ptr::read(&*value as *const A as *const B) }
+}
+
+/// Performs [`Matrix::elementwise_operation`] with the copy kernels, if
+/// `L`, `R` and `U` are the same lane type.
+///
+/// The matrices must be conformable. Operands of different orders are
+/// left to the generic code, which reads `rhs` with a stride instead of
+/// copying it.
+pub(crate) fn elementwise<L, R, U, F>(
+    lhs: &Matrix<L>,
+    rhs: &Matrix<R>,
+    op: &mut F,
+) -> Option<Matrix<U>>
+where
+    F: FnMut((&L, &R)) -> U,
+{
+    if lhs.order != rhs.order {
+        return None;
+    }
+    for_each_lane!(T => {
+        if is::<L, T>() && is::<R, T>() && is::<U, T>() {
+            // SAFETY: `L`, `R` and `U` are all `T`.
+            return Some(unsafe {
+                let lhs: &Matrix<T> = cast(lhs);
+                let rhs: &Matrix<T> = cast(rhs);
+                let data = zip_map(&lhs.data, &rhs.data, |x, y| cast(op((cast(&x), cast(&y)))));
+                let output = Matrix {
+                    order: lhs.order,
+                    shape: lhs.shape,
+                    data,
+                };
+                cast(output)
+            });
+        }
+    });
+    None
+}
+
+/// Performs [`Matrix::elementwise_operation_consume_self`] with the copy
+/// kernels, if `L`, `R` and `U` are the same lane type, or hands `lhs`
+/// back otherwise.
+///
+/// The matrices must be conformable, and are left to the generic code
+/// if their orders differ.
+pub(crate) fn elementwise_consume_self<L, R, U, F>(
+    lhs: Matrix<L>,
+    rhs: &Matrix<R>,
+    op: &mut F,
+) -> Result<Matrix<U>, Matrix<L>>
+where
+    F: FnMut((L, &R)) -> U,
+{
+    if lhs.order != rhs.order {
+        return Err(lhs);
+    }
+    for_each_lane!(T => {
+        if is::<L, T>() && is::<R, T>() && is::<U, T>() {
+            // SAFETY: `L`, `R` and `U` are all `T`.
+            return Ok(unsafe {
+                let mut lhs: Matrix<T> = cast(lhs);
+                let rhs: &Matrix<T> = cast(rhs);
+                zip_apply(&mut lhs.data, &rhs.data, |x, y| *x = cast(op((cast(*x), cast(&y)))));
+                cast(lhs)
+            });
+        }
+    });
+    Err(lhs)
+}
+
+/// Performs [`Matrix::elementwise_operation_assign`] with the copy
+/// kernels, if `L` and `R` are the same lane type, returning whether it
+/// did.
+///
+/// The matrices must be conformable, and are left to the generic code
+/// if their orders differ.
+pub(crate) fn elementwise_assign<L, R, F>(lhs: &mut Matrix<L>, rhs: &Matrix<R>, op: &mut F) -> bool
+where
+    F: FnMut((&mut L, &R)),
+{
+    if lhs.order != rhs.order {
+        return false;
+    }
+    for_each_lane!(T => {
+        if is::<L, T>() && is::<R, T>() {
+            // SAFETY: `L` and `R` are both `T`.
+            unsafe {
+                let lhs: &mut Matrix<T> = cast(lhs);
+                let rhs: &Matrix<T> = cast(rhs);
+                zip_apply(&mut lhs.data, &rhs.data, |x, y| op((cast(x), cast(&y))));
+            }
+            return true;
+        }
+    });
+    false
+}
+
+/// Performs [`Matrix::scalar_operation`] with the copy kernels, if `L`,
+/// `S` and `U` are the same lane type.
+pub(crate) fn scalar<L, S, U, F>(matrix: &Matrix<L>, scalar: &S, op: &mut F) -> Option<Matrix<U>>
+where
+    F: FnMut(&L, &S) -> U,
+{
+    for_each_lane!(T => {
+        if is::<L, T>() && is::<S, T>() && is::<U, T>() {
+            // SAFETY: `L`, `S` and `U` are all `T`.
+            return Some(unsafe {
+                let matrix: &Matrix<T> = cast(matrix);
+                let scalar: &T = cast(scalar);
+                let data = map(&matrix.data, *scalar, |x, y| cast(op(cast(&x), cast(&y))));
+                let output = Matrix {
+                    order: matrix.order,
+                    shape: matrix.shape,
+                    data,
+                };
+                cast(output)
+            });
+        }
+    });
+    None
+}
+
+/// Performs [`Matrix::scalar_operation_consume_self`] with the copy
+/// kernels, if `L`, `S` and `U` are the same lane type, or hands
+/// `matrix` back otherwise.
+pub(crate) fn scalar_consume_self<L, S, U, F>(
+    matrix: Matrix<L>,
+    scalar: &S,
+    op: &mut F,
+) -> Result<Matrix<U>, Matrix<L>>
+where
+    F: FnMut(L, &S) -> U,
+{
+    for_each_lane!(T => {
+        if is::<L, T>() && is::<S, T>() && is::<U, T>() {
+            // SAFETY: `L`, `S` and `U` are all `T`.
+            return Ok(unsafe {
+                let mut matrix: Matrix<T> = cast(matrix);
+                let scalar: &T = cast(scalar);
+                apply(&mut matrix.data, *scalar, |x, y| *x = cast(op(cast(*x), cast(&y))));
+                cast(matrix)
+            });
+        }
+    });
+    Err(matrix)
+}
+
+/// Performs [`Matrix::scalar_operation_assign`] with the copy kernels,
+/// if `L` and `S` are the same lane type, returning whether it did.
+pub(crate) fn scalar_assign<L, S, F>(matrix: &mut Matrix<L>, scalar: &S, op: &mut F) -> bool
+where
+    F: FnMut(&mut L, &S),
+{
+    for_each_lane!(T => {
+        if is::<L, T>() && is::<S, T>() {
+            // SAFETY: `L` and `S` are both `T`.
+            unsafe {
+                let matrix: &mut Matrix<T> = cast(matrix);
+                let scalar: &T = cast(scalar);
+                apply(&mut matrix.data, *scalar, |x, y| op(cast(x), cast(&y)));
+            }
+            return true;
+        }
+    });
+    false
+}
+
+/// Returns the dot product of two equally long slices with the SIMD
+/// kernels, if `L`, `R` and `U` are the same lane type.
+///
+/// Float results may differ from summing the products in order in the
+/// last bits.
+#[cfg(feature = "simd")]
+pub(crate) fn dot<L, R, U>(lhs: &[L], rhs: &[R]) -> Option<U> {
+    for_each_lane!(T => {
+        if is::<L, T>() && is::<R, T>() && is::<U, T>() {
+            // SAFETY: `L`, `R` and `U` are all `T`.
+            return Some(unsafe {
+                let lhs: &[T] = cast(lhs);
+                let rhs: &[T] = cast(rhs);
+                cast(dot_kernel(lhs, rhs))
+            });
+        }
+    });
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix;
+    use crate::matrix::order::Order;
+    use crate::matrix::Matrix;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_kernels_agree() {
+        let lhs: Vec<i64> = (0..37).map(|x| x * 7 - 100).collect();
+        let rhs: Vec<i64> = (0..37).map(|x| 50 - x * 3).collect();
+        let expected: Vec<i64> = lhs.iter().zip(&rhs).map(|(x, y)| x * y).collect();
+
+        assert_eq!(super::portable::zip_map(&lhs, &rhs, |x, y| x * y), expected);
+        assert_eq!(super::zip_map(&lhs, &rhs, |x, y| x * y), expected);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_dot_kernels_agree() {
+        let lhs: Vec<i64> = (0..37).map(|x| x * 7 - 100).collect();
+        let rhs: Vec<i64> = (0..37).map(|x| 50 - x * 3).collect();
+        let expected = lhs.iter().zip(&rhs).map(|(x, y)| x * y).sum::<i64>();
+
+        assert_eq!(super::portable::dot(&lhs, &rhs), expected);
+        assert_eq!(super::dot_kernel(&lhs, &rhs), expected);
+    }
+
+    #[test]
+    fn test_is() {
+        assert!(super::is::<f64, f64>());
+        assert!(!super::is::<f32, f64>());
+        assert!(!super::is::<&f64, f64>());
+        assert!(!super::is::<Matrix<f64>, f64>());
+    }
+
+    #[test]
+    fn test_elementwise() {
+        let mut lhs: Matrix<f64> = Matrix::new((3, 11));
+        for (index, element) in lhs.iter_elements_mut_with_index() {
+            *element = (index.row * 11 + index.col) as f64;
+        }
+        let mut rhs = &lhs * 0.5;
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                let mut expected = lhs.clone();
+                for (index, element) in expected.iter_elements_mut_with_index() {
+                    *element += rhs[index];
+                }
+                let output = &lhs + &rhs;
+                assert_eq!(output.order(), lhs.order());
+                assert_eq!(output, expected);
+                assert_eq!(lhs.clone() + &rhs, expected);
+                let mut output = lhs.clone();
+                output += &rhs;
+                assert_eq!(output, expected);
+
+                let output = lhs.elementwise_operation(&rhs, |(x, y)| x * y).unwrap();
+                for (index, element) in output.iter_elements_with_index() {
+                    assert_eq!(*element, lhs[index] * rhs[index]);
+                }
+                rhs.switch_order();
+            }
+            lhs.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_elementwise_non_lane() {
+        let lhs: Matrix<i32> = matrix![[0, 1, 2], [3, 4, 5]];
+        let rhs: Matrix<i64> = matrix![[2, 2, 2], [2, 2, 2]];
+        let output = lhs.elementwise_operation(&rhs, |(&x, &y)| x as i64 + y);
+        assert_eq!(output, Ok(matrix![[2, 3, 4], [5, 6, 7]]));
+
+        let lhs: Matrix<&i32> = matrix![[&0, &1, &2], [&3, &4, &5]];
+        assert_eq!(&lhs * 2, matrix![[0, 2, 4], [6, 8, 10]]);
+    }
+
+    #[test]
+    fn test_scalar() {
+        let mut matrix: Matrix<i32> = Matrix::new((5, 7));
+        for (index, element) in matrix.iter_elements_mut_with_index() {
+            *element = (index.row * 7 + index.col) as i32 - 10;
+        }
+
+        for _ in 0..2 {
+            let expected = matrix.clone().map(|x| x * -2);
+            let output = &matrix * -2;
+            assert_eq!(output.order(), matrix.order());
+            assert_eq!(output, expected);
+            assert_eq!(matrix.clone() * -2, expected);
+            let mut output = matrix.clone();
+            output *= -2;
+            assert_eq!(output, expected);
+            assert_eq!(3 - &matrix, matrix.clone().map(|x| 3 - x));
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<f32>::new((0, 3));
+        assert_eq!(&matrix * 2.0, Matrix::new((0, 3)));
+    }
+
+    #[test]
+    fn test_mat_mul() {
+        let mut lhs: Matrix<f64> = Matrix::new((5, 19));
+        for (index, element) in lhs.iter_elements_mut_with_index() {
+            *element = ((index.row * 3 + index.col * 5) % 7) as f64 - 3.0;
+        }
+        let mut rhs: Matrix<f64> = Matrix::new((19, 4));
+        for (index, element) in rhs.iter_elements_mut_with_index() {
+            *element = ((index.row + index.col * 2) % 5) as f64;
+        }
+        let mut expected: Matrix<f64> = Matrix::new((5, 4));
+        for (index, element) in expected.iter_elements_mut_with_index() {
+            *element = (0..19)
+                .map(|k| lhs[(index.row, k)] * rhs[(k, index.col)])
+                .sum();
+        }
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                let mut output = lhs.clone().mat_mul(rhs.clone()).unwrap();
+                assert_eq!(output.order(), lhs.order());
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, expected);
+                rhs.switch_order();
+            }
+            lhs.switch_order();
+        }
+
+        let mut vector: Matrix<i32> = Matrix::new((1, 19));
+        for (index, element) in vector.iter_elements_mut_with_index() {
+            *element = index.col as i32 - 9;
+        }
+        assert_eq!(vector.dot(&vector), Ok(570));
+    }
+}
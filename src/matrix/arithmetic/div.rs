@@ -1,7 +1,88 @@
 use super::super::Matrix;
+use super::elementwise_operation_panic;
 use crate::error::Result;
-use crate::impl_scalar_div;
-use std::ops::{Div, DivAssign};
+use core::ops::{Div, DivAssign};
+
+impl<L, R, U> Div<Matrix<R>> for Matrix<L>
+where
+    L: Div<R, Output = U>,
+    R: Clone,
+{
+    type Output = Matrix<U>;
+
+    fn div(self, rhs: Matrix<R>) -> Self::Output {
+        self / &rhs
+    }
+}
+
+impl<L, R, U> Div<&Matrix<R>> for Matrix<L>
+where
+    L: Div<R, Output = U>,
+    R: Clone,
+{
+    type Output = Matrix<U>;
+
+    fn div(self, rhs: &Matrix<R>) -> Self::Output {
+        let shape = self.shape();
+        match self.elementwise_div_consume_self(rhs) {
+            Err(error) => {
+                elementwise_operation_panic::<L, R>("division", shape, rhs.shape(), error)
+            }
+            Ok(output) => output,
+        }
+    }
+}
+
+impl<L, R, U> Div<Matrix<R>> for &Matrix<L>
+where
+    L: Div<R, Output = U> + Clone,
+    R: Clone,
+{
+    type Output = Matrix<U>;
+
+    fn div(self, rhs: Matrix<R>) -> Self::Output {
+        self / &rhs
+    }
+}
+
+impl<L, R, U> Div<&Matrix<R>> for &Matrix<L>
+where
+    L: Div<R, Output = U> + Clone,
+    R: Clone,
+{
+    type Output = Matrix<U>;
+
+    fn div(self, rhs: &Matrix<R>) -> Self::Output {
+        match self.elementwise_div(rhs) {
+            Err(error) => {
+                elementwise_operation_panic::<L, R>("division", self.shape(), rhs.shape(), error)
+            }
+            Ok(output) => output,
+        }
+    }
+}
+
+impl<L, R> DivAssign<Matrix<R>> for Matrix<L>
+where
+    L: DivAssign<R>,
+    R: Clone,
+{
+    fn div_assign(&mut self, rhs: Matrix<R>) {
+        *self /= &rhs
+    }
+}
+
+impl<L, R> DivAssign<&Matrix<R>> for Matrix<L>
+where
+    L: DivAssign<R>,
+    R: Clone,
+{
+    fn div_assign(&mut self, rhs: &Matrix<R>) {
+        if let Err(error) = self.elementwise_div_assign(rhs) {
+            elementwise_operation_panic::<L, R>("division", self.shape(), rhs.shape(), error);
+        }
+    }
+}
 
 impl<L> Matrix<L> {
     /// Performs elementwise division on two matrices.
@@ -14,6 +95,10 @@ impl<L> Matrix<L> {
     ///
     /// The resulting matrix will always have the same order as `self`.
     ///
+    /// This is what the `/` operator does for two matrices, except that
+    /// the operator panics if they are not conformable. Unlike `*`, which
+    /// performs matrix multiplication, `/` is always elementwise.
+    ///
     /// # Examples
     ///
     /// ```
@@ -125,4 +210,4 @@ impl<L> Matrix<L> {
     }
 }
 
-impl_scalar_div! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
+impl_scalar_copy! {Div, div, /, DivAssign, div_assign, /=; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
@@ -0,0 +1,257 @@
+use super::super::order::Order;
+use super::super::Matrix;
+use crate::error::Result;
+use core::ops::{AddAssign, Mul, MulAssign};
+
+impl<L> Matrix<L> {
+    /// Adds `alpha * x` to the matrix in place, in a single pass.
+    ///
+    /// This avoids the two temporaries that `self + alpha * x` would
+    /// otherwise allocate.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut y = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let x = matrix![[1, 1, 1], [2, 2, 2]];
+    ///
+    /// y.axpy(&2, &x).unwrap();
+    /// assert_eq!(y, matrix![[2, 3, 4], [7, 8, 9]]);
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn axpy<S, R, U>(&mut self, alpha: &S, x: &Matrix<R>) -> Result<&mut Self>
+    where
+        L: AddAssign<U>,
+        S: Mul<R, Output = U> + Clone,
+        R: Clone,
+    {
+        self.elementwise_operation_assign(x, |(left, right)| *left += alpha.clone() * right.clone())
+    }
+
+    /// Adds the elementwise product of `a` and `b` to the matrix in
+    /// place, in a single pass.
+    ///
+    /// This avoids the temporary that `self += &a.elementwise_mul(&b)?`
+    /// would otherwise allocate.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let a = matrix![[1, 1, 1], [2, 2, 2]];
+    /// let b = matrix![[1, 2, 3], [1, 2, 3]];
+    ///
+    /// matrix.fma_assign(&a, &b).unwrap();
+    /// assert_eq!(matrix, matrix![[1, 3, 5], [5, 8, 11]]);
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn fma_assign<A, B, U>(&mut self, a: &Matrix<A>, b: &Matrix<B>) -> Result<&mut Self>
+    where
+        L: AddAssign<U>,
+        A: Mul<B, Output = U> + Clone,
+        B: Clone,
+    {
+        self.ensure_elementwise_operation_conformable(a)?;
+        self.ensure_elementwise_operation_conformable(b)?;
+
+        let order = self.order;
+        let shape = self.shape;
+        let locate = |other: Order, index: usize| {
+            if other == order {
+                index
+            } else {
+                Self::reindex_to_different_order_unchecked(index, shape)
+            }
+        };
+        for (index, element) in self.data.iter_mut().enumerate() {
+            let x = locate(a.order, index);
+            let y = locate(b.order, index);
+            debug_assert!(x < a.data.len() && y < b.data.len());
+            let x = unsafe { a.data.get_unchecked(x) };
+            let y = unsafe { b.data.get_unchecked(y) };
+            *element += x.clone() * y.clone();
+        }
+
+        Ok(self)
+    }
+
+    /// Adds `a * k` to the matrix in place, in a single pass.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// Elements of `a` are multiplied on the right by `k`, which only
+    /// matters for non-commutative element types. See [`Matrix::axpy`]
+    /// for multiplication on the left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let a = matrix![[1, 1, 1], [2, 2, 2]];
+    ///
+    /// matrix.fma_assign_scalar(&a, &3).unwrap();
+    /// assert_eq!(matrix, matrix![[3, 4, 5], [9, 10, 11]]);
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn fma_assign_scalar<A, S, U>(&mut self, a: &Matrix<A>, k: &S) -> Result<&mut Self>
+    where
+        L: AddAssign<U>,
+        A: Mul<S, Output = U> + Clone,
+        S: Clone,
+    {
+        self.elementwise_operation_assign(a, |(left, right)| *left += right.clone() * k.clone())
+    }
+
+    /// Replaces each element `x` of the matrix with `x * alpha + beta`
+    /// in place, in a single pass.
+    ///
+    /// # Notes
+    ///
+    /// Elements are multiplied on the right by `alpha`, which only
+    /// matters for non-commutative element types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.scale_add(&2, &1);
+    /// assert_eq!(matrix, matrix![[1, 3, 5], [7, 9, 11]]);
+    /// ```
+    pub fn scale_add<S, B>(&mut self, alpha: &S, beta: &B) -> &mut Self
+    where
+        L: MulAssign<S> + AddAssign<B>,
+        S: Clone,
+        B: Clone,
+    {
+        self.data.iter_mut().for_each(|element| {
+            *element *= alpha.clone();
+            *element += beta.clone();
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::matrix;
+    use crate::matrix::order::Order;
+    use crate::matrix::shape::Shape;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn test_axpy() {
+        let mut y = matrix![[0, 1, 2], [3, 4, 5]];
+        let x = matrix![[1, 1, 1], [2, 2, 2]];
+
+        for _ in 0..2 {
+            let mut output = y.clone();
+            output.axpy(&-3, &x).unwrap();
+            if output.order() != x.order() {
+                output.switch_order();
+            }
+            assert_eq!(output, matrix![[-3, -2, -1], [-3, -2, -1]]);
+
+            assert_eq!(
+                output.axpy(&1, &matrix![[1, 2], [3, 4]]),
+                Err(Error::NotConformable {
+                    lhs: Shape::new(2, 3),
+                    rhs: Shape::new(2, 2)
+                })
+            );
+
+            y.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_fma_assign() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        let mut a = matrix![[1, 1, 1], [2, 2, 2]];
+        let mut b = matrix![[1, 2, 3], [1, 2, 3]];
+        let expected = matrix![[1, 3, 5], [5, 8, 11]];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                for _ in 0..2 {
+                    let mut output = matrix.clone();
+                    output.fma_assign(&a, &b).unwrap();
+                    assert_eq!(output.order(), matrix.order());
+                    output.set_order(Order::RowMajor);
+                    assert_eq!(output, expected);
+                    b.switch_order();
+                }
+                a.switch_order();
+            }
+            matrix.switch_order();
+        }
+
+        let error = Error::NotConformable {
+            lhs: Shape::new(2, 3),
+            rhs: Shape::new(3, 2),
+        };
+        let wrong = Matrix::<i32>::new((3, 2));
+        assert_eq!(matrix.fma_assign(&wrong, &b), Err(error));
+        assert_eq!(matrix.fma_assign(&a, &wrong), Err(error));
+    }
+
+    #[test]
+    fn test_fma_assign_scalar() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        let a = matrix![[1, 1, 1], [2, 2, 2]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.fma_assign_scalar(&a, &3).unwrap();
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[3, 4, 5], [9, 10, 11]]);
+
+            matrix.switch_order();
+        }
+
+        assert_eq!(
+            matrix.fma_assign_scalar(&matrix![[1, 2], [3, 4]], &1),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(2, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_scale_add() {
+        let mut matrix = matrix![[0.5, 1.0], [1.5, 2.0]];
+
+        matrix.scale_add(&2.0, &-1.0);
+        assert_eq!(matrix, matrix![[0.0, 1.0], [2.0, 3.0]]);
+
+        matrix.switch_order();
+        matrix.scale_add(&0.0, &4.0);
+        matrix.switch_order();
+        assert_eq!(matrix, matrix![[4.0, 4.0], [4.0, 4.0]]);
+    }
+}
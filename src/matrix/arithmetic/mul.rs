@@ -1,10 +1,14 @@
+use super::super::axis::Axis;
 use super::super::iter::ExactSizeDoubleEndedIterator;
 use super::super::order::Order;
+use super::super::progress::ProgressSink;
 use super::super::shape::{AxisShape, Shape};
 use super::super::Matrix;
-use crate::error::Result;
-use crate::impl_scalar_mul;
-use std::ops::{Add, Mul, MulAssign};
+use super::multiplication_panic;
+use crate::error::{Error, Result};
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, MulAssign};
+use core::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 
 impl<L, R, U> Mul<Matrix<R>> for Matrix<L>
 where
@@ -15,8 +19,9 @@ where
     type Output = Matrix<U>;
 
     fn mul(self, rhs: Matrix<R>) -> Self::Output {
+        let (lhs_shape, rhs_shape) = (self.shape(), rhs.shape());
         match self.mat_mul(rhs) {
-            Err(error) => panic!("{error}"),
+            Err(error) => multiplication_panic::<L, R>(lhs_shape, rhs_shape, error),
             Ok(output) => output,
         }
     }
@@ -152,6 +157,95 @@ impl<L> Matrix<L> {
         self.elementwise_operation_assign(rhs, |(left, right)| *left *= right.clone())
     }
 
+    /// Multiplies each row of the matrix by the corresponding factor in
+    /// place, which is equivalent to multiplying by a diagonal matrix on
+    /// the left.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the length of `factors` differs
+    ///   from the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.scale_rows(&[2, -1]).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 2, 4], [-3, -4, -5]]);
+    ///
+    /// let result = matrix.scale_rows(&[1, 2, 3]);
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(2, 3),
+    ///     rhs: Shape::new(3, 1),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn scale_rows<S>(&mut self, factors: &[S]) -> Result<&mut Self>
+    where
+        L: MulAssign<S>,
+        S: Clone,
+    {
+        self.scale_vectors(Axis::Row, factors)
+    }
+
+    /// Multiplies each column of the matrix by the corresponding factor
+    /// in place, which is equivalent to multiplying by a diagonal matrix
+    /// on the right.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the length of `factors` differs
+    ///   from the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.scale_cols(&[2, 0, -1]).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 0, -2], [6, 0, -5]]);
+    ///
+    /// let result = matrix.scale_cols(&[1, 2]);
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(2, 3),
+    ///     rhs: Shape::new(2, 1),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn scale_cols<S>(&mut self, factors: &[S]) -> Result<&mut Self>
+    where
+        L: MulAssign<S>,
+        S: Clone,
+    {
+        self.scale_vectors(Axis::Col, factors)
+    }
+
+    fn scale_vectors<S>(&mut self, axis: Axis, factors: &[S]) -> Result<&mut Self>
+    where
+        L: MulAssign<S>,
+        S: Clone,
+    {
+        if factors.len() != self.len_of(axis) {
+            return Err(Error::not_conformable_with_slice(
+                self.shape(),
+                factors.len(),
+            ));
+        }
+        for (vector, factor) in self.iter_axis_mut(axis).zip(factors) {
+            vector.for_each(|element| *element *= factor.clone());
+        }
+        Ok(self)
+    }
+
     /// Performs matrix multiplication on two matrices.
     ///
     /// # Errors
@@ -177,11 +271,165 @@ impl<L> Matrix<L> {
     /// ```
     ///
     /// [`Error::NotConformable`]: crate::error::Error::NotConformable
-    pub fn mat_mul<R, U>(mut self, mut rhs: Matrix<R>) -> Result<Matrix<U>>
+    pub fn mat_mul<R, U>(self, rhs: Matrix<R>) -> Result<Matrix<U>>
+    where
+        L: core::ops::Mul<R, Output = U> + Clone,
+        R: Clone,
+        U: core::ops::Add<Output = U> + Default,
+    {
+        self.mat_mul_with_progress(rhs, |_, _| {})
+    }
+
+    /// Performs matrix multiplication on two matrices, converting both
+    /// operands into the accumulator type `A` before multiplying.
+    ///
+    /// Unlike [`Matrix::mat_mul`], where the product type also serves as
+    /// the accumulator, this allows products and sums to run in a wider
+    /// type, e.g., multiplying `i16` matrices with `i64` accumulation.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// For performance reasons, this method consumes both `self` and `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let lhs: Matrix<i16> = matrix![[i16::MAX, i16::MAX]];
+    /// let rhs: Matrix<i16> = matrix![[i16::MAX], [i16::MAX]];
+    ///
+    /// let result = lhs.mat_mul_with::<i64, _>(rhs);
+    /// assert_eq!(result, Ok(matrix![[2 * (i16::MAX as i64).pow(2)]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn mat_mul_with<A, R>(self, rhs: Matrix<R>) -> Result<Matrix<A>>
+    where
+        L: Into<A> + Clone,
+        R: Into<A> + Clone,
+        A: Add<Output = A> + Mul<Output = A> + Default,
+    {
+        self.multiplication_like_operation(rhs, |lhs, rhs| {
+            lhs.zip(rhs)
+                .map(|(left, right)| left.clone().into() * right.clone().into())
+                .fold(A::default(), |accumulator, product| accumulator + product)
+        })
+    }
+
+    /// Performs matrix multiplication on two matrices, reporting progress
+    /// to `progress` as the result is computed.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// Progress is reported in elements of the resulting matrix, once
+    /// for each completed row (or column, if `self` is column-major).
+    ///
+    /// For performance reasons, this method consumes both `self` and `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+    ///
+    /// let mut reports = Vec::new();
+    /// let result = lhs.mat_mul_with_progress(rhs, |done, total| reports.push((done, total)));
+    /// assert_eq!(result, Ok(matrix![[10, 13], [28, 40]]));
+    /// assert_eq!(reports, vec![(2, 4), (4, 4)]);
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn mat_mul_with_progress<R, U, P>(
+        self,
+        rhs: Matrix<R>,
+        mut progress: P,
+    ) -> Result<Matrix<U>>
+    where
+        L: core::ops::Mul<R, Output = U> + Clone,
+        R: Clone,
+        U: core::ops::Add<Output = U> + Default,
+        P: ProgressSink,
+    {
+        self.mat_mul_with_hooks(rhs, &mut progress, None)
+    }
+
+    /// Performs matrix multiplication on two matrices, giving up as soon
+    /// as `cancel` is set.
+    ///
+    /// `cancel` is checked before each row (or column, if `self` is
+    /// column-major) of the result is computed, so another thread can
+    /// stop the operation cleanly by storing `true` into it.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    /// - [`Error::Cancelled`] if `cancel` is set before the operation
+    ///   completes.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// [`Error::Cancelled`] reports progress in elements of the resulting
+    /// matrix.
+    ///
+    /// For performance reasons, this method consumes both `self` and `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error};
+    /// use std::sync::atomic::AtomicBool;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+    ///
+    /// let cancel = AtomicBool::new(false);
+    /// let result = lhs.clone().mat_mul_cancellable(rhs.clone(), &cancel);
+    /// assert_eq!(result, Ok(matrix![[10, 13], [28, 40]]));
+    ///
+    /// let cancel = AtomicBool::new(true);
+    /// let result = lhs.mat_mul_cancellable(rhs, &cancel);
+    /// assert_eq!(result, Err(Error::Cancelled { done: 0, total: 4 }));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    /// [`Error::Cancelled`]: crate::error::Error::Cancelled
+    pub fn mat_mul_cancellable<R, U>(self, rhs: Matrix<R>, cancel: &AtomicBool) -> Result<Matrix<U>>
+    where
+        L: core::ops::Mul<R, Output = U> + Clone,
+        R: Clone,
+        U: core::ops::Add<Output = U> + Default,
+    {
+        self.mat_mul_with_hooks(rhs, &mut |_, _| {}, Some(cancel))
+    }
+
+    fn mat_mul_with_hooks<R, U, P>(
+        mut self,
+        mut rhs: Matrix<R>,
+        progress: &mut P,
+        cancel: Option<&AtomicBool>,
+    ) -> Result<Matrix<U>>
     where
-        L: std::ops::Mul<R, Output = U> + Clone,
+        L: core::ops::Mul<R, Output = U> + Clone,
         R: Clone,
-        U: std::ops::Add<Output = U> + Default,
+        U: core::ops::Add<Output = U> + Default,
+        P: ProgressSink,
     {
         self.ensure_multiplication_like_operation_conformable(&rhs)?;
 
@@ -194,47 +442,381 @@ impl<L> Matrix<L> {
 
         if self.ncols() == 0 {
             data.resize_with(size, U::default);
+            if size != 0 {
+                progress.report(size, size);
+            }
             return Ok(Matrix { order, shape, data });
         }
 
         self.set_order(Order::RowMajor);
         rhs.set_order(Order::ColMajor);
+        let len = self.ncols();
+        let lhs_row = |n: usize| &self.data[n * len..(n + 1) * len];
+        let rhs_col = |n: usize| &rhs.data[n * len..(n + 1) * len];
 
         match order {
             Order::RowMajor => {
                 for row in 0..nrows {
+                    if cancel.is_some_and(|cancel| cancel.load(AtomicOrdering::Relaxed)) {
+                        return Err(Error::Cancelled {
+                            done: data.len(),
+                            total: size,
+                        });
+                    }
                     for col in 0..ncols {
-                        match dot_product(
-                            unsafe { self.iter_nth_major_axis_vector_unchecked(row) },
-                            unsafe { rhs.iter_nth_major_axis_vector_unchecked(col) },
-                        ) {
+                        match dot_product_of_slices(lhs_row(row), rhs_col(col)) {
                             None => unreachable!(),
                             Some(element) => data.push(element),
                         }
                     }
+                    progress.report(data.len(), size);
                 }
             }
 
             Order::ColMajor => {
                 for col in 0..ncols {
+                    if cancel.is_some_and(|cancel| cancel.load(AtomicOrdering::Relaxed)) {
+                        return Err(Error::Cancelled {
+                            done: data.len(),
+                            total: size,
+                        });
+                    }
                     for row in 0..nrows {
-                        match dot_product(
-                            unsafe { self.iter_nth_major_axis_vector_unchecked(row) },
-                            unsafe { rhs.iter_nth_major_axis_vector_unchecked(col) },
-                        ) {
+                        match dot_product_of_slices(lhs_row(row), rhs_col(col)) {
                             None => unreachable!(),
                             Some(element) => data.push(element),
                         }
                     }
+                    progress.report(data.len(), size);
                 }
             }
         }
 
         Ok(Matrix { order, shape, data })
     }
+
+    /// Returns the dot product of two vector-shaped matrices.
+    ///
+    /// Both operands may be either row vectors (`1 x n`) or column vectors
+    /// (`n x 1`), in any combination.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if either operand is not a vector, or
+    ///   their lengths differ.
+    ///
+    /// # Notes
+    ///
+    /// The dot product of two empty vectors is `U::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape};
+    ///
+    /// let lhs = matrix![[1, 2, 3]];
+    /// let rhs = matrix![[4], [5], [6]];
+    /// assert_eq!(lhs.dot(&rhs), Ok(32));
+    ///
+    /// let rhs = matrix![[4, 5], [6, 7]];
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(1, 3),
+    ///     rhs: Shape::new(2, 2),
+    /// };
+    /// assert_eq!(lhs.dot(&rhs), Err(error));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn dot<R, U>(&self, rhs: &Matrix<R>) -> Result<U>
+    where
+        L: Mul<R, Output = U> + Clone,
+        R: Clone,
+        U: Add<Output = U> + Default,
+    {
+        if !self.is_vector() || !rhs.is_vector() || self.size() != rhs.size() {
+            return Err(Error::not_conformable(self.shape(), rhs.shape()));
+        }
+        Ok(dot_product_of_slices(&self.data, &rhs.data).unwrap_or_default())
+    }
+
+    /// Returns the outer product of two vector-shaped matrices.
+    ///
+    /// For vectors of lengths `m` and `n`, the result is the `m x n`
+    /// matrix whose element at `(i, j)` is `self[i] * rhs[j]`. Both
+    /// operands may be either row vectors or column vectors.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if either operand is not a vector.
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[1], [2]];
+    /// let rhs = matrix![[3, 4, 5]];
+    ///
+    /// let result = lhs.outer(&rhs);
+    /// assert_eq!(result, Ok(matrix![[3, 4, 5], [6, 8, 10]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn outer<R, U>(&self, rhs: &Matrix<R>) -> Result<Matrix<U>>
+    where
+        L: Mul<R, Output = U> + Clone,
+        R: Clone,
+    {
+        if !self.is_vector() || !rhs.is_vector() {
+            return Err(Error::not_conformable(self.shape(), rhs.shape()));
+        }
+        let order = self.order;
+        let shape = AxisShape::try_from_shape(Shape::new(self.size(), rhs.size()), order)?;
+        let size = Matrix::<U>::check_size(shape.size())?;
+        let mut data = Vec::with_capacity(size);
+        match order {
+            Order::RowMajor => {
+                for left in &self.data {
+                    data.extend(rhs.data.iter().map(|right| left.clone() * right.clone()));
+                }
+            }
+
+            Order::ColMajor => {
+                for right in &rhs.data {
+                    data.extend(self.data.iter().map(|left| left.clone() * right.clone()));
+                }
+            }
+        }
+        Ok(Matrix { order, shape, data })
+    }
+
+    fn is_vector(&self) -> bool {
+        self.nrows() == 1 || self.ncols() == 1
+    }
+
+    /// Performs the Kronecker product of two matrices.
+    ///
+    /// For an `m x n` matrix `self` and a `p x q` matrix `rhs`, the
+    /// result is the `(m * p) x (n * q)` block matrix whose block at
+    /// `(i, j)` is `self[(i, j)] * rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[1, 2], [3, 4]];
+    /// let rhs = matrix![[0, 1], [1, 0]];
+    ///
+    /// let result = lhs.kronecker(&rhs);
+    /// assert_eq!(
+    ///     result,
+    ///     Ok(matrix![
+    ///         [0, 1, 0, 2],
+    ///         [1, 0, 2, 0],
+    ///         [0, 3, 0, 4],
+    ///         [3, 0, 4, 0],
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn kronecker<R, U>(&self, rhs: &Matrix<R>) -> Result<Matrix<U>>
+    where
+        L: Mul<R, Output = U> + Clone,
+        R: Clone,
+    {
+        let (p, q) = (rhs.nrows(), rhs.ncols());
+        let nrows = self.nrows().checked_mul(p).ok_or(Error::SizeOverflow)?;
+        let ncols = self.ncols().checked_mul(q).ok_or(Error::SizeOverflow)?;
+        let order = self.order;
+        let shape = AxisShape::try_from_shape(Shape::new(nrows, ncols), order)?;
+        let size = Matrix::<U>::check_size(shape.size())?;
+        let mut data = Vec::with_capacity(size);
+
+        let mut push = |row: usize, col: usize| {
+            data.push(self[(row / p, col / q)].clone() * rhs[(row % p, col % q)].clone())
+        };
+        match order {
+            Order::RowMajor => {
+                for row in 0..nrows {
+                    for col in 0..ncols {
+                        push(row, col);
+                    }
+                }
+            }
+
+            Order::ColMajor => {
+                for col in 0..ncols {
+                    for row in 0..nrows {
+                        push(row, col);
+                    }
+                }
+            }
+        }
+
+        Ok(Matrix { order, shape, data })
+    }
+
+    /// Raises a square matrix to the power of `exp` by repeated squaring,
+    /// with `one` as the multiplicative identity of `L`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotSquare`] if the matrix is not square.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// Raising to the power of zero yields the identity matrix, whose
+    /// diagonal elements are `one` and whose other elements are
+    /// `L::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape};
+    ///
+    /// let matrix = matrix![[1, 1], [1, 0]];
+    ///
+    /// let result = matrix.clone().mat_pow(10, 1);
+    /// assert_eq!(result, Ok(matrix![[89, 55], [55, 34]]));
+    ///
+    /// let result = matrix.mat_pow(0, 1);
+    /// assert_eq!(result, Ok(matrix![[1, 0], [0, 1]]));
+    ///
+    /// let result = matrix![[0, 1, 2], [3, 4, 5]].mat_pow(2, 1);
+    /// let error = Error::NotSquare(Shape::new(2, 3));
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+    pub fn mat_pow(self, mut exp: u32, one: L) -> Result<Self>
+    where
+        L: Mul<Output = L> + Add<Output = L> + Clone + Default,
+    {
+        if self.nrows() != self.ncols() {
+            return Err(Error::not_square(self.shape()));
+        }
+
+        let mut base = self;
+        let mut output: Option<Self> = None;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                output = Some(match output {
+                    None => base.clone(),
+                    Some(output) => output.mat_mul(base.clone())?,
+                });
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone().mat_mul(base)?;
+            }
+        }
+
+        match output {
+            Some(output) => Ok(output),
+            None => {
+                let mut identity = base;
+                identity.data.fill(L::default());
+                let stride = identity.major_stride() + 1;
+                for element in identity.data.iter_mut().step_by(stride) {
+                    *element = one.clone();
+                }
+                Ok(identity)
+            }
+        }
+    }
+
+    /// Performs matrix multiplication on two matrices, returning an error
+    /// instead of panicking if they are not conformable.
+    ///
+    /// This is the non-panicking counterpart of the `*` operator. Unlike
+    /// [`Matrix::mat_mul`], it borrows both operands.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape};
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+    ///
+    /// let result = lhs.checked_mul(&rhs);
+    /// assert_eq!(result, Ok(matrix![[10, 13], [28, 40]]));
+    ///
+    /// let result = lhs.checked_mul(&lhs);
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(2, 3),
+    ///     rhs: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn checked_mul<R, U>(&self, rhs: &Matrix<R>) -> Result<Matrix<U>>
+    where
+        L: Mul<R, Output = U> + Clone,
+        R: Clone,
+        U: Add<Output = U> + Default,
+    {
+        self.ensure_multiplication_like_operation_conformable(rhs)?;
+        self.clone().mat_mul(rhs.clone())
+    }
+
+    /// Performs scalar multiplication on the matrix.
+    ///
+    /// Unlike [`Matrix::checked_mul`], this cannot fail, since a scalar
+    /// is conformable with any matrix. It completes the set of checked
+    /// variants so that callers can use them uniformly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let output = matrix.checked_mul_scalar(&2);
+    /// assert_eq!(output, matrix![[0, 2, 4], [6, 8, 10]]);
+    /// ```
+    pub fn checked_mul_scalar<S, U>(&self, scalar: &S) -> Matrix<U>
+    where
+        L: Mul<S, Output = U> + Clone,
+        S: Clone,
+    {
+        self.scalar_operation(scalar, |element, scalar| element.clone() * scalar.clone())
+    }
 }
 
-impl_scalar_mul! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
+impl_scalar_copy! {Mul, mul, *, MulAssign, mul_assign, *=; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
 
 #[inline]
 fn dot_product<'a, L, R, U>(
@@ -251,10 +833,337 @@ where
         .reduce(|accumulator, product| accumulator + product)
 }
 
+/// Returns the dot product of two equally long slices, running the SIMD
+/// kernels for primitive numeric types if the `simd` feature is enabled.
+#[inline]
+fn dot_product_of_slices<L, R, U>(lhs: &[L], rhs: &[R]) -> Option<U>
+where
+    L: Mul<R, Output = U> + Clone,
+    R: Clone,
+    U: Add<Output = U>,
+{
+    #[cfg(feature = "simd")]
+    if !lhs.is_empty() {
+        if let Some(output) = super::copy::dot(lhs, rhs) {
+            return Some(output);
+        }
+    }
+    dot_product(lhs.iter(), rhs.iter())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::error::Error;
     use crate::matrix;
+    use crate::matrix::order::Order;
+    use crate::matrix::shape::Shape;
+    use crate::matrix::Matrix;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+    #[test]
+    fn test_checked_mul() {
+        let mut lhs = matrix![[0, 1, 2], [3, 4, 5]];
+        let mut rhs = matrix![[0, 1], [2, 3], [4, 5]];
+        let expected = matrix![[10, 13], [28, 40]];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                let mut output = lhs.checked_mul(&rhs).unwrap();
+                if output.order() != expected.order() {
+                    output.switch_order();
+                }
+                assert_eq!(output, expected);
+
+                let result = lhs.checked_mul::<i32, i32>(&lhs);
+                assert_eq!(
+                    result,
+                    Err(Error::NotConformable {
+                        lhs: Shape::new(2, 3),
+                        rhs: Shape::new(2, 3)
+                    })
+                );
+
+                rhs.switch_order();
+            }
+            lhs.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_mat_mul_with() {
+        let mut lhs: Matrix<i16> = matrix![[i16::MAX, 1], [i16::MIN, -1]];
+        let rhs: Matrix<u8> = matrix![[u8::MAX, 0], [2, 1]];
+
+        for _ in 0..2 {
+            let mut output = lhs.clone().mat_mul_with::<i64, _>(rhs.clone()).unwrap();
+            assert_eq!(output.order(), lhs.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(
+                output,
+                matrix![
+                    [i16::MAX as i64 * 255 + 2, 1],
+                    [i16::MIN as i64 * 255 - 2, -1]
+                ]
+            );
+
+            let result = lhs.clone().mat_mul_with::<i64, _>(matrix![[1u8, 2u8]]);
+            assert_eq!(
+                result,
+                Err(Error::NotConformable {
+                    lhs: Shape::new(2, 2),
+                    rhs: Shape::new(1, 2)
+                })
+            );
+
+            lhs.switch_order();
+        }
+
+        let output = Matrix::<i16>::new((2, 0))
+            .mat_mul_with::<i64, i16>(Matrix::new((0, 1)))
+            .unwrap();
+        assert_eq!(output, Matrix::new((2, 1)));
+    }
+
+    #[test]
+    fn test_mat_mul_with_progress() {
+        let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+        let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+
+        let mut reports = Vec::new();
+        let output = lhs
+            .clone()
+            .mat_mul_with_progress(rhs.clone(), |done, total| reports.push((done, total)))
+            .unwrap();
+        assert_eq!(output, matrix![[10, 13], [28, 40]]);
+        assert_eq!(reports, vec![(2, 4), (4, 4)]);
+
+        let mut lhs = lhs;
+        lhs.switch_order();
+        let mut reports = Vec::new();
+        let mut output = lhs
+            .mat_mul_with_progress(rhs.clone(), |done, total| reports.push((done, total)))
+            .unwrap();
+        output.switch_order();
+        assert_eq!(output, matrix![[10, 13], [28, 40]]);
+        assert_eq!(reports, vec![(2, 4), (4, 4)]);
+
+        let mut reports = Vec::new();
+        let output = Matrix::<i32>::new((2, 0))
+            .mat_mul_with_progress(Matrix::<i32>::new((0, 3)), |done, total| {
+                reports.push((done, total))
+            })
+            .unwrap();
+        assert_eq!(output, Matrix::new((2, 3)));
+        assert_eq!(reports, vec![(6, 6)]);
+
+        let mut reports = Vec::new();
+        let result = rhs.mat_mul_with_progress(Matrix::<i32>::new((3, 3)), |done, total| {
+            reports.push((done, total))
+        });
+        assert_eq!(
+            result,
+            Err(Error::NotConformable {
+                lhs: Shape::new(3, 2),
+                rhs: Shape::new(3, 3)
+            })
+        );
+        assert!(reports.is_empty());
+    }
+
+    #[test]
+    fn test_mat_mul_cancellable() {
+        let mut lhs = matrix![[0, 1, 2], [3, 4, 5]];
+        let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+
+        for _ in 0..2 {
+            let cancel = AtomicBool::new(false);
+            let mut output = lhs
+                .clone()
+                .mat_mul_cancellable(rhs.clone(), &cancel)
+                .unwrap();
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[10, 13], [28, 40]]);
+
+            cancel.store(true, AtomicOrdering::Relaxed);
+            let result = lhs.clone().mat_mul_cancellable(rhs.clone(), &cancel);
+            assert_eq!(result, Err(Error::Cancelled { done: 0, total: 4 }));
+
+            let result = rhs.clone().mat_mul_cancellable(rhs.clone(), &cancel);
+            assert_eq!(
+                result,
+                Err(Error::NotConformable {
+                    lhs: Shape::new(3, 2),
+                    rhs: Shape::new(3, 2)
+                })
+            );
+
+            lhs.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_scale_rows_and_cols() {
+        let mut matrix = matrix![[1, 2, 3], [4, 5, 6]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.scale_rows(&[10, -1]).unwrap();
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[10, 20, 30], [-4, -5, -6]]);
+
+            let mut output = matrix.clone();
+            output.scale_cols(&[0, 1, 2]).unwrap();
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 2, 6], [0, 5, 12]]);
+
+            let mut output = matrix.clone();
+            assert_eq!(
+                output.scale_rows(&[1, 2, 3]),
+                Err(Error::NotConformable {
+                    lhs: Shape::new(2, 3),
+                    rhs: Shape::new(3, 1)
+                })
+            );
+            assert_eq!(
+                output.scale_cols(&[1, 2]),
+                Err(Error::NotConformable {
+                    lhs: Shape::new(2, 3),
+                    rhs: Shape::new(2, 1)
+                })
+            );
+            assert_eq!(output, matrix);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_dot() {
+        let mut lhs = matrix![[1, 2, 3]];
+        let mut rhs = matrix![[4], [5], [6]];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                assert_eq!(lhs.dot(&rhs), Ok(32));
+                assert_eq!(rhs.dot(&lhs), Ok(32));
+                assert_eq!(lhs.dot(&lhs), Ok(14));
+                assert_eq!(
+                    lhs.dot(&matrix![[1, 2]]),
+                    Err(Error::NotConformable {
+                        lhs: Shape::new(1, 3),
+                        rhs: Shape::new(1, 2)
+                    })
+                );
+                assert_eq!(
+                    lhs.dot(&matrix![[1, 2, 3], [4, 5, 6]]),
+                    Err(Error::NotConformable {
+                        lhs: Shape::new(1, 3),
+                        rhs: Shape::new(2, 3)
+                    })
+                );
+                rhs.switch_order();
+            }
+            lhs.switch_order();
+        }
+
+        assert_eq!(matrix![[7]].dot(&matrix![[6]]), Ok(42));
+        assert_eq!(
+            Matrix::<i32>::new((1, 0)).dot(&Matrix::<i32>::new((0, 1))),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_outer() {
+        let mut lhs = matrix![[1], [2]];
+        let mut rhs = matrix![[3, 4, 5]];
+        let expected = matrix![[3, 4, 5], [6, 8, 10]];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                let mut output = lhs.outer(&rhs).unwrap();
+                assert_eq!(output.order(), lhs.order());
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, expected);
+                rhs.switch_order();
+            }
+            lhs.switch_order();
+        }
+
+        let lhs = matrix![[1, 2]];
+        assert_eq!(lhs.outer(&lhs), Ok(matrix![[1, 2], [2, 4]]));
+        assert_eq!(
+            lhs.outer(&matrix![[1, 2], [3, 4]]),
+            Err(Error::NotConformable {
+                lhs: Shape::new(1, 2),
+                rhs: Shape::new(2, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_kronecker() {
+        let mut lhs = matrix![[1, 2, 3]];
+        let mut rhs = matrix![[1, 0], [0, 1]];
+        let expected = matrix![[1, 0, 2, 0, 3, 0], [0, 1, 0, 2, 0, 3]];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                let mut output = lhs.kronecker(&rhs).unwrap();
+                assert_eq!(output.order(), lhs.order());
+                output.set_order(Order::RowMajor);
+                assert_eq!(output, expected);
+
+                rhs.switch_order();
+            }
+            lhs.switch_order();
+        }
+
+        let output = lhs.kronecker(&Matrix::<i32>::new((0, 2))).unwrap();
+        assert_eq!(output.shape(), Shape::new(0, 6));
+
+        let huge = Matrix::<i32>::new((usize::MAX, 0));
+        assert_eq!(huge.kronecker(&rhs), Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    fn test_mat_pow() {
+        let mut matrix = matrix![[1, 1, 0], [1, 0, 1], [0, 0, 2]];
+        let expected = [
+            matrix![[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+            matrix![[1, 1, 0], [1, 0, 1], [0, 0, 2]],
+            matrix![[2, 1, 1], [1, 1, 2], [0, 0, 4]],
+            matrix![[3, 2, 3], [2, 1, 5], [0, 0, 8]],
+            matrix![[5, 3, 8], [3, 2, 11], [0, 0, 16]],
+        ];
+
+        for _ in 0..2 {
+            for (exp, expected) in expected.iter().enumerate() {
+                let mut output = matrix.clone().mat_pow(exp as u32, 1).unwrap();
+                assert_eq!(output.order(), matrix.order());
+                if output.order() != expected.order() {
+                    output.switch_order();
+                }
+                assert_eq!(&output, expected);
+            }
+            matrix.switch_order();
+        }
+
+        let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        assert_eq!(
+            matrix.mat_pow(1, 1),
+            Err(Error::NotSquare(Shape::new(2, 3)))
+        );
+
+        let matrix = Matrix::<i32>::new((0, 0));
+        assert_eq!(matrix.clone().mat_pow(0, 1), Ok(matrix));
+
+        let matrix: Matrix<i8> = matrix![[1, -1], [0, 1]];
+        assert_eq!(matrix.clone().mat_pow(3, 1), Ok(matrix![[1, -3], [0, 1]]));
+        assert_eq!(matrix.mat_pow(0, 1), Ok(matrix![[1, 0], [0, 1]]));
+    }
 
     #[test]
     fn test_mat_mul() {
@@ -322,14 +1231,26 @@ mod tests {
             let lhs = lhs.clone();
             let rhs = matrix![[0, 1], [2, 3]];
             let error = lhs.mat_mul(rhs).unwrap_err();
-            assert_eq!(error, Error::NotConformable);
+            assert_eq!(
+                error,
+                Error::NotConformable {
+                    lhs: Shape::new(2, 3),
+                    rhs: Shape::new(2, 2)
+                }
+            );
         }
 
         {
             let lhs = lhs.clone();
             let rhs = matrix![[0, 1, 3], [4, 5, 6]];
             let error = lhs.mat_mul(rhs).unwrap_err();
-            assert_eq!(error, Error::NotConformable);
+            assert_eq!(
+                error,
+                Error::NotConformable {
+                    lhs: Shape::new(2, 3),
+                    rhs: Shape::new(2, 3)
+                }
+            );
         }
     }
 }
@@ -0,0 +1,245 @@
+use super::super::Matrix;
+use crate::error::{Error, Result};
+use alloc::vec::Vec;
+
+macro_rules! impl_overflow_arithmetic {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                impl_overflow_arithmetic! {
+                    @op $t, "addition", MAX, 0, 5,
+                    elementwise_add_checked: checked_add,
+                    elementwise_add_saturating: saturating_add,
+                    elementwise_add_wrapping: wrapping_add
+                }
+
+                impl_overflow_arithmetic! {
+                    @op $t, "subtraction", MIN, 0, 1,
+                    elementwise_sub_checked: checked_sub,
+                    elementwise_sub_saturating: saturating_sub,
+                    elementwise_sub_wrapping: wrapping_sub
+                }
+
+                impl_overflow_arithmetic! {
+                    @op $t, "multiplication", MAX, 1, 6,
+                    elementwise_mul_checked: checked_mul,
+                    elementwise_mul_saturating: saturating_mul,
+                    elementwise_mul_wrapping: wrapping_mul
+                }
+            }
+        )*
+    };
+
+    (
+        @op $t:ty, $name:literal, $bound:ident, $identity:literal, $value:literal,
+        $checked_fn:ident: $checked:ident,
+        $saturating_fn:ident: $saturating:ident,
+        $wrapping_fn:ident: $wrapping:ident
+    ) => {
+        #[doc = concat!("Performs elementwise ", $name, " on two matrices, checking for overflow.")]
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::NotConformable`] if the matrices are not conformable.
+        /// - [`Error::ArithmeticOverflow`] if any element overflows.
+        ///
+        /// # Notes
+        ///
+        /// The resulting matrix will always have the same order as `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use matreex::{matrix, Error};
+        ///
+        #[doc = concat!("let lhs = matrix![[", stringify!($t), "::", stringify!($bound), ", 3]];")]
+        #[doc = concat!("let rhs = matrix![[", stringify!($identity), ", 2]];")]
+        #[doc = concat!("assert_eq!(lhs.", stringify!($checked_fn), "(&rhs), Ok(matrix![[", stringify!($t), "::", stringify!($bound), ", ", stringify!($value), "]]));")]
+        ///
+        /// let rhs = matrix![[2, 2]];
+        #[doc = concat!("assert_eq!(lhs.", stringify!($checked_fn), "(&rhs), Err(Error::ArithmeticOverflow));")]
+        /// ```
+        ///
+        /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+        /// [`Error::ArithmeticOverflow`]: crate::error::Error::ArithmeticOverflow
+        pub fn $checked_fn(&self, rhs: &Matrix<$t>) -> Result<Matrix<$t>> {
+            let output = self.elementwise_operation(rhs, |(left, right)| left.$checked(*right))?;
+            let Matrix { order, shape, data } = output;
+            let data = data
+                .into_iter()
+                .collect::<Option<Vec<$t>>>()
+                .ok_or(Error::ArithmeticOverflow)?;
+            Ok(Matrix { order, shape, data })
+        }
+
+        #[doc = concat!("Performs elementwise ", $name, " on two matrices, saturating at the numeric bounds.")]
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::NotConformable`] if the matrices are not conformable.
+        ///
+        /// # Notes
+        ///
+        /// The resulting matrix will always have the same order as `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use matreex::matrix;
+        ///
+        #[doc = concat!("let lhs = matrix![[", stringify!($t), "::", stringify!($bound), ", 3]];")]
+        /// let rhs = matrix![[2, 2]];
+        #[doc = concat!("assert_eq!(lhs.", stringify!($saturating_fn), "(&rhs), Ok(matrix![[", stringify!($t), "::", stringify!($bound), ", ", stringify!($value), "]]));")]
+        /// ```
+        ///
+        /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+        pub fn $saturating_fn(&self, rhs: &Matrix<$t>) -> Result<Matrix<$t>> {
+            self.elementwise_operation(rhs, |(left, right)| left.$saturating(*right))
+        }
+
+        #[doc = concat!("Performs elementwise ", $name, " on two matrices, wrapping around at the numeric bounds.")]
+        ///
+        /// # Errors
+        ///
+        /// - [`Error::NotConformable`] if the matrices are not conformable.
+        ///
+        /// # Notes
+        ///
+        /// The resulting matrix will always have the same order as `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use matreex::matrix;
+        ///
+        #[doc = concat!("let lhs = matrix![[", stringify!($t), "::", stringify!($bound), ", 3]];")]
+        /// let rhs = matrix![[2, 2]];
+        #[doc = concat!("let expected = matrix![[", stringify!($t), "::", stringify!($bound), ".", stringify!($wrapping), "(2), ", stringify!($value), "]];")]
+        #[doc = concat!("assert_eq!(lhs.", stringify!($wrapping_fn), "(&rhs), Ok(expected));")]
+        /// ```
+        ///
+        /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+        pub fn $wrapping_fn(&self, rhs: &Matrix<$t>) -> Result<Matrix<$t>> {
+            self.elementwise_operation(rhs, |(left, right)| left.$wrapping(*right))
+        }
+    };
+}
+
+impl_overflow_arithmetic! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::matrix;
+    use crate::matrix::shape::Shape;
+
+    #[test]
+    fn test_elementwise_checked() {
+        let mut lhs = matrix![[u8::MAX, 3], [0, 16]];
+        let rhs = matrix![[1, 2], [1, 16]];
+
+        for _ in 0..2 {
+            assert_eq!(
+                lhs.elementwise_add_checked(&rhs),
+                Err(Error::ArithmeticOverflow)
+            );
+            assert_eq!(
+                lhs.elementwise_sub_checked(&rhs),
+                Err(Error::ArithmeticOverflow)
+            );
+            assert_eq!(
+                lhs.elementwise_mul_checked(&rhs),
+                Err(Error::ArithmeticOverflow)
+            );
+            assert_eq!(
+                lhs.elementwise_add_checked(&matrix![[1, 2]]),
+                Err(Error::NotConformable {
+                    lhs: Shape::new(2, 2),
+                    rhs: Shape::new(1, 2)
+                })
+            );
+
+            let mut output = lhs
+                .elementwise_add_checked(&matrix![[0, 1], [2, 3]])
+                .unwrap();
+            if output.order() != rhs.order() {
+                output.switch_order();
+            }
+            assert_eq!(output, matrix![[u8::MAX, 4], [2, 19]]);
+
+            lhs.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_elementwise_saturating() {
+        let mut lhs = matrix![[i8::MAX, i8::MIN], [-3, 100]];
+        let rhs = matrix![[1, 1], [2, 2]];
+
+        for _ in 0..2 {
+            let mut output = lhs.elementwise_add_saturating(&rhs).unwrap();
+            if output.order() != rhs.order() {
+                output.switch_order();
+            }
+            assert_eq!(output, matrix![[i8::MAX, -127], [-1, 102]]);
+
+            let mut output = lhs.elementwise_sub_saturating(&rhs).unwrap();
+            if output.order() != rhs.order() {
+                output.switch_order();
+            }
+            assert_eq!(output, matrix![[126, i8::MIN], [-5, 98]]);
+
+            let mut output = lhs.elementwise_mul_saturating(&rhs).unwrap();
+            if output.order() != rhs.order() {
+                output.switch_order();
+            }
+            assert_eq!(output, matrix![[i8::MAX, i8::MIN], [-6, i8::MAX]]);
+
+            assert_eq!(
+                lhs.elementwise_mul_saturating(&matrix![[1], [2]]),
+                Err(Error::NotConformable {
+                    lhs: Shape::new(2, 2),
+                    rhs: Shape::new(2, 1)
+                })
+            );
+
+            lhs.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_elementwise_wrapping() {
+        let mut lhs = matrix![[i8::MAX, i8::MIN], [-3, 100]];
+        let rhs = matrix![[1, 1], [2, 2]];
+
+        for _ in 0..2 {
+            let mut output = lhs.elementwise_add_wrapping(&rhs).unwrap();
+            if output.order() != rhs.order() {
+                output.switch_order();
+            }
+            assert_eq!(output, matrix![[i8::MIN, -127], [-1, 102]]);
+
+            let mut output = lhs.elementwise_sub_wrapping(&rhs).unwrap();
+            if output.order() != rhs.order() {
+                output.switch_order();
+            }
+            assert_eq!(output, matrix![[126, i8::MAX], [-5, 98]]);
+
+            let mut output = lhs.elementwise_mul_wrapping(&rhs).unwrap();
+            if output.order() != rhs.order() {
+                output.switch_order();
+            }
+            assert_eq!(output, matrix![[i8::MAX, i8::MIN], [-6, -56]]);
+
+            assert_eq!(
+                lhs.elementwise_mul_wrapping(&matrix![[1], [2]]),
+                Err(Error::NotConformable {
+                    lhs: Shape::new(2, 2),
+                    rhs: Shape::new(2, 1)
+                })
+            );
+
+            lhs.switch_order();
+        }
+    }
+}
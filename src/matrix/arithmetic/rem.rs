@@ -1,7 +1,88 @@
 use super::super::Matrix;
+use super::elementwise_operation_panic;
 use crate::error::Result;
-use crate::impl_scalar_rem;
-use std::ops::{Rem, RemAssign};
+use core::ops::{Rem, RemAssign};
+
+impl<L, R, U> Rem<Matrix<R>> for Matrix<L>
+where
+    L: Rem<R, Output = U>,
+    R: Clone,
+{
+    type Output = Matrix<U>;
+
+    fn rem(self, rhs: Matrix<R>) -> Self::Output {
+        self % &rhs
+    }
+}
+
+impl<L, R, U> Rem<&Matrix<R>> for Matrix<L>
+where
+    L: Rem<R, Output = U>,
+    R: Clone,
+{
+    type Output = Matrix<U>;
+
+    fn rem(self, rhs: &Matrix<R>) -> Self::Output {
+        let shape = self.shape();
+        match self.elementwise_rem_consume_self(rhs) {
+            Err(error) => {
+                elementwise_operation_panic::<L, R>("remainder", shape, rhs.shape(), error)
+            }
+            Ok(output) => output,
+        }
+    }
+}
+
+impl<L, R, U> Rem<Matrix<R>> for &Matrix<L>
+where
+    L: Rem<R, Output = U> + Clone,
+    R: Clone,
+{
+    type Output = Matrix<U>;
+
+    fn rem(self, rhs: Matrix<R>) -> Self::Output {
+        self % &rhs
+    }
+}
+
+impl<L, R, U> Rem<&Matrix<R>> for &Matrix<L>
+where
+    L: Rem<R, Output = U> + Clone,
+    R: Clone,
+{
+    type Output = Matrix<U>;
+
+    fn rem(self, rhs: &Matrix<R>) -> Self::Output {
+        match self.elementwise_rem(rhs) {
+            Err(error) => {
+                elementwise_operation_panic::<L, R>("remainder", self.shape(), rhs.shape(), error)
+            }
+            Ok(output) => output,
+        }
+    }
+}
+
+impl<L, R> RemAssign<Matrix<R>> for Matrix<L>
+where
+    L: RemAssign<R>,
+    R: Clone,
+{
+    fn rem_assign(&mut self, rhs: Matrix<R>) {
+        *self %= &rhs
+    }
+}
+
+impl<L, R> RemAssign<&Matrix<R>> for Matrix<L>
+where
+    L: RemAssign<R>,
+    R: Clone,
+{
+    fn rem_assign(&mut self, rhs: &Matrix<R>) {
+        if let Err(error) = self.elementwise_rem_assign(rhs) {
+            elementwise_operation_panic::<L, R>("remainder", self.shape(), rhs.shape(), error);
+        }
+    }
+}
 
 impl<L> Matrix<L> {
     /// Performs elementwise remainder operation on two matrices.
@@ -14,6 +95,10 @@ impl<L> Matrix<L> {
     ///
     /// The resulting matrix will always have the same order as `self`.
     ///
+    /// This is what the `%` operator does for two matrices, except that
+    /// the operator panics if they are not conformable. Unlike `*`, which
+    /// performs matrix multiplication, `%` is always elementwise.
+    ///
     /// # Examples
     ///
     /// ```
@@ -126,4 +211,4 @@ impl<L> Matrix<L> {
     }
 }
 
-impl_scalar_rem! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
+impl_scalar_copy! {Rem, rem, %, RemAssign, rem_assign, %=; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
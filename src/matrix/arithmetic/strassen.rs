@@ -0,0 +1,230 @@
+use super::super::order::Order;
+use super::super::shape::{AxisShape, Shape};
+use super::super::Matrix;
+use crate::error::Result;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+impl<T> Matrix<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Clone + Default,
+{
+    /// Performs matrix multiplication on two matrices with Strassen's
+    /// algorithm.
+    ///
+    /// The operands are recursively split into quadrants, trading one of
+    /// the eight block multiplications for extra additions, until any
+    /// dimension drops to `cutoff` or below, where the naive algorithm
+    /// takes over. Odd dimensions are padded with `T::default()`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// This only pays off for very large matrices, and float results
+    /// may differ from [`Matrix::mat_mul`] by rounding error.
+    ///
+    /// For performance reasons, this method consumes both `self` and `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+    ///
+    /// let result = lhs.mat_mul_strassen(rhs, 1);
+    /// assert_eq!(result, Ok(matrix![[10, 13], [28, 40]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn mat_mul_strassen(mut self, mut rhs: Matrix<T>, cutoff: usize) -> Result<Self> {
+        self.ensure_multiplication_like_operation_conformable(&rhs)?;
+
+        let (m, k, n) = (self.nrows(), self.ncols(), rhs.ncols());
+        let order = self.order;
+        let shape = AxisShape::try_from_shape(Shape::new(m, n), Order::RowMajor)?;
+        Self::check_size(shape.size())?;
+
+        self.set_order(Order::RowMajor);
+        rhs.set_order(Order::RowMajor);
+        let data = strassen(&self.data, &rhs.data, m, k, n, cutoff.max(1));
+
+        let mut output = Matrix {
+            order: Order::RowMajor,
+            shape,
+            data,
+        };
+        output.set_order(order);
+        Ok(output)
+    }
+}
+
+/// Multiplies the row-major `m x k` matrix `a` by the row-major `k x n`
+/// matrix `b`.
+fn strassen<T>(a: &[T], b: &[T], m: usize, k: usize, n: usize, cutoff: usize) -> Vec<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Clone + Default,
+{
+    if m <= cutoff || k <= cutoff || n <= cutoff {
+        return naive(a, b, m, k, n);
+    }
+
+    let (hm, hk, hn) = (m.div_ceil(2), k.div_ceil(2), n.div_ceil(2));
+    let a11 = block(a, m, k, 0, 0, hm, hk);
+    let a12 = block(a, m, k, 0, hk, hm, hk);
+    let a21 = block(a, m, k, hm, 0, hm, hk);
+    let a22 = block(a, m, k, hm, hk, hm, hk);
+    let b11 = block(b, k, n, 0, 0, hk, hn);
+    let b12 = block(b, k, n, 0, hn, hk, hn);
+    let b21 = block(b, k, n, hk, 0, hk, hn);
+    let b22 = block(b, k, n, hk, hn, hk, hn);
+
+    let recurse = |a: &[T], b: &[T]| strassen(a, b, hm, hk, hn, cutoff);
+    let m1 = recurse(&add(&a11, &a22), &add(&b11, &b22));
+    let m2 = recurse(&add(&a21, &a22), &b11);
+    let m3 = recurse(&a11, &sub(&b12, &b22));
+    let m4 = recurse(&a22, &sub(&b21, &b11));
+    let m5 = recurse(&add(&a11, &a12), &b22);
+    let m6 = recurse(&sub(&a21, &a11), &add(&b11, &b12));
+    let m7 = recurse(&sub(&a12, &a22), &add(&b21, &b22));
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+
+    let mut c = Vec::with_capacity(m * n);
+    for row in 0..m {
+        let (left, right, local) = if row < hm {
+            (&c11, &c12, row)
+        } else {
+            (&c21, &c22, row - hm)
+        };
+        let start = local * hn;
+        c.extend_from_slice(&left[start..start + hn]);
+        c.extend_from_slice(&right[start..start + n - hn]);
+    }
+    c
+}
+
+fn naive<T>(a: &[T], b: &[T], m: usize, k: usize, n: usize) -> Vec<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Clone + Default,
+{
+    let mut c = Vec::with_capacity(m * n);
+    for row in 0..m {
+        for col in 0..n {
+            let element = (0..k)
+                .map(|index| a[row * k + index].clone() * b[index * n + col].clone())
+                .reduce(|accumulator, product| accumulator + product)
+                .unwrap_or_default();
+            c.push(element);
+        }
+    }
+    c
+}
+
+/// Copies the `height x width` block at `(row, col)` out of the
+/// row-major `nrows x ncols` matrix `src`, padding with `T::default()`
+/// where the block extends past its edges.
+fn block<T>(
+    src: &[T],
+    nrows: usize,
+    ncols: usize,
+    row: usize,
+    col: usize,
+    height: usize,
+    width: usize,
+) -> Vec<T>
+where
+    T: Clone + Default,
+{
+    let mut output = Vec::with_capacity(height * width);
+    for i in row..row + height {
+        for j in col..col + width {
+            if i < nrows && j < ncols {
+                output.push(src[i * ncols + j].clone());
+            } else {
+                output.push(T::default());
+            }
+        }
+    }
+    output
+}
+
+fn add<T>(lhs: &[T], rhs: &[T]) -> Vec<T>
+where
+    T: Add<Output = T> + Clone,
+{
+    lhs.iter()
+        .zip(rhs)
+        .map(|(left, right)| left.clone() + right.clone())
+        .collect()
+}
+
+fn sub<T>(lhs: &[T], rhs: &[T]) -> Vec<T>
+where
+    T: Sub<Output = T> + Clone,
+{
+    lhs.iter()
+        .zip(rhs)
+        .map(|(left, right)| left.clone() - right.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::matrix;
+    use crate::matrix::shape::Shape;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn test_mat_mul_strassen() {
+        let mut lhs = Matrix::<i64>::new((7, 5));
+        for (index, element) in lhs.data.iter_mut().enumerate() {
+            *element = index as i64 - 17;
+        }
+        let mut rhs = Matrix::<i64>::new((5, 6));
+        for (index, element) in rhs.data.iter_mut().enumerate() {
+            *element = (index as i64 % 7 - 2) * (index as i64 / 5 + 3);
+        }
+        let expected = lhs.clone().mat_mul(rhs.clone()).unwrap();
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                for cutoff in [0, 1, 2, 3, 8] {
+                    let mut output = lhs.clone().mat_mul_strassen(rhs.clone(), cutoff).unwrap();
+                    assert_eq!(output.order(), lhs.order());
+                    if output.order() != expected.order() {
+                        output.switch_order();
+                    }
+                    assert_eq!(output, expected);
+                }
+                rhs.switch_order();
+            }
+            lhs.switch_order();
+        }
+
+        let lhs = matrix![[1, 2], [3, 4]];
+        let result = lhs.clone().mat_mul_strassen(matrix![[1, 2, 3]], 1);
+        assert_eq!(
+            result,
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 2),
+                rhs: Shape::new(1, 3)
+            })
+        );
+
+        let output = Matrix::<i32>::new((3, 0))
+            .mat_mul_strassen(Matrix::new((0, 2)), 1)
+            .unwrap();
+        assert_eq!(output, Matrix::new((3, 2)));
+    }
+}
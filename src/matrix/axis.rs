@@ -0,0 +1,52 @@
+use super::order::Order;
+
+/// Represents an axis of a matrix.
+///
+/// [`Axis::Row`] refers to the rows of a matrix, and [`Axis::Col`] refers
+/// to the columns. Methods accepting an [`Axis`] operate on each vector
+/// along it, e.g., [`Matrix::iter_axis`] with [`Axis::Row`] iterates over
+/// the rows.
+///
+/// [`Matrix::iter_axis`]: crate::matrix::Matrix::iter_axis
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    Row,
+    Col,
+}
+
+impl Axis {
+    /// Returns the other axis.
+    pub fn switch(self) -> Self {
+        match self {
+            Self::Row => Self::Col,
+            Self::Col => Self::Row,
+        }
+    }
+
+    /// Returns `true` if the axis is the major axis for the given order.
+    pub(super) fn is_major(self, order: Order) -> bool {
+        matches!(
+            (self, order),
+            (Self::Row, Order::RowMajor) | (Self::Col, Order::ColMajor)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_switch() {
+        assert_eq!(Axis::Row.switch(), Axis::Col);
+        assert_eq!(Axis::Col.switch(), Axis::Row);
+    }
+
+    #[test]
+    fn test_is_major() {
+        assert!(Axis::Row.is_major(Order::RowMajor));
+        assert!(!Axis::Row.is_major(Order::ColMajor));
+        assert!(!Axis::Col.is_major(Order::RowMajor));
+        assert!(Axis::Col.is_major(Order::ColMajor));
+    }
+}
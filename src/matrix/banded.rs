@@ -0,0 +1,379 @@
+//! This module provides [`BandedMatrix`], a compact storage format for
+//! matrices whose nonzero elements lie near the main diagonal.
+//!
+//! A tridiagonal `n x n` matrix, for example, takes up `3n` elements
+//! instead of `n^2`.
+
+use super::shape::Shape;
+use super::Matrix;
+use crate::error::{Error, Result};
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+
+/// A matrix stored by its band of `lower` subdiagonals, the main
+/// diagonal, and `upper` superdiagonals. All elements outside the band
+/// are implicitly `T::default()`.
+///
+/// Each row stores `lower + upper + 1` elements, from column
+/// `row - lower` to column `row + upper`, with positions that fall
+/// outside the matrix padded with `T::default()`.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::matrix;
+///
+/// let dense = matrix![[2, -1, 0, 0], [-1, 2, -1, 0], [0, -1, 2, -1], [0, 0, -1, 2]];
+/// let banded = dense.to_banded(1, 1);
+///
+/// assert_eq!(banded.get(1, 2), Some(&-1));
+/// assert_eq!(banded.get(0, 3), None);
+/// assert_eq!(banded.mul_vector(&[1, 1, 1, 1]), Ok(vec![1, 0, 0, 1]));
+/// assert_eq!(banded.to_dense(), Ok(dense));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BandedMatrix<T> {
+    shape: Shape,
+    lower: usize,
+    upper: usize,
+    data: Vec<T>,
+}
+
+impl<T> BandedMatrix<T> {
+    /// Returns the shape of the matrix.
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn nrows(&self) -> usize {
+        self.shape.nrows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn ncols(&self) -> usize {
+        self.shape.ncols
+    }
+
+    /// Returns the number of stored subdiagonals.
+    pub fn lower(&self) -> usize {
+        self.lower
+    }
+
+    /// Returns the number of stored superdiagonals.
+    pub fn upper(&self) -> usize {
+        self.upper
+    }
+
+    /// Returns a reference to the element at `(row, col)`, or [`None`] if
+    /// it is out of bounds or outside the band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let banded = matrix![[1, 2, 0], [3, 4, 5]].to_banded(1, 1);
+    ///
+    /// assert_eq!(banded.get(1, 0), Some(&3));
+    /// assert_eq!(banded.get(0, 2), None);
+    /// assert_eq!(banded.get(2, 0), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.position(row, col).map(|position| &self.data[position])
+    }
+
+    /// Returns a mutable reference to the element at `(row, col)`, or
+    /// [`None`] if it is out of bounds or outside the band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut banded = matrix![[1, 2], [3, 4]].to_banded(0, 1);
+    ///
+    /// *banded.get_mut(0, 1).unwrap() = 5;
+    /// assert_eq!(banded.get_mut(1, 0), None);
+    /// assert_eq!(banded.to_dense(), Ok(matrix![[1, 5], [0, 4]]));
+    /// ```
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.position(row, col)
+            .map(|position| &mut self.data[position])
+    }
+
+    /// Converts the matrix into a dense matrix.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let banded = matrix![[1, 2, 3], [4, 5, 6]].to_banded(0, 1);
+    /// assert_eq!(banded.to_dense(), Ok(matrix![[1, 2, 0], [0, 5, 6]]));
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn to_dense(&self) -> Result<Matrix<T>>
+    where
+        T: Clone + Default,
+    {
+        let mut output = Matrix::build(self.shape)?;
+        for (row, col, value) in self.iter() {
+            output[(row, col)] = value.clone();
+        }
+        Ok(output)
+    }
+
+    /// Multiplies the matrix by a column vector.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the length of `rhs` does not match
+    ///   the number of columns.
+    ///
+    /// # Notes
+    ///
+    /// Only elements within the band are multiplied, so the cost is
+    /// proportional to the number of stored elements rather than to the
+    /// size of the dense matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape};
+    ///
+    /// let banded = matrix![[1, 2, 0], [3, 4, 5], [0, 6, 7]].to_banded(1, 1);
+    ///
+    /// assert_eq!(banded.mul_vector(&[1, 0, 2]), Ok(vec![1, 13, 14]));
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(3, 3),
+    ///     rhs: Shape::new(2, 1),
+    /// };
+    /// assert_eq!(banded.mul_vector(&[1, 0]), Err(error));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn mul_vector<R, U>(&self, rhs: &[R]) -> Result<Vec<U>>
+    where
+        T: Mul<R, Output = U> + Clone,
+        R: Clone,
+        U: Add<Output = U> + Default,
+    {
+        if rhs.len() != self.ncols() {
+            return Err(Error::not_conformable_with_slice(self.shape, rhs.len()));
+        }
+        let mut output: Vec<U> = Vec::with_capacity(self.nrows());
+        output.resize_with(self.nrows(), U::default);
+        for (row, col, value) in self.iter() {
+            let product = value.clone() * rhs[col].clone();
+            output[row] = core::mem::take(&mut output[row]) + product;
+        }
+        Ok(output)
+    }
+
+    /// Returns an iterator over the elements within the band that lie
+    /// inside the matrix, as `(row, col, value)` in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let banded = matrix![[1, 2], [3, 4]].to_banded(1, 0);
+    /// let entries: Vec<_> = banded.iter().collect();
+    ///
+    /// assert_eq!(entries, vec![(0, 0, &1), (1, 0, &3), (1, 1, &4)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        let width = self.width();
+        self.data
+            .iter()
+            .enumerate()
+            .filter_map(move |(position, value)| {
+                let row = position / width;
+                let col = (row + position % width).checked_sub(self.lower)?;
+                (col < self.ncols()).then_some((row, col, value))
+            })
+    }
+
+    fn width(&self) -> usize {
+        self.lower + self.upper + 1
+    }
+
+    fn position(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.nrows() || col >= self.ncols() {
+            return None;
+        }
+        if row > col + self.lower || col > row + self.upper {
+            return None;
+        }
+        Some(row * self.width() + col + self.lower - row)
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Returns a copy of the matrix with every element outside the band
+    /// of `lower` subdiagonals and `upper` superdiagonals set to zero.
+    ///
+    /// # Notes
+    ///
+    /// `T::default()` is taken as zero. The resulting matrix will always
+    /// have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    ///
+    /// assert_eq!(matrix.band(1, 0), matrix![[1, 0, 0], [4, 5, 0], [0, 8, 9]]);
+    /// assert_eq!(matrix.band(0, 0), matrix![[1, 0, 0], [0, 5, 0], [0, 0, 9]]);
+    /// ```
+    pub fn band(&self, lower: usize, upper: usize) -> Self
+    where
+        T: Clone + Default,
+    {
+        let mut output = self.clone();
+        for (index, element) in output.iter_elements_mut_with_index() {
+            let (row, col) = (index.row, index.col);
+            if row > col.saturating_add(lower) || col > row.saturating_add(upper) {
+                *element = T::default();
+            }
+        }
+        output
+    }
+
+    /// Converts the matrix into banded storage, keeping `lower`
+    /// subdiagonals and `upper` superdiagonals and discarding all other
+    /// elements.
+    ///
+    /// # Notes
+    ///
+    /// `lower` and `upper` are capped to the diagonals that exist in the
+    /// matrix, so the storage never exceeds that of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let banded = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]].to_banded(1, 5);
+    ///
+    /// assert_eq!(banded.lower(), 1);
+    /// assert_eq!(banded.upper(), 2);
+    /// assert_eq!(banded.to_dense(), Ok(matrix![[1, 2, 3], [4, 5, 6], [0, 8, 9]]));
+    /// ```
+    pub fn to_banded(&self, lower: usize, upper: usize) -> BandedMatrix<T>
+    where
+        T: Clone + Default,
+    {
+        let shape = self.shape();
+        let lower = lower.min(shape.nrows.saturating_sub(1));
+        let upper = upper.min(shape.ncols.saturating_sub(1));
+        let width = lower + upper + 1;
+        let mut data = Vec::with_capacity(shape.nrows * width);
+        for row in 0..shape.nrows {
+            data.extend(
+                (0..width).map(|offset| match (row + offset).checked_sub(lower) {
+                    Some(col) if col < shape.ncols => self[(row, col)].clone(),
+                    _ => T::default(),
+                }),
+            );
+        }
+        BandedMatrix {
+            shape,
+            lower,
+            upper,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::order::Order;
+    use alloc::vec;
+
+    #[test]
+    fn test_band() {
+        let mut matrix = matrix![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+        for _ in 0..2 {
+            let mut output = matrix.band(1, 1);
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1, 2, 0, 0], [5, 6, 7, 0], [0, 10, 11, 12]]);
+
+            let output = matrix.band(usize::MAX, usize::MAX);
+            assert_eq!(output, matrix);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_to_banded() {
+        let mut matrix = matrix![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+        for _ in 0..2 {
+            let banded = matrix.to_banded(1, 2);
+            assert_eq!(banded.shape(), Shape::new(3, 4));
+            assert_eq!((banded.lower(), banded.upper()), (1, 2));
+            assert_eq!(banded.data, vec![0, 1, 2, 3, 5, 6, 7, 8, 10, 11, 12, 0]);
+            assert_eq!(banded.get(2, 0), None);
+            assert_eq!(banded.get(2, 1), Some(&10));
+            assert_eq!(banded.get(0, 3), None);
+            assert_eq!(banded.get(3, 3), None);
+
+            let mut dense = banded.to_dense().unwrap();
+            dense.set_order(matrix.order());
+            assert_eq!(dense, matrix.band(1, 2));
+
+            let banded = matrix.to_banded(usize::MAX, usize::MAX);
+            assert_eq!((banded.lower(), banded.upper()), (2, 3));
+            let mut dense = banded.to_dense().unwrap();
+            dense.set_order(matrix.order());
+            assert_eq!(dense, matrix);
+
+            matrix.switch_order();
+        }
+
+        let banded = Matrix::<i32>::new((0, 3)).to_banded(1, 1);
+        assert_eq!(banded.iter().count(), 0);
+        assert_eq!(banded.to_dense(), Ok(Matrix::new((0, 3))));
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        let dense = matrix![[4, 1, 0, 0, 0], [1, 4, 1, 0, 0], [0, 1, 4, 1, 0]];
+        let banded = dense.to_banded(1, 1);
+        assert_eq!(banded.iter().count(), 8);
+
+        let rhs = [1, 2, 3, 4, 5];
+        let expected: Vec<i32> = dense
+            .iter_rows()
+            .map(|row| row.zip(&rhs).map(|(x, y)| x * y).sum())
+            .collect();
+        assert_eq!(banded.mul_vector(&rhs), Ok(expected));
+        assert_eq!(
+            banded.mul_vector(&rhs[..4]),
+            Err(Error::NotConformable {
+                lhs: Shape::new(3, 5),
+                rhs: Shape::new(4, 1)
+            })
+        );
+
+        let banded = Matrix::<i32>::new((2, 0)).to_banded(0, 0);
+        assert_eq!(banded.mul_vector::<i32, i32>(&[]), Ok(vec![0, 0]));
+    }
+}
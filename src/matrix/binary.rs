@@ -0,0 +1,614 @@
+//! This module defines a self-describing binary format for matrices.
+//!
+//! # Format
+//!
+//! Version 1 stores elements in little-endian byte order. Integers in
+//! the header are always little-endian.
+//!
+//! | Offset | Size           | Content                                          |
+//! | ------ | -------------- | ------------------------------------------------ |
+//! | 0      | 5              | magic bytes `MTRX1`                              |
+//! | 5      | 1              | element type tag, see [`Element::TAG`]           |
+//! | 6      | 1              | order, `0` for row-major and `1` for column-major |
+//! | 7      | 8              | number of rows as `u64`                          |
+//! | 15     | 8              | number of columns as `u64`                       |
+//! | 23     | `size * width` | elements in storage order                        |
+//!
+//! Version 2 adds the byte order and width of the elements to the
+//! header, so that data can be written in the native byte order of
+//! either side of a pipe.
+//!
+//! | Offset | Size           | Content                                          |
+//! | ------ | -------------- | ------------------------------------------------ |
+//! | 0      | 5              | magic bytes `MTRX2`                              |
+//! | 5      | 1              | element type tag, see [`Element::TAG`]           |
+//! | 6      | 1              | order, `0` for row-major and `1` for column-major |
+//! | 7      | 1              | endianness, `0` for little and `1` for big       |
+//! | 8      | 1              | element width in bytes, see [`Element::WIDTH`]   |
+//! | 9      | 8              | number of rows as `u64`                          |
+//! | 17     | 8              | number of columns as `u64`                       |
+//! | 25     | `size * width` | elements in storage order                        |
+//!
+//! The header records everything needed to interpret the payload, so the
+//! format does not depend on the in-memory layout of [`Matrix`].
+
+use super::order::Order;
+use super::shape::{AxisShape, Shape};
+use super::Matrix;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// Magic bytes identifying version 1 of the format.
+pub const MAGIC: [u8; 5] = *b"MTRX1";
+
+/// Magic bytes identifying version 2 of the format.
+pub const MAGIC_V2: [u8; 5] = *b"MTRX2";
+
+/// The byte order of elements in version 2 of the format.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Least significant byte first.
+    #[default]
+    Little,
+
+    /// Most significant byte first.
+    Big,
+}
+
+impl Endianness {
+    /// Returns the byte order of the target platform.
+    pub fn native() -> Self {
+        if cfg!(target_endian = "big") {
+            Self::Big
+        } else {
+            Self::Little
+        }
+    }
+}
+
+/// A type that can be stored in the binary format.
+///
+/// This trait is sealed and implemented for `bool` and all primitive
+/// integer and floating-point types except `usize` and `isize`, whose
+/// width depends on the platform.
+pub trait Element: Sized + sealed::Sealed {
+    /// Tag identifying the element type in the header.
+    const TAG: u8;
+
+    /// Width of the element in bytes.
+    const WIDTH: u8;
+
+    /// Writes the element in little-endian byte order.
+    fn write_le<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Reads an element in little-endian byte order.
+    fn read_le<R: Read>(reader: &mut R) -> io::Result<Self>;
+
+    /// Writes the element in big-endian byte order.
+    fn write_be<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Reads an element in big-endian byte order.
+    fn read_be<R: Read>(reader: &mut R) -> io::Result<Self>;
+}
+
+macro_rules! impl_element {
+    ($($t:ty => $tag:literal),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Element for $t {
+                const TAG: u8 = $tag;
+                const WIDTH: u8 = core::mem::size_of::<$t>() as u8;
+
+                fn write_le<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    writer.write_all(&self.to_le_bytes())
+                }
+
+                fn read_le<R: Read>(reader: &mut R) -> io::Result<Self> {
+                    let mut bytes = [0; core::mem::size_of::<$t>()];
+                    reader.read_exact(&mut bytes)?;
+                    Ok(<$t>::from_le_bytes(bytes))
+                }
+
+                fn write_be<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    writer.write_all(&self.to_be_bytes())
+                }
+
+                fn read_be<R: Read>(reader: &mut R) -> io::Result<Self> {
+                    let mut bytes = [0; core::mem::size_of::<$t>()];
+                    reader.read_exact(&mut bytes)?;
+                    Ok(<$t>::from_be_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_element! {
+    u8 => 0x01, u16 => 0x02, u32 => 0x03, u64 => 0x04, u128 => 0x05,
+    i8 => 0x11, i16 => 0x12, i32 => 0x13, i64 => 0x14, i128 => 0x15,
+    f32 => 0x21, f64 => 0x22,
+}
+
+impl sealed::Sealed for bool {}
+
+impl Element for bool {
+    const TAG: u8 = 0x30;
+    const WIDTH: u8 = 1;
+
+    fn write_le<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&[*self as u8])
+    }
+
+    fn read_le<R: Read>(reader: &mut R) -> io::Result<Self> {
+        match u8::read_le(reader)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_data("invalid boolean value")),
+        }
+    }
+
+    fn write_be<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write_le(writer)
+    }
+
+    fn read_be<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Self::read_le(reader)
+    }
+}
+
+impl<T: Element> Matrix<T> {
+    /// Saves the matrix to a file in the binary format.
+    ///
+    /// See the [module-level documentation](self) for the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error encountered while creating or writing the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let path = std::env::temp_dir().join("matreex-doc-save.mtrx");
+    ///
+    /// matrix.save(&path).unwrap();
+    /// assert_eq!(Matrix::<i32>::load(&path).unwrap(), matrix);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Loads a matrix from a file in the binary format.
+    ///
+    /// The matrix keeps the order recorded in the file.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidData`] if the file is not a valid matrix
+    ///   of element type `T`.
+    /// - Any other I/O error encountered while opening or reading the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let matrix = matrix![[0.0, 1.0], [2.0, 3.0]];
+    /// let path = std::env::temp_dir().join("matreex-doc-load.mtrx");
+    /// matrix.save(&path).unwrap();
+    ///
+    /// assert_eq!(Matrix::<f64>::load(&path).unwrap(), matrix);
+    /// assert!(Matrix::<f32>::load(&path).is_err());
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    /// Writes the matrix to `writer` in the binary format.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error encountered while writing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[1u8, 2u8]];
+    /// let mut bytes = Vec::new();
+    ///
+    /// matrix.write_to(&mut bytes).unwrap();
+    /// assert_eq!(&bytes[..5], b"MTRX1");
+    /// assert_eq!(bytes.len(), 23 + 2);
+    /// ```
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let order = match self.order {
+            Order::RowMajor => 0,
+            Order::ColMajor => 1,
+        };
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[T::TAG, order])?;
+        writer.write_all(&(self.nrows() as u64).to_le_bytes())?;
+        writer.write_all(&(self.ncols() as u64).to_le_bytes())?;
+        for element in &self.data {
+            element.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the matrix to `writer` in version 2 of the binary format,
+    /// with elements in the given byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error encountered while writing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Endianness};
+    ///
+    /// let matrix = matrix![[1u16, 2u16]];
+    /// let mut bytes = Vec::new();
+    ///
+    /// matrix.write_to_with_endianness(&mut bytes, Endianness::Big).unwrap();
+    /// assert_eq!(&bytes[..5], b"MTRX2");
+    /// assert_eq!(&bytes[25..], [0, 1, 0, 2]);
+    /// ```
+    pub fn write_to_with_endianness<W: Write>(
+        &self,
+        mut writer: W,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        let order = match self.order {
+            Order::RowMajor => 0,
+            Order::ColMajor => 1,
+        };
+        let endian = match endianness {
+            Endianness::Little => 0,
+            Endianness::Big => 1,
+        };
+        writer.write_all(&MAGIC_V2)?;
+        writer.write_all(&[T::TAG, order, endian, T::WIDTH])?;
+        writer.write_all(&(self.nrows() as u64).to_le_bytes())?;
+        writer.write_all(&(self.ncols() as u64).to_le_bytes())?;
+        for element in &self.data {
+            match endianness {
+                Endianness::Little => element.write_le(&mut writer)?,
+                Endianness::Big => element.write_be(&mut writer)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the matrix encoded in version 2 of the binary format, with
+    /// elements in the given byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Endianness, Matrix};
+    ///
+    /// let matrix = matrix![[0.0, 1.0], [2.0, 3.0]];
+    /// let bytes = matrix.to_bytes(Endianness::native());
+    ///
+    /// assert_eq!(bytes.len(), 25 + 4 * 8);
+    /// assert_eq!(Matrix::<f64>::from_bytes(&bytes).unwrap(), matrix);
+    /// ```
+    pub fn to_bytes(&self, endianness: Endianness) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_to_with_endianness(&mut bytes, endianness)
+            .expect("writing to a vector should not fail");
+        bytes
+    }
+
+    /// Decodes a matrix in either version of the binary format from
+    /// `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidData`] if `bytes` is not a valid matrix
+    ///   of element type `T`, or has trailing bytes.
+    /// - [`io::ErrorKind::UnexpectedEof`] if `bytes` is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Endianness, Matrix};
+    /// use std::io;
+    ///
+    /// let matrix = matrix![[0u16, 1, 2], [3, 4, 5]];
+    /// let mut bytes = matrix.to_bytes(Endianness::Big);
+    /// assert_eq!(Matrix::<u16>::from_bytes(&bytes).unwrap(), matrix);
+    ///
+    /// bytes.push(0);
+    /// let error = Matrix::<u16>::from_bytes(&bytes).unwrap_err();
+    /// assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    /// ```
+    pub fn from_bytes(mut bytes: &[u8]) -> io::Result<Self> {
+        let matrix = Self::read_from(&mut bytes)?;
+        if !bytes.is_empty() {
+            return Err(invalid_data("trailing bytes after matrix data"));
+        }
+        Ok(matrix)
+    }
+
+    /// Reads a matrix in either version of the binary format from
+    /// `reader`.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidData`] if the data is not a valid matrix
+    ///   of element type `T`.
+    /// - Any other I/O error encountered while reading.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let matrix = matrix![[0i64, 1], [2, 3]];
+    /// let mut bytes = Vec::new();
+    /// matrix.write_to(&mut bytes).unwrap();
+    ///
+    /// let result = Matrix::<i64>::read_from(bytes.as_slice());
+    /// assert_eq!(result.unwrap(), matrix);
+    /// ```
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        let version = match magic {
+            MAGIC => 1,
+            MAGIC_V2 => 2,
+            _ => return Err(invalid_data("invalid magic bytes")),
+        };
+
+        let [tag, order] = {
+            let mut bytes = [0; 2];
+            reader.read_exact(&mut bytes)?;
+            bytes
+        };
+        if tag != T::TAG {
+            return Err(invalid_data("element type mismatch"));
+        }
+        let order = match order {
+            0 => Order::RowMajor,
+            1 => Order::ColMajor,
+            _ => return Err(invalid_data("invalid order")),
+        };
+        let endianness = if version == 1 {
+            Endianness::Little
+        } else {
+            let [endian, width] = {
+                let mut bytes = [0; 2];
+                reader.read_exact(&mut bytes)?;
+                bytes
+            };
+            if width != T::WIDTH {
+                return Err(invalid_data("element width mismatch"));
+            }
+            match endian {
+                0 => Endianness::Little,
+                1 => Endianness::Big,
+                _ => return Err(invalid_data("invalid endianness")),
+            }
+        };
+
+        let nrows = read_dimension(&mut reader)?;
+        let ncols = read_dimension(&mut reader)?;
+        let shape = AxisShape::try_from_shape(Shape::new(nrows, ncols), order)
+            .map_err(|error| invalid_data(&error.to_string()))?;
+        let size =
+            Self::check_size(shape.size()).map_err(|error| invalid_data(&error.to_string()))?;
+
+        // The header is untrusted, so the buffer grows with the payload
+        // actually read instead of being allocated up front.
+        let mut data = Vec::new();
+        for _ in 0..size {
+            let element = match endianness {
+                Endianness::Little => T::read_le(&mut reader)?,
+                Endianness::Big => T::read_be(&mut reader)?,
+            };
+            data.push(element);
+        }
+
+        Ok(Self { order, shape, data })
+    }
+}
+
+fn read_dimension<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let value = u64::read_le(reader)?;
+    usize::try_from(value).map_err(|_| invalid_data("dimension exceeds usize::MAX"))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_write_to() {
+        let mut matrix = matrix![[1u16, 2, 3], [4, 5, 6]];
+        let mut bytes = Vec::new();
+        matrix.write_to(&mut bytes).unwrap();
+
+        let mut expected = b"MTRX1".to_vec();
+        expected.extend([0x02, 0]);
+        expected.extend(2u64.to_le_bytes());
+        expected.extend(3u64.to_le_bytes());
+        expected.extend([1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0]);
+        assert_eq!(bytes, expected);
+
+        matrix.switch_order();
+        let mut bytes = Vec::new();
+        matrix.write_to(&mut bytes).unwrap();
+
+        let mut expected = b"MTRX1".to_vec();
+        expected.extend([0x02, 1]);
+        expected.extend(2u64.to_le_bytes());
+        expected.extend(3u64.to_le_bytes());
+        expected.extend([1, 0, 4, 0, 2, 0, 5, 0, 3, 0, 6, 0]);
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_read_from() {
+        let mut matrix = matrix![[0.5f32, -1.0, 2.0], [3.0, 4.0, f32::MAX]];
+
+        for _ in 0..2 {
+            let mut bytes = Vec::new();
+            matrix.write_to(&mut bytes).unwrap();
+            let output = Matrix::<f32>::read_from(bytes.as_slice()).unwrap();
+            assert_eq!(output, matrix);
+
+            matrix.switch_order();
+        }
+
+        let matrix = matrix![[true, false], [false, true]];
+        let mut bytes = Vec::new();
+        matrix.write_to(&mut bytes).unwrap();
+        assert_eq!(Matrix::<bool>::read_from(bytes.as_slice()).unwrap(), matrix);
+
+        let matrix = Matrix::<i128>::new((0, 3));
+        let mut bytes = Vec::new();
+        matrix.write_to(&mut bytes).unwrap();
+        assert_eq!(Matrix::<i128>::read_from(bytes.as_slice()).unwrap(), matrix);
+    }
+
+    #[test]
+    fn test_read_from_fails() {
+        let matrix = matrix![[1u8, 2], [3, 4]];
+        let mut bytes = Vec::new();
+        matrix.write_to(&mut bytes).unwrap();
+
+        let read = |bytes: &[u8]| Matrix::<u8>::read_from(bytes).unwrap_err().kind();
+
+        let mut invalid = bytes.clone();
+        invalid[4] = b'2';
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+
+        assert_eq!(
+            Matrix::<i8>::read_from(bytes.as_slice())
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+
+        let mut invalid = bytes.clone();
+        invalid[6] = 2;
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+
+        let mut invalid = bytes.clone();
+        invalid[7..15].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+
+        assert_eq!(
+            read(&bytes[..bytes.len() - 1]),
+            io::ErrorKind::UnexpectedEof
+        );
+        assert_eq!(read(&bytes[..3]), io::ErrorKind::UnexpectedEof);
+
+        let mut invalid = Vec::new();
+        matrix![[true]].write_to(&mut invalid).unwrap();
+        *invalid.last_mut().unwrap() = 2;
+        assert_eq!(
+            Matrix::<bool>::read_from(invalid.as_slice())
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let matrix = matrix![[0i32, -1, 2], [3, -4, 5]];
+        let path = std::env::temp_dir().join(format!("matreex-test-{}.mtrx", std::process::id()));
+
+        matrix.save(&path).unwrap();
+        let output = Matrix::<i32>::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(output, matrix);
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let mut matrix = matrix![[1u16, 2, 3], [4, 5, 6]];
+
+        let bytes = matrix.to_bytes(Endianness::Little);
+        let mut expected = b"MTRX2".to_vec();
+        expected.extend([0x02, 0, 0, 2]);
+        expected.extend(2u64.to_le_bytes());
+        expected.extend(3u64.to_le_bytes());
+        expected.extend([1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0]);
+        assert_eq!(bytes, expected);
+
+        matrix.switch_order();
+        let bytes = matrix.to_bytes(Endianness::Big);
+        let mut expected = b"MTRX2".to_vec();
+        expected.extend([0x02, 1, 1, 2]);
+        expected.extend(2u64.to_le_bytes());
+        expected.extend(3u64.to_le_bytes());
+        expected.extend([0, 1, 0, 4, 0, 2, 0, 5, 0, 3, 0, 6]);
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let mut matrix = matrix![[0.5f64, -1.0, 2.0], [3.0, 4.0, f64::MIN]];
+
+        for _ in 0..2 {
+            for endianness in [Endianness::Little, Endianness::Big] {
+                let bytes = matrix.to_bytes(endianness);
+                assert_eq!(Matrix::<f64>::from_bytes(&bytes).unwrap(), matrix);
+            }
+            let mut bytes = Vec::new();
+            matrix.write_to(&mut bytes).unwrap();
+            assert_eq!(Matrix::<f64>::from_bytes(&bytes).unwrap(), matrix);
+
+            matrix.switch_order();
+        }
+
+        let matrix = matrix![[true, false]];
+        let bytes = matrix.to_bytes(Endianness::Big);
+        assert_eq!(Matrix::<bool>::from_bytes(&bytes).unwrap(), matrix);
+
+        let read = |bytes: &[u8]| Matrix::<i32>::from_bytes(bytes).unwrap_err().kind();
+        let bytes = matrix![[1i32, 2]].to_bytes(Endianness::Big);
+
+        let mut invalid = bytes.clone();
+        invalid.push(0);
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+
+        let mut invalid = bytes.clone();
+        invalid[7] = 2;
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+
+        let mut invalid = bytes.clone();
+        invalid[8] = 8;
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+
+        let result = Matrix::<u32>::from_bytes(&bytes);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            read(&bytes[..bytes.len() - 1]),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+}
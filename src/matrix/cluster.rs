@@ -0,0 +1,268 @@
+//! This module provides clustering utilities, available with the `rand`
+//! feature.
+
+use super::order::Order;
+use super::real::Real;
+use super::shape::{AxisShape, Shape};
+use super::Matrix;
+use rand::Rng;
+
+/// The result of k-means clustering.
+///
+/// This struct is created by [`Matrix::cluster_rows_kmeans`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct KMeans<T> {
+    /// Index of the cluster each row belongs to.
+    pub labels: Vec<usize>,
+
+    /// Centroids of the clusters, one per row.
+    pub centroids: Matrix<T>,
+}
+
+macro_rules! impl_cluster {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                /// Clusters the rows of the matrix into `k` groups with the
+                /// k-means algorithm.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `k` is zero or greater than the number of rows.
+                ///
+                /// # Notes
+                ///
+                /// Initial centroids are chosen with k-means++ seeding using
+                /// `rng`. Lloyd iterations then run until the labels stop
+                /// changing or `max_iters` is reached. A cluster that loses
+                /// all its rows keeps its previous centroid.
+                ///
+                /// The resulting centroid matrix is `k x ncols`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                /// use rand::rngs::StdRng;
+                /// use rand::SeedableRng;
+                ///
+                /// let points: Matrix<f64> = matrix![
+                ///     [0.0, 0.0],
+                ///     [0.0, 1.0],
+                ///     [10.0, 10.0],
+                ///     [10.0, 11.0],
+                /// ];
+                /// let mut rng = StdRng::seed_from_u64(0);
+                ///
+                /// let kmeans = points.cluster_rows_kmeans(2, 10, &mut rng);
+                /// assert_eq!(kmeans.labels[0], kmeans.labels[1]);
+                /// assert_eq!(kmeans.labels[2], kmeans.labels[3]);
+                /// assert_ne!(kmeans.labels[0], kmeans.labels[2]);
+                /// assert_eq!(kmeans.centroids.shape().nrows, 2);
+                /// ```
+                pub fn cluster_rows_kmeans<R>(
+                    &self,
+                    k: usize,
+                    max_iters: usize,
+                    rng: &mut R,
+                ) -> KMeans<$t>
+                where
+                    R: Rng + ?Sized,
+                {
+                    kmeans(self, k, max_iters, rng)
+                }
+            }
+        )*
+    };
+}
+
+impl_cluster! {f32 f64}
+
+fn kmeans<T, R>(matrix: &Matrix<T>, k: usize, max_iters: usize, rng: &mut R) -> KMeans<T>
+where
+    T: Real,
+    R: Rng + ?Sized,
+{
+    let nrows = matrix.nrows();
+    let ncols = matrix.ncols();
+    assert!(
+        k != 0 && k <= nrows,
+        "number of clusters must be in 1..={nrows}, got {k}"
+    );
+
+    let mut centroids = Vec::with_capacity(k * ncols);
+    let first = rng.gen_range(0..nrows);
+    centroids.extend(matrix.iter_nth_row(first).unwrap().copied());
+    let mut distances: Vec<T> = matrix
+        .iter_rows()
+        .map(|row| squared_distance(row, &centroids))
+        .collect();
+    for _ in 1..k {
+        let total: f64 = distances.iter().map(|distance| distance.to_f64()).sum();
+        let chosen = if total > 0.0 {
+            let mut target = rng.gen::<f64>() * total;
+            distances
+                .iter()
+                .position(|distance| {
+                    target -= distance.to_f64();
+                    target < 0.0
+                })
+                .unwrap_or(nrows - 1)
+        } else {
+            rng.gen_range(0..nrows)
+        };
+        let start = centroids.len();
+        centroids.extend(matrix.iter_nth_row(chosen).unwrap().copied());
+        let centroid = &centroids[start..];
+        for (distance, row) in distances.iter_mut().zip(matrix.iter_rows()) {
+            let candidate = squared_distance(row, centroid);
+            if candidate < *distance {
+                *distance = candidate;
+            }
+        }
+    }
+
+    let mut labels = vec![0; nrows];
+    assign(matrix, &centroids, &mut labels);
+    for _ in 0..max_iters {
+        update(matrix, &mut centroids, &labels);
+        if !assign(matrix, &centroids, &mut labels) {
+            break;
+        }
+    }
+
+    let order = Order::RowMajor;
+    let shape = AxisShape::from_shape_unchecked(Shape::new(k, ncols), order);
+    let centroids = Matrix {
+        order,
+        shape,
+        data: centroids,
+    };
+    KMeans { labels, centroids }
+}
+
+/// Assigns each row to its nearest centroid, returning whether any label
+/// has changed.
+fn assign<T: Real>(matrix: &Matrix<T>, centroids: &[T], labels: &mut [usize]) -> bool {
+    let ncols = matrix.ncols();
+    let mut changed = false;
+    for (label, row) in labels.iter_mut().zip(matrix.iter_rows()) {
+        let mut nearest = 0;
+        let mut min = None;
+        for (index, centroid) in centroids.chunks(ncols.max(1)).enumerate() {
+            let distance = squared_distance(row.clone(), centroid);
+            if min.is_none_or(|min| distance < min) {
+                nearest = index;
+                min = Some(distance);
+            }
+        }
+        if *label != nearest {
+            *label = nearest;
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Moves each centroid to the mean of the rows assigned to it.
+fn update<T: Real>(matrix: &Matrix<T>, centroids: &mut [T], labels: &[usize]) {
+    let ncols = matrix.ncols();
+    if ncols == 0 {
+        return;
+    }
+    let k = centroids.len() / ncols;
+    let mut sums = vec![T::ZERO; centroids.len()];
+    let mut counts = vec![0usize; k];
+    for (&label, row) in labels.iter().zip(matrix.iter_rows()) {
+        counts[label] += 1;
+        for (sum, &element) in sums[label * ncols..(label + 1) * ncols].iter_mut().zip(row) {
+            *sum = *sum + element;
+        }
+    }
+    for (label, &count) in counts.iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+        let count = T::from_f64(count as f64);
+        let range = label * ncols..(label + 1) * ncols;
+        for (centroid, &sum) in centroids[range.clone()].iter_mut().zip(&sums[range]) {
+            *centroid = sum / count;
+        }
+    }
+}
+
+fn squared_distance<'a, T, I>(row: I, centroid: &[T]) -> T
+where
+    T: Real + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    row.into_iter()
+        .zip(centroid)
+        .fold(T::ZERO, |acc, (&x, &c)| {
+            let diff = x - c;
+            acc + diff * diff
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_cluster_rows_kmeans() {
+        let mut points: Matrix<f64> = matrix![
+            [0.0, 0.0],
+            [10.0, 10.0],
+            [0.0, 2.0],
+            [10.0, 12.0],
+            [-20.0, 0.0],
+            [-20.0, 2.0],
+        ];
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..2 {
+            let kmeans = points.cluster_rows_kmeans(3, 100, &mut rng);
+            let labels = &kmeans.labels;
+            assert_eq!(labels.len(), 6);
+            assert_eq!(labels[0], labels[2]);
+            assert_eq!(labels[1], labels[3]);
+            assert_eq!(labels[4], labels[5]);
+            assert_ne!(labels[0], labels[1]);
+            assert_ne!(labels[0], labels[4]);
+            assert_ne!(labels[1], labels[4]);
+
+            let centroids = &kmeans.centroids;
+            assert_eq!(centroids.shape(), Shape::new(3, 2));
+            assert_eq!(centroids[(labels[0], 0)], 0.0);
+            assert_eq!(centroids[(labels[0], 1)], 1.0);
+            assert_eq!(centroids[(labels[1], 0)], 10.0);
+            assert_eq!(centroids[(labels[1], 1)], 11.0);
+            assert_eq!(centroids[(labels[4], 0)], -20.0);
+            assert_eq!(centroids[(labels[4], 1)], 1.0);
+
+            points.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_cluster_rows_kmeans_duplicates() {
+        let points: Matrix<f32> = matrix![[1.0, 1.0], [1.0, 1.0], [1.0, 1.0]];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let kmeans = points.cluster_rows_kmeans(3, 10, &mut rng);
+        assert_eq!(kmeans.labels, vec![0, 0, 0]);
+        assert_eq!(kmeans.centroids, matrix![[1.0; 2]; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cluster_rows_kmeans_fails() {
+        let points: Matrix<f64> = matrix![[0.0], [1.0]];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        points.cluster_rows_kmeans(3, 10, &mut rng);
+    }
+}
@@ -0,0 +1,187 @@
+use super::order::Order;
+use super::Matrix;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Hashes the shape followed by the elements in row-major order,
+/// regardless of the internal [`Order`].
+impl<T: Hash> Hash for Matrix<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.shape_key().hash(state);
+        for element in self.iter_rows().flatten() {
+            element.hash(state);
+        }
+    }
+}
+
+/// Compares lexicographically by number of rows, number of columns,
+/// then elements in row-major order.
+///
+/// Matrices that only differ in internal [`Order`] are not equal, so to
+/// stay consistent with [`PartialEq`], row-major matrices are ordered
+/// before otherwise identical column-major ones.
+///
+/// This ordering is part of the public API and will not change, so it
+/// can be relied on for keys of [`BTreeMap`] and for sorting.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::matrix;
+///
+/// assert!(matrix![[0, 1, 2]] < matrix![[0], [1], [2]]);
+/// assert!(matrix![[0, 1], [2, 3]] < matrix![[0, 2], [1, 3]]);
+/// assert!(matrix![[f64::NAN]].partial_cmp(&matrix![[0.0]]).is_none());
+/// ```
+///
+/// [`BTreeMap`]: std::collections::BTreeMap
+impl<T: PartialOrd> PartialOrd for Matrix<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.shape_key().cmp(&other.shape_key()) {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+        match self
+            .iter_rows()
+            .flatten()
+            .partial_cmp(other.iter_rows().flatten())?
+        {
+            Ordering::Equal => Some(order_key(self.order).cmp(&order_key(other.order))),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// See the [`PartialOrd`] implementation for the ordering used.
+impl<T: Ord> Ord for Matrix<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.shape_key()
+            .cmp(&other.shape_key())
+            .then_with(|| self.iter_rows().flatten().cmp(other.iter_rows().flatten()))
+            .then_with(|| order_key(self.order).cmp(&order_key(other.order)))
+    }
+}
+
+impl<T> Matrix<T> {
+    fn shape_key(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+}
+
+fn order_key(order: Order) -> u8 {
+    match order {
+        Order::RowMajor => 0,
+        Order::ColMajor => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use alloc::collections::{BTreeMap, BTreeSet};
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    use std::collections::hash_map::DefaultHasher;
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
+
+    #[cfg(feature = "std")]
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hash() {
+        let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+        let mut rhs = lhs.clone();
+        assert_eq!(hash(&lhs), hash(&rhs));
+
+        rhs.switch_order();
+        assert_eq!(hash(&lhs), hash(&rhs));
+
+        let reshaped = matrix![[0, 1], [2, 3], [4, 5]];
+        assert_ne!(hash(&lhs), hash(&reshaped));
+
+        let mut map = HashMap::new();
+        map.insert(lhs.clone(), "lhs");
+        map.insert(reshaped.clone(), "reshaped");
+        assert_eq!(map.get(&lhs), Some(&"lhs"));
+        assert_eq!(map.get(&reshaped), Some(&"reshaped"));
+        assert_eq!(map.get(&rhs), None);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut matrices = [
+            matrix![[0, 1], [2, 3]],
+            matrix![[0, 1, 2]],
+            matrix![[0, 1], [2, 4]],
+            matrix![[0], [1], [2]],
+            Matrix::new((0, 0)),
+        ];
+
+        for _ in 0..2 {
+            let mut sorted = matrices.to_vec();
+            sorted.sort();
+            let expected = [
+                Matrix::new((0, 0)),
+                matrix![[0, 1, 2]],
+                matrix![[0, 1], [2, 3]],
+                matrix![[0, 1], [2, 4]],
+                matrix![[0], [1], [2]],
+            ];
+            for (actual, expected) in sorted.iter().zip(&expected) {
+                let mut expected = expected.clone();
+                expected.set_order(actual.order());
+                assert_eq!(actual, &expected);
+            }
+
+            for matrix in matrices.iter_mut() {
+                matrix.switch_order();
+            }
+        }
+
+        let lhs = matrix![[0, 1], [2, 3]];
+        let mut rhs = lhs.clone();
+        rhs.switch_order();
+        assert_eq!(lhs.cmp(&lhs.clone()), Ordering::Equal);
+        assert_eq!(lhs.cmp(&rhs), Ordering::Less);
+        assert_eq!(rhs.cmp(&lhs), Ordering::Greater);
+        assert_eq!(lhs.partial_cmp(&rhs), Some(Ordering::Less));
+
+        let set: BTreeSet<_> = [lhs.clone(), rhs.clone(), lhs.clone()]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+
+        let nan: Matrix<f64> = matrix![[f64::NAN, 0.0]];
+        assert_eq!(nan.partial_cmp(&matrix![[f64::NAN, 0.0]]), None);
+        assert_eq!(
+            nan.partial_cmp(&matrix![[0.0, 0.0, 0.0]]),
+            Some(Ordering::Less)
+        );
+        let float: Matrix<f64> = matrix![[0.0, 1.0]];
+        assert!(float < matrix![[0.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_btree_map_keys() {
+        let mut map = BTreeMap::new();
+        map.insert(matrix![[1, 0], [0, 0]], "b");
+        map.insert(matrix![[0]], "a");
+        map.insert(matrix![[0, 0], [0, 1]], "c");
+        map.insert(matrix![[0, 0, 0]], "d");
+
+        let mut key = matrix![[1, 0], [0, 0]];
+        assert_eq!(map.get(&key), Some(&"b"));
+        key.switch_order();
+        assert_eq!(map.get(&key), None);
+
+        let values: Vec<_> = map.values().copied().collect();
+        assert_eq!(values, ["a", "d", "c", "b"]);
+    }
+}
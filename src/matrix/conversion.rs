@@ -1,7 +1,8 @@
 use super::order::Order;
-use super::shape::{AxisShape, Shape};
+use super::shape::{AxisShape, Shape, ShapeLike};
 use super::Matrix;
 use crate::error::{Error, Result};
+use alloc::vec::Vec;
 
 impl<T> Matrix<T> {
     /// Creates a new [`Matrix`] instance from the given 2D array.
@@ -40,6 +41,465 @@ impl<T> Matrix<T> {
     {
         Self::from(value)
     }
+
+    /// Creates a new row-major [`Matrix`] instance from an iterator over
+    /// rows.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::LengthInconsistent`] if rows differ in length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Matrix, Order};
+    ///
+    /// let matrix = Matrix::from_rows([vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+    /// assert_eq!(matrix.order(), Order::RowMajor);
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    ///
+    /// let result = Matrix::from_rows([vec![0, 1, 2], vec![3, 4]]);
+    /// assert_eq!(result, Err(Error::LengthInconsistent));
+    /// ```
+    ///
+    /// [`Error::LengthInconsistent`]: crate::error::Error::LengthInconsistent
+    pub fn from_rows<I, V>(rows: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = V>,
+        V: IntoIterator<Item = T>,
+    {
+        Self::from_vectors(Order::RowMajor, rows)
+    }
+
+    /// Creates a new column-major [`Matrix`] instance from an iterator
+    /// over columns.
+    ///
+    /// The columns are stored as given, without a [`Matrix::switch_order`]
+    /// pass.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::LengthInconsistent`] if columns differ in length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix, Order};
+    ///
+    /// let matrix = Matrix::from_cols([[0, 3], [1, 4], [2, 5]]).unwrap();
+    /// assert_eq!(matrix.order(), Order::ColMajor);
+    /// assert_eq!(matrix.as_slice(), &[0, 3, 1, 4, 2, 5]);
+    ///
+    /// let mut expected = matrix![[0, 1, 2], [3, 4, 5]];
+    /// expected.set_order(Order::ColMajor);
+    /// assert_eq!(matrix, expected);
+    /// ```
+    ///
+    /// [`Error::LengthInconsistent`]: crate::error::Error::LengthInconsistent
+    pub fn from_cols<I, V>(cols: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = V>,
+        V: IntoIterator<Item = T>,
+    {
+        Self::from_vectors(Order::ColMajor, cols)
+    }
+
+    fn from_vectors<I, V>(order: Order, vectors: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = V>,
+        V: IntoIterator<Item = T>,
+    {
+        let mut data = Vec::new();
+        let mut major = 0;
+        let mut minor = None;
+        for vector in vectors {
+            let len = data.len();
+            data.extend(vector);
+            match minor {
+                None => minor = Some(data.len() - len),
+                Some(minor) if minor != data.len() - len => {
+                    return Err(Error::LengthInconsistent);
+                }
+                Some(_) => {}
+            }
+            major += 1;
+        }
+        let minor = minor.unwrap_or(0);
+        let shape = match order {
+            Order::RowMajor => Shape::new(major, minor),
+            Order::ColMajor => Shape::new(minor, major),
+        };
+        Self::from_raw_parts(order, shape, data)
+    }
+
+    /// Creates a new [`Matrix`] instance from its raw parts, with `data`
+    /// laid out according to `order`.
+    ///
+    /// Together with [`Matrix::into_raw_parts`], this moves the data in
+    /// and out of a matrix without copying.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::SizeMismatch`] if the length of `data` does not match
+    ///   the size of `shape`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{Error, Matrix, Order};
+    ///
+    /// let data = vec![0, 3, 1, 4, 2, 5];
+    /// let result = Matrix::from_raw_parts(Order::ColMajor, (2, 3), data);
+    /// assert_eq!(result.unwrap()[(0, 1)], 1);
+    ///
+    /// let result = Matrix::from_raw_parts(Order::RowMajor, (2, 2), vec![0; 6]);
+    /// assert_eq!(result, Err(Error::SizeMismatch));
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::SizeMismatch`]: crate::error::Error::SizeMismatch
+    pub fn from_raw_parts<S: ShapeLike>(order: Order, shape: S, data: Vec<T>) -> Result<Self> {
+        let shape = AxisShape::try_from_shape(shape, order)?;
+        if shape.size() != data.len() {
+            return Err(Error::SizeMismatch);
+        }
+        Ok(Self { order, shape, data })
+    }
+
+    /// Creates a new [`Matrix`] instance from `data` laid out with the
+    /// given `strides`, in number of elements as returned by
+    /// [`Matrix::strides`].
+    ///
+    /// The strides must describe a contiguous row-major or column-major
+    /// layout, which is then taken over without copying. For arbitrary
+    /// strides, see [`Matrix::from_slice_with_strides`].
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::NotContiguous`] if `strides` is neither row-major nor
+    ///   column-major contiguous for `shape`.
+    /// - [`Error::SizeMismatch`] if the length of `data` does not match
+    ///   the size of `shape`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{Error, Matrix, Order};
+    ///
+    /// let data = vec![0, 3, 1, 4, 2, 5];
+    /// let matrix = Matrix::from_vec_with_strides(data, (2, 3), (1, 2)).unwrap();
+    /// assert_eq!(matrix.order(), Order::ColMajor);
+    /// assert_eq!(matrix[(0, 1)], 1);
+    ///
+    /// let result = Matrix::from_vec_with_strides(vec![0; 6], (2, 3), (6, 2));
+    /// assert!(matches!(result, Err(Error::NotContiguous { .. })));
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::NotContiguous`]: crate::error::Error::NotContiguous
+    /// [`Error::SizeMismatch`]: crate::error::Error::SizeMismatch
+    pub fn from_vec_with_strides<S: ShapeLike>(
+        data: Vec<T>,
+        shape: S,
+        strides: (usize, usize),
+    ) -> Result<Self> {
+        let shape = Shape::new(shape.nrows(), shape.ncols());
+        shape.size()?;
+        let (row_stride, col_stride) = strides;
+        let (nrows, ncols) = (shape.nrows(), shape.ncols());
+        let order = if (col_stride == 1 || ncols <= 1) && (row_stride == ncols || nrows <= 1) {
+            Order::RowMajor
+        } else if (row_stride == 1 || nrows <= 1) && (col_stride == nrows || ncols <= 1) {
+            Order::ColMajor
+        } else {
+            return Err(Error::NotContiguous { strides, shape });
+        };
+        Self::from_raw_parts(order, shape, data)
+    }
+
+    /// Decomposes the matrix into its order, shape and data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Order, Shape};
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let (order, shape, data) = matrix.into_raw_parts();
+    ///
+    /// assert_eq!(order, Order::RowMajor);
+    /// assert_eq!(shape, Shape::new(2, 3));
+    /// assert_eq!(data, vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn into_raw_parts(self) -> (Order, Shape, Vec<T>) {
+        let shape = self.shape();
+        (self.order, shape, self.data)
+    }
+
+    /// Converts the matrix into a vector of rows, in logical row order
+    /// regardless of the internal [`Order`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.switch_order();
+    ///
+    /// let rows = matrix.into_nested_vec();
+    /// assert_eq!(rows, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+    /// ```
+    pub fn into_nested_vec(mut self) -> Vec<Vec<T>> {
+        self.set_order(Order::RowMajor);
+        let (nrows, ncols) = (self.nrows(), self.ncols());
+        let mut data = self.data.into_iter();
+        (0..nrows)
+            .map(|_| data.by_ref().take(ncols).collect())
+            .collect()
+    }
+
+    /// Returns the elements in row-major order, regardless of the
+    /// internal [`Order`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.switch_order();
+    ///
+    /// assert_eq!(matrix.to_row_major_vec(), vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn to_row_major_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter_rows().flatten().cloned().collect()
+    }
+
+    /// Returns the elements in column-major order, regardless of the
+    /// internal [`Order`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// assert_eq!(matrix.to_col_major_vec(), vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn to_col_major_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter_cols().flatten().cloned().collect()
+    }
+
+    /// Converts the matrix into its elements in row-major order,
+    /// regardless of the internal [`Order`].
+    ///
+    /// # Notes
+    ///
+    /// No elements are cloned. If the matrix is column-major, its data
+    /// is rearranged in place, as with [`Matrix::switch_order`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.switch_order();
+    ///
+    /// assert_eq!(matrix.into_row_major_vec(), vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn into_row_major_vec(mut self) -> Vec<T> {
+        self.set_order(Order::RowMajor);
+        self.data
+    }
+
+    /// Converts the matrix into its elements in column-major order,
+    /// regardless of the internal [`Order`].
+    ///
+    /// # Notes
+    ///
+    /// No elements are cloned. If the matrix is row-major, its data is
+    /// rearranged in place, as with [`Matrix::switch_order`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// assert_eq!(matrix.into_col_major_vec(), vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn into_col_major_vec(mut self) -> Vec<T> {
+        self.set_order(Order::ColMajor);
+        self.data
+    }
+
+    /// Creates a new [`Matrix`] instance by stitching a grid of blocks
+    /// together.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if blocks in the same row of the grid
+    ///   differ in number of rows, or blocks in the same column of the
+    ///   grid differ in number of columns.
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// A grid without any blocks results in an empty matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let blocks = matrix![
+    ///     [matrix![[0, 1], [4, 5]], matrix![[2], [6]]],
+    ///     [matrix![[8, 9]], matrix![[10]]],
+    /// ];
+    ///
+    /// let result = Matrix::from_blocks(blocks);
+    /// assert_eq!(result, Ok(matrix![[0, 1, 2], [4, 5, 6], [8, 9, 10]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn from_blocks(mut blocks: Matrix<Matrix<T>>) -> Result<Self> {
+        if blocks.is_empty() {
+            return Ok(Self::empty());
+        }
+        blocks.set_order(Order::RowMajor);
+        let grid_ncols = blocks.ncols();
+
+        let heights: Vec<usize> = blocks
+            .data
+            .chunks(grid_ncols)
+            .map(|row| row[0].nrows())
+            .collect();
+        let widths: Vec<usize> = blocks.data[..grid_ncols]
+            .iter()
+            .map(Matrix::ncols)
+            .collect();
+        for (index, block) in blocks.data.iter().enumerate() {
+            let (i, j) = (index / grid_ncols, index % grid_ncols);
+            let expected = Shape::new(heights[i], widths[j]);
+            if block.shape() != expected {
+                return Err(Error::not_conformable(expected, block.shape()));
+            }
+        }
+
+        let nrows = heights
+            .iter()
+            .try_fold(0usize, |sum, &height| sum.checked_add(height))
+            .ok_or(Error::SizeOverflow)?;
+        let ncols = widths
+            .iter()
+            .try_fold(0usize, |sum, &width| sum.checked_add(width))
+            .ok_or(Error::SizeOverflow)?;
+        let shape = AxisShape::try_from_shape(Shape::new(nrows, ncols), Order::RowMajor)?;
+        let size = Self::check_size(shape.size())?;
+
+        let mut data = Vec::with_capacity(size);
+        let mut blocks = blocks.data.into_iter();
+        for height in heights {
+            let mut row: Vec<_> = blocks
+                .by_ref()
+                .take(grid_ncols)
+                .map(|mut block| {
+                    block.set_order(Order::RowMajor);
+                    block.data.into_iter()
+                })
+                .collect();
+            for _ in 0..height {
+                for (block, &width) in row.iter_mut().zip(&widths) {
+                    data.extend(block.by_ref().take(width));
+                }
+            }
+        }
+
+        let mut output = Self {
+            order: Order::RowMajor,
+            shape,
+            data,
+        };
+        output.set_order(Order::default());
+        Ok(output)
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Creates a new [`Matrix`] instance by copying elements out of
+    /// `data` with the given `strides`, in number of elements.
+    ///
+    /// The element at `(row, col)` is read from position
+    /// `row * row_stride + col * col_stride` of `data`, so strides may
+    /// skip elements, as in a subsampled image, or even repeat them.
+    /// The result is in [`Order::RowMajor`].
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size or the last position exceeds
+    ///   [`usize::MAX`].
+    /// - [`Error::SizeMismatch`] if `data` is too short to hold the last
+    ///   position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let frame = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    /// let matrix = Matrix::from_slice_with_strides(&frame, (2, 3), (6, 2)).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 2, 4], [6, 8, 10]]);
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::SizeMismatch`]: crate::error::Error::SizeMismatch
+    pub fn from_slice_with_strides<S: ShapeLike>(
+        data: &[T],
+        shape: S,
+        strides: (usize, usize),
+    ) -> Result<Self> {
+        let order = Order::RowMajor;
+        let shape = AxisShape::try_from_shape(shape, order)?;
+        let (row_stride, col_stride) = strides;
+        if shape.size() != 0 {
+            let last = (shape.major() - 1)
+                .checked_mul(row_stride)
+                .zip((shape.minor() - 1).checked_mul(col_stride))
+                .and_then(|(row, col)| row.checked_add(col))
+                .ok_or(Error::SizeOverflow)?;
+            if last >= data.len() {
+                return Err(Error::SizeMismatch);
+            }
+        }
+        let mut vec = Vec::with_capacity(shape.size());
+        for row in 0..shape.major() {
+            for col in 0..shape.minor() {
+                let position = row * row_stride + col * col_stride;
+                debug_assert!(position < data.len());
+                vec.push(unsafe { data.get_unchecked(position) }.clone());
+            }
+        }
+        Ok(Self {
+            order,
+            shape,
+            data: vec,
+        })
+    }
 }
 
 impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T> {
@@ -81,6 +541,33 @@ impl<T: Clone> TryFrom<&[Vec<T>]> for Matrix<T> {
     }
 }
 
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = Error;
+
+    /// Creates a new [`Matrix`] instance from a vector of rows, moving
+    /// the elements instead of cloning them.
+    ///
+    /// The allocation of the first row is reused to hold the whole
+    /// matrix.
+    fn try_from(value: Vec<Vec<T>>) -> Result<Self> {
+        let order = Order::default();
+        let nrows = value.len();
+        let ncols = value.first().map_or(0, |row| row.len());
+        if value.iter().any(|row| row.len() != ncols) {
+            return Err(Error::LengthInconsistent);
+        }
+        let shape = AxisShape::try_from_shape(Shape::new(nrows, ncols), order)?;
+        Self::check_size(shape.size())?;
+        let mut rows = value.into_iter();
+        let mut data = rows.next().unwrap_or_default();
+        data.reserve_exact(shape.size() - data.len());
+        for mut row in rows {
+            data.append(&mut row);
+        }
+        Ok(Self { order, shape, data })
+    }
+}
+
 impl<T: Clone> From<&[T]> for Matrix<T> {
     fn from(value: &[T]) -> Self {
         let order = Order::default();
@@ -98,36 +585,36 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if length in each iteration is inconsistent.
+    /// Panics if length in each iteration is inconsistent. Use
+    /// [`Matrix::from_rows`] to get [`Error::LengthInconsistent`] instead.
+    ///
+    /// [`Error::LengthInconsistent`]: crate::error::Error::LengthInconsistent
     fn from_iter<M: IntoIterator<Item = V>>(iter: M) -> Self {
-        let mut data = Vec::with_capacity(0x1000);
-        let mut data_len;
-        let mut nrows;
-        let ncols;
-        let mut iter = iter.into_iter();
-        match iter.next() {
-            None => {
-                return Self::empty();
-            }
-            Some(row) => {
-                nrows = 1;
-                data.extend(row);
-                data_len = data.len();
-                ncols = data_len;
-            }
+        match Self::from_rows(iter) {
+            Err(error) => panic!("{error}"),
+            Ok(matrix) => matrix,
         }
-        for row in iter {
-            data.extend(row);
-            if data.len() - data_len != ncols {
-                panic!("{}", Error::LengthInconsistent);
-            }
-            data_len = data.len();
-            nrows += 1;
+    }
+}
+
+impl<T, V> Extend<V> for Matrix<T>
+where
+    V: IntoIterator<Item = T>,
+{
+    /// Appends rows from an iterator to the bottom of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if length in each iteration is inconsistent. Use
+    /// [`Matrix::extend_rows`] to get [`Error::LengthInconsistent`]
+    /// instead, or [`Matrix::extend_rows_saturating`] to pad or truncate
+    /// rows.
+    ///
+    /// [`Error::LengthInconsistent`]: crate::error::Error::LengthInconsistent
+    fn extend<M: IntoIterator<Item = V>>(&mut self, iter: M) {
+        if let Err(error) = self.extend_rows(iter) {
+            panic!("{error}");
         }
-        data.shrink_to_fit();
-        let order = Order::default();
-        let shape = AxisShape::from_shape_unchecked(Shape::new(nrows, ncols), order);
-        Self { order, shape, data }
     }
 }
 
@@ -135,6 +622,8 @@ where
 mod tests {
     use super::*;
     use crate::matrix;
+    use alloc::string::ToString;
+    use alloc::vec;
 
     #[test]
     fn test_from_2darray() {
@@ -206,6 +695,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_vec_of_vecs() {
+        const MAX: usize = isize::MAX as usize;
+
+        let expected = matrix![[0, 1, 2], [3, 4, 5]];
+
+        let vecs = vec![vec![0, 1, 2], vec![3, 4, 5]];
+        assert_eq!(Matrix::try_from(vecs).unwrap(), expected);
+
+        let vecs = vec![vec![0, 1], vec![2, 3], vec![4, 5]];
+        assert_ne!(Matrix::try_from(vecs).unwrap(), expected);
+
+        let vecs = vec![vec!["0".to_string()], vec!["1".to_string()]];
+        let matrix = Matrix::try_from(vecs).unwrap();
+        assert_eq!(matrix, matrix![["0".to_string()], ["1".to_string()]]);
+
+        let vecs: Vec<Vec<i32>> = Vec::new();
+        assert_eq!(Matrix::try_from(vecs).unwrap(), Matrix::new((0, 0)));
+
+        let vecs: Vec<Vec<i32>> = vec![vec![], vec![]];
+        assert_eq!(Matrix::try_from(vecs).unwrap(), Matrix::new((2, 0)));
+
+        let vecs = vec![vec![(); MAX], vec![(); MAX], vec![(); MAX]];
+        assert_eq!(Matrix::<()>::try_from(vecs), Err(Error::SizeOverflow));
+
+        let vecs = vec![vec![0, 1, 2], vec![3, 4]];
+        assert_eq!(Matrix::try_from(vecs), Err(Error::LengthInconsistent));
+    }
+
+    #[test]
+    fn test_into_nested_vec() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]].map(|x| x.to_string());
+
+        for _ in 0..2 {
+            let rows = matrix.clone().into_nested_vec();
+            assert_eq!(rows, vec![vec!["0", "1", "2"], vec!["3", "4", "5"]]);
+            assert_eq!(Matrix::try_from(rows).unwrap().shape(), matrix.shape());
+
+            matrix.switch_order();
+        }
+
+        assert!(Matrix::<i32>::new((0, 3)).into_nested_vec().is_empty());
+        assert_eq!(
+            Matrix::<i32>::new((2, 0)).into_nested_vec(),
+            vec![Vec::<i32>::new(); 2]
+        );
+    }
+
+    #[test]
+    fn test_to_major_vecs() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]].map(|x| x.to_string());
+        let row_major = ["0", "1", "2", "3", "4", "5"];
+        let col_major = ["0", "3", "1", "4", "2", "5"];
+
+        for _ in 0..2 {
+            assert_eq!(matrix.to_row_major_vec(), row_major);
+            assert_eq!(matrix.to_col_major_vec(), col_major);
+            assert_eq!(matrix.clone().into_row_major_vec(), row_major);
+            assert_eq!(matrix.clone().into_col_major_vec(), col_major);
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<i32>::new((2, 0));
+        assert!(matrix.to_row_major_vec().is_empty());
+        assert!(matrix.into_col_major_vec().is_empty());
+    }
+
     #[test]
     fn test_from_slice() {
         let expected = matrix![[0, 1, 2, 3, 4, 5]];
@@ -227,6 +784,182 @@ mod tests {
         assert_ne!(Matrix::from_slice(&vec), expected);
     }
 
+    #[test]
+    fn test_from_rows() {
+        let matrix = Matrix::from_rows([[0, 1, 2], [3, 4, 5]]).unwrap();
+        assert_eq!(matrix.order, Order::RowMajor);
+        assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+
+        let matrix = Matrix::from_rows((0..3).map(|row| (0..2).map(move |col| row * 2 + col)));
+        assert_eq!(matrix, Ok(matrix![[0, 1], [2, 3], [4, 5]]));
+
+        let matrix = Matrix::<i32>::from_rows(Vec::<Vec<i32>>::new()).unwrap();
+        assert_eq!(matrix.shape(), Shape::new(0, 0));
+
+        let matrix = Matrix::<i32>::from_rows([vec![], vec![]]).unwrap();
+        assert_eq!(matrix.shape(), Shape::new(2, 0));
+
+        let result = Matrix::from_rows([vec![0, 1], vec![2]]);
+        assert_eq!(result, Err(Error::LengthInconsistent));
+        let result = Matrix::from_rows([vec![0], vec![1, 2]]);
+        assert_eq!(result, Err(Error::LengthInconsistent));
+    }
+
+    #[test]
+    fn test_from_cols() {
+        let matrix = Matrix::from_cols([[0, 3], [1, 4], [2, 5]]).unwrap();
+        assert_eq!(matrix.order, Order::ColMajor);
+        assert_eq!(matrix.data, vec![0, 3, 1, 4, 2, 5]);
+        let mut expected = matrix![[0, 1, 2], [3, 4, 5]];
+        expected.set_order(Order::ColMajor);
+        assert_eq!(matrix, expected);
+
+        let matrix = Matrix::<i32>::from_cols([vec![], vec![], vec![]]).unwrap();
+        assert_eq!(matrix.shape(), Shape::new(0, 3));
+
+        let result = Matrix::from_cols([vec![0, 1], vec![2]]);
+        assert_eq!(result, Err(Error::LengthInconsistent));
+    }
+
+    #[test]
+    fn test_raw_parts() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let (row_stride, col_stride) = matrix.strides();
+            for row in 0..2 {
+                for col in 0..3 {
+                    let position = row * row_stride + col * col_stride;
+                    assert_eq!(matrix.as_slice()[position], matrix[(row, col)]);
+                }
+            }
+
+            let (order, shape, data) = matrix.clone().into_raw_parts();
+            assert_eq!(order, matrix.order());
+            assert_eq!(shape, matrix.shape());
+            assert_eq!(
+                Matrix::from_raw_parts(order, shape, data),
+                Ok(matrix.clone())
+            );
+
+            matrix.switch_order();
+        }
+
+        let result = Matrix::from_raw_parts(Order::RowMajor, (2, 2), vec![0; 3]);
+        assert_eq!(result, Err(Error::SizeMismatch));
+        let result = Matrix::<()>::from_raw_parts(Order::RowMajor, (usize::MAX, 2), vec![]);
+        assert_eq!(result, Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    fn test_from_vec_with_strides() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let strides = matrix.strides();
+            let (_, shape, data) = matrix.clone().into_raw_parts();
+            let output = Matrix::from_vec_with_strides(data, shape, strides).unwrap();
+            assert_eq!(output.order(), matrix.order());
+            assert_eq!(output, matrix);
+
+            matrix.switch_order();
+        }
+
+        let output = Matrix::from_vec_with_strides(vec![0, 1, 2], (1, 3), (7, 1)).unwrap();
+        assert_eq!(output.order(), Order::RowMajor);
+        assert_eq!(output, matrix![[0, 1, 2]]);
+        let output = Matrix::from_vec_with_strides(vec![0, 1, 2], (1, 3), (1, 1)).unwrap();
+        assert_eq!(output, matrix![[0, 1, 2]]);
+
+        let result = Matrix::from_vec_with_strides(vec![0; 6], (2, 3), (6, 2));
+        assert_eq!(
+            result,
+            Err(Error::NotContiguous {
+                strides: (6, 2),
+                shape: Shape::new(2, 3)
+            })
+        );
+        let result = Matrix::from_vec_with_strides(vec![0; 5], (2, 3), (3, 1));
+        assert_eq!(result, Err(Error::SizeMismatch));
+        let result = Matrix::<()>::from_vec_with_strides(vec![], (usize::MAX, 2), (2, 1));
+        assert_eq!(result, Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    fn test_from_slice_with_strides() {
+        let frame: Vec<i32> = (0..24).collect();
+
+        let output = Matrix::from_slice_with_strides(&frame, (4, 6), (6, 1)).unwrap();
+        assert_eq!(
+            output,
+            Matrix::from_vec_with_strides(frame.clone(), (4, 6), (6, 1)).unwrap()
+        );
+
+        let output = Matrix::from_slice_with_strides(&frame, (2, 3), (12, 2)).unwrap();
+        assert_eq!(output.order(), Order::RowMajor);
+        assert_eq!(output, matrix![[0, 2, 4], [12, 14, 16]]);
+
+        let output = Matrix::from_slice_with_strides(&frame, (2, 3), (1, 6)).unwrap();
+        assert_eq!(output, matrix![[0, 6, 12], [1, 7, 13]]);
+
+        let output = Matrix::from_slice_with_strides(&frame, (2, 2), (0, 0)).unwrap();
+        assert_eq!(output, matrix![[0, 0], [0, 0]]);
+
+        let output = Matrix::<i32>::from_slice_with_strides(&[], (0, 3), (3, 1)).unwrap();
+        assert_eq!(output.shape(), Shape::new(0, 3));
+
+        let result = Matrix::from_slice_with_strides(&frame, (2, 3), (12, 6));
+        assert_eq!(result, Err(Error::SizeMismatch));
+        let result = Matrix::from_slice_with_strides(&frame, (3, 1), (usize::MAX, 1));
+        assert_eq!(result, Err(Error::SizeOverflow));
+        let result = Matrix::<()>::from_slice_with_strides(&[], (usize::MAX, 2), (2, 1));
+        assert_eq!(result, Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    fn test_from_blocks() {
+        let expected = matrix![[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]];
+        let mut blocks = matrix![
+            [matrix![[0], [4]], matrix![[1, 2, 3], [5, 6, 7]]],
+            [matrix![[8]], matrix![[9, 10, 11]]],
+        ];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                let output = Matrix::from_blocks(blocks.clone()).unwrap();
+                assert_eq!(output, expected);
+                blocks.apply(|block| {
+                    block.switch_order();
+                });
+            }
+            blocks.switch_order();
+        }
+
+        let blocks = matrix![[matrix![[0], [4]], matrix![[1, 2, 3]]]];
+        assert_eq!(
+            Matrix::from_blocks(blocks),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(1, 3)
+            })
+        );
+
+        let blocks = matrix![[matrix![[0, 1]]], [matrix![[2]]]];
+        assert_eq!(
+            Matrix::from_blocks(blocks),
+            Err(Error::NotConformable {
+                lhs: Shape::new(1, 2),
+                rhs: Shape::new(1, 1)
+            })
+        );
+
+        let blocks = matrix![[Matrix::<i32>::new((0, 2)), Matrix::new((0, 1))]];
+        assert_eq!(Matrix::from_blocks(blocks), Ok(Matrix::new((0, 3))));
+
+        let blocks = Matrix::<Matrix<i32>>::new((2, 0));
+        assert_eq!(Matrix::from_blocks(blocks), Ok(Matrix::empty()));
+    }
+
     #[test]
     fn test_from_iterator() {
         let expected = matrix![[0, 1, 2], [3, 4, 5]];
@@ -236,6 +969,18 @@ mod tests {
 
         let iterable = [[0, 1], [2, 3], [4, 5]];
         assert_ne!(Matrix::from_iter(iterable), expected);
+
+        let matrix: Matrix<i32> = core::iter::empty::<Vec<i32>>().collect();
+        assert_eq!(matrix, Matrix::empty());
+
+        let rows = [vec![0, 1, 2], vec![3, 4]];
+        let result = Matrix::from_rows(rows.clone());
+        assert_eq!(result, Err(Error::LengthInconsistent));
+        #[cfg(feature = "std")]
+        {
+            let result = std::panic::catch_unwind(|| Matrix::from_iter(rows));
+            assert!(result.is_err());
+        }
     }
 
     #[test]
@@ -244,4 +989,30 @@ mod tests {
         let iterable = [vec![0, 1, 2], vec![3, 4]];
         Matrix::from_iter(iterable);
     }
+
+    #[test]
+    fn test_extend() {
+        let mut matrix = matrix![[0, 1, 2]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.extend([[3, 4, 5], [6, 7, 8]]);
+            output.extend(core::iter::empty::<Vec<i32>>());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]]);
+
+            matrix.switch_order();
+        }
+
+        let mut matrix = Matrix::empty();
+        matrix.extend((0..2).map(|row| (0..3).map(move |col| row * 3 + col)));
+        assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_extend_fails() {
+        let mut matrix = matrix![[0, 1, 2]];
+        matrix.extend([vec![3, 4]]);
+    }
 }
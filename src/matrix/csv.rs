@@ -0,0 +1,339 @@
+//! This module provides reading and writing of matrices as delimited
+//! text, such as CSV.
+//!
+//! Each line holds one row, with fields separated by a single-byte
+//! delimiter. Quoting is not supported, since fields are expected to be
+//! numbers or other values without delimiters. Empty lines are skipped,
+//! and both `\n` and `\r\n` line endings are accepted.
+
+use super::order::Order;
+use super::shape::Shape;
+use super::Matrix;
+use crate::error::Error;
+use core::fmt::Display;
+use core::str::FromStr;
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// Options for reading and writing delimited text.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::{matrix, CsvOptions, Matrix};
+///
+/// let options = CsvOptions {
+///     delimiter: b';',
+///     has_header: true,
+///     ..Default::default()
+/// };
+/// let text = "a;b\n0.5;1\n2;3e1\n";
+///
+/// let matrix = Matrix::<f64>::from_csv_reader_with_options(text.as_bytes(), &options);
+/// assert_eq!(matrix.unwrap(), matrix![[0.5, 1.0], [2.0, 30.0]]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// ASCII byte separating fields within a line. Defaults to `b','`.
+    pub delimiter: u8,
+
+    /// Whether the first non-empty line is a header to skip when
+    /// reading. Defaults to `false`. No header is written.
+    pub has_header: bool,
+
+    /// Whether to trim whitespace around fields before parsing. Defaults
+    /// to `true`.
+    pub trim: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_header: false,
+            trim: true,
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Reads a matrix from comma-separated text, parsing each field with
+    /// [`FromStr`].
+    ///
+    /// See the [module-level documentation](self) for the format.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidData`] if the text is not valid UTF-8, a
+    ///   field fails to parse, or rows differ in length, the latter
+    ///   wrapping [`Error::LengthInconsistent`].
+    /// - Any other I/O error encountered while reading.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix is row-major. Empty input yields an empty
+    /// matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let text = "0, 1, 2\n3, 4, 5\n";
+    /// let matrix = Matrix::<i32>::from_csv_reader(text.as_bytes());
+    /// assert_eq!(matrix.unwrap(), matrix![[0, 1, 2], [3, 4, 5]]);
+    /// ```
+    ///
+    /// [`Error::LengthInconsistent`]: crate::error::Error::LengthInconsistent
+    pub fn from_csv_reader<R: Read>(reader: R) -> io::Result<Self>
+    where
+        T: FromStr,
+    {
+        Self::from_csv_reader_with_options(reader, &CsvOptions::default())
+    }
+
+    /// Reads a matrix from delimited text according to `options`,
+    /// parsing each field with [`FromStr`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Matrix::from_csv_reader`].
+    pub fn from_csv_reader_with_options<R: Read>(
+        reader: R,
+        options: &CsvOptions,
+    ) -> io::Result<Self>
+    where
+        T: FromStr,
+    {
+        Self::from_csv_reader_with(reader, options, |field| field.parse().ok())
+    }
+
+    /// Reads a matrix from delimited text according to `options`,
+    /// parsing each field with `parse`, which returns [`None`] for
+    /// invalid fields.
+    ///
+    /// This allows custom parsing, such as decimal commas or markers for
+    /// missing values.
+    ///
+    /// # Errors
+    ///
+    /// See [`Matrix::from_csv_reader`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{CsvOptions, Matrix};
+    ///
+    /// let options = CsvOptions {
+    ///     delimiter: b'\t',
+    ///     ..Default::default()
+    /// };
+    /// let text = "1,5\tNA\n";
+    ///
+    /// let matrix = Matrix::<f64>::from_csv_reader_with(text.as_bytes(), &options, |field| {
+    ///     match field {
+    ///         "NA" => Some(f64::NAN),
+    ///         _ => field.replace(',', ".").parse().ok(),
+    ///     }
+    /// })
+    /// .unwrap();
+    /// assert_eq!(matrix[(0, 0)], 1.5);
+    /// assert!(matrix[(0, 1)].is_nan());
+    /// ```
+    pub fn from_csv_reader_with<R, F>(
+        reader: R,
+        options: &CsvOptions,
+        mut parse: F,
+    ) -> io::Result<Self>
+    where
+        R: Read,
+        F: FnMut(&str) -> Option<T>,
+    {
+        let delimiter = char::from(options.delimiter);
+        let mut skip_header = options.has_header;
+        let mut nrows = 0;
+        let mut ncols = None;
+        let mut data = Vec::new();
+        for (n, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+            if line.is_empty() {
+                continue;
+            }
+            if skip_header {
+                skip_header = false;
+                continue;
+            }
+            let len = data.len();
+            for field in line.split(delimiter) {
+                let field = if options.trim { field.trim() } else { field };
+                let element = parse(field).ok_or_else(|| {
+                    invalid_data(format!("invalid field {field:?} on line {}", n + 1))
+                })?;
+                data.push(element);
+            }
+            match ncols {
+                None => ncols = Some(data.len() - len),
+                Some(ncols) if ncols != data.len() - len => {
+                    return Err(invalid_data(Error::LengthInconsistent));
+                }
+                Some(_) => {}
+            }
+            nrows += 1;
+        }
+        let shape = Shape::new(nrows, ncols.unwrap_or(0));
+        Self::from_raw_parts(Order::RowMajor, shape, data).map_err(invalid_data)
+    }
+
+    /// Writes the matrix to `writer` as comma-separated text, formatting
+    /// each element with [`Display`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error encountered while writing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0.5, 1.0], [2.0, -3.0]];
+    /// let mut bytes = Vec::new();
+    ///
+    /// matrix.write_csv(&mut bytes).unwrap();
+    /// assert_eq!(String::from_utf8(bytes).unwrap(), "0.5,1\n2,-3\n");
+    /// ```
+    pub fn write_csv<W: Write>(&self, writer: W) -> io::Result<()>
+    where
+        T: Display,
+    {
+        self.write_csv_with_options(writer, &CsvOptions::default())
+    }
+
+    /// Writes the matrix to `writer` as delimited text according to
+    /// `options`, formatting each element with [`Display`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error encountered while writing.
+    pub fn write_csv_with_options<W: Write>(
+        &self,
+        mut writer: W,
+        options: &CsvOptions,
+    ) -> io::Result<()>
+    where
+        T: Display,
+    {
+        let delimiter = char::from(options.delimiter);
+        for row in self.iter_rows() {
+            for (n, element) in row.enumerate() {
+                if n > 0 {
+                    write!(writer, "{delimiter}")?;
+                }
+                write!(writer, "{element}")?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+fn invalid_data<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_from_csv_reader() {
+        let text = "0, 1, 2\r\n\n3,4 ,5\n";
+        let matrix = Matrix::<i32>::from_csv_reader(text.as_bytes()).unwrap();
+        assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+
+        let text = "x|y\n0.5|-1\n|2\n";
+        let options = CsvOptions {
+            delimiter: b'|',
+            has_header: true,
+            trim: false,
+        };
+        let result = Matrix::<f64>::from_csv_reader_with_options(text.as_bytes(), &options);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        let matrix = Matrix::<f64>::from_csv_reader_with(text.as_bytes(), &options, |field| {
+            if field.is_empty() {
+                Some(0.0)
+            } else {
+                field.parse().ok()
+            }
+        })
+        .unwrap();
+        assert_eq!(matrix, matrix![[0.5, -1.0], [0.0, 2.0]]);
+
+        let matrix = Matrix::<u8>::from_csv_reader("".as_bytes()).unwrap();
+        assert_eq!(matrix, Matrix::new((0, 0)));
+
+        let options = CsvOptions {
+            has_header: true,
+            ..Default::default()
+        };
+        let matrix = Matrix::<u8>::from_csv_reader_with_options("a,b\n".as_bytes(), &options);
+        assert_eq!(matrix.unwrap(), Matrix::new((0, 0)));
+    }
+
+    #[test]
+    fn test_from_csv_reader_fails() {
+        let read = |text: &str| {
+            Matrix::<i32>::from_csv_reader(text.as_bytes())
+                .unwrap_err()
+                .kind()
+        };
+
+        assert_eq!(read("0,1\n2\n"), io::ErrorKind::InvalidData);
+        assert_eq!(read("0,1\n2,3,4\n"), io::ErrorKind::InvalidData);
+        assert_eq!(read("0,x\n"), io::ErrorKind::InvalidData);
+        assert_eq!(read("0,,1\n"), io::ErrorKind::InvalidData);
+
+        let bytes: &[u8] = &[b'0', b',', 0xff, b'\n'];
+        let result = Matrix::<i32>::from_csv_reader(bytes);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut matrix = matrix![[0.5, 1.0, -2.0], [3.0, 4.25, 5.0]];
+
+        for _ in 0..2 {
+            let mut bytes = Vec::new();
+            matrix.write_csv(&mut bytes).unwrap();
+            assert_eq!(String::from_utf8(bytes).unwrap(), "0.5,1,-2\n3,4.25,5\n");
+
+            let options = CsvOptions {
+                delimiter: b'\t',
+                ..Default::default()
+            };
+            let mut bytes = Vec::new();
+            matrix.write_csv_with_options(&mut bytes, &options).unwrap();
+            assert_eq!(
+                String::from_utf8(bytes).unwrap(),
+                "0.5\t1\t-2\n3\t4.25\t5\n"
+            );
+
+            let mut bytes = Vec::new();
+            matrix.write_csv(&mut bytes).unwrap();
+            let output = Matrix::<f64>::from_csv_reader(bytes.as_slice()).unwrap();
+            let mut expected = matrix.clone();
+            expected.set_order(Order::RowMajor);
+            assert_eq!(output, expected);
+
+            matrix.switch_order();
+        }
+
+        let mut bytes = Vec::new();
+        Matrix::<i32>::new((2, 0)).write_csv(&mut bytes).unwrap();
+        assert_eq!(bytes, b"\n\n");
+    }
+}
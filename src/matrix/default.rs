@@ -1,6 +1,7 @@
 use super::order::Order;
 use super::shape::AxisShape;
 use super::Matrix;
+use alloc::vec::Vec;
 
 impl<T> Default for Matrix<T> {
     fn default() -> Self {
@@ -0,0 +1,170 @@
+//! This module provides [`DynMatrix`], an object-safe view of matrices
+//! for passing them across dynamic boundaries.
+
+use super::shape::Shape;
+use super::Matrix;
+use crate::error::Result;
+use alloc::boxed::Box;
+
+/// An object-safe interface to a matrix whose elements convert to and
+/// from [`f64`].
+///
+/// This allows plugin systems and other dynamic boundaries to accept
+/// `&dyn DynMatrix` or `Box<dyn DynMatrix>` instead of being generic
+/// over the element type.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::{matrix, DynMatrix, Matrix};
+///
+/// fn sum(matrix: &dyn DynMatrix) -> f64 {
+///     matrix.iter_f64().sum()
+/// }
+///
+/// let mut matrix: Box<dyn DynMatrix> = Box::new(matrix![[0.0, 1.0], [2.0, 3.0]]);
+/// matrix.set_f64(0, 0, 4.0).unwrap();
+///
+/// assert_eq!(matrix.get_f64(0, 0), Ok(4.0));
+/// assert_eq!(sum(&*matrix), 10.0);
+/// ```
+pub trait DynMatrix {
+    /// Returns the shape of the matrix.
+    fn shape(&self) -> Shape;
+
+    /// Returns the element at `(row, col)` as [`f64`].
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `(row, col)` is out of bounds.
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    fn get_f64(&self, row: usize, col: usize) -> Result<f64>;
+
+    /// Sets the element at `(row, col)` from `value`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `(row, col)` is out of bounds.
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    fn set_f64(&mut self, row: usize, col: usize, value: f64) -> Result<()>;
+
+    /// Returns an iterator over the elements as [`f64`], in row-major
+    /// order.
+    fn iter_f64(&self) -> Box<dyn Iterator<Item = f64> + '_>;
+
+    /// Returns the number of rows in the matrix.
+    fn nrows(&self) -> usize {
+        self.shape().nrows
+    }
+
+    /// Returns the number of columns in the matrix.
+    fn ncols(&self) -> usize {
+        self.shape().ncols
+    }
+
+    /// Copies the elements into a new [`Matrix<f64>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, DynMatrix, Matrix};
+    ///
+    /// let matrix: &dyn DynMatrix = &matrix![[0.0, 1.0], [2.0, 3.0]];
+    /// assert_eq!(matrix.to_matrix(), matrix![[0.0, 1.0], [2.0, 3.0]]);
+    /// ```
+    fn to_matrix(&self) -> Matrix<f64> {
+        let ncols = self.ncols();
+        let mut output = Matrix::new((self.nrows(), ncols));
+        for (index, value) in self.iter_f64().enumerate() {
+            output[(index / ncols, index % ncols)] = value;
+        }
+        output
+    }
+}
+
+impl<T> DynMatrix for Matrix<T>
+where
+    T: Clone + Into<f64> + From<f64>,
+{
+    fn shape(&self) -> Shape {
+        Matrix::shape(self)
+    }
+
+    fn get_f64(&self, row: usize, col: usize) -> Result<f64> {
+        self.get((row, col)).map(|element| element.clone().into())
+    }
+
+    fn set_f64(&mut self, row: usize, col: usize, value: f64) -> Result<()> {
+        *self.get_mut((row, col))? = T::from(value);
+        Ok(())
+    }
+
+    fn iter_f64(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        Box::new(
+            self.iter_rows()
+                .flatten()
+                .map(|element| element.clone().into()),
+        )
+    }
+
+    fn nrows(&self) -> usize {
+        Matrix::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        Matrix::ncols(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::matrix;
+    use crate::matrix::index::Index;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_dyn_matrix() {
+        let mut matrix: Matrix<f64> = matrix![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]];
+
+        for _ in 0..2 {
+            let mut boxed: Box<dyn DynMatrix> = Box::new(matrix.clone());
+            assert_eq!(boxed.shape(), Shape::new(2, 3));
+            assert_eq!(boxed.nrows(), 2);
+            assert_eq!(boxed.ncols(), 3);
+            assert_eq!(boxed.get_f64(1, 0), Ok(3.0));
+            assert_eq!(
+                boxed.get_f64(2, 0),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
+
+            boxed.set_f64(1, 2, -1.0).unwrap();
+            assert_eq!(
+                boxed.set_f64(0, 3, 0.0),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                })
+            );
+            assert_eq!(
+                boxed.iter_f64().collect::<Vec<_>>(),
+                [0.0, 1.0, 2.0, 3.0, 4.0, -1.0]
+            );
+            assert_eq!(
+                boxed.to_matrix(),
+                matrix![[0.0, 1.0, 2.0], [3.0, 4.0, -1.0]]
+            );
+
+            matrix.switch_order();
+        }
+
+        let empty: &dyn DynMatrix = &Matrix::<f64>::new((0, 3));
+        assert_eq!(empty.to_matrix(), Matrix::new((0, 3)));
+    }
+}
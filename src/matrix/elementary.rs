@@ -0,0 +1,263 @@
+use super::axis::Axis;
+use super::Matrix;
+use crate::error::{Error, Result};
+use core::ops::{AddAssign, Mul, MulAssign};
+
+impl<T> Matrix<T> {
+    /// Swaps rows `i` and `j` of the matrix in place.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if either row is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Index, Shape};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.swap_rows(0, 1).unwrap();
+    /// assert_eq!(matrix, matrix![[3, 4, 5], [0, 1, 2]]);
+    ///
+    /// let result = matrix.swap_rows(0, 2);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(2, 0),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn swap_rows(&mut self, i: usize, j: usize) -> Result<&mut Self> {
+        self.swap_vectors(Axis::Row, i, j)
+    }
+
+    /// Swaps columns `i` and `j` of the matrix in place.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if either column is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Index, Shape};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.swap_cols(0, 2).unwrap();
+    /// assert_eq!(matrix, matrix![[2, 1, 0], [5, 4, 3]]);
+    ///
+    /// let result = matrix.swap_cols(0, 3);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(0, 3),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn swap_cols(&mut self, i: usize, j: usize) -> Result<&mut Self> {
+        self.swap_vectors(Axis::Col, i, j)
+    }
+
+    /// Multiplies row `i` of the matrix by `factor` in place.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if the row is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Index, Shape};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.scale_row(1, &-2).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [-6, -8, -10]]);
+    ///
+    /// let result = matrix.scale_row(2, &-2);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(2, 0),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn scale_row<S>(&mut self, i: usize, factor: &S) -> Result<&mut Self>
+    where
+        T: MulAssign<S>,
+        S: Clone,
+    {
+        let layout = self.vector_layout(Axis::Row);
+        if i >= layout.count {
+            return Err(Error::vector_out_of_bounds(Axis::Row, i, self.shape()));
+        }
+        for k in 0..layout.len {
+            self.data[i * layout.offset + k * layout.step] *= factor.clone();
+        }
+        Ok(self)
+    }
+
+    /// Adds row `src` multiplied by `factor` to row `dst` in place.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if either row is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Index, Shape};
+    ///
+    /// let mut matrix = matrix![[1, 2, 3], [3, 4, 5]];
+    ///
+    /// matrix.add_scaled_row(0, 1, &-3).unwrap();
+    /// assert_eq!(matrix, matrix![[1, 2, 3], [0, -2, -4]]);
+    ///
+    /// let result = matrix.add_scaled_row(2, 1, &-3);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(2, 0),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn add_scaled_row<S>(&mut self, src: usize, dst: usize, factor: &S) -> Result<&mut Self>
+    where
+        T: AddAssign + Mul<S, Output = T> + Clone,
+        S: Clone,
+    {
+        let layout = self.vector_layout(Axis::Row);
+        if let Some(&n) = [src, dst].iter().find(|&&n| n >= layout.count) {
+            return Err(Error::vector_out_of_bounds(Axis::Row, n, self.shape()));
+        }
+        for k in 0..layout.len {
+            let addend = self.data[src * layout.offset + k * layout.step].clone() * factor.clone();
+            self.data[dst * layout.offset + k * layout.step] += addend;
+        }
+        Ok(self)
+    }
+
+    pub(super) fn swap_vectors(&mut self, axis: Axis, i: usize, j: usize) -> Result<&mut Self> {
+        let layout = self.vector_layout(axis);
+        if let Some(&n) = [i, j].iter().find(|&&n| n >= layout.count) {
+            return Err(Error::vector_out_of_bounds(axis, n, self.shape()));
+        }
+        if i != j {
+            for k in 0..layout.len {
+                let step = k * layout.step;
+                self.data
+                    .swap(i * layout.offset + step, j * layout.offset + step);
+            }
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::index::Index;
+    use crate::matrix::order::Order;
+    use crate::matrix::shape::Shape;
+
+    #[test]
+    fn test_swap_rows() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+
+        for _ in 0..2 {
+            let mut swapped = matrix.clone();
+            swapped.swap_rows(0, 2).unwrap().swap_rows(1, 1).unwrap();
+            assert_eq!(swapped.order(), matrix.order());
+            assert_eq!(
+                swapped.swap_rows(3, 0),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(3, 0),
+                    shape: Shape::new(3, 3)
+                })
+            );
+            swapped.set_order(Order::RowMajor);
+            assert_eq!(swapped, matrix![[6, 7, 8], [3, 4, 5], [0, 1, 2]]);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_swap_cols() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut swapped = matrix.clone();
+            swapped.swap_cols(2, 0).unwrap();
+            assert_eq!(swapped[(0, 0)], 2);
+            assert_eq!(swapped[(1, 0)], 5);
+            assert_eq!(swapped[(0, 1)], 1);
+            assert_eq!(swapped[(0, 2)], 0);
+            assert_eq!(swapped[(1, 2)], 3);
+            assert_eq!(
+                swapped.swap_cols(0, 3),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                })
+            );
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_scale_row() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut scaled = matrix.clone();
+            scaled.scale_row(0, &3).unwrap();
+            assert_eq!(scaled[(0, 2)], 6);
+            assert_eq!(scaled[(1, 2)], 5);
+            assert_eq!(
+                scaled.scale_row(2, &3),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_add_scaled_row() {
+        let mut matrix = matrix![[1, 2, 3], [4, 5, 6]];
+
+        for _ in 0..2 {
+            let mut reduced = matrix.clone();
+            reduced.add_scaled_row(0, 1, &-4).unwrap();
+            assert_eq!(reduced[(1, 0)], 0);
+            assert_eq!(reduced[(1, 1)], -3);
+            assert_eq!(reduced[(1, 2)], -6);
+            assert_eq!(reduced[(0, 2)], 3);
+
+            reduced.add_scaled_row(1, 1, &1).unwrap();
+            assert_eq!(reduced[(1, 2)], -12);
+
+            assert_eq!(
+                reduced.add_scaled_row(0, 2, &1),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
+
+            matrix.switch_order();
+        }
+    }
+}
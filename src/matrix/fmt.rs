@@ -1,5 +1,8 @@
 use super::index::Index;
 use super::Matrix;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 const LEFT_DELIMITER: &str = "[";
 const RIGHT_DELIMITER: &str = "]";
@@ -11,12 +14,12 @@ const INNER_GAP: usize = 1;
 
 macro_rules! write_dim {
     ($dst:expr, $($arg:tt)*) => {
-        std::write!($dst, "\u{001b}[2m{}\u{001b}[22m", std::format_args!($($arg)*))
+        core::write!($dst, "\u{001b}[2m{}\u{001b}[22m", core::format_args!($($arg)*))
     };
 }
 
-impl<T: std::fmt::Debug> std::fmt::Debug for Matrix<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl<T: core::fmt::Debug> core::fmt::Debug for Matrix<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         let shape = self.shape();
         let nrows = shape.nrows;
         let ncols = shape.ncols;
@@ -110,8 +113,8 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Matrix<T> {
     }
 }
 
-impl<T: std::fmt::Display> std::fmt::Display for Matrix<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl<T: core::fmt::Display> core::fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         let shape = self.shape();
         let nrows = shape.nrows;
         let ncols = shape.ncols;
@@ -174,14 +177,14 @@ impl<T: std::fmt::Display> std::fmt::Display for Matrix<T> {
     }
 }
 
-struct Lines(std::collections::VecDeque<String>);
+struct Lines(alloc::collections::VecDeque<String>);
 
 impl Lines {
-    fn from_debug<T: std::fmt::Debug>(element: T) -> Self {
+    fn from_debug<T: core::fmt::Debug>(element: T) -> Self {
         Self(format!("{:?}", element).lines().map(String::from).collect())
     }
 
-    fn from_display<T: std::fmt::Display>(element: T) -> Self {
+    fn from_display<T: core::fmt::Display>(element: T) -> Self {
         Self(format!("{}", element).lines().map(String::from).collect())
     }
 
@@ -209,11 +212,12 @@ impl Iterator for Lines {
 #[cfg(test)]
 mod tests {
     use crate::matrix;
+    use alloc::format;
 
     struct Mock(usize);
 
-    impl std::fmt::Debug for Mock {
-        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    impl core::fmt::Debug for Mock {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
             for i in 0..self.0 {
                 writeln!(f, "{}", "+".repeat(i + 1))?;
             }
@@ -221,8 +225,8 @@ mod tests {
         }
     }
 
-    impl std::fmt::Display for Mock {
-        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    impl core::fmt::Display for Mock {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
             for i in 0..self.0 {
                 writeln!(f, "{}", "=".repeat(i + 1))?;
             }
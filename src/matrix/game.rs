@@ -0,0 +1,225 @@
+//! This module provides solvers for matrix games.
+
+use super::real::Real;
+use super::Matrix;
+
+/// The solution of a two-player zero-sum matrix game.
+///
+/// This struct is created by [`Matrix::solve_zero_sum_game`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZeroSumGame<T> {
+    /// Optimal mixed strategy of the row player, one probability per row.
+    pub row_strategy: Vec<T>,
+
+    /// Optimal mixed strategy of the column player, one probability per
+    /// column.
+    pub col_strategy: Vec<T>,
+
+    /// Expected payoff to the row player under optimal play.
+    pub value: T,
+}
+
+macro_rules! impl_game {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                /// Solves the two-player zero-sum game with payoff matrix
+                /// `self`, in which the row player receives `self[(i, j)]`
+                /// from the column player when they play row `i` and
+                /// column `j`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the matrix is empty.
+                ///
+                /// # Notes
+                ///
+                /// The game is reduced to a linear program, after shifting
+                /// all payoffs to be positive, and solved with the simplex
+                /// method using Bland's rule. The column player's strategy
+                /// comes from the primal solution and the row player's from
+                /// the dual.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// // matching pennies
+                /// let payoff: Matrix<f64> = matrix![[1.0, -1.0], [-1.0, 1.0]];
+                /// let game = payoff.solve_zero_sum_game();
+                ///
+                /// assert!(game.value.abs() < 1e-12);
+                /// assert!((game.row_strategy[0] - 0.5).abs() < 1e-12);
+                /// assert!((game.col_strategy[0] - 0.5).abs() < 1e-12);
+                /// ```
+                pub fn solve_zero_sum_game(&self) -> ZeroSumGame<$t> {
+                    solve_zero_sum_game(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_game! {f32 f64}
+
+fn solve_zero_sum_game<T>(payoff: &Matrix<T>) -> ZeroSumGame<T>
+where
+    T: Real,
+{
+    let (nrows, ncols) = (payoff.nrows(), payoff.ncols());
+    assert!(nrows > 0 && ncols > 0, "payoff matrix is empty");
+
+    // maximize sum(y) subject to (A + shift) * y <= 1 and y >= 0, where
+    // the shift makes every payoff at least one
+    let min = payoff
+        .data
+        .iter()
+        .fold(payoff.data[0], |acc, &x| if x < acc { x } else { acc });
+    let shift = T::ONE - min;
+    let width = ncols + nrows + 1;
+    let rhs = width - 1;
+    let mut tableau = vec![T::ZERO; (nrows + 1) * width];
+    for i in 0..nrows {
+        for j in 0..ncols {
+            tableau[i * width + j] = payoff[(i, j)] + shift;
+        }
+        tableau[i * width + ncols + i] = T::ONE;
+        tableau[i * width + rhs] = T::ONE;
+    }
+    let objective = nrows * width;
+    for j in 0..ncols {
+        tableau[objective + j] = -T::ONE;
+    }
+    let mut basis: Vec<usize> = (ncols..ncols + nrows).collect();
+
+    let eps = T::EPSILON * T::from_f64(64.0);
+    while let Some(entering) = (0..rhs).find(|&j| tableau[objective + j] < -eps) {
+        let mut leaving: Option<(usize, T)> = None;
+        for i in 0..nrows {
+            let coefficient = tableau[i * width + entering];
+            if coefficient <= eps {
+                continue;
+            }
+            let ratio = tableau[i * width + rhs] / coefficient;
+            leaving = match leaving {
+                Some((best, best_ratio))
+                    if best_ratio < ratio || (best_ratio == ratio && basis[best] < basis[i]) =>
+                {
+                    Some((best, best_ratio))
+                }
+                _ => Some((i, ratio)),
+            };
+        }
+        let (pivot, _) = leaving.expect("positive payoffs keep the program bounded");
+
+        let factor = tableau[pivot * width + entering];
+        for x in &mut tableau[pivot * width..(pivot + 1) * width] {
+            *x = *x / factor;
+        }
+        for i in (0..=nrows).filter(|&i| i != pivot) {
+            let factor = tableau[i * width + entering];
+            if factor == T::ZERO {
+                continue;
+            }
+            for j in 0..width {
+                let delta = factor * tableau[pivot * width + j];
+                tableau[i * width + j] = tableau[i * width + j] - delta;
+            }
+        }
+        basis[pivot] = entering;
+    }
+
+    let total = tableau[objective + rhs];
+    let mut col_strategy = vec![T::ZERO; ncols];
+    for (i, &variable) in basis.iter().enumerate() {
+        if variable < ncols {
+            col_strategy[variable] = tableau[i * width + rhs] / total;
+        }
+    }
+    let row_strategy = (0..nrows)
+        .map(|i| tableau[objective + ncols + i] / total)
+        .collect();
+    ZeroSumGame {
+        row_strategy,
+        col_strategy,
+        value: T::ONE / total - shift,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (x, y) in actual.iter().zip(expected) {
+            assert!((x - y).abs() < 1e-9, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_solve_zero_sum_game() {
+        let third = 1.0 / 3.0;
+        let mut cases: [(Matrix<f64>, [f64; 3], f64); 2] = [
+            // rock paper scissors
+            (
+                matrix![[0.0, -1.0, 1.0], [1.0, 0.0, -1.0], [-1.0, 1.0, 0.0]],
+                [third; 3],
+                0.0,
+            ),
+            // column player's third strategy is dominated
+            (
+                matrix![[4.0, 2.0, 5.0], [1.0, 3.0, 4.0], [0.0, 0.0, 0.0]],
+                [0.5, 0.5, 0.0],
+                2.5,
+            ),
+        ];
+
+        for _ in 0..2 {
+            for (payoff, row_strategy, value) in &mut cases {
+                let game = payoff.solve_zero_sum_game();
+                assert_close(&game.row_strategy, row_strategy);
+                assert_close(&[game.value], &[*value]);
+                payoff.switch_order();
+            }
+        }
+
+        let payoff: Matrix<f64> = matrix![[4.0, 2.0], [1.0, 3.0]];
+        let game = payoff.solve_zero_sum_game();
+        assert_close(&game.row_strategy, &[0.5, 0.5]);
+        assert_close(&game.col_strategy, &[0.25, 0.75]);
+        assert_close(&[game.value], &[2.5]);
+
+        // saddle point
+        let payoff: Matrix<f64> = matrix![[3.0, 5.0], [1.0, 4.0]];
+        let game = payoff.solve_zero_sum_game();
+        assert_close(&game.row_strategy, &[1.0, 0.0]);
+        assert_close(&game.col_strategy, &[1.0, 0.0]);
+        assert_close(&[game.value], &[3.0]);
+
+        let payoff: Matrix<f64> = matrix![[-2.0, 3.0, 1.0], [3.0, -4.0, 0.5]];
+        let game = payoff.solve_zero_sum_game();
+        for j in 0..3 {
+            let payoff = (0..2).map(|i| game.row_strategy[i] * payoff[(i, j)]);
+            assert!(payoff.sum::<f64>() >= game.value - 1e-9);
+        }
+        for i in 0..2 {
+            let payoff = (0..3).map(|j| game.col_strategy[j] * payoff[(i, j)]);
+            assert!(payoff.sum::<f64>() <= game.value + 1e-9);
+        }
+
+        let payoff: Matrix<f32> = matrix![[7.0]];
+        let game = payoff.solve_zero_sum_game();
+        assert_eq!(game.row_strategy, [1.0]);
+        assert_eq!(game.col_strategy, [1.0]);
+        assert_eq!(game.value, 7.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_solve_zero_sum_game_empty() {
+        Matrix::<f64>::new((0, 2)).solve_zero_sum_game();
+    }
+}
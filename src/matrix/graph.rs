@@ -0,0 +1,258 @@
+use super::Matrix;
+use crate::error::{Error, Result};
+use alloc::vec::Vec;
+
+impl<T> Matrix<T>
+where
+    T: Default + PartialEq,
+{
+    /// Returns the oriented incidence matrix of the undirected graph
+    /// whose adjacency matrix is `self`.
+    ///
+    /// For `n` vertices and `m` edges, the result is `n x m`. Edges are
+    /// numbered in order of `(i, j)` with `i < j`, and the column of
+    /// edge `(i, j)` holds `1.0` at row `i`, `-1.0` at row `j`, and `0.0`
+    /// elsewhere, so that `B * B^T` equals [`Matrix::laplacian_matrix`].
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotSquare`] if the matrix is not square.
+    ///
+    /// # Notes
+    ///
+    /// Vertices `i` and `j` are adjacent if either `(i, j)` or `(j, i)`
+    /// is not `T::default()`. Weights and self-loops are ignored. The
+    /// resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[false, true, true], [true, false, false], [true, false, false]];
+    /// let output = matrix.adjacency_to_incidence();
+    /// assert_eq!(output, Ok(matrix![[1.0, 1.0], [-1.0, 0.0], [0.0, -1.0]]));
+    /// ```
+    ///
+    /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+    pub fn adjacency_to_incidence(&self) -> Result<Matrix<f64>> {
+        let edges = self.edges()?;
+        let mut output = Matrix::build((self.nrows(), edges.len()))?;
+        for (n, (i, j)) in edges.into_iter().enumerate() {
+            output[(i, n)] = 1.0;
+            output[(j, n)] = -1.0;
+        }
+        output.set_order(self.order);
+        Ok(output)
+    }
+
+    /// Returns the degree matrix of the undirected graph whose adjacency
+    /// matrix is `self`, i.e., the diagonal matrix of vertex degrees.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotSquare`] if the matrix is not square.
+    ///
+    /// # Notes
+    ///
+    /// Adjacency is determined as in [`Matrix::adjacency_to_incidence`].
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 2, 1], [2, 0, 0], [1, 0, 0]];
+    /// let output = matrix.degree_matrix();
+    /// assert_eq!(output, Ok(matrix![[2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]));
+    /// ```
+    ///
+    /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+    pub fn degree_matrix(&self) -> Result<Matrix<f64>> {
+        let edges = self.edges()?;
+        let mut output = Matrix::build(self.shape())?;
+        for (i, j) in edges {
+            output[(i, i)] += 1.0;
+            output[(j, j)] += 1.0;
+        }
+        output.set_order(self.order);
+        Ok(output)
+    }
+
+    /// Returns the Laplacian matrix `D - A` of the undirected graph whose
+    /// adjacency matrix is `self`, where `D` is the degree matrix and `A`
+    /// is the unweighted adjacency matrix.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotSquare`] if the matrix is not square.
+    ///
+    /// # Notes
+    ///
+    /// Adjacency is determined as in [`Matrix::adjacency_to_incidence`].
+    /// The result is symmetric and positive semidefinite, and the
+    /// multiplicity of its zero eigenvalue is the number of connected
+    /// components. The resulting matrix will always have the same order
+    /// as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 1], [1, 0, 0], [1, 0, 0]];
+    /// let output = matrix.laplacian_matrix();
+    /// assert_eq!(output, Ok(matrix![[2.0, -1.0, -1.0], [-1.0, 1.0, 0.0], [-1.0, 0.0, 1.0]]));
+    /// ```
+    ///
+    /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+    pub fn laplacian_matrix(&self) -> Result<Matrix<f64>> {
+        let edges = self.edges()?;
+        let mut output = Matrix::build(self.shape())?;
+        for (i, j) in edges {
+            output[(i, i)] += 1.0;
+            output[(j, j)] += 1.0;
+            output[(i, j)] = -1.0;
+            output[(j, i)] = -1.0;
+        }
+        output.set_order(self.order);
+        Ok(output)
+    }
+
+    /// Returns the edges `(i, j)` with `i < j` of the undirected graph
+    /// whose adjacency matrix is `self`.
+    fn edges(&self) -> Result<Vec<(usize, usize)>> {
+        let size = self.nrows();
+        if size != self.ncols() {
+            return Err(Error::not_square(self.shape()));
+        }
+        let zero = T::default();
+        let mut edges = Vec::new();
+        for i in 0..size {
+            for j in i + 1..size {
+                if self[(i, j)] != zero || self[(j, i)] != zero {
+                    edges.push((i, j));
+                }
+            }
+        }
+        Ok(edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::shape::Shape;
+
+    #[test]
+    fn test_adjacency_to_incidence() {
+        // path 0 - 1 - 2 plus a self-loop and a one-sided edge 3 -> 0
+        let mut matrix = matrix![[1, 1, 0, 0], [1, 0, 1, 0], [0, 1, 0, 0], [5, 0, 0, 0]];
+
+        for _ in 0..2 {
+            let output = matrix.adjacency_to_incidence().unwrap();
+            let expected = matrix![
+                [1.0, 1.0, 0.0],
+                [-1.0, 0.0, 1.0],
+                [0.0, 0.0, -1.0],
+                [0.0, -1.0, 0.0],
+            ];
+            assert_eq!(output.order(), matrix.order());
+            assert_eq!(output.shape(), expected.shape());
+            for row in 0..4 {
+                for col in 0..3 {
+                    assert_eq!(output[(row, col)], expected[(row, col)]);
+                }
+            }
+
+            matrix.switch_order();
+        }
+
+        let matrix = matrix![[false, false], [false, false]];
+        let output = matrix.adjacency_to_incidence().unwrap();
+        assert_eq!(output.shape().nrows, 2);
+        assert_eq!(output.shape().ncols, 0);
+
+        let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        assert_eq!(
+            matrix.adjacency_to_incidence(),
+            Err(Error::NotSquare(Shape::new(2, 3)))
+        );
+    }
+
+    #[test]
+    fn test_degree_matrix() {
+        let mut matrix = matrix![
+            [true, true, false, false],
+            [true, false, true, false],
+            [false, true, false, false],
+            [true, false, false, false],
+        ];
+
+        for _ in 0..2 {
+            let output = matrix.degree_matrix().unwrap();
+            let expected = [2.0, 2.0, 1.0, 1.0];
+            assert_eq!(output.order(), matrix.order());
+            for row in 0..4 {
+                for col in 0..4 {
+                    let element = if row == col { expected[row] } else { 0.0 };
+                    assert_eq!(output[(row, col)], element);
+                }
+            }
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<i32>::new((0, 0));
+        assert_eq!(matrix.degree_matrix(), Ok(Matrix::new((0, 0))));
+
+        let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        assert_eq!(
+            matrix.degree_matrix(),
+            Err(Error::NotSquare(Shape::new(2, 3)))
+        );
+    }
+
+    #[test]
+    fn test_laplacian_matrix() {
+        let mut matrix = matrix![
+            [0.0, 0.5, 0.0, 0.0],
+            [0.5, 0.0, 2.0, 0.0],
+            [0.0, 2.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ];
+
+        for _ in 0..2 {
+            let output = matrix.laplacian_matrix().unwrap();
+            let mut expected = matrix![
+                [1.0, -1.0, 0.0, 0.0],
+                [-1.0, 2.0, -1.0, 0.0],
+                [0.0, -1.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+            ];
+            expected.set_order(matrix.order());
+            assert_eq!(output, expected);
+
+            let degree = matrix.degree_matrix().unwrap();
+            let incidence = matrix.adjacency_to_incidence().unwrap();
+            let mut transposed = incidence.clone();
+            transposed.transpose();
+            let mut product = incidence.mat_mul(transposed).unwrap();
+            product.set_order(matrix.order());
+            assert_eq!(product, expected);
+            for n in 0..4 {
+                assert_eq!(degree[(n, n)], expected[(n, n)]);
+            }
+
+            matrix.switch_order();
+        }
+
+        let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        assert_eq!(
+            matrix.laplacian_matrix(),
+            Err(Error::NotSquare(Shape::new(2, 3)))
+        );
+    }
+}
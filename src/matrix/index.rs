@@ -75,11 +75,15 @@ impl<T> Matrix<T> {
     /// # Examples
     ///
     /// ```
-    /// use matreex::{matrix, Error};
+    /// use matreex::{matrix, Error, Index, Shape};
     ///
     /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
     /// assert_eq!(matrix.get((1, 1)), Ok(&4));
-    /// assert_eq!(matrix.get((2, 3)), Err(Error::IndexOutOfBounds));
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(2, 3),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(matrix.get((2, 3)), Err(error));
     /// ```
     pub fn get<I>(&self, index: I) -> Result<&I::Output>
     where
@@ -98,11 +102,15 @@ impl<T> Matrix<T> {
     /// # Examples
     ///
     /// ```
-    /// use matreex::{matrix, Error};
+    /// use matreex::{matrix, Error, Index, Shape};
     ///
     /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
     /// assert_eq!(matrix.get_mut((1, 1)), Ok(&mut 4));
-    /// assert_eq!(matrix.get_mut((2, 3)), Err(Error::IndexOutOfBounds));
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(2, 3),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(matrix.get_mut((2, 3)), Err(error));
     /// ```
     pub fn get_mut<I>(&mut self, index: I) -> Result<&mut I::Output>
     where
@@ -166,7 +174,7 @@ impl<T> Matrix<T> {
     }
 }
 
-impl<T, I> std::ops::Index<I> for Matrix<T>
+impl<T, I> core::ops::Index<I> for Matrix<T>
 where
     I: MatrixIndex<T>,
 {
@@ -177,7 +185,7 @@ where
     }
 }
 
-impl<T, I> std::ops::IndexMut<I> for Matrix<T>
+impl<T, I> core::ops::IndexMut<I> for Matrix<T>
 where
     I: MatrixIndex<T>,
 {
@@ -243,8 +251,8 @@ impl Index {
     }
 }
 
-impl std::fmt::Display for Index {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Index {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "({}, {})", self.row, self.col)
     }
 }
@@ -317,10 +325,6 @@ pub(super) struct AxisIndex {
 }
 
 impl AxisIndex {
-    pub(super) fn new(major: usize, minor: usize) -> Self {
-        Self { major, minor }
-    }
-
     pub(super) fn is_out_of_bounds(&self, shape: AxisShape) -> bool {
         self.major >= shape.major() || self.minor >= shape.minor()
     }
@@ -362,9 +366,12 @@ impl AxisIndex {
         self.major * shape.major_stride() + self.minor
     }
 
-    pub(super) fn try_into_flattened(self, shape: AxisShape) -> Result<usize> {
+    pub(super) fn try_into_flattened(self, shape: AxisShape, order: Order) -> Result<usize> {
         if self.is_out_of_bounds(shape) {
-            Err(Error::IndexOutOfBounds)
+            Err(Error::IndexOutOfBounds {
+                index: self.into_index(order),
+                shape: shape.interpret(order),
+            })
         } else {
             Ok(self.into_flattened_unchecked(shape))
         }
@@ -375,12 +382,12 @@ unsafe impl<T> MatrixIndex<T> for AxisIndex {
     type Output = T;
 
     fn get(self, matrix: &Matrix<T>) -> Result<&Self::Output> {
-        let index = self.try_into_flattened(matrix.shape)?;
+        let index = self.try_into_flattened(matrix.shape, matrix.order)?;
         unsafe { Ok(matrix.data.get_unchecked(index)) }
     }
 
     fn get_mut(self, matrix: &mut Matrix<T>) -> Result<&mut Self::Output> {
-        let index = self.try_into_flattened(matrix.shape)?;
+        let index = self.try_into_flattened(matrix.shape, matrix.order)?;
         unsafe { Ok(matrix.data.get_unchecked_mut(index)) }
     }
 
@@ -395,14 +402,14 @@ unsafe impl<T> MatrixIndex<T> for AxisIndex {
     }
 
     fn index(self, matrix: &Matrix<T>) -> &Self::Output {
-        match self.try_into_flattened(matrix.shape) {
+        match self.try_into_flattened(matrix.shape, matrix.order) {
             Err(error) => panic!("{error}"),
             Ok(index) => unsafe { matrix.data.get_unchecked(index) },
         }
     }
 
     fn index_mut(self, matrix: &mut Matrix<T>) -> &mut Self::Output {
-        match self.try_into_flattened(matrix.shape) {
+        match self.try_into_flattened(matrix.shape, matrix.order) {
             Err(error) => panic!("{error}"),
             Ok(index) => unsafe { matrix.data.get_unchecked_mut(index) },
         }
@@ -428,7 +435,7 @@ impl<T> Matrix<T> {
         order: Order,
         shape: AxisShape,
     ) -> Result<usize> {
-        AxisIndex::from_index(index, order).try_into_flattened(shape)
+        AxisIndex::from_index(index, order).try_into_flattened(shape, order)
     }
 
     #[inline]
@@ -473,8 +480,55 @@ impl<T> Matrix<T> {
 
         index.into_flattened_unchecked(dest_shape)
     }
+
+    /// Returns [`Self::reindex_to_different_order_unchecked`] of each
+    /// flattened index in turn, stepping through the axes instead of
+    /// dividing every index by the shape.
+    #[inline]
+    pub(super) fn reindices_to_different_order(src_shape: AxisShape) -> DifferentOrderIndices {
+        DifferentOrderIndices {
+            major: src_shape.major(),
+            next: 0,
+            size: src_shape.size(),
+            remaining: src_shape.size(),
+        }
+    }
+}
+
+/// Iterator returned by [`Matrix::reindices_to_different_order`].
+pub(super) struct DifferentOrderIndices {
+    major: usize,
+    next: usize,
+    size: usize,
+    remaining: usize,
+}
+
+impl Iterator for DifferentOrderIndices {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let index = self.next;
+        self.next += self.major;
+        if self.next >= self.size {
+            // Move on to the next vector along the source major axis.
+            self.next -= self.size - 1;
+        }
+        Some(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
 }
 
+impl ExactSizeIterator for DifferentOrderIndices {}
+
 mod internal {
     pub trait Sealed {}
 
@@ -487,6 +541,9 @@ mod internal {
 mod tests {
     use super::*;
     use crate::matrix;
+    use crate::matrix::shape::Shape;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
 
     #[test]
     fn test_get() {
@@ -497,7 +554,13 @@ mod tests {
         assert_eq!(matrix.get((1, 0)), Ok(&3));
         assert_eq!(matrix.get((1, 1)), Ok(&4));
         assert_eq!(matrix.get((1, 2)), Ok(&5));
-        assert_eq!(matrix.get((2, 0)), Err(Error::IndexOutOfBounds));
+        assert_eq!(
+            matrix.get((2, 0)),
+            Err(Error::IndexOutOfBounds {
+                index: Index::new(2, 0),
+                shape: Shape::new(2, 3)
+            })
+        );
     }
 
     #[test]
@@ -509,7 +572,13 @@ mod tests {
         assert_eq!(matrix.get_mut((1, 0)), Ok(&mut 3));
         assert_eq!(matrix.get_mut((1, 1)), Ok(&mut 4));
         assert_eq!(matrix.get_mut((1, 2)), Ok(&mut 5));
-        assert_eq!(matrix.get_mut((2, 0)), Err(Error::IndexOutOfBounds));
+        assert_eq!(
+            matrix.get_mut((2, 0)),
+            Err(Error::IndexOutOfBounds {
+                index: Index::new(2, 0),
+                shape: Shape::new(2, 3)
+            })
+        );
     }
 
     #[test]
@@ -601,6 +670,21 @@ mod tests {
         assert!([2, 3].is_out_of_bounds(&matrix));
     }
 
+    #[test]
+    fn test_reindices_to_different_order() {
+        for (nrows, ncols) in [(0, 3), (3, 0), (1, 1), (1, 4), (4, 1), (3, 5)] {
+            for order in [Order::RowMajor, Order::ColMajor] {
+                let shape = AxisShape::from_shape_unchecked((nrows, ncols), order);
+                let indices = Matrix::<()>::reindices_to_different_order(shape);
+                assert_eq!(indices.len(), nrows * ncols);
+                let expected: Vec<usize> = (0..nrows * ncols)
+                    .map(|index| Matrix::<()>::reindex_to_different_order_unchecked(index, shape))
+                    .collect();
+                assert_eq!(indices.collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
     #[test]
     fn test_struct_index_new() {
         let expected = Index { row: 2, col: 3 };
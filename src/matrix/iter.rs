@@ -1,7 +1,13 @@
-use super::index::{AxisIndex, Index};
+use super::axis::Axis;
+use super::index::Index;
 use super::order::Order;
+use super::shape::Shape;
 use super::Matrix;
 use crate::error::{Error, Result};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
 
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
@@ -13,12 +19,590 @@ pub trait ExactSizeDoubleEndedIterator: ExactSizeIterator + DoubleEndedIterator
 impl<I> ExactSizeDoubleEndedIterator for I where I: ExactSizeIterator + DoubleEndedIterator {}
 
 /// A trait object that represents a double-ended iterator over a vector.
+#[deprecated(since = "0.14.0", note = "use `StrideIter` or `StrideIterMut` instead")]
 pub type VectorIter<'a, T> = Box<dyn ExactSizeDoubleEndedIterator<Item = T> + 'a>;
 
 /// A trait object that represents a double-ended iterator over a matrix.
+#[deprecated(since = "0.14.0", note = "use `AxisIter` or `AxisIterMut` instead")]
+#[allow(deprecated)]
 pub type MatrixIter<'a, T> = Box<dyn ExactSizeDoubleEndedIterator<Item = VectorIter<'a, T>> + 'a>;
 
+/// An iterator over the elements of a vector in a matrix.
+///
+/// Elements are yielded from the underlying buffer starting at some
+/// offset and advancing by a fixed step, so no allocation is involved.
+///
+/// This struct is created by methods such as [`Matrix::iter_nth_row`]
+/// and [`Matrix::iter_rows`], or over any slice with [`StrideIter::new`].
+#[derive(Clone, Debug)]
+pub struct StrideIter<'a, T> {
+    data: &'a [T],
+    start: usize,
+    step: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> StrideIter<'a, T> {
+    /// Creates an iterator over `len` elements of `data`, starting at
+    /// index `start` and advancing by `step`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `len` is non-zero and the last
+    ///   element `start + (len - 1) * step` is out of bounds. `data` is
+    ///   reported as a column vector, and an offset that overflows as
+    ///   [`usize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// Bounds are checked once here, so iterating performs no further
+    /// bounds checks. A `step` of zero yields the same element `len` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{Error, Index, Shape, StrideIter};
+    ///
+    /// let data = [0, 1, 2, 3, 4, 5];
+    ///
+    /// let iter = StrideIter::new(&data, 1, 2, 3).unwrap();
+    /// assert_eq!(iter.copied().collect::<Vec<_>>(), vec![1, 3, 5]);
+    ///
+    /// let result = StrideIter::new(&data, 1, 2, 4);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(7, 0),
+    ///     shape: Shape::new(6, 1),
+    /// };
+    /// assert_eq!(result.err(), Some(error));
+    /// ```
+    pub fn new(data: &'a [T], start: usize, step: usize, len: usize) -> Result<Self> {
+        if len != 0 {
+            let last = (len - 1)
+                .checked_mul(step)
+                .and_then(|offset| offset.checked_add(start))
+                .unwrap_or(usize::MAX);
+            if last >= data.len() {
+                return Err(Error::IndexOutOfBounds {
+                    index: Index::new(last, 0),
+                    shape: Shape::new(data.len(), 1),
+                });
+            }
+        }
+        unsafe { Ok(Self::new_unchecked(data, start, step, len)) }
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method when `start + (len - 1) * step` is out of bounds
+    /// for a non-zero `len` is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub(super) unsafe fn new_unchecked(
+        data: &'a [T],
+        start: usize,
+        step: usize,
+        len: usize,
+    ) -> Self {
+        debug_assert!(len == 0 || start + (len - 1) * step < data.len());
+        Self {
+            data,
+            start,
+            step,
+            front: 0,
+            back: len,
+        }
+    }
+}
+
+impl<'a, T> Iterator for StrideIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let index = self.start + self.front * self.step;
+        self.front += 1;
+        // SAFETY: `new_unchecked` requires every position before `back` to
+        // be within `data`, and `front < back`.
+        unsafe { Some(self.data.get_unchecked(index)) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for StrideIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let index = self.start + self.back * self.step;
+        // SAFETY: `new_unchecked` requires every position before `back` to
+        // be within `data`, and `back` was just decremented.
+        unsafe { Some(self.data.get_unchecked(index)) }
+    }
+}
+
+impl<T> ExactSizeIterator for StrideIter<'_, T> {}
+
+impl<T> FusedIterator for StrideIter<'_, T> {}
+
+/// An iterator that allows modifying each element of a vector in a matrix.
+///
+/// This is the mutable counterpart of [`StrideIter`].
+#[derive(Debug)]
+pub struct StrideIterMut<'a, T> {
+    data: *mut T,
+    start: usize,
+    step: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> StrideIterMut<'a, T> {
+    /// # Safety
+    ///
+    /// Calling this method when `start + (len - 1) * step` is out of bounds
+    /// for a non-zero `len`, or when the elements yielded are borrowed
+    /// elsewhere, is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub(super) unsafe fn new_unchecked(
+        data: *mut [T],
+        start: usize,
+        step: usize,
+        len: usize,
+    ) -> Self {
+        debug_assert!(len == 0 || start + (len - 1) * step < data.len());
+        Self {
+            data: data.cast(),
+            start,
+            step,
+            front: 0,
+            back: len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for StrideIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let index = self.start + self.front * self.step;
+        self.front += 1;
+        unsafe { Some(&mut *self.data.add(index)) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for StrideIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let index = self.start + self.back * self.step;
+        unsafe { Some(&mut *self.data.add(index)) }
+    }
+}
+
+impl<T> ExactSizeIterator for StrideIterMut<'_, T> {}
+
+impl<T> FusedIterator for StrideIterMut<'_, T> {}
+
+unsafe impl<T: Send> Send for StrideIterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for StrideIterMut<'_, T> {}
+
+/// An iterator over the vectors along an axis of a matrix.
+///
+/// This struct is created by methods such as [`Matrix::iter_rows`]
+/// and [`Matrix::iter_axis`].
+#[derive(Clone, Debug)]
+pub struct AxisIter<'a, T> {
+    data: &'a [T],
+    layout: VectorLayout,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> AxisIter<'a, T> {
+    fn vector(&self, n: usize) -> StrideIter<'a, T> {
+        let start = n * self.layout.offset;
+        unsafe { StrideIter::new_unchecked(self.data, start, self.layout.step, self.layout.len) }
+    }
+}
+
+impl<'a, T> Iterator for AxisIter<'a, T> {
+    type Item = StrideIter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let vector = self.vector(self.front);
+        self.front += 1;
+        Some(vector)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for AxisIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.vector(self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for AxisIter<'_, T> {}
+
+impl<T> FusedIterator for AxisIter<'_, T> {}
+
+/// An iterator over the vectors along an axis of a matrix, where each
+/// vector allows modifying its elements.
+///
+/// The vectors yielded borrow disjoint elements of the matrix, so they
+/// can be held and modified at the same time.
+///
+/// This struct is created by methods such as [`Matrix::iter_rows_mut`]
+/// and [`Matrix::iter_axis_mut`].
+#[derive(Debug)]
+pub struct AxisIterMut<'a, T> {
+    data: *mut [T],
+    layout: VectorLayout,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> AxisIterMut<'a, T> {
+    fn vector(&self, n: usize) -> StrideIterMut<'a, T> {
+        // Each vector is yielded at most once, and distinct vectors
+        // consist of distinct elements, so the references never alias.
+        let start = n * self.layout.offset;
+        unsafe { StrideIterMut::new_unchecked(self.data, start, self.layout.step, self.layout.len) }
+    }
+}
+
+impl<'a, T> Iterator for AxisIterMut<'a, T> {
+    type Item = StrideIterMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let vector = self.vector(self.front);
+        self.front += 1;
+        Some(vector)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for AxisIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.vector(self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for AxisIterMut<'_, T> {}
+
+impl<T> FusedIterator for AxisIterMut<'_, T> {}
+
+unsafe impl<T: Send> Send for AxisIterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for AxisIterMut<'_, T> {}
+
+/// Describes how the vectors along an axis are laid out in memory.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct VectorLayout {
+    /// Number of vectors.
+    pub(super) count: usize,
+
+    /// Distance between the first elements of two adjacent vectors.
+    pub(super) offset: usize,
+
+    /// Distance between two adjacent elements in a vector.
+    pub(super) step: usize,
+
+    /// Number of elements in each vector.
+    pub(super) len: usize,
+}
+
+/// An iterator that drops the elements of a matrix in chunks.
+///
+/// This struct is created by [`Matrix::into_drop_chunks`].
+#[derive(Debug)]
+pub struct DropChunks<T> {
+    data: alloc::vec::IntoIter<T>,
+    chunk_size: usize,
+}
+
+impl<T> Iterator for DropChunks<T> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let count = self.data.by_ref().take(self.chunk_size).count();
+        if count == 0 {
+            None
+        } else {
+            Some(count)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.data.len().div_ceil(self.chunk_size);
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for DropChunks<T> {}
+
+impl<T> FusedIterator for DropChunks<T> {}
+
+/// An iterator whose items borrow from the iterator itself.
+///
+/// Unlike [`Iterator`], each item must be dropped before the next call
+/// to [`next`], which lets implementors hand out references into an
+/// internal buffer that is reused between items.
+///
+/// [`next`]: LendingIterator::next
+pub trait LendingIterator {
+    /// The type of the items being iterated over.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// Advances the iterator and returns the next item.
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+
+    /// Returns the bounds on the remaining length of the iterator.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// A lending iterator over the rows of a matrix, each yielded as a
+/// contiguous slice.
+///
+/// For a row-major matrix, each slice borrows directly from the
+/// matrix. For a column-major matrix, each row is cloned into a single
+/// buffer owned by the iterator and reused for every row, so no
+/// allocation occurs after the first row.
+///
+/// This struct is created by [`Matrix::rows_lending`].
+#[derive(Clone, Debug)]
+pub struct RowsLending<'a, T> {
+    matrix: &'a Matrix<T>,
+    buffer: Vec<T>,
+    front: usize,
+}
+
+impl<'a, T: Clone> LendingIterator for RowsLending<'a, T> {
+    type Item<'b>
+        = &'b [T]
+    where
+        Self: 'b;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.front == self.matrix.nrows() {
+            return None;
+        }
+        let n = self.front;
+        self.front += 1;
+        match self.matrix.order {
+            Order::RowMajor => {
+                let ncols = self.matrix.ncols();
+                Some(&self.matrix.data[n * ncols..(n + 1) * ncols])
+            }
+            Order::ColMajor => {
+                let row = unsafe { self.matrix.iter_nth_minor_axis_vector_unchecked(n) };
+                self.buffer.clear();
+                self.buffer.extend(row.cloned());
+                Some(&self.buffer)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.matrix.nrows() - self.front;
+        (len, Some(len))
+    }
+}
+
 impl<T> Matrix<T> {
+    /// Returns an iterator over the vectors along the given axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let mut cols = matrix.iter_axis(Axis::Col);
+    ///
+    /// let mut col_0 = cols.next().unwrap();
+    /// assert_eq!(col_0.next(), Some(&0));
+    /// assert_eq!(col_0.next(), Some(&3));
+    /// assert_eq!(col_0.next(), None);
+    ///
+    /// assert_eq!(cols.len(), 2);
+    /// ```
+    pub fn iter_axis(&self, axis: Axis) -> AxisIter<'_, T> {
+        let layout = self.vector_layout(axis);
+        AxisIter {
+            data: &self.data,
+            layout,
+            front: 0,
+            back: layout.count,
+        }
+    }
+
+    /// Returns an iterator over the vectors along the given axis, where
+    /// each vector allows modifying its elements.
+    ///
+    /// # Notes
+    ///
+    /// The vectors yielded borrow disjoint elements of the matrix, so they
+    /// can be held and modified at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// for (n, col) in matrix.iter_axis_mut(Axis::Col).enumerate() {
+    ///     for element in col {
+    ///         *element += n as i32;
+    ///     }
+    /// }
+    /// assert_eq!(matrix, matrix![[0, 2, 4], [3, 5, 7]]);
+    /// ```
+    pub fn iter_axis_mut(&mut self, axis: Axis) -> AxisIterMut<'_, T> {
+        let layout = self.vector_layout(axis);
+        AxisIterMut {
+            data: self.data.as_mut_slice(),
+            layout,
+            front: 0,
+            back: layout.count,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the elements of the nth vector along
+    /// the given axis.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is greater than or equal to
+    ///   the length of the matrix along `axis`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let mut row_1 = matrix.iter_nth_vector(Axis::Row, 1).unwrap();
+    /// assert_eq!(row_1.next(), Some(&3));
+    /// assert_eq!(row_1.next(), Some(&4));
+    /// assert_eq!(row_1.next(), Some(&5));
+    /// assert_eq!(row_1.next(), None);
+    /// ```
+    pub fn iter_nth_vector(&self, axis: Axis, n: usize) -> Result<StrideIter<'_, T>> {
+        let layout = self.vector_layout(axis);
+        if n >= layout.count {
+            return Err(Error::vector_out_of_bounds(axis, n, self.shape()));
+        }
+        let start = n * layout.offset;
+        unsafe {
+            Ok(StrideIter::new_unchecked(
+                &self.data,
+                start,
+                layout.step,
+                layout.len,
+            ))
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element of the
+    /// nth vector along the given axis.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is greater than or equal to
+    ///   the length of the matrix along `axis`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// for element in matrix.iter_nth_vector_mut(Axis::Col, 1).unwrap() {
+    ///    *element += 1;
+    /// }
+    /// assert_eq!(matrix, matrix![[0, 2, 2], [3, 5, 5]]);
+    /// ```
+    pub fn iter_nth_vector_mut(&mut self, axis: Axis, n: usize) -> Result<StrideIterMut<'_, T>> {
+        let layout = self.vector_layout(axis);
+        if n >= layout.count {
+            return Err(Error::vector_out_of_bounds(axis, n, self.shape()));
+        }
+        let start = n * layout.offset;
+        let data = self.data.as_mut_slice();
+        unsafe {
+            Ok(StrideIterMut::new_unchecked(
+                data,
+                start,
+                layout.step,
+                layout.len,
+            ))
+        }
+    }
+
     /// Returns an iterator over the rows of the matrix.
     ///
     /// # Examples
@@ -43,15 +627,29 @@ impl<T> Matrix<T> {
     ///
     /// assert!(rows.next().is_none());
     /// ```
-    pub fn iter_rows(&self) -> MatrixIter<&T> {
-        match self.order {
-            Order::RowMajor => Box::new((0..self.major()).map(|n| -> VectorIter<&T> {
-                unsafe { Box::new(self.iter_nth_major_axis_vector_unchecked(n)) }
-            })),
-            Order::ColMajor => Box::new((0..self.minor()).map(|n| -> VectorIter<&T> {
-                unsafe { Box::new(self.iter_nth_minor_axis_vector_unchecked(n)) }
-            })),
-        }
+    pub fn iter_rows(&self) -> AxisIter<'_, T> {
+        self.iter_axis(Axis::Row)
+    }
+
+    /// Returns an iterator over the rows of the matrix, where each row
+    /// allows modifying its elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// for (n, row) in matrix.iter_rows_mut().enumerate() {
+    ///     for element in row {
+    ///         *element *= n as i32 + 1;
+    ///     }
+    /// }
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [6, 8, 10]]);
+    /// ```
+    pub fn iter_rows_mut(&mut self) -> AxisIterMut<'_, T> {
+        self.iter_axis_mut(Axis::Row)
     }
 
     /// Returns an iterator over the columns of the matrix.
@@ -81,15 +679,29 @@ impl<T> Matrix<T> {
     ///
     /// assert!(cols.next().is_none());
     /// ```
-    pub fn iter_cols(&self) -> MatrixIter<&T> {
-        match self.order {
-            Order::RowMajor => Box::new((0..self.minor()).map(|n| -> VectorIter<&T> {
-                unsafe { Box::new(self.iter_nth_minor_axis_vector_unchecked(n)) }
-            })),
-            Order::ColMajor => Box::new((0..self.major()).map(|n| -> VectorIter<&T> {
-                unsafe { Box::new(self.iter_nth_major_axis_vector_unchecked(n)) }
-            })),
-        }
+    pub fn iter_cols(&self) -> AxisIter<'_, T> {
+        self.iter_axis(Axis::Col)
+    }
+
+    /// Returns an iterator over the columns of the matrix, where each
+    /// column allows modifying its elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// for (n, col) in matrix.iter_cols_mut().enumerate() {
+    ///     for element in col {
+    ///         *element *= n as i32 + 1;
+    ///     }
+    /// }
+    /// assert_eq!(matrix, matrix![[0, 2, 6], [3, 8, 15]]);
+    /// ```
+    pub fn iter_cols_mut(&mut self) -> AxisIterMut<'_, T> {
+        self.iter_axis_mut(Axis::Col)
     }
 
     /// Returns an iterator over the elements of the nth row in the matrix.
@@ -97,7 +709,7 @@ impl<T> Matrix<T> {
     /// # Errors
     ///
     /// - [`Error::IndexOutOfBounds`] if `n` is greater than or equal to
-    /// the number of rows in the matrix.
+    ///   the number of rows in the matrix.
     ///
     /// # Examples
     ///
@@ -112,11 +724,8 @@ impl<T> Matrix<T> {
     /// assert_eq!(row_1.next(), Some(&5));
     /// assert_eq!(row_1.next(), None);
     /// ```
-    pub fn iter_nth_row(&self, n: usize) -> Result<VectorIter<&T>> {
-        match self.order {
-            Order::RowMajor => Ok(Box::new(self.iter_nth_major_axis_vector(n)?)),
-            Order::ColMajor => Ok(Box::new(self.iter_nth_minor_axis_vector(n)?)),
-        }
+    pub fn iter_nth_row(&self, n: usize) -> Result<StrideIter<'_, T>> {
+        self.iter_nth_vector(Axis::Row, n)
     }
 
     /// Returns an iterator that allows modifying each element of the
@@ -125,7 +734,7 @@ impl<T> Matrix<T> {
     /// # Errors
     ///
     /// - [`Error::IndexOutOfBounds`] if `n` is greater than or equal to
-    /// the number of rows in the matrix.
+    ///   the number of rows in the matrix.
     ///
     /// # Examples
     ///
@@ -139,11 +748,8 @@ impl<T> Matrix<T> {
     /// }
     /// assert_eq!(matrix, matrix![[0, 1, 2], [4, 5, 6]]);
     /// ```
-    pub fn iter_nth_row_mut(&mut self, n: usize) -> Result<VectorIter<&mut T>> {
-        match self.order {
-            Order::RowMajor => Ok(Box::new(self.iter_nth_major_axis_vector_mut(n)?)),
-            Order::ColMajor => Ok(Box::new(self.iter_nth_minor_axis_vector_mut(n)?)),
-        }
+    pub fn iter_nth_row_mut(&mut self, n: usize) -> Result<StrideIterMut<'_, T>> {
+        self.iter_nth_vector_mut(Axis::Row, n)
     }
 
     /// Returns an iterator over the elements of the nth column in the matrix.
@@ -151,7 +757,7 @@ impl<T> Matrix<T> {
     /// # Errors
     ///
     /// - [`Error::IndexOutOfBounds`] if `n` is greater than or equal to
-    /// the number of columns in the matrix.
+    ///   the number of columns in the matrix.
     ///
     /// # Examples
     ///
@@ -165,11 +771,8 @@ impl<T> Matrix<T> {
     /// assert_eq!(col_1.next(), Some(&4));
     /// assert_eq!(col_1.next(), None);
     /// ```
-    pub fn iter_nth_col(&self, n: usize) -> Result<VectorIter<&T>> {
-        match self.order {
-            Order::RowMajor => Ok(Box::new(self.iter_nth_minor_axis_vector(n)?)),
-            Order::ColMajor => Ok(Box::new(self.iter_nth_major_axis_vector(n)?)),
-        }
+    pub fn iter_nth_col(&self, n: usize) -> Result<StrideIter<'_, T>> {
+        self.iter_nth_vector(Axis::Col, n)
     }
 
     /// Returns an iterator that allows modifying each element of the
@@ -178,7 +781,7 @@ impl<T> Matrix<T> {
     /// # Errors
     ///
     /// - [`Error::IndexOutOfBounds`] if `n` is greater than or equal to
-    /// the number of columns in the matrix.
+    ///   the number of columns in the matrix.
     ///
     /// # Examples
     ///
@@ -192,11 +795,8 @@ impl<T> Matrix<T> {
     /// }
     /// assert_eq!(matrix, matrix![[0, 2, 2], [3, 5, 5]]);
     /// ```
-    pub fn iter_nth_col_mut(&mut self, n: usize) -> Result<VectorIter<&mut T>> {
-        match self.order {
-            Order::RowMajor => Ok(Box::new(self.iter_nth_minor_axis_vector_mut(n)?)),
-            Order::ColMajor => Ok(Box::new(self.iter_nth_major_axis_vector_mut(n)?)),
-        }
+    pub fn iter_nth_col_mut(&mut self, n: usize) -> Result<StrideIterMut<'_, T>> {
+        self.iter_nth_vector_mut(Axis::Col, n)
     }
 
     /// Returns an iterator over the elements of the matrix.
@@ -356,6 +956,81 @@ impl<T> Matrix<T> {
                 (index, element)
             })
     }
+
+    /// Creates an iterator that drops the elements of the matrix in chunks
+    /// of `chunk_size`, yielding the number of elements dropped at each step.
+    ///
+    /// This allows the destruction of huge matrices with non-trivial drop
+    /// to be amortized, e.g., spread across iterations of an event loop.
+    /// The remaining elements are dropped at once if the iterator itself
+    /// is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]].map(|x| x.to_string());
+    ///
+    /// let mut chunks = matrix.into_drop_chunks(4);
+    /// assert_eq!(chunks.next(), Some(4));
+    /// assert_eq!(chunks.next(), Some(2));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn into_drop_chunks(self, chunk_size: usize) -> DropChunks<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        DropChunks {
+            data: self.data.into_iter(),
+            chunk_size,
+        }
+    }
+
+    /// Returns a lending iterator over the rows of the matrix, each
+    /// yielded as a contiguous slice.
+    ///
+    /// # Notes
+    ///
+    /// Rows of a row-major matrix are borrowed in place. Rows of a
+    /// column-major matrix are cloned into a buffer that is reused
+    /// between rows, so iterating allocates at most once. Prefer
+    /// [`Matrix::iter_rows`] when a strided iterator is enough.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, LendingIterator};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.switch_order();
+    ///
+    /// let mut rows = matrix.rows_lending();
+    /// while let Some(row) = rows.next() {
+    ///     assert_eq!(row.len(), 3);
+    /// }
+    ///
+    /// let mut rows = matrix.rows_lending();
+    /// assert_eq!(rows.next(), Some(&[0, 1, 2][..]));
+    /// assert_eq!(rows.next(), Some(&[3, 4, 5][..]));
+    /// assert_eq!(rows.next(), None);
+    /// ```
+    pub fn rows_lending(&self) -> RowsLending<'_, T>
+    where
+        T: Clone,
+    {
+        let buffer = match self.order {
+            Order::RowMajor => Vec::new(),
+            Order::ColMajor => Vec::with_capacity(self.ncols()),
+        };
+        RowsLending {
+            matrix: self,
+            buffer,
+            front: 0,
+        }
+    }
 }
 
 #[cfg(feature = "rayon")]
@@ -363,6 +1038,65 @@ impl<T> Matrix<T>
 where
     T: Sync + Send,
 {
+    /// Returns a parallel iterator over the vectors along the given axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let sums: Vec<i32> = matrix.par_iter_axis(Axis::Col).map(|col| col.sum()).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    pub fn par_iter_axis(
+        &self,
+        axis: Axis,
+    ) -> impl IndexedParallelIterator<Item = StrideIter<'_, T>> {
+        let data = self.data.as_slice();
+        let layout = self.vector_layout(axis);
+        (0..layout.count).into_par_iter().map(move |n| {
+            let start = n * layout.offset;
+            unsafe { StrideIter::new_unchecked(data, start, layout.step, layout.len) }
+        })
+    }
+
+    /// Returns a parallel iterator over the rows of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let sums: Vec<i32> = matrix.par_iter_rows().map(|row| row.sum()).collect();
+    /// assert_eq!(sums, vec![3, 12]);
+    /// ```
+    pub fn par_iter_rows(&self) -> impl IndexedParallelIterator<Item = StrideIter<'_, T>> {
+        self.par_iter_axis(Axis::Row)
+    }
+
+    /// Returns a parallel iterator over the columns of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// let sums: Vec<i32> = matrix.par_iter_cols().map(|col| col.sum()).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    pub fn par_iter_cols(&self) -> impl IndexedParallelIterator<Item = StrideIter<'_, T>> {
+        self.par_iter_axis(Axis::Col)
+    }
+
     /// Returns a parallel iterator over the elements of the matrix.
     ///
     /// # Examples
@@ -418,28 +1152,21 @@ where
 }
 
 impl<T> Matrix<T> {
-    /// # Safety
-    ///
-    /// Calling this method when `n >= self.major()` is *[undefined behavior]*.
-    ///
-    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
-    pub(super) unsafe fn iter_nth_major_axis_vector_unchecked(
-        &self,
-        n: usize,
-    ) -> impl ExactSizeDoubleEndedIterator<Item = &T> {
-        let lower = n * self.major_stride();
-        let upper = lower + self.major_stride();
-        unsafe { self.data.get_unchecked(lower..upper).iter() }
-    }
-
-    pub(super) fn iter_nth_major_axis_vector(
-        &self,
-        n: usize,
-    ) -> Result<impl ExactSizeDoubleEndedIterator<Item = &T>> {
-        if n >= self.major() {
-            Err(Error::IndexOutOfBounds)
+    pub(super) fn vector_layout(&self, axis: Axis) -> VectorLayout {
+        if axis.is_major(self.order) {
+            VectorLayout {
+                count: self.major(),
+                offset: self.major_stride(),
+                step: 1,
+                len: self.minor(),
+            }
         } else {
-            unsafe { Ok(self.iter_nth_major_axis_vector_unchecked(n)) }
+            VectorLayout {
+                count: self.minor(),
+                offset: 1,
+                step: self.major_stride(),
+                len: self.major(),
+            }
         }
     }
 
@@ -448,81 +1175,261 @@ impl<T> Matrix<T> {
     /// Calling this method when `n >= self.major()` is *[undefined behavior]*.
     ///
     /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
-    pub(super) unsafe fn iter_nth_major_axis_vector_unchecked_mut(
-        &mut self,
-        n: usize,
-    ) -> impl ExactSizeDoubleEndedIterator<Item = &mut T> {
-        let lower = n * self.major_stride();
-        let upper = lower + self.major_stride();
-        unsafe { self.data.get_unchecked_mut(lower..upper).iter_mut() }
-    }
-
-    pub(super) fn iter_nth_major_axis_vector_mut(
-        &mut self,
+    pub(super) unsafe fn iter_nth_major_axis_vector_unchecked(
+        &self,
         n: usize,
-    ) -> Result<impl ExactSizeDoubleEndedIterator<Item = &mut T>> {
-        if n >= self.major() {
-            Err(Error::IndexOutOfBounds)
-        } else {
-            unsafe { Ok(self.iter_nth_major_axis_vector_unchecked_mut(n)) }
-        }
+    ) -> StrideIter<'_, T> {
+        let start = n * self.major_stride();
+        unsafe { StrideIter::new_unchecked(&self.data, start, 1, self.minor()) }
     }
 
     /// # Safety
     ///
     /// Calling this method when `n >= self.minor()` is *[undefined behavior]*.
-    /// To be more specific, the last element accessed will be out of bounds,
-    /// while other elements might either be incorrect or out of bounds.
     ///
     /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
     pub(super) unsafe fn iter_nth_minor_axis_vector_unchecked(
         &self,
         n: usize,
-    ) -> impl ExactSizeDoubleEndedIterator<Item = &T> {
-        (0..self.major()).map(move |m| {
-            let index = AxisIndex::new(m, n);
-            unsafe { self.get_unchecked(index) }
-        })
+    ) -> StrideIter<'_, T> {
+        let step = self.major_stride();
+        unsafe { StrideIter::new_unchecked(&self.data, n, step, self.major()) }
     }
+}
 
-    pub(super) fn iter_nth_minor_axis_vector(
-        &self,
-        n: usize,
-    ) -> Result<impl ExactSizeDoubleEndedIterator<Item = &T>> {
-        if n >= self.minor() {
-            Err(Error::IndexOutOfBounds)
-        } else {
-            unsafe { Ok(self.iter_nth_minor_axis_vector_unchecked(n)) }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+
+    #[test]
+    fn test_iter_axis() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        {
+            let rows: Vec<Vec<&i32>> = matrix.iter_axis(Axis::Row).map(|v| v.collect()).collect();
+            assert_eq!(rows, vec![vec![&0, &1, &2], vec![&3, &4, &5]]);
+            let cols: Vec<Vec<&i32>> = matrix.iter_axis(Axis::Col).map(|v| v.collect()).collect();
+            assert_eq!(cols, vec![vec![&0, &3], vec![&1, &4], vec![&2, &5]]);
+        }
+
+        matrix.switch_order();
+
+        {
+            let rows: Vec<Vec<&i32>> = matrix.iter_axis(Axis::Row).map(|v| v.collect()).collect();
+            assert_eq!(rows, vec![vec![&0, &1, &2], vec![&3, &4, &5]]);
+            let cols: Vec<Vec<&i32>> = matrix.iter_axis(Axis::Col).map(|v| v.collect()).collect();
+            assert_eq!(cols, vec![vec![&0, &3], vec![&1, &4], vec![&2, &5]]);
         }
     }
 
-    /// # Safety
-    ///
-    /// Calling this method when `n >= self.minor()` is safe but incorrect.
-    pub(super) fn iter_nth_minor_axis_vector_unchecked_mut(
-        &mut self,
-        n: usize,
-    ) -> impl ExactSizeDoubleEndedIterator<Item = &mut T> {
-        let step = self.major_stride();
-        self.data.iter_mut().skip(n).step_by(step)
+    #[test]
+    fn test_stride_iter() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut col_1 = matrix.iter_nth_col(1).unwrap();
+            assert_eq!(col_1.len(), 2);
+            assert_eq!(col_1.next_back(), Some(&4));
+            assert_eq!(col_1.len(), 1);
+            assert_eq!(col_1.next(), Some(&1));
+            assert_eq!(col_1.len(), 0);
+            assert_eq!(col_1.next(), None);
+            assert_eq!(col_1.next_back(), None);
+
+            let mut row_0 = matrix.iter_nth_row(0).unwrap();
+            assert_eq!(row_0.nth(1), Some(&1));
+            assert_eq!(row_0.clone().next_back(), Some(&2));
+            assert_eq!(row_0.nth(5), None);
+            assert_eq!(row_0.next(), None);
+
+            matrix.switch_order();
+        }
     }
 
-    pub(super) fn iter_nth_minor_axis_vector_mut(
-        &mut self,
-        n: usize,
-    ) -> Result<impl ExactSizeDoubleEndedIterator<Item = &mut T>> {
-        if n >= self.minor() {
-            Err(Error::IndexOutOfBounds)
-        } else {
-            Ok(self.iter_nth_minor_axis_vector_unchecked_mut(n))
+    #[test]
+    fn test_stride_iter_new() {
+        let data = [0, 1, 2, 3, 4, 5];
+
+        let iter = StrideIter::new(&data, 0, 1, 6).unwrap();
+        assert_eq!(iter.collect::<Vec<_>>(), vec![&0, &1, &2, &3, &4, &5]);
+        let iter = StrideIter::new(&data, 2, 3, 2).unwrap();
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![&5, &2]);
+        let iter = StrideIter::new(&data, 4, 0, 3).unwrap();
+        assert_eq!(iter.collect::<Vec<_>>(), vec![&4, &4, &4]);
+        let iter = StrideIter::new(&data, 100, 100, 0).unwrap();
+        assert_eq!(iter.len(), 0);
+
+        assert_eq!(
+            StrideIter::new(&data, 6, 1, 1).err(),
+            Some(Error::IndexOutOfBounds {
+                index: Index::new(6, 0),
+                shape: Shape::new(6, 1)
+            })
+        );
+        assert_eq!(
+            StrideIter::new(&data, 2, 3, 3).err(),
+            Some(Error::IndexOutOfBounds {
+                index: Index::new(8, 0),
+                shape: Shape::new(6, 1)
+            })
+        );
+        assert_eq!(
+            StrideIter::new(&data, 1, usize::MAX, 2).err(),
+            Some(Error::IndexOutOfBounds {
+                index: Index::new(usize::MAX, 0),
+                shape: Shape::new(6, 1)
+            })
+        );
+        assert_eq!(
+            StrideIter::<i32>::new(&[], 0, 1, 1).err(),
+            Some(Error::IndexOutOfBounds {
+                index: Index::new(0, 0),
+                shape: Shape::new(0, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_axis_iter() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut rows = matrix.iter_rows();
+            assert_eq!(rows.len(), 2);
+            let row_1: Vec<&i32> = rows.next_back().unwrap().collect();
+            assert_eq!(row_1, vec![&3, &4, &5]);
+            let row_0: Vec<&i32> = rows.next().unwrap().rev().collect();
+            assert_eq!(row_0, vec![&2, &1, &0]);
+            assert!(rows.next().is_none());
+            assert!(rows.next_back().is_none());
+
+            let cols = matrix.iter_cols();
+            assert_eq!(cols.len(), 3);
+            let lens: Vec<usize> = cols.map(|col| col.len()).collect();
+            assert_eq!(lens, vec![2, 2, 2]);
+
+            matrix.switch_order();
         }
+
+        let matrix = Matrix::<i32>::new((2, 0));
+        let rows: Vec<usize> = matrix.iter_rows().map(|row| row.len()).collect();
+        assert_eq!(rows, vec![0, 0]);
+        assert_eq!(matrix.iter_cols().len(), 0);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::matrix;
+    #[test]
+    fn test_axis_iter_mut() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            {
+                let mut rows = matrix.iter_rows_mut();
+                let row_0 = rows.next().unwrap();
+                let row_1 = rows.next().unwrap();
+                for (x, y) in row_0.zip(row_1) {
+                    core::mem::swap(x, y);
+                }
+            }
+            let mut expected = matrix![[3, 4, 5], [0, 1, 2]];
+            if matrix.order() != expected.order() {
+                expected.switch_order();
+            }
+            assert_eq!(matrix, expected);
+
+            {
+                let mut rows = matrix.iter_rows_mut();
+                let row_0 = rows.next().unwrap();
+                let row_1 = rows.next().unwrap();
+                for (x, y) in row_0.zip(row_1) {
+                    core::mem::swap(x, y);
+                }
+            }
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_iter_nth_vector() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        {
+            let row_1: Vec<&i32> = matrix.iter_nth_vector(Axis::Row, 1).unwrap().collect();
+            assert_eq!(row_1, vec![&3, &4, &5]);
+            let col_2: Vec<&i32> = matrix.iter_nth_vector(Axis::Col, 2).unwrap().collect();
+            assert_eq!(col_2, vec![&2, &5]);
+            assert_eq!(
+                matrix.iter_nth_vector(Axis::Row, 2).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
+            assert_eq!(
+                matrix.iter_nth_vector(Axis::Col, 3).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                })
+            );
+        }
+
+        matrix.switch_order();
+
+        {
+            let row_1: Vec<&i32> = matrix.iter_nth_vector(Axis::Row, 1).unwrap().collect();
+            assert_eq!(row_1, vec![&3, &4, &5]);
+            let col_2: Vec<&i32> = matrix.iter_nth_vector(Axis::Col, 2).unwrap().collect();
+            assert_eq!(col_2, vec![&2, &5]);
+            assert_eq!(
+                matrix.iter_nth_vector(Axis::Row, 2).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
+            assert_eq!(
+                matrix.iter_nth_vector(Axis::Col, 3).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_iter_nth_vector_mut() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        {
+            let mut matrix = matrix.clone();
+            for element in matrix.iter_nth_vector_mut(Axis::Row, 0).unwrap() {
+                *element += 1;
+            }
+            for element in matrix.iter_nth_vector_mut(Axis::Col, 2).unwrap() {
+                *element *= 2;
+            }
+            assert_eq!(matrix, matrix![[1, 2, 6], [3, 4, 10]]);
+        }
+
+        matrix.switch_order();
+
+        {
+            let mut matrix = matrix.clone();
+            for element in matrix.iter_nth_vector_mut(Axis::Row, 0).unwrap() {
+                *element += 1;
+            }
+            for element in matrix.iter_nth_vector_mut(Axis::Col, 2).unwrap() {
+                *element *= 2;
+            }
+            matrix.switch_order();
+            assert_eq!(matrix, matrix![[1, 2, 6], [3, 4, 10]]);
+        }
+    }
 
     #[test]
     fn test_iter_rows() {
@@ -662,6 +1569,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_iter_rows_mut() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        {
+            let mut matrix = matrix.clone();
+            {
+                let mut rows = matrix.iter_rows_mut();
+                let row_1 = rows.next_back().unwrap();
+                let row_0 = rows.next().unwrap();
+                assert!(rows.next().is_none());
+                for (x, y) in row_0.zip(row_1) {
+                    core::mem::swap(x, y);
+                }
+            }
+            assert_eq!(matrix, matrix![[3, 4, 5], [0, 1, 2]]);
+        }
+
+        matrix.switch_order();
+
+        {
+            let mut matrix = matrix.clone();
+            {
+                let mut rows = matrix.iter_rows_mut();
+                let row_1 = rows.next_back().unwrap();
+                let row_0 = rows.next().unwrap();
+                assert!(rows.next().is_none());
+                for (x, y) in row_0.zip(row_1) {
+                    core::mem::swap(x, y);
+                }
+            }
+            matrix.switch_order();
+            assert_eq!(matrix, matrix![[3, 4, 5], [0, 1, 2]]);
+        }
+
+        let mut matrix = Matrix::<i32>::new((2, 0));
+        let mut rows = matrix.iter_rows_mut();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows.next().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_iter_cols_mut() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        {
+            let mut matrix = matrix.clone();
+            {
+                let mut cols = matrix.iter_cols_mut();
+                let col_0 = cols.next().unwrap();
+                let col_2 = cols.next_back().unwrap();
+                for (x, y) in col_0.zip(col_2) {
+                    core::mem::swap(x, y);
+                }
+                for element in cols.next().unwrap().rev() {
+                    *element *= 2;
+                }
+                assert!(cols.next().is_none());
+            }
+            assert_eq!(matrix, matrix![[2, 2, 0], [5, 8, 3]]);
+        }
+
+        matrix.switch_order();
+
+        {
+            let mut matrix = matrix.clone();
+            {
+                let mut cols = matrix.iter_cols_mut();
+                let col_0 = cols.next().unwrap();
+                let col_2 = cols.next_back().unwrap();
+                for (x, y) in col_0.zip(col_2) {
+                    core::mem::swap(x, y);
+                }
+                for element in cols.next().unwrap().rev() {
+                    *element *= 2;
+                }
+                assert!(cols.next().is_none());
+            }
+            matrix.switch_order();
+            assert_eq!(matrix, matrix![[2, 2, 0], [5, 8, 3]]);
+        }
+    }
+
     #[test]
     fn test_iter_nth_row() {
         let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
@@ -679,10 +1669,13 @@ mod tests {
             assert_eq!(row_1.next(), Some(&5));
             assert_eq!(row_1.next(), None);
 
-            assert!(matches!(
-                matrix.iter_nth_row(2),
-                Err(Error::IndexOutOfBounds)
-            ));
+            assert_eq!(
+                matrix.iter_nth_row(2).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
         }
 
         matrix.switch_order();
@@ -700,10 +1693,13 @@ mod tests {
             assert_eq!(row_1.next(), Some(&5));
             assert_eq!(row_1.next(), None);
 
-            assert!(matches!(
-                matrix.iter_nth_row(2),
-                Err(Error::IndexOutOfBounds)
-            ));
+            assert_eq!(
+                matrix.iter_nth_row(2).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
         }
     }
 
@@ -724,10 +1720,13 @@ mod tests {
                 *element -= 1;
             }
 
-            assert!(matches!(
-                matrix.iter_nth_row_mut(2),
-                Err(Error::IndexOutOfBounds)
-            ));
+            assert_eq!(
+                matrix.iter_nth_row_mut(2).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
 
             assert_eq!(matrix, matrix![[1, 2, 3], [2, 3, 4]]);
         }
@@ -747,10 +1746,13 @@ mod tests {
                 *element -= 1;
             }
 
-            assert!(matches!(
-                matrix.iter_nth_row_mut(2),
-                Err(Error::IndexOutOfBounds)
-            ));
+            assert_eq!(
+                matrix.iter_nth_row_mut(2).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                })
+            );
 
             matrix.switch_order();
             assert_eq!(matrix, matrix![[1, 2, 3], [2, 3, 4]]);
@@ -777,10 +1779,13 @@ mod tests {
             assert_eq!(col_2.next(), Some(&5));
             assert_eq!(col_2.next(), None);
 
-            assert!(matches!(
-                matrix.iter_nth_col(3),
-                Err(Error::IndexOutOfBounds)
-            ));
+            assert_eq!(
+                matrix.iter_nth_col(3).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                })
+            );
         }
 
         matrix.switch_order();
@@ -801,10 +1806,13 @@ mod tests {
             assert_eq!(col_2.next(), Some(&5));
             assert_eq!(col_2.next(), None);
 
-            assert!(matches!(
-                matrix.iter_nth_col(3),
-                Err(Error::IndexOutOfBounds)
-            ));
+            assert_eq!(
+                matrix.iter_nth_col(3).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                })
+            );
         }
     }
 
@@ -830,10 +1838,13 @@ mod tests {
                 *element *= 2;
             }
 
-            assert!(matches!(
-                matrix.iter_nth_col_mut(3),
-                Err(Error::IndexOutOfBounds)
-            ));
+            assert_eq!(
+                matrix.iter_nth_col_mut(3).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                })
+            );
 
             assert_eq!(matrix, matrix![[1, 0, 4], [4, 3, 10]]);
         }
@@ -858,10 +1869,13 @@ mod tests {
                 *element *= 2;
             }
 
-            assert!(matches!(
-                matrix.iter_nth_col_mut(3),
-                Err(Error::IndexOutOfBounds)
-            ));
+            assert_eq!(
+                matrix.iter_nth_col_mut(3).err(),
+                Some(Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                })
+            );
 
             matrix.switch_order();
             assert_eq!(matrix, matrix![[1, 0, 4], [4, 3, 10]]);
@@ -916,6 +1930,65 @@ mod tests {
         assert_eq!(data, vec![0, 1, 2, 3, 4, 5]);
     }
 
+    #[test]
+    fn test_into_drop_chunks() {
+        let matrix = matrix![[0, 1, 2], [3, 4, 5]].map(|x| x.to_string());
+
+        let chunks = matrix.clone().into_drop_chunks(4);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.collect::<Vec<_>>(), vec![4, 2]);
+
+        let chunks = matrix.clone().into_drop_chunks(1);
+        assert_eq!(chunks.sum::<usize>(), 6);
+
+        let chunks = matrix.clone().into_drop_chunks(6);
+        assert_eq!(chunks.collect::<Vec<_>>(), vec![6]);
+
+        let mut chunks = Matrix::<String>::empty().into_drop_chunks(3);
+        assert_eq!(chunks.len(), 0);
+        assert_eq!(chunks.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_into_drop_chunks_fails() {
+        let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        matrix.into_drop_chunks(0);
+    }
+
+    #[test]
+    fn test_rows_lending() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut rows = matrix.rows_lending();
+            assert_eq!(rows.size_hint(), (2, Some(2)));
+            assert_eq!(rows.next(), Some(&[0, 1, 2][..]));
+            assert_eq!(rows.size_hint(), (1, Some(1)));
+            assert_eq!(rows.next(), Some(&[3, 4, 5][..]));
+            assert_eq!(rows.next(), None);
+            assert_eq!(rows.next(), None);
+
+            let mut rows = matrix.rows_lending();
+            let mut expected = matrix.iter_rows();
+            while let Some(row) = rows.next() {
+                assert!(row.iter().eq(expected.next().unwrap()));
+            }
+            assert!(expected.next().is_none());
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<String>::new((2, 0));
+        let mut rows = matrix.rows_lending();
+        assert_eq!(rows.next(), Some(&[][..]));
+        assert_eq!(rows.next(), Some(&[][..]));
+        assert_eq!(rows.next(), None);
+
+        let matrix = Matrix::<String>::empty();
+        assert_eq!(matrix.rows_lending().next(), None);
+    }
+
     #[test]
     fn test_iter_elements_with_index() {
         let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
@@ -964,6 +2037,34 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_iter_rows() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let rows: Vec<Vec<&i32>> = matrix.par_iter_rows().map(|row| row.collect()).collect();
+            assert_eq!(rows, vec![vec![&0, &1, &2], vec![&3, &4, &5]]);
+            assert_eq!(matrix.par_iter_rows().len(), 2);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_iter_cols() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let cols: Vec<Vec<&i32>> = matrix.par_iter_cols().map(|col| col.collect()).collect();
+            assert_eq!(cols, vec![vec![&0, &3], vec![&1, &4], vec![&2, &5]]);
+            assert_eq!(matrix.par_iter_cols().len(), 3);
+
+            matrix.switch_order();
+        }
+    }
+
     #[cfg(feature = "rayon")]
     #[test]
     fn test_par_iter_elements() {
@@ -0,0 +1,1738 @@
+//! This module provides linear algebra routines, most of which are only
+//! available for float matrices.
+
+#[cfg(feature = "std")]
+use super::axis::Axis;
+use super::order::Order;
+#[cfg(feature = "std")]
+use super::progress::ProgressSink;
+#[cfg(feature = "std")]
+use super::real::Real;
+#[cfg(feature = "std")]
+use super::shape::{AxisShape, Shape};
+use super::Matrix;
+use crate::error::{Error, Result};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "std")]
+macro_rules! impl_linalg {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                /// Returns the determinant of the matrix.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotSquare`] if the matrix is not square.
+                ///
+                /// # Notes
+                ///
+                /// The determinant is computed with LU decomposition with
+                /// partial pivoting, as the product of the pivots. Rounding
+                /// may leave a nearly singular matrix with a tiny nonzero
+                /// determinant.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Error, Matrix, Shape};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0], [3.0, 4.0]];
+                /// assert_eq!(matrix.determinant(), Ok(-2.0));
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0]];
+                /// let error = Error::NotSquare(Shape::new(1, 3));
+                /// assert_eq!(matrix.determinant(), Err(error));
+                /// ```
+                ///
+                /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+                pub fn determinant(&self) -> Result<$t> {
+                    LuFactors::factorize(self).map(|lu| lu.determinant())
+                }
+
+                /// Returns the inverse of the matrix.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotSquare`] if the matrix is not square.
+                /// - [`Error::Singular`] if the matrix is singular.
+                ///
+                /// # Notes
+                ///
+                /// A matrix is singular if LU decomposition leaves a pivot
+                /// that is zero or not finite. A nearly singular matrix
+                /// therefore yields an inverse with very large elements.
+                ///
+                /// The resulting matrix will always have the same order as `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Error, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[2.0, 1.0], [1.0, 1.0]];
+                /// assert_eq!(matrix.inverse(), Ok(matrix![[1.0, -1.0], [-1.0, 2.0]]));
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0], [2.0, 4.0]];
+                /// assert_eq!(matrix.inverse(), Err(Error::Singular));
+                /// ```
+                ///
+                /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+                /// [`Error::Singular`]: crate::error::Error::Singular
+                pub fn inverse(&self) -> Result<Matrix<$t>> {
+                    LuFactors::factorize(self)?.inverse(self.order)
+                }
+
+                /// Computes the LU decomposition of the matrix with partial
+                /// pivoting.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotSquare`] if the matrix is not square.
+                ///
+                /// # Notes
+                ///
+                /// Singular matrices can be decomposed as well, but solving
+                /// against their decomposition fails.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[2.0, 1.0], [1.0, 1.0]];
+                /// let lu = matrix.lu().unwrap();
+                ///
+                /// assert_eq!(lu.det(), 1.0);
+                /// assert_eq!(lu.solve(&matrix![[3.0], [2.0]]), Ok(matrix![[1.0], [1.0]]));
+                /// assert_eq!(lu.solve(&matrix![[1.0], [0.0]]), Ok(matrix![[1.0], [-1.0]]));
+                /// ```
+                ///
+                /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+                pub fn lu(&self) -> Result<LuDecomposition<$t>> {
+                    LuFactors::factorize(self).map(|factors| LuDecomposition { factors })
+                }
+
+                /// Computes the LU decomposition of the matrix with partial
+                /// pivoting, reporting progress to `progress` as it goes.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotSquare`] if the matrix is not square.
+                ///
+                /// # Notes
+                ///
+                /// Progress is reported in elimination steps, once for each
+                /// column of the matrix.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[2.0, 1.0], [1.0, 1.0]];
+                ///
+                /// let mut reports = Vec::new();
+                /// let lu = matrix
+                ///     .lu_with_progress(|done, total| reports.push((done, total)))
+                ///     .unwrap();
+                /// assert_eq!(lu.det(), 1.0);
+                /// assert_eq!(reports, vec![(1, 2), (2, 2)]);
+                /// ```
+                ///
+                /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+                pub fn lu_with_progress<P>(&self, mut progress: P) -> Result<LuDecomposition<$t>>
+                where
+                    P: ProgressSink,
+                {
+                    LuFactors::factorize_with_hooks(self, &mut progress, None)
+                        .map(|factors| LuDecomposition { factors })
+                }
+
+                /// Computes the LU decomposition of the matrix with partial
+                /// pivoting, giving up as soon as `cancel` is set.
+                ///
+                /// `cancel` is checked before each elimination step, so
+                /// another thread can stop the operation cleanly by storing
+                /// `true` into it.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotSquare`] if the matrix is not square.
+                /// - [`Error::Cancelled`] if `cancel` is set before the
+                ///   decomposition completes.
+                ///
+                /// # Notes
+                ///
+                /// [`Error::Cancelled`] reports progress in elimination steps.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Error, Matrix};
+                /// use std::sync::atomic::AtomicBool;
+                ///
+                /// let matrix: Matrix<f64> = matrix![[2.0, 1.0], [1.0, 1.0]];
+                ///
+                /// let cancel = AtomicBool::new(false);
+                /// assert_eq!(matrix.lu_cancellable(&cancel).unwrap().det(), 1.0);
+                ///
+                /// let cancel = AtomicBool::new(true);
+                /// assert!(matches!(
+                ///     matrix.lu_cancellable(&cancel),
+                ///     Err(Error::Cancelled { done: 0, total: 2 })
+                /// ));
+                /// ```
+                ///
+                /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+                /// [`Error::Cancelled`]: crate::error::Error::Cancelled
+                pub fn lu_cancellable(&self, cancel: &AtomicBool) -> Result<LuDecomposition<$t>> {
+                    LuFactors::factorize_with_hooks(self, &mut |_, _| {}, Some(cancel))
+                        .map(|factors| LuDecomposition { factors })
+                }
+            }
+
+            impl Matrix<$t> {
+                /// Returns the rank of the matrix, computed by row reduction
+                /// with a default tolerance.
+                ///
+                /// # Notes
+                ///
+                /// Pivots whose absolute value does not exceed
+                /// `EPSILON * max(nrows, ncols) * max_abs` are treated as zero,
+                /// where `max_abs` is the largest absolute value among the
+                /// elements. Use [`Matrix::rank_with_tolerance`] to choose
+                /// the tolerance explicitly.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]];
+                /// assert_eq!(matrix.rank(), 1);
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0], [3.0, 4.0]];
+                /// assert_eq!(matrix.rank(), 2);
+                /// ```
+                pub fn rank(&self) -> usize {
+                    let scale = max_abs(self);
+                    let len = self.nrows().max(self.ncols());
+                    rank(self, <$t>::EPSILON * len as $t * scale)
+                }
+
+                /// Returns the rank of the matrix, computed by row reduction
+                /// with pivots whose absolute value does not exceed
+                /// `tolerance` treated as zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 0.0], [0.0, 1e-6]];
+                /// assert_eq!(matrix.rank_with_tolerance(1e-9), 2);
+                /// assert_eq!(matrix.rank_with_tolerance(1e-3), 1);
+                /// ```
+                pub fn rank_with_tolerance(&self, tolerance: $t) -> usize {
+                    rank(self, tolerance)
+                }
+
+                /// Reduces the matrix to reduced row echelon form in place,
+                /// returning the indices of its pivot columns in ascending
+                /// order.
+                ///
+                /// # Notes
+                ///
+                /// Partial pivoting is used, with the same default tolerance
+                /// as [`Matrix::rank`]. Pivots are set to exactly one and the
+                /// rest of their columns to exactly zero, so the number of
+                /// pivot columns is the rank of the matrix.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let mut matrix: Matrix<f64> = matrix![
+                ///     [1.0, 2.0, 1.0, 4.0],
+                ///     [2.0, 4.0, 0.0, 6.0],
+                /// ];
+                ///
+                /// let pivots = matrix.rref();
+                /// assert_eq!(pivots, vec![0, 2]);
+                /// assert_eq!(matrix, matrix![[1.0, 2.0, 0.0, 3.0], [0.0, 0.0, 1.0, 1.0]]);
+                /// ```
+                pub fn rref(&mut self) -> Vec<usize> {
+                    let scale = max_abs(self);
+                    let len = self.nrows().max(self.ncols());
+                    rref(self, <$t>::EPSILON * len as $t * scale)
+                }
+
+                /// Consumes the matrix and returns its reduced row echelon
+                /// form, along with the indices of its pivot columns.
+                ///
+                /// See [`Matrix::rref`] for details.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[0.0, 2.0], [0.0, 4.0]];
+                ///
+                /// let (reduced, pivots) = matrix.into_rref();
+                /// assert_eq!(reduced, matrix![[0.0, 1.0], [0.0, 0.0]]);
+                /// assert_eq!(pivots, vec![1]);
+                /// ```
+                pub fn into_rref(mut self) -> (Self, Vec<usize>) {
+                    let pivots = self.rref();
+                    (self, pivots)
+                }
+
+                /// Returns the norm of the matrix of the given kind.
+                ///
+                /// # Notes
+                ///
+                /// The norm of an empty matrix is zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix, NormKind};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, -2.0], [-3.0, 4.0]];
+                ///
+                /// assert_eq!(matrix.norm(NormKind::Frobenius), 30.0_f64.sqrt());
+                /// assert_eq!(matrix.norm(NormKind::L1), 6.0);
+                /// assert_eq!(matrix.norm(NormKind::Infinity), 7.0);
+                /// ```
+                pub fn norm(&self, kind: NormKind) -> $t {
+                    norm(self, kind)
+                }
+
+                /// Returns the matrix exponential `e^A` of the matrix.
+                ///
+                /// This is the solution operator of the linear system of
+                /// ODEs `x' = A x`, that is, `x(t) = e^(A t) x(0)`.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotSquare`] if the matrix is not square.
+                ///
+                /// # Notes
+                ///
+                /// The exponential is computed with a degree 6 Padé
+                /// approximant combined with scaling and squaring, so that
+                /// the approximant is only evaluated on a matrix whose
+                /// infinity norm is at most one half.
+                ///
+                /// If any element is NaN or infinite, every element of the
+                /// result is NaN. Elements of `e^A` that overflow may come
+                /// out as NaN rather than infinite. The resulting matrix
+                /// will always have the same order as `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Approx, Error, Matrix, Shape};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[0.0, 1.0], [0.0, 0.0]];
+                /// assert_eq!(matrix.expm(), Ok(matrix![[1.0, 1.0], [0.0, 1.0]]));
+                ///
+                /// let e = std::f64::consts::E;
+                /// let matrix: Matrix<f64> = matrix![[1.0, 0.0], [0.0, 2.0]];
+                /// let expected = matrix![[e, 0.0], [0.0, e * e]];
+                /// assert_eq!(Approx(expected, 1e-12), matrix.expm().unwrap());
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0]];
+                /// let error = Error::NotSquare(Shape::new(1, 3));
+                /// assert_eq!(matrix.expm(), Err(error));
+                /// ```
+                ///
+                /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+                pub fn expm(&self) -> Result<Matrix<$t>> {
+                    expm(self)
+                }
+            }
+
+            impl LuDecomposition<$t> {
+                /// Returns the unit lower triangular factor `L`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 1.0], [2.0, 1.0]];
+                /// let lu = matrix.lu().unwrap();
+                ///
+                /// assert_eq!(lu.l(), matrix![[1.0, 0.0], [0.5, 1.0]]);
+                /// ```
+                pub fn l(&self) -> Matrix<$t> {
+                    self.factors.triangle(|row, col| row > col, |row, col| row == col)
+                }
+
+                /// Returns the upper triangular factor `U`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 1.0], [2.0, 1.0]];
+                /// let lu = matrix.lu().unwrap();
+                ///
+                /// assert_eq!(lu.u(), matrix![[2.0, 1.0], [0.0, 0.5]]);
+                /// ```
+                pub fn u(&self) -> Matrix<$t> {
+                    self.factors.triangle(|row, col| row <= col, |_, _| false)
+                }
+
+                /// Returns the row permutation `P`, such that row `i` of
+                /// `P * A` is row `permutation[i]` of `A`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 1.0], [2.0, 1.0]];
+                /// let lu = matrix.lu().unwrap();
+                ///
+                /// assert_eq!(lu.permutation(), &[1, 0]);
+                /// ```
+                pub fn permutation(&self) -> &[usize] {
+                    &self.factors.permutation
+                }
+
+                /// Returns the determinant of the decomposed matrix.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0], [3.0, 4.0]];
+                /// let lu = matrix.lu().unwrap();
+                ///
+                /// assert_eq!(lu.det(), -2.0);
+                /// ```
+                pub fn det(&self) -> $t {
+                    self.factors.determinant()
+                }
+
+                /// Solves `A * X = B` for `X`, where `A` is the decomposed
+                /// matrix and each column of `B` is a right-hand side.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotConformable`] if the number of rows in `rhs`
+                ///   differs from the size of the decomposed matrix.
+                /// - [`Error::Singular`] if the decomposed matrix is singular.
+                ///
+                /// # Notes
+                ///
+                /// The resulting matrix will always have the same order as `rhs`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Error, Matrix, Shape};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[2.0, 1.0], [1.0, 1.0]];
+                /// let lu = matrix.lu().unwrap();
+                ///
+                /// let rhs = matrix![[3.0, 1.0], [2.0, 0.0]];
+                /// assert_eq!(lu.solve(&rhs), Ok(matrix![[1.0, 1.0], [1.0, -1.0]]));
+                ///
+                /// let rhs = matrix![[3.0, 2.0]];
+                /// let error = Error::NotConformable {
+                ///     lhs: Shape::new(2, 2),
+                ///     rhs: Shape::new(1, 2),
+                /// };
+                /// assert_eq!(lu.solve(&rhs), Err(error));
+                /// ```
+                ///
+                /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+                /// [`Error::Singular`]: crate::error::Error::Singular
+                pub fn solve(&self, rhs: &Matrix<$t>) -> Result<Matrix<$t>> {
+                    self.factors.solve(rhs)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+impl_linalg! {f32 f64}
+
+impl<T> Matrix<T> {
+    /// Returns the trace of the matrix, i.e., the sum of its diagonal
+    /// elements.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotSquare`] if the matrix is not square.
+    ///
+    /// # Notes
+    ///
+    /// The trace of an empty matrix is `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape};
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+    /// assert_eq!(matrix.trace(), Ok(12));
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let error = Error::NotSquare(Shape::new(2, 3));
+    /// assert_eq!(matrix.trace(), Err(error));
+    /// ```
+    ///
+    /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+    pub fn trace(&self) -> Result<T>
+    where
+        T: Add<Output = T> + Clone + Default,
+    {
+        if self.nrows() != self.ncols() {
+            return Err(Error::not_square(self.shape()));
+        }
+        let stride = self.major_stride() + 1;
+        Ok(self
+            .data
+            .iter()
+            .step_by(stride)
+            .fold(T::default(), |acc, element| acc + element.clone()))
+    }
+
+    /// Returns the sum of the elements on the anti-diagonal, i.e., from
+    /// the top-right to the bottom-left corner.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotSquare`] if the matrix is not square.
+    ///
+    /// # Notes
+    ///
+    /// The anti-trace of an empty matrix is `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape};
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+    /// assert_eq!(matrix.anti_trace(), Ok(12));
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let error = Error::NotSquare(Shape::new(2, 3));
+    /// assert_eq!(matrix.anti_trace(), Err(error));
+    /// ```
+    ///
+    /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+    pub fn anti_trace(&self) -> Result<T>
+    where
+        T: Add<Output = T> + Clone + Default,
+    {
+        Ok(self
+            .iter_anti_diagonal()?
+            .fold(T::default(), |acc, element| acc + element.clone()))
+    }
+
+    /// Returns the elements on the anti-diagonal, from the top-right to
+    /// the bottom-left corner.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotSquare`] if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+    /// assert_eq!(matrix.anti_diagonal(), Ok(vec![2, 4, 6]));
+    /// ```
+    ///
+    /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+    pub fn anti_diagonal(&self) -> Result<Vec<T>>
+    where
+        T: Clone,
+    {
+        let mut output: Vec<T> = self.iter_anti_diagonal()?.cloned().collect();
+        if self.order == Order::ColMajor {
+            output.reverse();
+        }
+        Ok(output)
+    }
+
+    /// Returns an iterator over the anti-diagonal in storage order, which
+    /// is from the top-right corner for row-major matrices and from the
+    /// bottom-left corner for column-major ones.
+    fn iter_anti_diagonal(&self) -> Result<impl Iterator<Item = &T>> {
+        let size = self.nrows();
+        if size != self.ncols() {
+            return Err(Error::not_square(self.shape()));
+        }
+        let step = size.saturating_sub(1);
+        Ok(self.data.iter().skip(step).step_by(step.max(1)).take(size))
+    }
+
+    /// Evaluates the matrix polynomial
+    /// `c[0] * I + c[1] * A + c[2] * A^2 + ...` at `A = self`, where `c`
+    /// is `coefficients`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotSquare`] if the matrix is not square.
+    ///
+    /// # Notes
+    ///
+    /// Horner's scheme is used, so a polynomial of degree `n` costs `n`
+    /// matrix multiplications and only one temporary matrix. Empty
+    /// `coefficients` yield the zero matrix, with `T::default()` taken as
+    /// zero. The resulting matrix will always have the same order as
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[1, 1], [0, 1]];
+    ///
+    /// // 2 * I - 3 * A + A^2
+    /// let output = matrix.polyval(&[2, -3, 1]);
+    /// assert_eq!(output, Ok(matrix![[0, -1], [0, 0]]));
+    /// ```
+    ///
+    /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+    pub fn polyval(&self, coefficients: &[T]) -> Result<Self>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Clone + Default,
+    {
+        let size = self.nrows();
+        if size != self.ncols() {
+            return Err(Error::not_square(self.shape()));
+        }
+        let stride = self.major_stride() + 1;
+        let mut output = Self {
+            order: self.order,
+            shape: self.shape,
+            data: vec![T::default(); self.size()],
+        };
+        let Some((last, rest)) = coefficients.split_last() else {
+            return Ok(output);
+        };
+        output
+            .data
+            .iter_mut()
+            .step_by(stride)
+            .for_each(|element| *element = last.clone());
+
+        let mut temp = output.clone();
+        for coefficient in rest.iter().rev() {
+            for row in 0..size {
+                for col in 0..size {
+                    temp[(row, col)] = (0..size).fold(T::default(), |acc, n| {
+                        acc + output[(row, n)].clone() * self[(n, col)].clone()
+                    });
+                }
+            }
+            core::mem::swap(&mut output, &mut temp);
+            output
+                .data
+                .iter_mut()
+                .step_by(stride)
+                .for_each(|element| *element = element.clone() + coefficient.clone());
+        }
+        Ok(output)
+    }
+
+    /// Returns `true` if the matrix is square and equal to its transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// assert!(matrix![[1, 2], [2, 3]].is_symmetric());
+    /// assert!(!matrix![[1, 2], [0, 3]].is_symmetric());
+    /// assert!(!matrix![[1, 2, 3]].is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.nrows() == self.ncols()
+            && self.iter_elements_with_index().all(|(index, element)| {
+                index.row <= index.col || *element == self[(index.col, index.row)]
+            })
+    }
+
+    /// Returns `true` if every element off the main diagonal is zero.
+    ///
+    /// # Notes
+    ///
+    /// `T::default()` is taken as zero. Non-square matrices are accepted,
+    /// in which case the main diagonal starts at the top-left corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// assert!(matrix![[1, 0], [0, 3]].is_diagonal());
+    /// assert!(matrix![[1, 0, 0], [0, 3, 0]].is_diagonal());
+    /// assert!(!matrix![[1, 2], [0, 3]].is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool
+    where
+        T: PartialEq + Default,
+    {
+        self.is_zero_where(|row, col| row != col)
+    }
+
+    /// Returns `true` if every element below the main diagonal is zero.
+    ///
+    /// # Notes
+    ///
+    /// `T::default()` is taken as zero. Non-square matrices are accepted,
+    /// in which case the main diagonal starts at the top-left corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// assert!(matrix![[1, 2], [0, 3]].is_upper_triangular());
+    /// assert!(!matrix![[1, 2], [4, 3]].is_upper_triangular());
+    /// ```
+    pub fn is_upper_triangular(&self) -> bool
+    where
+        T: PartialEq + Default,
+    {
+        self.is_zero_where(|row, col| row > col)
+    }
+
+    /// Returns `true` if every element above the main diagonal is zero.
+    ///
+    /// # Notes
+    ///
+    /// `T::default()` is taken as zero. Non-square matrices are accepted,
+    /// in which case the main diagonal starts at the top-left corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// assert!(matrix![[1, 0], [4, 3]].is_lower_triangular());
+    /// assert!(!matrix![[1, 2], [4, 3]].is_lower_triangular());
+    /// ```
+    pub fn is_lower_triangular(&self) -> bool
+    where
+        T: PartialEq + Default,
+    {
+        self.is_zero_where(|row, col| row < col)
+    }
+
+    /// Returns a copy of the matrix with every element below the `k`-th
+    /// diagonal set to zero.
+    ///
+    /// # Notes
+    ///
+    /// The `k`-th diagonal holds the elements at `(i, i + k)`, so `k = 0`
+    /// is the main diagonal, positive `k` lies above it and negative `k`
+    /// below it. `T::default()` is taken as zero. The resulting matrix will
+    /// always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    ///
+    /// assert_eq!(matrix.triu(0), matrix![[1, 2, 3], [0, 5, 6], [0, 0, 9]]);
+    /// assert_eq!(matrix.triu(1), matrix![[0, 2, 3], [0, 0, 6], [0, 0, 0]]);
+    /// assert_eq!(matrix.triu(-1), matrix![[1, 2, 3], [4, 5, 6], [0, 8, 9]]);
+    /// ```
+    pub fn triu(&self, k: isize) -> Self
+    where
+        T: Clone + Default,
+    {
+        self.zeroed_where(|row, col| (col as isize) - (row as isize) < k)
+    }
+
+    /// Returns a copy of the matrix with every element above the `k`-th
+    /// diagonal set to zero.
+    ///
+    /// # Notes
+    ///
+    /// The diagonals are numbered as in [`Matrix::triu`]. `T::default()`
+    /// is taken as zero. The resulting matrix will always have the same
+    /// order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    ///
+    /// assert_eq!(matrix.tril(0), matrix![[1, 0, 0], [4, 5, 0], [7, 8, 9]]);
+    /// assert_eq!(matrix.tril(-1), matrix![[0, 0, 0], [4, 0, 0], [7, 8, 0]]);
+    /// assert_eq!(matrix.tril(1), matrix![[1, 2, 0], [4, 5, 6], [7, 8, 9]]);
+    /// ```
+    pub fn tril(&self, k: isize) -> Self
+    where
+        T: Clone + Default,
+    {
+        self.zeroed_where(|row, col| (col as isize) - (row as isize) > k)
+    }
+
+    fn is_zero_where<F>(&self, mut f: F) -> bool
+    where
+        T: PartialEq + Default,
+        F: FnMut(usize, usize) -> bool,
+    {
+        let zero = T::default();
+        self.iter_elements_with_index()
+            .all(|(index, element)| !f(index.row, index.col) || *element == zero)
+    }
+
+    fn zeroed_where<F>(&self, mut f: F) -> Self
+    where
+        T: Clone + Default,
+        F: FnMut(usize, usize) -> bool,
+    {
+        let mut output = self.clone();
+        for (index, element) in output.iter_elements_mut_with_index() {
+            if f(index.row, index.col) {
+                *element = T::default();
+            }
+        }
+        output
+    }
+}
+
+/// The kind of matrix norm computed by [`Matrix::norm`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NormKind {
+    /// The square root of the sum of squared absolute values.
+    Frobenius,
+
+    /// The maximum absolute column sum.
+    L1,
+
+    /// The maximum absolute row sum.
+    Infinity,
+}
+
+/// The LU decomposition of a square matrix with partial pivoting,
+/// such that `P * A = L * U`.
+///
+/// Keeping the decomposition around allows solving against the same
+/// matrix repeatedly without factorizing it again.
+///
+/// This struct is created by [`Matrix::lu`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct LuDecomposition<T> {
+    factors: LuFactors<T>,
+}
+
+#[cfg(feature = "std")]
+fn max_abs<T: Real>(matrix: &Matrix<T>) -> T {
+    matrix
+        .data
+        .iter()
+        .fold(T::ZERO, |max, x| if x.abs() > max { x.abs() } else { max })
+}
+
+#[cfg(feature = "std")]
+fn rank<T: Real>(matrix: &Matrix<T>, tolerance: T) -> usize {
+    let ncols = matrix.ncols();
+    let mut data: Vec<T> = matrix.iter_rows().flatten().copied().collect();
+    let nrows = matrix.nrows();
+    let mut rank = 0;
+    for col in 0..ncols {
+        if rank == nrows {
+            break;
+        }
+        let mut pivot = rank;
+        for row in rank + 1..nrows {
+            if data[row * ncols + col].abs() > data[pivot * ncols + col].abs() {
+                pivot = row;
+            }
+        }
+        if data[pivot * ncols + col].abs() <= tolerance {
+            continue;
+        }
+        for j in col..ncols {
+            data.swap(rank * ncols + j, pivot * ncols + j);
+        }
+        let diagonal = data[rank * ncols + col];
+        for row in rank + 1..nrows {
+            let factor = data[row * ncols + col] / diagonal;
+            for j in col..ncols {
+                data[row * ncols + j] = data[row * ncols + j] - factor * data[rank * ncols + j];
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+#[cfg(feature = "std")]
+fn rref<T: Real>(matrix: &mut Matrix<T>, tolerance: T) -> Vec<usize> {
+    let order = matrix.order;
+    matrix.set_order(Order::RowMajor);
+    let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+    let data = &mut matrix.data;
+    let mut pivots = Vec::new();
+    for col in 0..ncols {
+        let rank = pivots.len();
+        if rank == nrows {
+            break;
+        }
+        let mut pivot = rank;
+        for row in rank + 1..nrows {
+            if data[row * ncols + col].abs() > data[pivot * ncols + col].abs() {
+                pivot = row;
+            }
+        }
+        if data[pivot * ncols + col].abs() <= tolerance {
+            for row in rank..nrows {
+                data[row * ncols + col] = T::ZERO;
+            }
+            continue;
+        }
+        for j in col..ncols {
+            data.swap(rank * ncols + j, pivot * ncols + j);
+        }
+        let diagonal = data[rank * ncols + col];
+        for j in col..ncols {
+            data[rank * ncols + j] = data[rank * ncols + j] / diagonal;
+        }
+        data[rank * ncols + col] = T::ONE;
+        for row in (0..nrows).filter(|&row| row != rank) {
+            let factor = data[row * ncols + col];
+            for j in col..ncols {
+                data[row * ncols + j] = data[row * ncols + j] - factor * data[rank * ncols + j];
+            }
+            data[row * ncols + col] = T::ZERO;
+        }
+        pivots.push(col);
+    }
+    matrix.set_order(order);
+    pivots
+}
+
+#[cfg(feature = "std")]
+fn norm<T: Real>(matrix: &Matrix<T>, kind: NormKind) -> T {
+    let max_abs_sum = |axis| {
+        matrix
+            .iter_axis(axis)
+            .map(|vector| vector.fold(T::ZERO, |acc, x| acc + x.abs()))
+            .fold(T::ZERO, |max, sum| if sum > max { sum } else { max })
+    };
+    match kind {
+        NormKind::Frobenius => matrix
+            .data
+            .iter()
+            .fold(T::ZERO, |acc, &x| acc + x * x)
+            .sqrt(),
+        NormKind::L1 => max_abs_sum(Axis::Col),
+        NormKind::Infinity => max_abs_sum(Axis::Row),
+    }
+}
+
+#[cfg(feature = "std")]
+fn expm<T: Real>(matrix: &Matrix<T>) -> Result<Matrix<T>> {
+    // Degree of both the numerator and the denominator of the approximant.
+    const DEGREE: usize = 6;
+
+    let size = matrix.nrows();
+    if size != matrix.ncols() {
+        return Err(Error::not_square(matrix.shape()));
+    }
+    if matrix.data.iter().any(|x| !x.is_finite()) {
+        let mut output = matrix.clone();
+        output.data.fill(T::from_f64(f64::NAN));
+        return Ok(output);
+    }
+
+    // Scales `A` by `2^-squarings` so that its norm is at most one half.
+    let half = T::from_f64(0.5);
+    let mut factor = T::ONE;
+    let mut squarings = 0;
+    let mut scaled_norm = norm(matrix, NormKind::Infinity);
+    if !scaled_norm.is_finite() {
+        // The elements are finite, so a row sum overflows by a factor of
+        // at most `size`, which scaling by `2^-64` more than undoes.
+        factor = T::from_f64(2f64.powi(-64));
+        squarings = 64;
+        scaled_norm = matrix
+            .iter_rows()
+            .map(|row| row.fold(T::ZERO, |acc, &x| acc + (x * factor).abs()))
+            .fold(T::ZERO, |max, sum| if sum > max { sum } else { max });
+    }
+    while scaled_norm > half {
+        scaled_norm = scaled_norm * half;
+        factor = factor * half;
+        squarings += 1;
+    }
+    let scaled: Vec<T> = matrix.iter_rows().flatten().map(|&x| x * factor).collect();
+
+    let identity: Vec<T> = (0..size * size)
+        .map(|i| if i % (size + 1) == 0 { T::ONE } else { T::ZERO })
+        .collect();
+    let mut power = identity.clone();
+    let mut numerator = identity.clone();
+    let mut denominator = identity;
+    let mut coefficient = T::ONE;
+    for k in 1..=DEGREE {
+        let ratio = (DEGREE - k + 1) as f64 / ((2 * DEGREE - k + 1) * k) as f64;
+        coefficient = coefficient * T::from_f64(ratio);
+        power = square_mul(&scaled, &power, size);
+        for ((n, d), &x) in numerator.iter_mut().zip(&mut denominator).zip(&power) {
+            *n = *n + coefficient * x;
+            *d = if k % 2 == 0 {
+                *d + coefficient * x
+            } else {
+                *d - coefficient * x
+            };
+        }
+    }
+
+    let order = Order::RowMajor;
+    let shape = AxisShape::from_shape_unchecked(Shape::new(size, size), order);
+    let denominator = Matrix {
+        order,
+        shape,
+        data: denominator,
+    };
+    let numerator = Matrix {
+        order,
+        shape,
+        data: numerator,
+    };
+    let mut output = LuFactors::factorize(&denominator)?.solve(&numerator)?;
+    for _ in 0..squarings {
+        output.data = square_mul(&output.data, &output.data, size);
+    }
+    output.set_order(matrix.order);
+    Ok(output)
+}
+
+/// Multiplies two square matrices of the given size stored in row-major
+/// order.
+#[cfg(feature = "std")]
+fn square_mul<T: Real>(lhs: &[T], rhs: &[T], size: usize) -> Vec<T> {
+    let mut output = vec![T::ZERO; size * size];
+    for row in 0..size {
+        for k in 0..size {
+            let x = lhs[row * size + k];
+            for col in 0..size {
+                output[row * size + col] = output[row * size + col] + x * rhs[k * size + col];
+            }
+        }
+    }
+    output
+}
+
+/// LU decomposition with partial pivoting, such that `P * A = L * U`.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub(super) struct LuFactors<T> {
+    /// Number of rows and columns of the decomposed matrix.
+    pub(super) size: usize,
+
+    /// Both factors in row-major order, with `L` strictly below the
+    /// diagonal (its unit diagonal is implicit) and `U` on and above it.
+    pub(super) data: Vec<T>,
+
+    /// Row `i` of `P * A` is row `permutation[i]` of `A`.
+    pub(super) permutation: Vec<usize>,
+
+    /// Whether an odd number of row swaps has been performed.
+    pub(super) odd: bool,
+
+    /// Whether a pivot has vanished during elimination.
+    pub(super) singular: bool,
+}
+
+#[cfg(feature = "std")]
+impl<T: Real> LuFactors<T> {
+    pub(super) fn factorize(matrix: &Matrix<T>) -> Result<Self> {
+        Self::factorize_with_hooks(matrix, &mut |_, _| {}, None)
+    }
+
+    pub(super) fn factorize_with_hooks<P>(
+        matrix: &Matrix<T>,
+        progress: &mut P,
+        cancel: Option<&AtomicBool>,
+    ) -> Result<Self>
+    where
+        P: ProgressSink,
+    {
+        let size = matrix.nrows();
+        if size != matrix.ncols() {
+            return Err(Error::not_square(matrix.shape()));
+        }
+
+        let mut data: Vec<T> = matrix.iter_rows().flatten().copied().collect();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut odd = false;
+        let mut singular = false;
+
+        for k in 0..size {
+            if cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed)) {
+                return Err(Error::Cancelled {
+                    done: k,
+                    total: size,
+                });
+            }
+            let mut pivot = k;
+            for i in k + 1..size {
+                if data[i * size + k].abs() > data[pivot * size + k].abs() {
+                    pivot = i;
+                }
+            }
+            let candidate = data[pivot * size + k];
+            if candidate == T::ZERO || !candidate.is_finite() {
+                // Nothing can be eliminated with this column, so the
+                // multipliers are all zero.
+                for i in k + 1..size {
+                    data[i * size + k] = T::ZERO;
+                }
+                singular = true;
+                progress.report(k + 1, size);
+                continue;
+            }
+            if pivot != k {
+                for j in 0..size {
+                    data.swap(k * size + j, pivot * size + j);
+                }
+                permutation.swap(k, pivot);
+                odd = !odd;
+            }
+
+            let diagonal = data[k * size + k];
+            for i in k + 1..size {
+                let factor = data[i * size + k] / diagonal;
+                data[i * size + k] = factor;
+                for j in k + 1..size {
+                    data[i * size + j] = data[i * size + j] - factor * data[k * size + j];
+                }
+            }
+            progress.report(k + 1, size);
+        }
+
+        Ok(Self {
+            size,
+            data,
+            permutation,
+            odd,
+            singular,
+        })
+    }
+
+    pub(super) fn determinant(&self) -> T {
+        let product = (0..self.size).fold(T::ONE, |acc, k| acc * self.data[k * self.size + k]);
+        if self.odd {
+            -product
+        } else {
+            product
+        }
+    }
+
+    /// Solves `L * U * x = rhs` in place, where `rhs` is already permuted.
+    ///
+    /// The factorization must not be singular.
+    pub(super) fn solve_permuted_in_place(&self, rhs: &mut [T]) {
+        let size = self.size;
+        let data = &self.data;
+        for i in 0..size {
+            let mut value = rhs[i];
+            for k in 0..i {
+                value = value - data[i * size + k] * rhs[k];
+            }
+            rhs[i] = value;
+        }
+        for i in (0..size).rev() {
+            let mut value = rhs[i];
+            for k in i + 1..size {
+                value = value - data[i * size + k] * rhs[k];
+            }
+            rhs[i] = value / data[i * size + i];
+        }
+    }
+
+    /// Returns the elements selected by `keep`, with ones where `unit`
+    /// holds and zeros elsewhere.
+    pub(super) fn triangle<K, U>(&self, keep: K, unit: U) -> Matrix<T>
+    where
+        K: Fn(usize, usize) -> bool,
+        U: Fn(usize, usize) -> bool,
+    {
+        let size = self.size;
+        let data = (0..size * size)
+            .map(|index| {
+                let (row, col) = (index / size, index % size);
+                if keep(row, col) {
+                    self.data[index]
+                } else if unit(row, col) {
+                    T::ONE
+                } else {
+                    T::ZERO
+                }
+            })
+            .collect();
+        let order = Order::RowMajor;
+        let shape = AxisShape::from_shape_unchecked(Shape::new(size, size), order);
+        Matrix { order, shape, data }
+    }
+
+    pub(super) fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        if rhs.nrows() != self.size {
+            return Err(Error::not_conformable(
+                Shape::square(self.size),
+                rhs.shape(),
+            ));
+        }
+        if self.singular {
+            return Err(Error::Singular);
+        }
+        let ncols = rhs.ncols();
+        let mut data = Vec::with_capacity(self.size * ncols);
+        for col in 0..ncols {
+            let start = data.len();
+            data.extend(self.permutation.iter().map(|&row| rhs[(row, col)]));
+            self.solve_permuted_in_place(&mut data[start..]);
+        }
+        let shape = AxisShape::from_shape_unchecked(Shape::new(self.size, ncols), Order::ColMajor);
+        let mut output = Matrix {
+            order: Order::ColMajor,
+            shape,
+            data,
+        };
+        output.set_order(rhs.order);
+        Ok(output)
+    }
+
+    pub(super) fn inverse(&self, order: Order) -> Result<Matrix<T>> {
+        if self.singular {
+            return Err(Error::Singular);
+        }
+        let size = self.size;
+        let mut data = Vec::with_capacity(size * size);
+        for col in 0..size {
+            let start = data.len();
+            data.extend(self.permutation.iter().map(
+                |&row| {
+                    if row == col {
+                        T::ONE
+                    } else {
+                        T::ZERO
+                    }
+                },
+            ));
+            self.solve_permuted_in_place(&mut data[start..]);
+        }
+        let shape = AxisShape::from_shape_unchecked(Shape::new(size, size), Order::ColMajor);
+        let mut inverse = Matrix {
+            order: Order::ColMajor,
+            shape,
+            data,
+        };
+        inverse.set_order(order);
+        Ok(inverse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::shape::Shape;
+
+    fn assert_close(lhs: &Matrix<f64>, rhs: &Matrix<f64>) {
+        assert_eq!(lhs.shape(), rhs.shape());
+        for (index, x) in lhs.iter_elements_with_index() {
+            let y = rhs[index];
+            assert!((x - y).abs() < 1e-12, "{x} != {y} at {index}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_determinant() {
+        let mut matrix: Matrix<f64> = matrix![[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]];
+
+        for _ in 0..2 {
+            let determinant = matrix.determinant().unwrap();
+            assert!((determinant - 49.0).abs() < 1e-12);
+            matrix.switch_order();
+        }
+
+        let matrix: Matrix<f64> = matrix![[0.0, 1.0], [1.0, 0.0]];
+        assert_eq!(matrix.determinant(), Ok(-1.0));
+
+        let matrix: Matrix<f32> = matrix![[1.0, 2.0], [2.0, 4.0]];
+        assert_eq!(matrix.determinant(), Ok(0.0));
+
+        let matrix: Matrix<f64> = matrix![[1e20, 0.0], [0.0, 1.0]];
+        assert_eq!(matrix.determinant(), Ok(1e20));
+
+        let matrix: Matrix<f64> = matrix![[1e-20, 0.0], [0.0, 1e-20]];
+        assert_eq!(matrix.determinant(), Ok(1e-40));
+
+        let matrix = Matrix::<f64>::new((0, 0));
+        assert_eq!(matrix.determinant(), Ok(1.0));
+
+        let matrix = Matrix::<f64>::new((2, 3));
+        assert_eq!(
+            matrix.determinant(),
+            Err(Error::NotSquare(Shape::new(2, 3)))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_lu() {
+        let mut matrix: Matrix<f64> = matrix![[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]];
+
+        for _ in 0..2 {
+            let lu = matrix.lu().unwrap();
+            assert!((lu.det() - 49.0).abs() < 1e-12);
+
+            let mut permuted = matrix.clone();
+            permuted.set_order(Order::RowMajor);
+            let mut data = Vec::new();
+            for &row in lu.permutation() {
+                data.extend(matrix.iter_nth_row(row).unwrap().copied());
+            }
+            permuted.data = data;
+            let mut product = lu.l().checked_mul(&lu.u()).unwrap();
+            product.set_order(Order::RowMajor);
+            assert_close(&product, &permuted);
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<f64>::new((2, 3));
+        assert_eq!(matrix.lu().err(), Some(Error::NotSquare(Shape::new(2, 3))));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_lu_with_progress() {
+        let matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]];
+
+        let mut reports = Vec::new();
+        let lu = matrix
+            .lu_with_progress(|done, total| reports.push((done, total)))
+            .unwrap();
+        assert_eq!(lu.det(), 0.0);
+        assert_eq!(reports, vec![(1, 3), (2, 3), (3, 3)]);
+
+        let mut reports = Vec::new();
+        let result =
+            Matrix::<f64>::new((2, 3)).lu_with_progress(|done, total| reports.push((done, total)));
+        assert_eq!(result.err(), Some(Error::NotSquare(Shape::new(2, 3))));
+        assert!(reports.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_lu_cancellable() {
+        let matrix: Matrix<f64> = matrix![[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]];
+
+        let cancel = AtomicBool::new(false);
+        let lu = matrix.lu_cancellable(&cancel).unwrap();
+        assert!((lu.det() - 49.0).abs() < 1e-12);
+
+        cancel.store(true, Ordering::Relaxed);
+        assert!(matches!(
+            matrix.lu_cancellable(&cancel),
+            Err(Error::Cancelled { done: 0, total: 3 })
+        ));
+        assert_eq!(
+            Matrix::<f64>::new((2, 3)).lu_cancellable(&cancel).err(),
+            Some(Error::NotSquare(Shape::new(2, 3)))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_lu_solve() {
+        let matrix: Matrix<f64> = matrix![[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]];
+        let lu = matrix.lu().unwrap();
+        let mut expected = matrix![[1.0, 0.0], [2.0, -1.0], [3.0, 0.5]];
+        let mut rhs = matrix.checked_mul(&expected).unwrap();
+
+        for _ in 0..2 {
+            let output = lu.solve(&rhs).unwrap();
+            assert_eq!(output.order(), rhs.order());
+            assert_close(&output, &expected);
+
+            rhs.switch_order();
+            expected.switch_order();
+        }
+
+        assert_eq!(
+            lu.solve(&matrix![[1.0, 2.0]]),
+            Err(Error::NotConformable {
+                lhs: Shape::new(3, 3),
+                rhs: Shape::new(1, 2)
+            })
+        );
+
+        let matrix: Matrix<f64> = matrix![[1e20, 0.0], [0.0, 1.0]];
+        let lu = matrix.lu().unwrap();
+        assert_eq!(lu.det(), 1e20);
+        assert_eq!(lu.solve(&matrix![[1e20], [2.0]]), Ok(matrix![[1.0], [2.0]]));
+
+        let matrix: Matrix<f64> = matrix![[1.0, 2.0], [2.0, 4.0]];
+        let lu = matrix.lu().unwrap();
+        assert_eq!(lu.det(), 0.0);
+        assert_eq!(lu.solve(&matrix![[1.0], [2.0]]), Err(Error::Singular));
+        let mut product = lu.l().checked_mul(&lu.u()).unwrap();
+        product.set_order(Order::RowMajor);
+        assert_close(&product, &matrix![[2.0, 4.0], [1.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut matrix = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+        for _ in 0..2 {
+            assert_eq!(matrix.trace(), Ok(15));
+            matrix.switch_order();
+        }
+
+        assert_eq!(Matrix::<i32>::new((0, 0)).trace(), Ok(0));
+        assert_eq!(
+            Matrix::<i32>::new((2, 3)).trace(),
+            Err(Error::NotSquare(Shape::new(2, 3)))
+        );
+    }
+
+    #[test]
+    fn test_anti_diagonal() {
+        let mut matrix = matrix![
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+            [13, 14, 15, 16]
+        ];
+
+        for _ in 0..2 {
+            assert_eq!(matrix.anti_trace(), Ok(34));
+            assert_eq!(matrix.anti_diagonal(), Ok(vec![4, 7, 10, 13]));
+            matrix.switch_order();
+        }
+
+        let mut matrix = matrix![[7]];
+        for _ in 0..2 {
+            assert_eq!(matrix.anti_trace(), Ok(7));
+            assert_eq!(matrix.anti_diagonal(), Ok(vec![7]));
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<i32>::new((0, 0));
+        assert_eq!(matrix.anti_trace(), Ok(0));
+        assert_eq!(matrix.anti_diagonal(), Ok(vec![]));
+
+        let matrix = Matrix::<i32>::new((2, 3));
+        assert_eq!(matrix.anti_trace(), Err(Error::NotSquare(Shape::new(2, 3))));
+        assert_eq!(
+            matrix.anti_diagonal(),
+            Err(Error::NotSquare(Shape::new(2, 3)))
+        );
+    }
+
+    #[test]
+    fn test_structure_predicates() {
+        let mut symmetric = matrix![[1, 2, 3], [2, 4, 5], [3, 5, 6]];
+        let mut diagonal = matrix![[1, 0, 0], [0, 2, 0]];
+        let mut upper = matrix![[1, 2, 3], [0, 4, 5], [0, 0, 6]];
+        let mut lower = matrix![[1, 0], [2, 3], [4, 5]];
+
+        for _ in 0..2 {
+            assert!(symmetric.is_symmetric());
+            assert!(!upper.is_symmetric());
+            assert!(!diagonal.is_symmetric());
+
+            assert!(diagonal.is_diagonal());
+            assert!(diagonal.is_upper_triangular());
+            assert!(diagonal.is_lower_triangular());
+            assert!(!symmetric.is_diagonal());
+
+            assert!(upper.is_upper_triangular());
+            assert!(!upper.is_lower_triangular());
+            assert!(lower.is_lower_triangular());
+            assert!(!lower.is_upper_triangular());
+
+            symmetric.switch_order();
+            diagonal.switch_order();
+            upper.switch_order();
+            lower.switch_order();
+        }
+
+        let empty = Matrix::<i32>::new((0, 0));
+        assert!(empty.is_symmetric());
+        assert!(empty.is_diagonal());
+    }
+
+    #[test]
+    fn test_triu_tril() {
+        let mut matrix = matrix![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+        for _ in 0..2 {
+            let mut output = matrix.triu(0);
+            assert_eq!(output.order(), matrix.order());
+            assert!(output.is_upper_triangular());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1, 2, 3, 4], [0, 6, 7, 8], [0, 0, 11, 12]]);
+
+            let mut output = matrix.triu(2);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 0, 3, 4], [0, 0, 0, 8], [0, 0, 0, 0]]);
+
+            let mut output = matrix.tril(0);
+            assert!(output.is_lower_triangular());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1, 0, 0, 0], [5, 6, 0, 0], [9, 10, 11, 0]]);
+
+            let mut output = matrix.tril(-2);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 0, 0, 0], [0, 0, 0, 0], [9, 0, 0, 0]]);
+
+            let mut output = matrix.triu(-5);
+            output.set_order(matrix.order());
+            assert_eq!(output, matrix);
+            assert_eq!(matrix.tril(3), matrix);
+            assert!(matrix.triu(4).iter_elements().all(|&x| x == 0));
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_polyval() {
+        let mut matrix: Matrix<f64> = matrix![[1.0, 2.0], [3.0, 4.0]];
+
+        for _ in 0..2 {
+            // 1 - 2x + 0.5x^3
+            let output = matrix.polyval(&[1.0, -2.0, 0.0, 0.5]).unwrap();
+            assert_eq!(output.order(), matrix.order());
+            let expected = matrix![[17.5, 23.0], [34.5, 52.0]];
+            assert_close(&output, &expected);
+
+            let output = matrix.polyval(&[3.0]).unwrap();
+            assert_close(&output, &matrix![[3.0, 0.0], [0.0, 3.0]]);
+
+            let output = matrix.polyval(&[]).unwrap();
+            assert_close(&output, &matrix![[0.0, 0.0], [0.0, 0.0]]);
+
+            matrix.switch_order();
+        }
+
+        let matrix = matrix![[0, 1, 0], [0, 0, 1], [0, 0, 0]];
+        let output = matrix.polyval(&[1, 1, 1, 1, 1]);
+        assert_eq!(output, Ok(matrix![[1, 1, 1], [0, 1, 1], [0, 0, 1]]));
+
+        assert_eq!(
+            Matrix::<i32>::new((0, 0)).polyval(&[1, 2]),
+            Ok(Matrix::new((0, 0)))
+        );
+        assert_eq!(
+            Matrix::<i32>::new((2, 3)).polyval(&[1]),
+            Err(Error::NotSquare(Shape::new(2, 3)))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_rank() {
+        let mut matrix: Matrix<f64> = matrix![
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 1.0, 1.0, 0.0],
+        ];
+
+        for _ in 0..2 {
+            assert_eq!(matrix.rank(), 2);
+            matrix.switch_order();
+        }
+
+        let matrix: Matrix<f64> = matrix![[0.1, 0.2], [0.3, 0.6]];
+        assert_eq!(matrix.rank(), 1);
+
+        let matrix: Matrix<f64> = matrix![[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+        assert_eq!(matrix.rank(), 2);
+
+        assert_eq!(Matrix::<f64>::new((2, 3)).rank(), 0);
+        assert_eq!(Matrix::<f64>::new((0, 3)).rank(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_rref() {
+        let mut matrix: Matrix<f64> = matrix![
+            [0.0, 2.0, 4.0, 2.0],
+            [1.0, 1.0, 1.0, 1.0],
+            [2.0, 4.0, 6.0, 4.0],
+        ];
+        let expected: Matrix<f64> = matrix![
+            [1.0, 0.0, -1.0, 0.0],
+            [0.0, 1.0, 2.0, 1.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ];
+
+        for _ in 0..2 {
+            let order = matrix.order();
+            let mut reduced = matrix.clone();
+            assert_eq!(reduced.rref(), vec![0, 1]);
+            assert_eq!(reduced.order(), order);
+            assert_close(&reduced, &expected);
+
+            let (reduced, pivots) = matrix.clone().into_rref();
+            assert_eq!(pivots, vec![0, 1]);
+            assert_close(&reduced, &expected);
+            assert_eq!(pivots.len(), matrix.rank());
+
+            matrix.switch_order();
+        }
+
+        let mut identity: Matrix<f32> = matrix![[0.0, 1.0], [1.0, 0.0]];
+        assert_eq!(identity.rref(), vec![0, 1]);
+        assert_eq!(identity, matrix![[1.0, 0.0], [0.0, 1.0]]);
+
+        let mut zeros = Matrix::<f64>::new((2, 3));
+        assert_eq!(zeros.rref(), Vec::<usize>::new());
+        assert_eq!(zeros, Matrix::new((2, 3)));
+        assert_eq!(Matrix::<f64>::new((0, 3)).rref(), Vec::<usize>::new());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_norm() {
+        let mut matrix: Matrix<f64> = matrix![[1.0, -2.0, 0.0], [-3.0, 4.0, 5.0]];
+
+        for _ in 0..2 {
+            assert_eq!(matrix.norm(NormKind::Frobenius), 55.0_f64.sqrt());
+            assert_eq!(matrix.norm(NormKind::L1), 6.0);
+            assert_eq!(matrix.norm(NormKind::Infinity), 12.0);
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<f64>::new((0, 3));
+        assert_eq!(matrix.norm(NormKind::Frobenius), 0.0);
+        assert_eq!(matrix.norm(NormKind::L1), 0.0);
+        assert_eq!(matrix.norm(NormKind::Infinity), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_expm() {
+        let e = core::f64::consts::E;
+        let mut matrix: Matrix<f64> = matrix![[1.0, 1.0], [0.0, 2.0]];
+
+        for _ in 0..2 {
+            let mut output = matrix.expm().unwrap();
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_close(&output, &matrix![[e, e * e - e], [0.0, e * e]]);
+
+            matrix.switch_order();
+        }
+
+        let t = 10.0_f64;
+        let matrix: Matrix<f64> = matrix![[0.0, -t], [t, 0.0]];
+        let expected = matrix![[t.cos(), -t.sin()], [t.sin(), t.cos()]];
+        assert_close(&matrix.expm().unwrap(), &expected);
+
+        let matrix: Matrix<f64> = matrix![[-20.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 5.0]];
+        let output = matrix.expm().unwrap();
+        for (i, lambda) in [-20.0_f64, 0.0, 5.0].into_iter().enumerate() {
+            let exact = lambda.exp();
+            assert!((output[(i, i)] - exact).abs() <= 1e-13 * exact);
+        }
+
+        let matrix = Matrix::<f64>::new((3, 3));
+        let mut identity = Matrix::<f64>::new((3, 3));
+        identity.data.iter_mut().step_by(4).for_each(|x| *x = 1.0);
+        assert_eq!(matrix.expm(), Ok(identity));
+
+        let matrix: Matrix<f32> = matrix![[f32::NAN, 0.0], [0.0, 1.0]];
+        assert!(matrix.expm().unwrap().iter_elements().all(|x| x.is_nan()));
+
+        let matrix: Matrix<f64> = matrix![[1e308, 1e308], [0.0, 0.0]];
+        assert!(!matrix.expm().unwrap()[(0, 0)].is_finite());
+
+        let matrix: Matrix<f64> = matrix![[0.0, 1e308, 1e308], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]];
+        let expected = matrix![[1.0, 1e308, 1e308], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert_eq!(matrix.expm(), Ok(expected));
+
+        let matrix = Matrix::<f64>::new((0, 0));
+        assert_eq!(matrix.expm(), Ok(Matrix::new((0, 0))));
+
+        let matrix = Matrix::<f64>::new((2, 3));
+        assert_eq!(matrix.expm(), Err(Error::NotSquare(Shape::new(2, 3))));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_inverse() {
+        let mut matrix: Matrix<f64> = matrix![[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]];
+        let identity = matrix![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+        for _ in 0..2 {
+            let inverse = matrix.inverse().unwrap();
+            assert_eq!(inverse.order(), matrix.order());
+
+            let mut product = matrix.checked_mul(&inverse).unwrap();
+            product.set_order(Order::RowMajor);
+            assert_close(&product, &identity);
+
+            let mut product = inverse.checked_mul(&matrix).unwrap();
+            product.set_order(Order::RowMajor);
+            assert_close(&product, &identity);
+
+            matrix.switch_order();
+        }
+
+        let matrix: Matrix<f64> = matrix![[1e20, 0.0], [0.0, 1.0]];
+        assert_eq!(matrix.inverse(), Ok(matrix![[1e-20, 0.0], [0.0, 1.0]]));
+
+        let matrix: Matrix<f64> = matrix![[1e-20, 2e-20], [3e-20, 4e-20]];
+        let product = matrix.checked_mul(&matrix.inverse().unwrap()).unwrap();
+        assert_close(&product, &matrix![[1.0, 0.0], [0.0, 1.0]]);
+
+        let matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [4.0, 8.0, 12.0]];
+        assert_eq!(matrix.inverse(), Err(Error::Singular));
+
+        let matrix = Matrix::<f32>::new((2, 2));
+        assert_eq!(matrix.inverse(), Err(Error::Singular));
+
+        let matrix = Matrix::<f64>::new((0, 0));
+        assert_eq!(matrix.inverse(), Ok(Matrix::new((0, 0))));
+
+        let matrix = Matrix::<f64>::new((3, 2));
+        assert_eq!(matrix.inverse(), Err(Error::NotSquare(Shape::new(3, 2))));
+    }
+}
@@ -0,0 +1,657 @@
+use super::axis::Axis;
+use super::Matrix;
+use crate::error::Result;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+impl Matrix<bool> {
+    /// Returns whether any element is `true` along the given axis.
+    ///
+    /// # Notes
+    ///
+    /// Reducing along [`Axis::Row`] tests each row and yields an `m x 1`
+    /// matrix, while reducing along [`Axis::Col`] tests each column and
+    /// yields a `1 x n` matrix. Empty vectors yield `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[false, true, false], [false, false, false]];
+    ///
+    /// assert_eq!(matrix.any(Axis::Row), matrix![[true], [false]]);
+    /// assert_eq!(matrix.any(Axis::Col), matrix![[false, true, false]]);
+    /// ```
+    pub fn any(&self, axis: Axis) -> Matrix<bool> {
+        self.map_axis(axis, |mut vector| vector.any(|element| *element))
+    }
+
+    /// Returns whether all elements are `true` along the given axis.
+    ///
+    /// # Notes
+    ///
+    /// Reducing along [`Axis::Row`] tests each row and yields an `m x 1`
+    /// matrix, while reducing along [`Axis::Col`] tests each column and
+    /// yields a `1 x n` matrix. Empty vectors yield `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[true, true, false], [true, true, true]];
+    ///
+    /// assert_eq!(matrix.all(Axis::Row), matrix![[false], [true]]);
+    /// assert_eq!(matrix.all(Axis::Col), matrix![[true, true, false]]);
+    /// ```
+    pub fn all(&self, axis: Axis) -> Matrix<bool> {
+        self.map_axis(axis, |mut vector| vector.all(|element| *element))
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Compares two matrices elementwise, returning whether each element
+    /// of `self` is equal to the corresponding element of `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 2, 2], [2, 4, 6]];
+    ///
+    /// let output = lhs.eq_elementwise(&rhs);
+    /// assert_eq!(output, Ok(matrix![[true, false, true], [false, true, false]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn eq_elementwise<R>(&self, rhs: &Matrix<R>) -> Result<Matrix<bool>>
+    where
+        T: PartialEq<R>,
+    {
+        self.elementwise_operation(rhs, |(left, right)| left == right)
+    }
+
+    /// Compares two matrices elementwise, returning whether each element
+    /// of `self` is less than the corresponding element of `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 2, 2], [2, 4, 6]];
+    ///
+    /// let output = lhs.lt_elementwise(&rhs);
+    /// assert_eq!(output, Ok(matrix![[false, true, false], [false, false, true]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn lt_elementwise<R>(&self, rhs: &Matrix<R>) -> Result<Matrix<bool>>
+    where
+        T: PartialOrd<R>,
+    {
+        self.elementwise_operation(rhs, |(left, right)| left < right)
+    }
+
+    /// Compares two matrices elementwise, returning whether each element
+    /// of `self` is greater than the corresponding element of `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 2, 2], [2, 4, 6]];
+    ///
+    /// let output = lhs.gt_elementwise(&rhs);
+    /// assert_eq!(output, Ok(matrix![[false, false, false], [true, false, false]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn gt_elementwise<R>(&self, rhs: &Matrix<R>) -> Result<Matrix<bool>>
+    where
+        T: PartialOrd<R>,
+    {
+        self.elementwise_operation(rhs, |(left, right)| left > right)
+    }
+
+    /// Compares two matrices elementwise, returning whether each element
+    /// of `self` is less than or equal to the corresponding element of `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 2, 2], [2, 4, 6]];
+    ///
+    /// let output = lhs.le_elementwise(&rhs);
+    /// assert_eq!(output, Ok(matrix![[true, true, true], [false, true, true]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn le_elementwise<R>(&self, rhs: &Matrix<R>) -> Result<Matrix<bool>>
+    where
+        T: PartialOrd<R>,
+    {
+        self.elementwise_operation(rhs, |(left, right)| left <= right)
+    }
+
+    /// Compares two matrices elementwise, returning whether each element
+    /// of `self` is greater than or equal to the corresponding element of `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 2, 2], [2, 4, 6]];
+    ///
+    /// let output = lhs.ge_elementwise(&rhs);
+    /// assert_eq!(output, Ok(matrix![[true, false, true], [true, true, false]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn ge_elementwise<R>(&self, rhs: &Matrix<R>) -> Result<Matrix<bool>>
+    where
+        T: PartialOrd<R>,
+    {
+        self.elementwise_operation(rhs, |(left, right)| left >= right)
+    }
+
+    /// Returns `true` if any element satisfies `predicate`.
+    ///
+    /// # Notes
+    ///
+    /// Elements are checked in memory order, stopping at the first one
+    /// that satisfies `predicate`. An empty matrix yields `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// assert!(matrix.any_element(|&x| x > 4));
+    /// assert!(!matrix.any_element(|&x| x > 5));
+    /// ```
+    pub fn any_element<F>(&self, predicate: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.data.iter().any(predicate)
+    }
+
+    /// Returns `true` if all elements satisfy `predicate`.
+    ///
+    /// # Notes
+    ///
+    /// Elements are checked in memory order, stopping at the first one
+    /// that does not satisfy `predicate`. An empty matrix yields `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// assert!(matrix.all_elements(|&x| x >= 0));
+    /// assert!(!matrix.all_elements(|&x| x > 0));
+    /// ```
+    pub fn all_elements<F>(&self, predicate: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.data.iter().all(predicate)
+    }
+
+    /// Returns the number of elements satisfying `predicate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// assert_eq!(matrix.count_where(|&x| x % 2 == 0), 3);
+    /// ```
+    pub fn count_where<F>(&self, mut predicate: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.data
+            .iter()
+            .filter(|element| predicate(element))
+            .count()
+    }
+
+    /// Sets the elements at positions where `mask` is `true` to `value`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if `mask` is not conformable with `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let mask = matrix.gt_elementwise(&matrix![[2, 2, 2], [2, 2, 2]]).unwrap();
+    ///
+    /// matrix.set_where(&mask, 0).unwrap();
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [0, 0, 0]]);
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn set_where(&mut self, mask: &Matrix<bool>, value: T) -> Result<&mut Self>
+    where
+        T: Clone,
+    {
+        self.elementwise_operation_assign(mask, |(element, &selected)| {
+            if selected {
+                *element = value.clone();
+            }
+        })
+    }
+
+    /// Sets the elements at positions where `mask` is `true` to the
+    /// corresponding elements of `other`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if `mask` or `other` is not conformable
+    ///   with `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let other = matrix![[5, 4, 3], [2, 1, 0]];
+    /// let mask = matrix.lt_elementwise(&other).unwrap();
+    ///
+    /// matrix.zip_set_where(&mask, &other).unwrap();
+    /// assert_eq!(matrix, matrix![[5, 4, 3], [3, 4, 5]]);
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn zip_set_where(&mut self, mask: &Matrix<bool>, other: &Matrix<T>) -> Result<&mut Self>
+    where
+        T: Clone,
+    {
+        self.ensure_elementwise_operation_conformable(mask)?;
+        self.ensure_elementwise_operation_conformable(other)?;
+        for (index, element) in self.iter_elements_mut_with_index() {
+            if mask[index] {
+                *element = other[index].clone();
+            }
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> Matrix<T>
+where
+    T: Sync,
+{
+    /// Returns `true` if any element satisfies `predicate`, checking
+    /// elements in parallel.
+    ///
+    /// # Notes
+    ///
+    /// Like [`Matrix::any_element`], this stops early once a match is
+    /// found, though elements may be checked in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// assert!(matrix.par_any_element(|&x| x > 4));
+    /// assert!(!matrix.par_any_element(|&x| x > 5));
+    /// ```
+    pub fn par_any_element<F>(&self, predicate: F) -> bool
+    where
+        F: Fn(&T) -> bool + Sync + Send,
+    {
+        self.data.par_iter().any(predicate)
+    }
+
+    /// Returns `true` if all elements satisfy `predicate`, checking
+    /// elements in parallel.
+    ///
+    /// # Notes
+    ///
+    /// Like [`Matrix::all_elements`], this stops early once a mismatch is
+    /// found, though elements may be checked in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// assert!(matrix.par_all_elements(|&x| x >= 0));
+    /// assert!(!matrix.par_all_elements(|&x| x > 0));
+    /// ```
+    pub fn par_all_elements<F>(&self, predicate: F) -> bool
+    where
+        F: Fn(&T) -> bool + Sync + Send,
+    {
+        self.data.par_iter().all(predicate)
+    }
+
+    /// Returns the number of elements satisfying `predicate`, checking
+    /// elements in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// assert_eq!(matrix.par_count_where(|&x| x % 2 == 0), 3);
+    /// ```
+    pub fn par_count_where<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool + Sync + Send,
+    {
+        self.data
+            .par_iter()
+            .filter(|element| predicate(element))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::matrix;
+    use crate::matrix::shape::Shape;
+
+    #[test]
+    fn test_any() {
+        let mut matrix = matrix![[false, true, false], [false, false, false]];
+
+        assert_eq!(matrix.any(Axis::Row), matrix![[true], [false]]);
+        assert_eq!(matrix.any(Axis::Col), matrix![[false, true, false]]);
+
+        matrix.switch_order();
+
+        let mut output = matrix.any(Axis::Row);
+        output.switch_order();
+        assert_eq!(output, matrix![[true], [false]]);
+        let mut output = matrix.any(Axis::Col);
+        output.switch_order();
+        assert_eq!(output, matrix![[false, true, false]]);
+
+        let matrix = Matrix::<bool>::new((2, 0));
+        assert_eq!(matrix.any(Axis::Row), matrix![[false], [false]]);
+        assert_eq!(matrix.any(Axis::Col), Matrix::new((1, 0)));
+    }
+
+    #[test]
+    fn test_all() {
+        let mut matrix = matrix![[true, true, false], [true, true, true]];
+
+        assert_eq!(matrix.all(Axis::Row), matrix![[false], [true]]);
+        assert_eq!(matrix.all(Axis::Col), matrix![[true, true, false]]);
+
+        matrix.switch_order();
+
+        let mut output = matrix.all(Axis::Row);
+        output.switch_order();
+        assert_eq!(output, matrix![[false], [true]]);
+        let mut output = matrix.all(Axis::Col);
+        output.switch_order();
+        assert_eq!(output, matrix![[true, true, false]]);
+
+        let matrix = Matrix::<bool>::new((2, 0));
+        assert_eq!(matrix.all(Axis::Row), matrix![[true], [true]]);
+        assert_eq!(matrix.all(Axis::Col), Matrix::new((1, 0)));
+    }
+
+    #[test]
+    fn test_comparison() {
+        let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+        let mut rhs = matrix![[0, 2, 2], [2, 4, 6]];
+
+        for _ in 0..2 {
+            let mut output = lhs.eq_elementwise(&rhs).unwrap();
+            assert_eq!(output.order(), lhs.order());
+            assert_eq!(output, matrix![[true, false, true], [false, true, false]]);
+            output = lhs.lt_elementwise(&rhs).unwrap();
+            assert_eq!(output, matrix![[false, true, false], [false, false, true]]);
+            output = lhs.gt_elementwise(&rhs).unwrap();
+            assert_eq!(output, matrix![[false, false, false], [true, false, false]]);
+            output = lhs.le_elementwise(&rhs).unwrap();
+            assert_eq!(output, matrix![[true, true, true], [false, true, true]]);
+            output = lhs.ge_elementwise(&rhs).unwrap();
+            assert_eq!(output, matrix![[true, false, true], [true, true, false]]);
+
+            rhs.switch_order();
+        }
+
+        let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+        assert_eq!(
+            lhs.eq_elementwise(&rhs),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 2)
+            })
+        );
+        assert_eq!(
+            lhs.lt_elementwise(&rhs),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 2)
+            })
+        );
+        assert_eq!(
+            lhs.ge_elementwise(&rhs),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 2)
+            })
+        );
+
+        let nan: Matrix<f64> = matrix![[f64::NAN]];
+        assert_eq!(nan.eq_elementwise(&nan), Ok(matrix![[false]]));
+        assert_eq!(nan.le_elementwise(&nan), Ok(matrix![[false]]));
+
+        // The `PartialOrd` methods are not shadowed by inherent ones.
+        let lhs = matrix![[0, 1]];
+        let rhs = matrix![[0, 2]];
+        assert!(lhs.lt(&rhs));
+        assert!(lhs.le(&rhs));
+        assert!(!lhs.gt(&rhs));
+        assert!(!lhs.ge(&rhs));
+    }
+
+    #[test]
+    fn test_predicates() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            assert!(matrix.any_element(|&x| x == 5));
+            assert!(!matrix.any_element(|&x| x < 0));
+            assert!(matrix.all_elements(|&x| x < 6));
+            assert!(!matrix.all_elements(|&x| x < 5));
+            assert_eq!(matrix.count_where(|&x| x > 1), 4);
+            assert_eq!(matrix.count_where(|&x| x > 5), 0);
+
+            matrix.switch_order();
+        }
+
+        let mut checked = 0;
+        assert!(matrix.any_element(|&x| {
+            checked += 1;
+            x == 0
+        }));
+        assert_eq!(checked, 1);
+        let mut checked = 0;
+        assert!(!matrix.all_elements(|&x| {
+            checked += 1;
+            x != 0
+        }));
+        assert_eq!(checked, 1);
+
+        let matrix = Matrix::<i32>::new((2, 0));
+        assert!(!matrix.any_element(|_| true));
+        assert!(matrix.all_elements(|_| false));
+        assert_eq!(matrix.count_where(|_| true), 0);
+    }
+
+    #[test]
+    fn test_set_where() {
+        let mut mask = matrix![[true, false, true], [false, false, true]];
+
+        for _ in 0..2 {
+            let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+            matrix.set_where(&mask, -1).unwrap();
+            assert_eq!(matrix, matrix![[-1, 1, -1], [3, 4, -1]]);
+
+            matrix.switch_order();
+            matrix.set_where(&mask, 9).unwrap();
+            matrix.switch_order();
+            assert_eq!(matrix, matrix![[9, 1, 9], [3, 4, 9]]);
+
+            mask.switch_order();
+        }
+
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        let mask = matrix![[true, false], [false, true], [true, true]];
+        assert_eq!(
+            matrix.set_where(&mask, 0),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 2)
+            })
+        );
+        assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    }
+
+    #[test]
+    fn test_zip_set_where() {
+        let mut mask = matrix![[true, false, true], [false, false, true]];
+        let mut other = matrix![[6, 7, 8], [9, 10, 11]];
+
+        for _ in 0..2 {
+            for _ in 0..2 {
+                let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+                matrix.zip_set_where(&mask, &other).unwrap();
+                assert_eq!(matrix, matrix![[6, 1, 8], [3, 4, 11]]);
+
+                matrix.switch_order();
+                matrix
+                    .zip_set_where(&mask, &matrix![[0, 0, 0], [0, 0, 0]])
+                    .unwrap();
+                matrix.switch_order();
+                assert_eq!(matrix, matrix![[0, 1, 0], [3, 4, 0]]);
+
+                other.switch_order();
+            }
+            mask.switch_order();
+        }
+
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        let mask = matrix![[true, false, true], [false, false, true]];
+        let other = matrix![[0, 1], [2, 3], [4, 5]];
+        assert_eq!(
+            matrix.zip_set_where(&mask, &other),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 2)
+            })
+        );
+        let mask = matrix![[true, false], [false, true], [true, true]];
+        let other = matrix![[0, 1, 2], [3, 4, 5]];
+        assert_eq!(
+            matrix.zip_set_where(&mask, &other),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 2)
+            })
+        );
+        assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_predicates() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            assert!(matrix.par_any_element(|&x| x == 5));
+            assert!(!matrix.par_any_element(|&x| x < 0));
+            assert!(matrix.par_all_elements(|&x| x < 6));
+            assert!(!matrix.par_all_elements(|&x| x < 5));
+            assert_eq!(matrix.par_count_where(|&x| x > 1), 4);
+            assert_eq!(matrix.par_count_where(|&x| x > 5), 0);
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<i32>::new((2, 0));
+        assert!(!matrix.par_any_element(|_| true));
+        assert!(matrix.par_all_elements(|_| false));
+        assert_eq!(matrix.par_count_where(|_| true), 0);
+    }
+}
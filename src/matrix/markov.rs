@@ -0,0 +1,222 @@
+//! This module provides utilities for Markov chain transition matrices.
+
+use super::real::Real;
+use super::Matrix;
+use crate::error::{Error, Result};
+
+/// How [`Matrix::to_row_stochastic`] treats rows summing to zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ZeroRowPolicy {
+    /// Leave the row as zeros.
+    #[default]
+    Keep,
+
+    /// Replace the row with the uniform distribution.
+    Uniform,
+}
+
+macro_rules! impl_markov {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                /// Returns a copy of the matrix with each row divided by its
+                /// sum, so that every row sums to one.
+                ///
+                /// Rows summing to zero are handled according to `policy`.
+                ///
+                /// # Notes
+                ///
+                /// Elements are not checked for negativity. The resulting
+                /// matrix will always have the same order as `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix, ZeroRowPolicy};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 3.0], [0.0, 0.0]];
+                ///
+                /// let output = matrix.to_row_stochastic(ZeroRowPolicy::Keep);
+                /// assert_eq!(output, matrix![[0.25, 0.75], [0.0, 0.0]]);
+                ///
+                /// let output = matrix.to_row_stochastic(ZeroRowPolicy::Uniform);
+                /// assert_eq!(output, matrix![[0.25, 0.75], [0.5, 0.5]]);
+                /// ```
+                pub fn to_row_stochastic(&self, policy: ZeroRowPolicy) -> Self {
+                    to_row_stochastic(self, policy)
+                }
+
+                /// Returns the stationary distribution `pi` of the Markov
+                /// chain with transition matrix `self`, i.e., the `1 x n`
+                /// probability vector satisfying `pi * P = pi`.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotSquare`] if the matrix is not square.
+                ///
+                /// # Notes
+                ///
+                /// The distribution is found by power iteration on the
+                /// transpose, starting from the uniform distribution. Each
+                /// step applies the lazy chain `(I + P) / 2`, which shares
+                /// the stationary distribution of `P` but also converges
+                /// for periodic chains. Iteration stops once the L1
+                /// distance between successive estimates is at most `tol`,
+                /// or after `max_iters` steps, in which case the latest
+                /// estimate is returned.
+                ///
+                /// The matrix is expected to be row stochastic. For
+                /// reducible chains, the result depends on the starting
+                /// distribution. The resulting matrix will always have the
+                /// same order as `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[0.9, 0.1], [0.5, 0.5]];
+                /// let pi = matrix.stationary_distribution(1e-12, 1000).unwrap();
+                ///
+                /// assert!((pi[(0, 0)] - 5.0 / 6.0).abs() < 1e-9);
+                /// assert!((pi[(0, 1)] - 1.0 / 6.0).abs() < 1e-9);
+                /// ```
+                ///
+                /// [`Error::NotSquare`]: crate::error::Error::NotSquare
+                pub fn stationary_distribution(&self, tol: $t, max_iters: usize) -> Result<Self> {
+                    stationary_distribution(self, tol, max_iters)
+                }
+            }
+        )*
+    };
+}
+
+impl_markov! {f32 f64}
+
+fn to_row_stochastic<T>(matrix: &Matrix<T>, policy: ZeroRowPolicy) -> Matrix<T>
+where
+    T: Real,
+{
+    let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+    let mut output = matrix.clone();
+    for row in 0..nrows {
+        let sum = (0..ncols).fold(T::ZERO, |acc, col| acc + matrix[(row, col)]);
+        if sum != T::ZERO {
+            for col in 0..ncols {
+                output[(row, col)] = matrix[(row, col)] / sum;
+            }
+        } else if policy == ZeroRowPolicy::Uniform {
+            let uniform = T::ONE / T::from_f64(ncols as f64);
+            for col in 0..ncols {
+                output[(row, col)] = uniform;
+            }
+        }
+    }
+    output
+}
+
+fn stationary_distribution<T>(matrix: &Matrix<T>, tol: T, max_iters: usize) -> Result<Matrix<T>>
+where
+    T: Real,
+{
+    let size = matrix.nrows();
+    if size != matrix.ncols() {
+        return Err(Error::not_square(matrix.shape()));
+    }
+    let half = T::from_f64(0.5);
+    let mut pi = vec![T::ONE / T::from_f64(size as f64); size];
+    let mut next = vec![T::ZERO; size];
+    for _ in 0..max_iters {
+        for (col, element) in next.iter_mut().enumerate() {
+            let product = (0..size).fold(T::ZERO, |acc, row| acc + pi[row] * matrix[(row, col)]);
+            *element = half * (pi[col] + product);
+        }
+        let sum = next.iter().fold(T::ZERO, |acc, &x| acc + x);
+        if sum != T::ZERO {
+            next.iter_mut().for_each(|x| *x = *x / sum);
+        }
+        let distance = pi
+            .iter()
+            .zip(&next)
+            .fold(T::ZERO, |acc, (&x, &y)| acc + (x - y).abs());
+        core::mem::swap(&mut pi, &mut next);
+        if distance <= tol {
+            break;
+        }
+    }
+
+    let mut output = Matrix::build((1, size))?;
+    for (col, probability) in pi.into_iter().enumerate() {
+        output[(0, col)] = probability;
+    }
+    output.set_order(matrix.order);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::shape::Shape;
+
+    #[test]
+    fn test_to_row_stochastic() {
+        let mut matrix: Matrix<f64> = matrix![[1.0, 1.0, 2.0], [0.0, 0.0, 0.0], [0.0, 5.0, 0.0]];
+
+        for _ in 0..2 {
+            let mut output = matrix.to_row_stochastic(ZeroRowPolicy::Keep);
+            assert_eq!(output.order(), matrix.order());
+            let mut expected = matrix![[0.25, 0.25, 0.5], [0.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+            expected.set_order(matrix.order());
+            assert_eq!(output, expected);
+
+            output = matrix.to_row_stochastic(ZeroRowPolicy::Uniform);
+            let third = 1.0 / 3.0;
+            expected[(1, 0)] = third;
+            expected[(1, 1)] = third;
+            expected[(1, 2)] = third;
+            assert_eq!(output, expected);
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<f32>::new((2, 0));
+        let output = matrix.to_row_stochastic(ZeroRowPolicy::Uniform);
+        assert_eq!(output, Matrix::new((2, 0)));
+    }
+
+    #[test]
+    fn test_stationary_distribution() {
+        let mut matrix: Matrix<f64> = matrix![[0.5, 0.5, 0.0], [0.25, 0.5, 0.25], [0.0, 0.5, 0.5]];
+
+        for _ in 0..2 {
+            let pi = matrix.stationary_distribution(1e-12, 10000).unwrap();
+            assert_eq!(pi.order(), matrix.order());
+            assert_eq!(pi.shape().nrows, 1);
+            assert_eq!(pi.shape().ncols, 3);
+            for (col, expected) in [0.25, 0.5, 0.25].into_iter().enumerate() {
+                assert!((pi[(0, col)] - expected).abs() < 1e-9);
+            }
+
+            matrix.switch_order();
+        }
+
+        // periodic chain, on which plain power iteration oscillates
+        let matrix: Matrix<f64> = matrix![[0.0, 1.0], [1.0, 0.0]];
+        let pi = matrix.stationary_distribution(1e-12, 1000).unwrap();
+        assert!((pi[(0, 0)] - 0.5).abs() < 1e-9);
+        assert!((pi[(0, 1)] - 0.5).abs() < 1e-9);
+
+        let matrix: Matrix<f32> = matrix![[0.9, 0.1], [0.5, 0.5]];
+        let pi = matrix.stationary_distribution(0.0, 0).unwrap();
+        assert_eq!(pi, matrix![[0.5, 0.5]]);
+
+        let matrix = Matrix::<f64>::new((0, 0));
+        let pi = matrix.stationary_distribution(1e-12, 10).unwrap();
+        assert_eq!(pi, Matrix::new((1, 0)));
+
+        let matrix = Matrix::<f64>::new((2, 3));
+        let result = matrix.stationary_distribution(1e-12, 10);
+        assert_eq!(result, Err(Error::NotSquare(Shape::new(2, 3))));
+    }
+}
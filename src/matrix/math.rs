@@ -0,0 +1,270 @@
+use super::Matrix;
+
+impl<T> Matrix<T> {
+    /// Restricts every element of the matrix to the interval `[min, max]`,
+    /// consuming `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`, or if they are incomparable.
+    ///
+    /// # Notes
+    ///
+    /// Elements incomparable to the bounds, such as NaN, are left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[-3, 0, 3], [6, 9, 12]];
+    /// assert_eq!(matrix.clamp(0, 9), matrix![[0, 0, 3], [6, 9, 9]]);
+    /// ```
+    pub fn clamp(mut self, min: T, max: T) -> Self
+    where
+        T: PartialOrd + Clone,
+    {
+        assert!(min <= max, "`min` must not be greater than `max`");
+        for element in self.data.iter_mut() {
+            if *element < min {
+                *element = min.clone();
+            } else if *element > max {
+                *element = max.clone();
+            }
+        }
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_math {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                /// Takes the absolute value of each element, consuming `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[-1.5, 0.0], [2.0, -0.5]];
+                /// assert_eq!(matrix.abs(), matrix![[1.5, 0.0], [2.0, 0.5]]);
+                /// ```
+                pub fn abs(self) -> Self {
+                    self.map_elements(<$t>::abs)
+                }
+
+                /// Rounds each element to the nearest integer, rounding half-way
+                /// cases away from zero, consuming `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[-1.5, 0.4], [2.5, -0.6]];
+                /// assert_eq!(matrix.round(), matrix![[-2.0, 0.0], [3.0, -1.0]]);
+                /// ```
+                pub fn round(self) -> Self {
+                    self.map_elements(<$t>::round)
+                }
+
+                /// Rounds each element down to the nearest integer, consuming `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[-1.5, 0.4], [2.5, -0.6]];
+                /// assert_eq!(matrix.floor(), matrix![[-2.0, 0.0], [2.0, -1.0]]);
+                /// ```
+                pub fn floor(self) -> Self {
+                    self.map_elements(<$t>::floor)
+                }
+
+                /// Rounds each element up to the nearest integer, consuming `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[-1.5, 0.4], [2.5, -0.6]];
+                /// assert_eq!(matrix.ceil(), matrix![[-1.0, 1.0], [3.0, -0.0]]);
+                /// ```
+                pub fn ceil(self) -> Self {
+                    self.map_elements(<$t>::ceil)
+                }
+
+                /// Raises each element to the integer power `n`, consuming `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, -2.0], [3.0, 0.5]];
+                /// assert_eq!(matrix.powi(2), matrix![[1.0, 4.0], [9.0, 0.25]]);
+                /// ```
+                pub fn powi(self, n: i32) -> Self {
+                    self.map_elements(|x| x.powi(n))
+                }
+
+                /// Takes the square root of each element, consuming `self`.
+                ///
+                /// # Notes
+                ///
+                /// Negative elements yield NaN.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[0.0, -1.0], [4.0, -0.25]];
+                /// assert_eq!(matrix.abs().sqrt(), matrix![[0.0, 1.0], [2.0, 0.5]]);
+                /// ```
+                pub fn sqrt(self) -> Self {
+                    self.map_elements(<$t>::sqrt)
+                }
+
+                /// Raises `e` to the power of each element, consuming `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[0.0, 1.0]];
+                /// assert_eq!(matrix.exp(), matrix![[1.0, std::f64::consts::E]]);
+                /// ```
+                pub fn exp(self) -> Self {
+                    self.map_elements(<$t>::exp)
+                }
+
+                /// Takes the natural logarithm of each element, consuming `self`.
+                ///
+                /// # Notes
+                ///
+                /// Zero yields negative infinity, and negative elements
+                /// yield NaN.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, std::f64::consts::E]];
+                /// assert_eq!(matrix.ln(), matrix![[0.0, 1.0]]);
+                /// ```
+                pub fn ln(self) -> Self {
+                    self.map_elements(<$t>::ln)
+                }
+
+                fn map_elements<F>(mut self, f: F) -> Self
+                where
+                    F: Fn($t) -> $t,
+                {
+                    self.data.iter_mut().for_each(|x| *x = f(*x));
+                    self
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+impl_math! {f32 f64}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::order::Order;
+
+    #[test]
+    fn test_clamp() {
+        let mut matrix = matrix![[-3, 0, 3], [6, 9, 12]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone().clamp(1, 6);
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1, 1, 3], [6, 6, 6]]);
+
+            matrix.switch_order();
+        }
+
+        let matrix: Matrix<f64> = matrix![[f64::NAN, -1.0, 2.0]];
+        let output = matrix.clamp(0.0, 1.0);
+        assert!(output[(0, 0)].is_nan());
+        assert_eq!(output[(0, 1)], 0.0);
+        assert_eq!(output[(0, 2)], 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_fails() {
+        let matrix = matrix![[0, 1]];
+        matrix.clamp(1, 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_rounding() {
+        let mut matrix: Matrix<f32> = matrix![[-1.5, -0.4], [0.5, 2.7]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone().round();
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[-2.0, -0.0], [1.0, 3.0]]);
+
+            let mut output = matrix.clone().floor();
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[-2.0, -1.0], [0.0, 2.0]]);
+
+            let mut output = matrix.clone().ceil();
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[-1.0, -0.0], [1.0, 3.0]]);
+
+            let mut output = matrix.clone().abs();
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1.5, 0.4], [0.5, 2.7]]);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_powers() {
+        let mut matrix: Matrix<f64> = matrix![[1.0, 4.0], [9.0, 0.0]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone().sqrt();
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1.0, 2.0], [3.0, 0.0]]);
+
+            let mut output = matrix.clone().powi(-1);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1.0, 0.25], [1.0 / 9.0, f64::INFINITY]]);
+
+            let mut output = matrix.clone().ln().exp();
+            output.set_order(Order::RowMajor);
+            for (x, y) in output.iter_elements().zip([1.0, 4.0, 9.0, 0.0]) {
+                assert!((x - y).abs() < 1e-12);
+            }
+
+            matrix.switch_order();
+        }
+
+        let matrix: Matrix<f64> = matrix![[-1.0]];
+        assert!(matrix.clone().sqrt()[(0, 0)].is_nan());
+        assert!(matrix.ln()[(0, 0)].is_nan());
+    }
+}
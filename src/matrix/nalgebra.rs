@@ -0,0 +1,92 @@
+use super::order::Order;
+use super::shape::{AxisShape, Shape};
+use super::Matrix;
+use nalgebra::{DMatrix, Dyn, VecStorage};
+
+impl<T> From<DMatrix<T>> for Matrix<T> {
+    /// Creates a new column-major [`Matrix`] instance from a
+    /// [`DMatrix`], taking over its storage without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix, Order};
+    /// use nalgebra::DMatrix;
+    ///
+    /// let dmatrix = DMatrix::from_row_slice(2, 3, &[0, 1, 2, 3, 4, 5]);
+    /// let matrix = Matrix::from(dmatrix);
+    /// assert_eq!(matrix.order(), Order::ColMajor);
+    ///
+    /// let mut expected = matrix![[0, 1, 2], [3, 4, 5]];
+    /// expected.set_order(Order::ColMajor);
+    /// assert_eq!(matrix, expected);
+    /// ```
+    fn from(value: DMatrix<T>) -> Self {
+        let order = Order::ColMajor;
+        let (nrows, ncols) = value.shape();
+        let shape = AxisShape::from_shape_unchecked(Shape::new(nrows, ncols), order);
+        let data = value.data.into();
+        Self { order, shape, data }
+    }
+}
+
+impl<T> From<Matrix<T>> for DMatrix<T> {
+    /// Creates a new [`DMatrix`] instance from a [`Matrix`].
+    ///
+    /// # Notes
+    ///
+    /// A column-major matrix hands over its storage without copying.
+    /// A row-major one is switched to column-major in place first, as
+    /// [`DMatrix`] is always column-major.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let dmatrix = DMatrix::from(matrix);
+    /// assert_eq!(dmatrix, DMatrix::from_row_slice(2, 3, &[0, 1, 2, 3, 4, 5]));
+    /// ```
+    fn from(mut value: Matrix<T>) -> Self {
+        value.set_order(Order::ColMajor);
+        let (_, shape, data) = value.into_raw_parts();
+        let storage = VecStorage::new(Dyn(shape.nrows), Dyn(shape.ncols), data);
+        DMatrix::from_data(storage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_from_dmatrix() {
+        let dmatrix = DMatrix::from_row_slice(2, 3, &[0, 1, 2, 3, 4, 5]);
+        let matrix = Matrix::from(dmatrix);
+        assert_eq!(matrix.order(), Order::ColMajor);
+        assert_eq!(matrix.as_slice(), &[0, 3, 1, 4, 2, 5]);
+        assert_eq!(matrix[(0, 1)], 1);
+
+        let matrix = Matrix::from(DMatrix::<i32>::zeros(0, 3));
+        assert_eq!(matrix.shape(), Shape::new(0, 3));
+    }
+
+    #[test]
+    fn test_into_dmatrix() {
+        let expected = DMatrix::from_row_slice(2, 3, &[0, 1, 2, 3, 4, 5]);
+
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        matrix.set_order(Order::RowMajor);
+        assert_eq!(DMatrix::from(matrix.clone()), expected);
+
+        matrix.set_order(Order::ColMajor);
+        assert_eq!(DMatrix::from(matrix.clone()), expected);
+        assert_eq!(Matrix::from(DMatrix::from(matrix.clone())), matrix);
+
+        let dmatrix = DMatrix::from(Matrix::<i32>::new((3, 0)));
+        assert_eq!(dmatrix.shape(), (3, 0));
+    }
+}
@@ -0,0 +1,152 @@
+use super::order::Order;
+use super::shape::{AxisShape, Shape};
+use super::Matrix;
+use crate::error::Error;
+use ndarray::{Array2, ShapeBuilder};
+
+impl<T: Clone> From<Array2<T>> for Matrix<T> {
+    /// Creates a new [`Matrix`] instance from an [`Array2`].
+    ///
+    /// An array in standard layout becomes a row-major matrix, and one
+    /// in Fortran layout a column-major matrix, both taking over its
+    /// storage without copying. An array in neither layout, e.g., after
+    /// slicing with a step or inverting an axis, is copied into a
+    /// row-major matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix, Order};
+    /// use ndarray::{array, Array2, ShapeBuilder};
+    ///
+    /// let array = array![[0, 1, 2], [3, 4, 5]];
+    /// let matrix = Matrix::from(array);
+    /// assert_eq!(matrix.order(), Order::RowMajor);
+    /// assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    ///
+    /// let array = Array2::from_shape_vec((2, 3).f(), vec![0, 3, 1, 4, 2, 5]).unwrap();
+    /// let matrix = Matrix::from(array);
+    /// assert_eq!(matrix.order(), Order::ColMajor);
+    /// assert_eq!(matrix[(0, 1)], 1);
+    /// ```
+    fn from(value: Array2<T>) -> Self {
+        let (order, value) = if value.is_standard_layout() {
+            (Order::RowMajor, value)
+        } else if value.t().is_standard_layout() {
+            (Order::ColMajor, value)
+        } else {
+            (Order::RowMajor, value.as_standard_layout().into_owned())
+        };
+        let (nrows, ncols) = value.dim();
+        let shape = AxisShape::from_shape_unchecked(Shape::new(nrows, ncols), order);
+        let (mut data, offset) = value.into_raw_vec_and_offset();
+        // a sliced array may own elements outside its contiguous range
+        let offset = offset.unwrap_or(0);
+        data.truncate(offset + shape.size());
+        data.drain(..offset);
+        Self { order, shape, data }
+    }
+}
+
+impl<T> From<Matrix<T>> for Array2<T> {
+    /// Creates a new [`Array2`] instance from a [`Matrix`], taking over
+    /// its storage without copying.
+    ///
+    /// A row-major matrix becomes an array in standard layout, and a
+    /// column-major matrix one in Fortran layout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if size exceeds [`isize::MAX`], which only matrices of
+    /// zero-sized types can reach.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Order};
+    /// use ndarray::{array, Array2};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    /// matrix.set_order(Order::ColMajor);
+    /// let array = Array2::from(matrix);
+    /// assert!(array.t().is_standard_layout());
+    /// assert_eq!(array, array![[0, 1, 2], [3, 4, 5]]);
+    /// ```
+    fn from(value: Matrix<T>) -> Self {
+        let (order, shape, data) = value.into_raw_parts();
+        let dim = (shape.nrows, shape.ncols);
+        let result = match order {
+            Order::RowMajor => Array2::from_shape_vec(dim, data),
+            Order::ColMajor => Array2::from_shape_vec(dim.f(), data),
+        };
+        match result {
+            Err(_) => panic!("{}", Error::SizeOverflow),
+            Ok(array) => array,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use alloc::vec;
+    use ndarray::{array, s, Axis};
+
+    #[test]
+    fn test_from_array2() {
+        let expected = matrix![[0, 1, 2], [3, 4, 5]];
+
+        let matrix = Matrix::from(array![[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(matrix.order(), Order::RowMajor);
+        assert_eq!(matrix.as_slice(), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(matrix, expected);
+
+        let array = Array2::from_shape_vec((2, 3).f(), vec![0, 3, 1, 4, 2, 5]).unwrap();
+        let matrix = Matrix::from(array);
+        assert_eq!(matrix.order(), Order::ColMajor);
+        assert_eq!(matrix.as_slice(), &[0, 3, 1, 4, 2, 5]);
+        assert_eq!(matrix[(0, 1)], 1);
+
+        let mut array = array![[9, 9, 9], [0, 1, 2], [3, 4, 5], [9, 9, 9]];
+        array.slice_collapse(s![1..3, ..]);
+        let matrix = Matrix::from(array);
+        assert_eq!(matrix.as_slice(), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(matrix, expected);
+
+        let matrix = Matrix::from(Array2::<i32>::zeros((0, 3)));
+        assert_eq!(matrix.shape(), Shape::new(0, 3));
+    }
+
+    #[test]
+    fn test_from_array2_not_contiguous() {
+        let mut array = array![[0, 1, 2], [3, 4, 5]];
+        array.slice_collapse(s![.., ..;2]);
+        assert_eq!(Matrix::from(array), matrix![[0, 2], [3, 5]]);
+
+        let mut array = array![[0, 1, 2], [3, 4, 5]];
+        array.invert_axis(Axis(1));
+        assert_eq!(Matrix::from(array), matrix![[2, 1, 0], [5, 4, 3]]);
+    }
+
+    #[test]
+    fn test_into_array2() {
+        let expected = array![[0, 1, 2], [3, 4, 5]];
+
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+        matrix.set_order(Order::RowMajor);
+        let array = Array2::from(matrix.clone());
+        assert!(array.is_standard_layout());
+        assert_eq!(array, expected);
+
+        matrix.set_order(Order::ColMajor);
+        let array = Array2::from(matrix.clone());
+        assert!(array.t().is_standard_layout());
+        assert_eq!(array, expected);
+
+        assert_eq!(Matrix::from(Array2::from(matrix.clone())), matrix);
+
+        let array = Array2::from(Matrix::<i32>::new((3, 0)));
+        assert_eq!(array.dim(), (3, 0));
+    }
+}
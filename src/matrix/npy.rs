@@ -0,0 +1,385 @@
+//! This module provides reading and writing of NumPy `.npy` files,
+//! available with the `npy` feature.
+//!
+//! Files are written in version 1.0 of the format, and versions 1.0, 2.0
+//! and 3.0 are accepted when reading. C order maps to
+//! [`Order::RowMajor`] and Fortran order to [`Order::ColMajor`], so the
+//! data is copied as is in both directions.
+//!
+//! Only little-endian and single-byte element types are supported, and
+//! arrays must have one or two dimensions, with one-dimensional arrays
+//! read as row vectors.
+
+use super::binary::Element;
+use super::order::Order;
+use super::shape::{AxisShape, Shape};
+use super::Matrix;
+use std::io::{self, Read, Write};
+
+/// Magic bytes starting every `.npy` file.
+pub const MAGIC: [u8; 6] = *b"\x93NUMPY";
+
+/// A type that can be stored in `.npy` files.
+///
+/// This trait is implemented for `bool`, [`f32`], [`f64`] and all
+/// primitive integer types up to 64 bits except `usize` and `isize`.
+pub trait NpyElement: Element {
+    /// NumPy type descriptor of the element type, e.g., `<f8`.
+    const DESCR: &'static str;
+}
+
+macro_rules! impl_npy_element {
+    ($($t:ty => $descr:literal),* $(,)?) => {
+        $(
+            impl NpyElement for $t {
+                const DESCR: &'static str = $descr;
+            }
+        )*
+    };
+}
+
+impl_npy_element! {
+    u8 => "|u1", u16 => "<u2", u32 => "<u4", u64 => "<u8",
+    i8 => "|i1", i16 => "<i2", i32 => "<i4", i64 => "<i8",
+    f32 => "<f4", f64 => "<f8", bool => "|b1",
+}
+
+impl<T: NpyElement> Matrix<T> {
+    /// Returns the matrix encoded as a `.npy` file.
+    ///
+    /// See the [module-level documentation](self) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let matrix = matrix![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]];
+    /// let bytes = matrix.to_npy_bytes();
+    ///
+    /// assert_eq!(&bytes[..6], b"\x93NUMPY");
+    /// assert_eq!(Matrix::<f64>::from_npy_bytes(&bytes).unwrap(), matrix);
+    /// ```
+    pub fn to_npy_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_npy(&mut bytes)
+            .expect("writing to a vector should not fail");
+        bytes
+    }
+
+    /// Decodes a matrix from the contents of a `.npy` file.
+    ///
+    /// The matrix keeps the order recorded in the file.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidData`] if `bytes` is not a valid `.npy`
+    ///   file of a one- or two-dimensional array of element type `T`, or
+    ///   has trailing bytes.
+    /// - [`io::ErrorKind::UnexpectedEof`] if `bytes` is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix, Order};
+    ///
+    /// let mut matrix = matrix![[0i32, 1, 2], [3, 4, 5]];
+    /// matrix.set_order(Order::ColMajor);
+    /// let bytes = matrix.to_npy_bytes();
+    ///
+    /// let output = Matrix::<i32>::from_npy_bytes(&bytes).unwrap();
+    /// assert_eq!(output.order(), Order::ColMajor);
+    /// assert_eq!(output, matrix);
+    /// assert!(Matrix::<f64>::from_npy_bytes(&bytes).is_err());
+    /// ```
+    pub fn from_npy_bytes(mut bytes: &[u8]) -> io::Result<Self> {
+        let matrix = Self::read_npy(&mut bytes)?;
+        if !bytes.is_empty() {
+            return Err(invalid_data("trailing bytes after array data"));
+        }
+        Ok(matrix)
+    }
+
+    /// Writes the matrix to `writer` as a `.npy` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error encountered while writing.
+    pub fn write_npy<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let fortran_order = match self.order {
+            Order::RowMajor => "False",
+            Order::ColMajor => "True",
+        };
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': ({}, {}), }}",
+            T::DESCR,
+            fortran_order,
+            self.nrows(),
+            self.ncols(),
+        );
+        // magic, version and header length take 10 bytes, and the header
+        // is padded so that the data starts at a multiple of 64 bytes
+        let len = 10 + header.len() + 1;
+        header.extend(core::iter::repeat_n(' ', len.next_multiple_of(64) - len));
+        header.push('\n');
+        let header_len = u16::try_from(header.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "header too long"))?;
+
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&header_len.to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+        for element in &self.data {
+            element.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a matrix in the `.npy` format from `reader`, leaving any
+    /// bytes after the array data unread.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidData`] if the data is not a valid `.npy`
+    ///   file of a one- or two-dimensional array of element type `T`.
+    /// - Any other I/O error encountered while reading.
+    pub fn read_npy<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(invalid_data("invalid magic bytes"));
+        }
+
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        let header_len = match version[0] {
+            1 => {
+                let mut bytes = [0; 2];
+                reader.read_exact(&mut bytes)?;
+                u16::from_le_bytes(bytes) as usize
+            }
+            2 | 3 => {
+                let mut bytes = [0; 4];
+                reader.read_exact(&mut bytes)?;
+                usize::try_from(u32::from_le_bytes(bytes))
+                    .map_err(|_| invalid_data("header too long"))?
+            }
+            _ => return Err(invalid_data("unsupported format version")),
+        };
+        let mut header = Vec::new();
+        reader
+            .by_ref()
+            .take(header_len as u64)
+            .read_to_end(&mut header)?;
+        if header.len() != header_len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let header = core::str::from_utf8(&header).map_err(|_| invalid_data("invalid header"))?;
+
+        let descr = parse_string(value_of(header, "descr")?)?;
+        if descr != T::DESCR {
+            return Err(invalid_data("element type mismatch"));
+        }
+        let order = match parse_literal(value_of(header, "fortran_order")?) {
+            "False" => Order::RowMajor,
+            "True" => Order::ColMajor,
+            _ => return Err(invalid_data("invalid fortran_order")),
+        };
+        let shape = match parse_tuple(value_of(header, "shape")?)?[..] {
+            [ncols] => Shape::new(1, ncols),
+            [nrows, ncols] => Shape::new(nrows, ncols),
+            _ => return Err(invalid_data("array is not one- or two-dimensional")),
+        };
+        let shape = AxisShape::try_from_shape(shape, order)
+            .map_err(|error| invalid_data(&error.to_string()))?;
+        let size =
+            Self::check_size(shape.size()).map_err(|error| invalid_data(&error.to_string()))?;
+
+        // The header is untrusted, so the buffer grows with the payload
+        // actually read instead of being allocated up front.
+        let mut data = Vec::new();
+        for _ in 0..size {
+            data.push(T::read_le(&mut reader)?);
+        }
+
+        Ok(Self { order, shape, data })
+    }
+}
+
+/// Returns the text following `'key':` in the header dictionary.
+fn value_of<'a>(header: &'a str, key: &str) -> io::Result<&'a str> {
+    let pattern = format!("'{key}':");
+    let start = header
+        .find(&pattern)
+        .ok_or_else(|| invalid_data(&format!("missing key {key:?}")))?;
+    Ok(header[start + pattern.len()..].trim_start())
+}
+
+fn parse_string(value: &str) -> io::Result<&str> {
+    let quote = value
+        .chars()
+        .next()
+        .filter(|&c| c == '\'' || c == '"')
+        .ok_or_else(|| invalid_data("invalid string"))?;
+    let value = &value[1..];
+    let end = value
+        .find(quote)
+        .ok_or_else(|| invalid_data("invalid string"))?;
+    Ok(&value[..end])
+}
+
+fn parse_literal(value: &str) -> &str {
+    let end = value
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(value.len());
+    &value[..end]
+}
+
+fn parse_tuple(value: &str) -> io::Result<Vec<usize>> {
+    let value = value
+        .strip_prefix('(')
+        .and_then(|value| value.split_once(')'))
+        .map(|(value, _)| value)
+        .ok_or_else(|| invalid_data("invalid shape"))?;
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|dimension| !dimension.is_empty())
+        .map(|dimension| {
+            let dimension = dimension.strip_suffix('L').unwrap_or(dimension);
+            dimension
+                .parse()
+                .map_err(|_| invalid_data("invalid dimension"))
+        })
+        .collect()
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    fn npy(header: &str, data: &[u8]) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend([1, 0]);
+        bytes.extend((header.len() as u16).to_le_bytes());
+        bytes.extend(header.as_bytes());
+        bytes.extend(data);
+        bytes
+    }
+
+    #[test]
+    fn test_to_npy_bytes() {
+        let mut matrix = matrix![[1u16, 2, 3], [4, 5, 6]];
+
+        let bytes = matrix.to_npy_bytes();
+        assert_eq!(bytes.len(), 128 + 12);
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        assert_eq!(u16::from_le_bytes([bytes[8], bytes[9]]), 118);
+        let header = core::str::from_utf8(&bytes[10..128]).unwrap();
+        assert!(header.starts_with("{'descr': '<u2', 'fortran_order': False, 'shape': (2, 3), }"));
+        assert!(header.ends_with(" \n"));
+        assert_eq!(&bytes[128..], [1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0]);
+
+        matrix.switch_order();
+        let bytes = matrix.to_npy_bytes();
+        let header = core::str::from_utf8(&bytes[10..128]).unwrap();
+        assert!(header.contains("'fortran_order': True, 'shape': (2, 3)"));
+        assert_eq!(&bytes[128..], [1, 0, 4, 0, 2, 0, 5, 0, 3, 0, 6, 0]);
+    }
+
+    #[test]
+    fn test_from_npy_bytes() {
+        let mut matrix = matrix![[0.5f32, -1.0, 2.0], [3.0, 4.0, f32::MAX]];
+
+        for _ in 0..2 {
+            let bytes = matrix.to_npy_bytes();
+            let output = Matrix::<f32>::from_npy_bytes(&bytes).unwrap();
+            assert_eq!(output, matrix);
+
+            matrix.switch_order();
+        }
+
+        let matrix = matrix![[true, false], [false, true]];
+        let output = Matrix::<bool>::from_npy_bytes(&matrix.to_npy_bytes());
+        assert_eq!(output.unwrap(), matrix);
+
+        let matrix = Matrix::<i64>::new((0, 3));
+        let output = Matrix::<i64>::from_npy_bytes(&matrix.to_npy_bytes());
+        assert_eq!(output.unwrap(), matrix);
+
+        // as written by NumPy for a one-dimensional array
+        let header = "{'descr': '|u1', 'fortran_order': False, 'shape': (3,), }";
+        let output = Matrix::<u8>::from_npy_bytes(&npy(header, &[7, 8, 9]));
+        assert_eq!(output.unwrap(), matrix![[7, 8, 9]]);
+
+        let header = "{'descr': '<i2', 'fortran_order': True, 'shape': (2, 1)}";
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend([2, 0]);
+        bytes.extend((header.len() as u32).to_le_bytes());
+        bytes.extend(header.as_bytes());
+        bytes.extend([1, 0, 255, 255]);
+        let output = Matrix::<i16>::from_npy_bytes(&bytes).unwrap();
+        assert_eq!(output.order(), Order::ColMajor);
+        let mut expected = matrix![[1], [-1]];
+        expected.set_order(Order::ColMajor);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_from_npy_bytes_fails() {
+        let bytes = matrix![[1u8, 2], [3, 4]].to_npy_bytes();
+        let read = |bytes: &[u8]| Matrix::<u8>::from_npy_bytes(bytes).unwrap_err().kind();
+
+        let mut invalid = bytes.clone();
+        invalid[1] = b'n';
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+
+        let mut invalid = bytes.clone();
+        invalid[6] = 4;
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+
+        let result = Matrix::<i8>::from_npy_bytes(&bytes);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let mut invalid = bytes.clone();
+        invalid.push(0);
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+
+        assert_eq!(
+            read(&bytes[..bytes.len() - 1]),
+            io::ErrorKind::UnexpectedEof
+        );
+        assert_eq!(read(&bytes[..20]), io::ErrorKind::UnexpectedEof);
+
+        let invalid = npy(
+            "{'descr': '|u1', 'fortran_order': False, 'shape': (1, 1, 1)}",
+            &[0],
+        );
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+        let invalid = npy(
+            "{'descr': '|u1', 'fortran_order': False, 'shape': ()}",
+            &[0],
+        );
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+        let invalid = npy("{'descr': '|u1', 'fortran_order': 0, 'shape': (1,)}", &[0]);
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+        let invalid = npy("{'descr': '|u1', 'shape': (1,)}", &[0]);
+        assert_eq!(read(&invalid), io::ErrorKind::InvalidData);
+        let invalid = npy(
+            "{'descr': '>f8', 'fortran_order': False, 'shape': (1,)}",
+            &[0; 8],
+        );
+        let result = Matrix::<f64>::from_npy_bytes(&invalid);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        let header = format!(
+            "{{'descr': '|u1', 'fortran_order': False, 'shape': ({}, 2)}}",
+            usize::MAX
+        );
+        assert_eq!(read(&npy(&header, &[])), io::ErrorKind::InvalidData);
+    }
+}
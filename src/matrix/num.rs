@@ -0,0 +1,72 @@
+use super::shape::ShapeLike;
+use super::Matrix;
+use num_traits::{One, Zero};
+
+impl<T> Matrix<T> {
+    /// Creates a new [`Matrix`] instance with every element set to
+    /// [`Zero::zero`].
+    ///
+    /// Unlike [`Matrix::new`], which relies on [`Default`], this is
+    /// guaranteed to be the additive identity of `T`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if size exceeds [`usize::MAX`].
+    /// - Panics if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let matrix = Matrix::<f64>::zeros((2, 3));
+    /// assert_eq!(matrix, matrix![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+    /// ```
+    pub fn zeros<S: ShapeLike>(shape: S) -> Self
+    where
+        T: Zero + Clone,
+    {
+        Self::full(shape, T::zero())
+    }
+
+    /// Creates a new [`Matrix`] instance with every element set to
+    /// [`One::one`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if size exceeds [`usize::MAX`].
+    /// - Panics if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Matrix};
+    ///
+    /// let matrix = Matrix::<i32>::ones((2, 3));
+    /// assert_eq!(matrix, matrix![[1, 1, 1], [1, 1, 1]]);
+    /// ```
+    pub fn ones<S: ShapeLike>(shape: S) -> Self
+    where
+        T: One + Clone,
+    {
+        Self::full(shape, T::one())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_zeros() {
+        assert_eq!(Matrix::<i32>::zeros((2, 3)), Matrix::new((2, 3)));
+        assert_eq!(Matrix::<f32>::zeros((0, 3)), Matrix::new((0, 3)));
+    }
+
+    #[test]
+    fn test_ones() {
+        assert_eq!(Matrix::<u8>::ones((2, 2)), matrix![[1, 1], [1, 1]]);
+        assert_eq!(Matrix::<f64>::ones((1, 2)), matrix![[1.0, 1.0]]);
+    }
+}
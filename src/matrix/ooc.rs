@@ -0,0 +1,658 @@
+//! This module provides [`TiledMatrix`], an experimental disk-backed
+//! matrix of [`f64`] for datasets larger than memory.
+//!
+//! The matrix is split into square tiles stored back to back in a file,
+//! and at most a fixed number of tiles is kept in memory at any time,
+//! evicting the least recently used one when the cache is full.
+
+use super::axis::Axis;
+use super::index::Index;
+use super::shape::{Shape, ShapeLike};
+use super::Matrix;
+use crate::error::Error;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+const ELEMENT_SIZE: usize = core::mem::size_of::<f64>();
+
+/// A disk-backed matrix of [`f64`], accessed through an LRU cache of
+/// square tiles.
+///
+/// # Notes
+///
+/// Each tile occupies `tile_size * tile_size` elements in the file, with
+/// tiles on the bottom and right edges padded with zeros. Modified tiles
+/// are written back when evicted, on [`TiledMatrix::flush`], and when
+/// the matrix is dropped, in which case errors are ignored.
+///
+/// I/O errors are returned as is. Other errors are returned as
+/// [`io::ErrorKind::InvalidInput`], wrapping an [`Error`] where one
+/// applies.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::{matrix, TiledMatrix};
+///
+/// let path = |name: &str| {
+///     let name = format!("matreex-doc-ooc-{}-{name}", std::process::id());
+///     std::env::temp_dir().join(name)
+/// };
+/// let lhs = matrix![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]];
+/// let rhs = matrix![[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]];
+///
+/// let mut lhs = TiledMatrix::from_matrix(path("lhs"), &lhs, 2, 4).unwrap();
+/// let mut rhs = TiledMatrix::from_matrix(path("rhs"), &rhs, 2, 4).unwrap();
+/// let mut product = lhs.mat_mul(&mut rhs, path("product"), 4).unwrap();
+///
+/// assert_eq!(product.get(1, 1).unwrap(), 40.0);
+/// assert_eq!(product.to_matrix().unwrap(), matrix![[10.0, 13.0], [28.0, 40.0]]);
+/// # drop((lhs, rhs, product));
+/// # for name in ["lhs", "rhs", "product"] {
+/// #     std::fs::remove_file(path(name)).unwrap();
+/// # }
+/// ```
+pub struct TiledMatrix {
+    file: File,
+    shape: Shape,
+    tile_size: usize,
+    cache: TileCache,
+}
+
+struct TileCache {
+    capacity: usize,
+    tiles: HashMap<usize, Tile>,
+    clock: u64,
+}
+
+struct Tile {
+    data: Vec<f64>,
+    dirty: bool,
+    last_used: u64,
+}
+
+impl TiledMatrix {
+    /// Creates a new zero-filled matrix backed by the file at `path`,
+    /// truncating it if it already exists.
+    ///
+    /// At most `cache_capacity` tiles, but at least one, are kept in
+    /// memory.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidInput`] if `tile_size` is zero, or the
+    ///   file size overflows, wrapping [`Error::SizeOverflow`].
+    /// - Any error from creating or resizing the file.
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    pub fn create<P, S>(
+        path: P,
+        shape: S,
+        tile_size: usize,
+        cache_capacity: usize,
+    ) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+        S: ShapeLike,
+    {
+        let shape = Shape::new(shape.nrows(), shape.ncols());
+        let len = file_len(shape, tile_size)?;
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len(len)?;
+        Ok(Self::with_file(file, shape, tile_size, cache_capacity))
+    }
+
+    /// Opens an existing matrix backed by the file at `path`, which must
+    /// have been created with the same shape and tile size.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidInput`] if `tile_size` is zero, or the
+    ///   file size overflows, wrapping [`Error::SizeOverflow`].
+    /// - [`io::ErrorKind::InvalidInput`] if the file size does not match,
+    ///   wrapping [`Error::SizeMismatch`].
+    /// - Any error from opening the file.
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::SizeMismatch`]: crate::error::Error::SizeMismatch
+    pub fn open<P, S>(
+        path: P,
+        shape: S,
+        tile_size: usize,
+        cache_capacity: usize,
+    ) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+        S: ShapeLike,
+    {
+        let shape = Shape::new(shape.nrows(), shape.ncols());
+        let len = file_len(shape, tile_size)?;
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        if file.metadata()?.len() != len {
+            return Err(invalid_input(Error::SizeMismatch));
+        }
+        Ok(Self::with_file(file, shape, tile_size, cache_capacity))
+    }
+
+    /// Creates a new matrix backed by the file at `path`, holding a copy
+    /// of `matrix`.
+    ///
+    /// # Errors
+    ///
+    /// See [`TiledMatrix::create`].
+    pub fn from_matrix<P>(
+        path: P,
+        matrix: &Matrix<f64>,
+        tile_size: usize,
+        cache_capacity: usize,
+    ) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let mut tiled = Self::create(path, matrix.shape(), tile_size, cache_capacity)?;
+        let mut data = vec![0.0; tile_size * tile_size];
+        for index in 0..tiled.tile_count() {
+            let (row_offset, col_offset, nrows, ncols) = tiled.tile_extent(index);
+            data.fill(0.0);
+            for i in 0..nrows {
+                for j in 0..ncols {
+                    data[i * tile_size + j] = matrix[(row_offset + i, col_offset + j)];
+                }
+            }
+            tiled.write_tile(index, &data)?;
+        }
+        Ok(tiled)
+    }
+
+    /// Returns the shape of the matrix.
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn nrows(&self) -> usize {
+        self.shape.nrows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn ncols(&self) -> usize {
+        self.shape.ncols
+    }
+
+    /// Returns the number of rows and columns of each tile.
+    pub fn tile_size(&self) -> usize {
+        self.tile_size
+    }
+
+    /// Returns the element at `(row, col)`.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidInput`] if `(row, col)` is out of bounds,
+    ///   wrapping [`Error::IndexOutOfBounds`].
+    /// - Any error from reading or writing back tiles.
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn get(&mut self, row: usize, col: usize) -> io::Result<f64> {
+        let (index, offset) = self.locate(row, col)?;
+        Ok(self.tile(index)?.data[offset])
+    }
+
+    /// Sets the element at `(row, col)` to `value`.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidInput`] if `(row, col)` is out of bounds,
+    ///   wrapping [`Error::IndexOutOfBounds`].
+    /// - Any error from reading or writing back tiles.
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn set(&mut self, row: usize, col: usize, value: f64) -> io::Result<()> {
+        let (index, offset) = self.locate(row, col)?;
+        let tile = self.tile(index)?;
+        tile.data[offset] = value;
+        tile.dirty = true;
+        Ok(())
+    }
+
+    /// Writes all modified tiles back to the file.
+    ///
+    /// # Errors
+    ///
+    /// Any error from writing to the file.
+    pub fn flush(&mut self) -> io::Result<()> {
+        let tile_len = self.tile_len();
+        for (&index, tile) in self.cache.tiles.iter_mut() {
+            if tile.dirty {
+                write_tile(&mut self.file, index, tile_len, &tile.data)?;
+                tile.dirty = false;
+            }
+        }
+        self.file.flush()
+    }
+
+    /// Loads the whole matrix into memory.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidInput`] if the matrix does not fit in
+    ///   memory, wrapping the [`Error`] from [`Matrix::build`].
+    /// - Any error from reading or writing back tiles.
+    pub fn to_matrix(&mut self) -> io::Result<Matrix<f64>> {
+        let mut output = Matrix::build(self.shape).map_err(invalid_input)?;
+        self.for_each_tile(|row_offset, col_offset, nrows, ncols, data| {
+            for i in 0..nrows {
+                for j in 0..ncols {
+                    output[(row_offset + i, col_offset + j)] = data[i * ncols + j];
+                }
+            }
+        })?;
+        Ok(output)
+    }
+
+    /// Returns the sum of all elements.
+    ///
+    /// # Errors
+    ///
+    /// Any error from reading or writing back tiles.
+    pub fn sum(&mut self) -> io::Result<f64> {
+        let mut sum = 0.0;
+        self.for_each_tile(|_, _, _, _, data| {
+            sum += data.iter().sum::<f64>();
+        })?;
+        Ok(sum)
+    }
+
+    /// Returns the sums along the given axis.
+    ///
+    /// # Notes
+    ///
+    /// Reducing along [`Axis::Row`] sums each row and yields an `m x 1`
+    /// matrix, while reducing along [`Axis::Col`] sums each column and
+    /// yields a `1 x n` matrix.
+    ///
+    /// # Errors
+    ///
+    /// Any error from reading or writing back tiles.
+    pub fn sum_axis(&mut self, axis: Axis) -> io::Result<Matrix<f64>> {
+        let mut output = match axis {
+            Axis::Row => Matrix::new((self.nrows(), 1)),
+            Axis::Col => Matrix::new((1, self.ncols())),
+        };
+        self.for_each_tile(|row_offset, col_offset, nrows, ncols, data| {
+            for i in 0..nrows {
+                for j in 0..ncols {
+                    let element = data[i * ncols + j];
+                    match axis {
+                        Axis::Row => output[(row_offset + i, 0)] += element,
+                        Axis::Col => output[(0, col_offset + j)] += element,
+                    }
+                }
+            }
+        })?;
+        Ok(output)
+    }
+
+    /// Performs out-of-core matrix multiplication, writing the result to
+    /// a new matrix backed by the file at `path`.
+    ///
+    /// Only one tile of each operand and of the result is needed in
+    /// memory at a time.
+    ///
+    /// # Errors
+    ///
+    /// - [`io::ErrorKind::InvalidInput`] if the matrices are not
+    ///   conformable, wrapping [`Error::NotConformable`].
+    /// - [`io::ErrorKind::InvalidInput`] if the tile sizes differ.
+    /// - Any error from creating the result or accessing tiles.
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn mat_mul<P>(
+        &mut self,
+        rhs: &mut TiledMatrix,
+        path: P,
+        cache_capacity: usize,
+    ) -> io::Result<TiledMatrix>
+    where
+        P: AsRef<Path>,
+    {
+        if self.ncols() != rhs.nrows() {
+            return Err(invalid_input(Error::not_conformable(
+                self.shape(),
+                rhs.shape(),
+            )));
+        }
+        if self.tile_size != rhs.tile_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "tile sizes differ",
+            ));
+        }
+
+        let size = self.tile_size;
+        let shape = Shape::new(self.nrows(), rhs.ncols());
+        let mut output = TiledMatrix::create(path, shape, size, cache_capacity)?;
+        let inner = self.tile_cols();
+        let mut acc = vec![0.0; self.tile_len()];
+        for index in 0..output.tile_count() {
+            let (ti, tj) = (index / output.tile_cols(), index % output.tile_cols());
+            acc.fill(0.0);
+            for tk in 0..inner {
+                let lhs = &self.tile(ti * inner + tk)?.data;
+                let rhs = &rhs.tile(tk * rhs.tile_cols() + tj)?.data;
+                for i in 0..size {
+                    for k in 0..size {
+                        let left = lhs[i * size + k];
+                        for j in 0..size {
+                            acc[i * size + j] += left * rhs[k * size + j];
+                        }
+                    }
+                }
+            }
+            output.write_tile(index, &acc)?;
+        }
+        Ok(output)
+    }
+
+    fn with_file(file: File, shape: Shape, tile_size: usize, cache_capacity: usize) -> Self {
+        Self {
+            file,
+            shape,
+            tile_size,
+            cache: TileCache {
+                capacity: cache_capacity.max(1),
+                tiles: HashMap::new(),
+                clock: 0,
+            },
+        }
+    }
+
+    fn tile_len(&self) -> usize {
+        self.tile_size * self.tile_size
+    }
+
+    fn tile_rows(&self) -> usize {
+        self.nrows().div_ceil(self.tile_size)
+    }
+
+    fn tile_cols(&self) -> usize {
+        self.ncols().div_ceil(self.tile_size)
+    }
+
+    fn tile_count(&self) -> usize {
+        self.tile_rows() * self.tile_cols()
+    }
+
+    /// Returns the row and column offsets of tile `index`, along with
+    /// the number of rows and columns of it that lie within the matrix.
+    fn tile_extent(&self, index: usize) -> (usize, usize, usize, usize) {
+        let row_offset = index / self.tile_cols() * self.tile_size;
+        let col_offset = index % self.tile_cols() * self.tile_size;
+        let nrows = self.tile_size.min(self.nrows() - row_offset);
+        let ncols = self.tile_size.min(self.ncols() - col_offset);
+        (row_offset, col_offset, nrows, ncols)
+    }
+
+    /// Returns the tile holding `(row, col)` and the offset within it.
+    fn locate(&self, row: usize, col: usize) -> io::Result<(usize, usize)> {
+        if row >= self.nrows() || col >= self.ncols() {
+            return Err(invalid_input(Error::IndexOutOfBounds {
+                index: Index::new(row, col),
+                shape: self.shape(),
+            }));
+        }
+        let size = self.tile_size;
+        let index = row / size * self.tile_cols() + col / size;
+        let offset = row % size * size + col % size;
+        Ok((index, offset))
+    }
+
+    /// Returns tile `index`, loading it into the cache if necessary.
+    fn tile(&mut self, index: usize) -> io::Result<&mut Tile> {
+        self.cache.clock += 1;
+        let clock = self.cache.clock;
+        if !self.cache.tiles.contains_key(&index) {
+            if self.cache.tiles.len() >= self.cache.capacity {
+                self.evict()?;
+            }
+            let tile_len = self.tile_len();
+            let data = read_tile(&mut self.file, index, tile_len)?;
+            let tile = Tile {
+                data,
+                dirty: false,
+                last_used: clock,
+            };
+            self.cache.tiles.insert(index, tile);
+        }
+        let tile = self
+            .cache
+            .tiles
+            .get_mut(&index)
+            .expect("tile should have been loaded");
+        tile.last_used = clock;
+        Ok(tile)
+    }
+
+    /// Removes the least recently used tile from the cache, writing it
+    /// back if modified.
+    fn evict(&mut self) -> io::Result<()> {
+        let lru = self
+            .cache
+            .tiles
+            .iter()
+            .min_by_key(|(_, tile)| tile.last_used)
+            .map(|(&index, _)| index);
+        if let Some(index) = lru {
+            if let Some(tile) = self.cache.tiles.remove(&index) {
+                if tile.dirty {
+                    let tile_len = self.tile_len();
+                    write_tile(&mut self.file, index, tile_len, &tile.data)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes `data` as tile `index`, bypassing the cache.
+    fn write_tile(&mut self, index: usize, data: &[f64]) -> io::Result<()> {
+        self.cache.tiles.remove(&index);
+        let tile_len = self.tile_len();
+        write_tile(&mut self.file, index, tile_len, data)
+    }
+
+    /// Calls `f` with the offsets, extent and data of each tile in turn,
+    /// with the data cropped to the extent.
+    fn for_each_tile<F>(&mut self, mut f: F) -> io::Result<()>
+    where
+        F: FnMut(usize, usize, usize, usize, &[f64]),
+    {
+        let size = self.tile_size;
+        let mut cropped = Vec::with_capacity(self.tile_len());
+        for index in 0..self.tile_count() {
+            let (row_offset, col_offset, nrows, ncols) = self.tile_extent(index);
+            let tile = self.tile(index)?;
+            cropped.clear();
+            for i in 0..nrows {
+                cropped.extend_from_slice(&tile.data[i * size..i * size + ncols]);
+            }
+            f(row_offset, col_offset, nrows, ncols, &cropped);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TiledMatrix {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+impl core::fmt::Debug for TiledMatrix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TiledMatrix")
+            .field("shape", &self.shape)
+            .field("tile_size", &self.tile_size)
+            .field("cached_tiles", &self.cache.tiles.len())
+            .field("cache_capacity", &self.cache.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+fn invalid_input(error: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, error)
+}
+
+/// Returns the length in bytes of the file backing a matrix of the
+/// given shape and tile size.
+fn file_len(shape: Shape, tile_size: usize) -> io::Result<u64> {
+    if tile_size == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "tile size is zero",
+        ));
+    }
+    let tile_rows = shape.nrows.div_ceil(tile_size);
+    let tile_cols = shape.ncols.div_ceil(tile_size);
+    [tile_cols, tile_size, tile_size, ELEMENT_SIZE]
+        .into_iter()
+        .try_fold(tile_rows, usize::checked_mul)
+        .and_then(|len| u64::try_from(len).ok())
+        .ok_or_else(|| invalid_input(Error::SizeOverflow))
+}
+
+fn read_tile(file: &mut File, index: usize, tile_len: usize) -> io::Result<Vec<f64>> {
+    let mut bytes = vec![0; tile_len * ELEMENT_SIZE];
+    file.seek(SeekFrom::Start((index * bytes.len()) as u64))?;
+    file.read_exact(&mut bytes)?;
+    Ok(bytes
+        .chunks_exact(ELEMENT_SIZE)
+        .map(|chunk| f64::from_le_bytes(chunk.try_into().expect("chunk of element size")))
+        .collect())
+}
+
+fn write_tile(file: &mut File, index: usize, tile_len: usize, data: &[f64]) -> io::Result<()> {
+    let bytes: Vec<u8> = data.iter().flat_map(|x| x.to_le_bytes()).collect();
+    file.seek(SeekFrom::Start((index * tile_len * ELEMENT_SIZE) as u64))?;
+    file.write_all(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("matreex-ooc-{}-{name}", std::process::id()))
+    }
+
+    fn sample(nrows: usize, ncols: usize) -> Matrix<f64> {
+        let mut matrix = Matrix::new((nrows, ncols));
+        for (index, element) in matrix.iter_elements_mut_with_index() {
+            *element = (index.row * 7 + index.col * 3) as f64 % 11.0 - 5.0;
+        }
+        matrix
+    }
+
+    #[test]
+    fn test_get_set_and_reopen() {
+        let path = temp_path("reopen");
+        {
+            let mut tiled = TiledMatrix::create(&path, (5, 7), 3, 1).unwrap();
+            assert_eq!(tiled.shape(), Shape::new(5, 7));
+            assert_eq!(tiled.get(4, 6).unwrap(), 0.0);
+
+            tiled.set(0, 0, 1.0).unwrap();
+            tiled.set(4, 6, 2.0).unwrap();
+            tiled.set(2, 3, 3.0).unwrap();
+            assert_eq!(tiled.get(0, 0).unwrap(), 1.0);
+            assert_eq!(tiled.get(4, 6).unwrap(), 2.0);
+            assert_eq!(tiled.get(2, 3).unwrap(), 3.0);
+
+            let error = tiled.get(5, 0).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+            assert!(tiled.set(0, 7, 0.0).is_err());
+        }
+        {
+            let mut tiled = TiledMatrix::open(&path, (5, 7), 3, 2).unwrap();
+            assert_eq!(tiled.get(0, 0).unwrap(), 1.0);
+            assert_eq!(tiled.get(4, 6).unwrap(), 2.0);
+            assert_eq!(tiled.get(2, 3).unwrap(), 3.0);
+            assert_eq!(tiled.sum().unwrap(), 6.0);
+        }
+        assert!(TiledMatrix::open(&path, (5, 7), 2, 2).is_err());
+        assert!(TiledMatrix::create(&path, (5, 7), 0, 2).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_to_matrix_and_reductions() {
+        let path = temp_path("reductions");
+        let mut matrix = sample(5, 4);
+
+        for _ in 0..2 {
+            for (tile_size, cache) in [(1, 1), (2, 1), (3, 2), (8, 4)] {
+                let mut tiled = TiledMatrix::from_matrix(&path, &matrix, tile_size, cache).unwrap();
+
+                let mut dense = tiled.to_matrix().unwrap();
+                dense.set_order(matrix.order());
+                assert_eq!(dense, matrix);
+
+                let sum: f64 = matrix.iter_elements().sum();
+                assert_eq!(tiled.sum().unwrap(), sum);
+
+                let rows = tiled.sum_axis(Axis::Row).unwrap();
+                let cols = tiled.sum_axis(Axis::Col).unwrap();
+                assert_eq!(rows.shape(), Shape::new(5, 1));
+                assert_eq!(cols.shape(), Shape::new(1, 4));
+                for row in 0..5 {
+                    let expected: f64 = (0..4).map(|col| matrix[(row, col)]).sum();
+                    assert_eq!(rows[(row, 0)], expected);
+                }
+                for col in 0..4 {
+                    let expected: f64 = (0..5).map(|row| matrix[(row, col)]).sum();
+                    assert_eq!(cols[(0, col)], expected);
+                }
+            }
+            matrix.switch_order();
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_mat_mul() {
+        let paths = [temp_path("lhs"), temp_path("rhs"), temp_path("product")];
+        let lhs = sample(7, 5);
+        let rhs = sample(5, 6);
+        let expected = lhs.clone().mat_mul(rhs.clone()).unwrap();
+
+        for (tile_size, cache) in [(1, 1), (2, 2), (3, 1), (16, 1)] {
+            let mut tiled_lhs =
+                TiledMatrix::from_matrix(&paths[0], &lhs, tile_size, cache).unwrap();
+            let mut tiled_rhs =
+                TiledMatrix::from_matrix(&paths[1], &rhs, tile_size, cache).unwrap();
+            let mut product = tiled_lhs.mat_mul(&mut tiled_rhs, &paths[2], cache).unwrap();
+            assert_eq!(product.shape(), Shape::new(7, 6));
+            assert_eq!(product.to_matrix().unwrap(), expected);
+        }
+
+        let mut tiled_lhs = TiledMatrix::from_matrix(&paths[0], &lhs, 2, 1).unwrap();
+        let mut tiled_rhs = TiledMatrix::from_matrix(&paths[1], &rhs, 3, 1).unwrap();
+        assert!(tiled_lhs.mat_mul(&mut tiled_rhs, &paths[2], 1).is_err());
+        let mut tiled_rhs = TiledMatrix::from_matrix(&paths[1], &matrix![[1.0]], 2, 1).unwrap();
+        let error = tiled_lhs.mat_mul(&mut tiled_rhs, &paths[2], 1).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        drop((tiled_lhs, tiled_rhs));
+        for path in paths {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
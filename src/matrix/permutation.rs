@@ -0,0 +1,422 @@
+//! This module provides permutations of rows and columns.
+
+use super::axis::Axis;
+use super::index::Index;
+use super::order::Order;
+use super::shape::{AxisShape, Shape};
+use super::Matrix;
+use crate::error::{Error, Result};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+/// A permutation matrix, stored as the permutation it represents.
+///
+/// Row `i` of the permutation matrix has its one in column
+/// `indices[i]`, so multiplying it on the left of a matrix moves row
+/// `indices[i]` to row `i`, and multiplying it on the right permutes
+/// columns by the inverse permutation. Neither materializes a dense
+/// matrix.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::{matrix, PermutationMatrix};
+///
+/// let p = PermutationMatrix::new(vec![2, 0, 1]).unwrap();
+/// let matrix = matrix![[0, 0], [1, 1], [2, 2]];
+///
+/// assert_eq!(&p * &matrix, matrix![[2, 2], [0, 0], [1, 1]]);
+/// assert_eq!(&p * &matrix, p.to_dense::<i32>() * &matrix);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PermutationMatrix {
+    indices: Vec<usize>,
+}
+
+impl PermutationMatrix {
+    /// Creates a new [`PermutationMatrix`] from a permutation of
+    /// `0..indices.len()`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if an index is out of bounds.
+    /// - [`Error::InvalidPermutation`] if an index appears more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{Error, Index, PermutationMatrix, Shape};
+    ///
+    /// let result = PermutationMatrix::new(vec![1, 0, 2]);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = PermutationMatrix::new(vec![1, 0, 3]);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(3, 0),
+    ///     shape: Shape::new(3, 1),
+    /// };
+    /// assert_eq!(result, Err(error));
+    ///
+    /// let result = PermutationMatrix::new(vec![1, 0, 1]);
+    /// assert_eq!(result, Err(Error::InvalidPermutation));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    /// [`Error::InvalidPermutation`]: crate::error::Error::InvalidPermutation
+    pub fn new(indices: Vec<usize>) -> Result<Self> {
+        check_permutation(&indices)?;
+        Ok(Self { indices })
+    }
+
+    /// Creates the identity permutation of the given size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::identity(3);
+    /// assert_eq!(p.indices(), &[0, 1, 2]);
+    /// ```
+    pub fn identity(size: usize) -> Self {
+        Self {
+            indices: (0..size).collect(),
+        }
+    }
+
+    /// Returns the number of rows, which equals the number of columns.
+    pub fn size(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns the permutation, where row `i` has its one in column
+    /// `indices()[i]`.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the inverse, which is also the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::PermutationMatrix;
+    ///
+    /// let p = PermutationMatrix::new(vec![2, 0, 1]).unwrap();
+    ///
+    /// assert_eq!(p.inverse().indices(), &[1, 2, 0]);
+    /// assert_eq!(&p * &p.inverse(), PermutationMatrix::identity(3));
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let mut indices = vec![0; self.size()];
+        for (i, &j) in self.indices.iter().enumerate() {
+            indices[j] = i;
+        }
+        Self { indices }
+    }
+
+    /// Materializes the permutation matrix as a dense row-major
+    /// [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, PermutationMatrix};
+    ///
+    /// let p = PermutationMatrix::new(vec![1, 0]).unwrap();
+    /// assert_eq!(p.to_dense::<f64>(), matrix![[0.0, 1.0], [1.0, 0.0]]);
+    /// ```
+    pub fn to_dense<T>(&self) -> Matrix<T>
+    where
+        T: From<bool>,
+    {
+        let order = Order::default();
+        let size = self.size();
+        let shape = AxisShape::from_shape_unchecked((size, size), order);
+        let data = self
+            .indices
+            .iter()
+            .flat_map(|&one| (0..size).map(move |col| T::from(col == one)))
+            .collect();
+        Matrix { order, shape, data }
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Permutes the rows of the matrix in place, moving row
+    /// `permutation[i]` to row `i`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if an index is out of bounds.
+    /// - [`Error::InvalidPermutation`] if an index appears more than
+    ///   once, or the length of `permutation` differs from the number of
+    ///   rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error};
+    ///
+    /// let mut matrix = matrix![[0, 0], [1, 1], [2, 2]];
+    ///
+    /// matrix.permute_rows(&[2, 0, 1]).unwrap();
+    /// assert_eq!(matrix, matrix![[2, 2], [0, 0], [1, 1]]);
+    ///
+    /// let result = matrix.permute_rows(&[0, 0, 1]);
+    /// assert_eq!(result, Err(Error::InvalidPermutation));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    /// [`Error::InvalidPermutation`]: crate::error::Error::InvalidPermutation
+    pub fn permute_rows(&mut self, permutation: &[usize]) -> Result<&mut Self> {
+        self.try_permute_vectors(Axis::Row, permutation)
+    }
+
+    /// Permutes the columns of the matrix in place, moving column
+    /// `permutation[j]` to column `j`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if an index is out of bounds.
+    /// - [`Error::InvalidPermutation`] if an index appears more than
+    ///   once, or the length of `permutation` differs from the number of
+    ///   columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Index, Shape};
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///
+    /// matrix.permute_cols(&[1, 2, 0]).unwrap();
+    /// assert_eq!(matrix, matrix![[1, 2, 0], [4, 5, 3]]);
+    ///
+    /// let result = matrix.permute_cols(&[0, 1, 3]);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(3, 0),
+    ///     shape: Shape::new(3, 1),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    /// [`Error::InvalidPermutation`]: crate::error::Error::InvalidPermutation
+    pub fn permute_cols(&mut self, permutation: &[usize]) -> Result<&mut Self> {
+        self.try_permute_vectors(Axis::Col, permutation)
+    }
+
+    fn try_permute_vectors(&mut self, axis: Axis, permutation: &[usize]) -> Result<&mut Self> {
+        check_permutation(permutation)?;
+        if permutation.len() != self.len_of(axis) {
+            return Err(Error::InvalidPermutation);
+        }
+        self.permute_vectors(axis, permutation);
+        Ok(self)
+    }
+}
+
+impl<T> Mul<Matrix<T>> for &PermutationMatrix {
+    type Output = Matrix<T>;
+
+    fn mul(self, mut rhs: Matrix<T>) -> Self::Output {
+        assert_conformable(self.size(), rhs.nrows());
+        rhs.permute_vectors(Axis::Row, &self.indices);
+        rhs
+    }
+}
+
+impl<T: Clone> Mul<&Matrix<T>> for &PermutationMatrix {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self * rhs.clone()
+    }
+}
+
+impl<T> Mul<&PermutationMatrix> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(mut self, rhs: &PermutationMatrix) -> Self::Output {
+        assert_conformable(self.ncols(), rhs.size());
+        self.permute_vectors(Axis::Col, &rhs.inverse().indices);
+        self
+    }
+}
+
+impl<T: Clone> Mul<&PermutationMatrix> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &PermutationMatrix) -> Self::Output {
+        self.clone() * rhs
+    }
+}
+
+impl Mul<&PermutationMatrix> for &PermutationMatrix {
+    type Output = PermutationMatrix;
+
+    fn mul(self, rhs: &PermutationMatrix) -> Self::Output {
+        assert_conformable(self.size(), rhs.size());
+        let indices = self.indices.iter().map(|&i| rhs.indices[i]).collect();
+        PermutationMatrix { indices }
+    }
+}
+
+fn assert_conformable(lhs_ncols: usize, rhs_nrows: usize) {
+    assert!(
+        lhs_ncols == rhs_nrows,
+        "shape not conformable for permutation matrix multiplication: \
+         {lhs_ncols} columns against {rhs_nrows} rows"
+    );
+}
+
+fn check_permutation(indices: &[usize]) -> Result<()> {
+    let mut seen = vec![false; indices.len()];
+    for &index in indices {
+        match seen.get_mut(index) {
+            None => {
+                return Err(Error::IndexOutOfBounds {
+                    index: Index::new(index, 0),
+                    shape: Shape::new(indices.len(), 1),
+                })
+            }
+            Some(true) => return Err(Error::InvalidPermutation),
+            Some(seen) => *seen = true,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_new() {
+        assert!(PermutationMatrix::new(vec![]).is_ok());
+        assert!(PermutationMatrix::new(vec![0]).is_ok());
+        assert!(PermutationMatrix::new(vec![3, 1, 0, 2]).is_ok());
+        assert_eq!(
+            PermutationMatrix::new(vec![1]),
+            Err(Error::IndexOutOfBounds {
+                index: Index::new(1, 0),
+                shape: Shape::new(1, 1)
+            })
+        );
+        assert_eq!(
+            PermutationMatrix::new(vec![2, 2, 0]),
+            Err(Error::InvalidPermutation)
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let p = PermutationMatrix::new(vec![3, 1, 0, 2]).unwrap();
+        let inverse = p.inverse();
+        assert_eq!(inverse.indices(), &[2, 1, 3, 0]);
+        assert_eq!(&p * &inverse, PermutationMatrix::identity(4));
+        assert_eq!(&inverse * &p, PermutationMatrix::identity(4));
+
+        let mut dense = p.to_dense::<i32>();
+        dense.transpose().set_order(Order::RowMajor);
+        assert_eq!(dense, inverse.to_dense::<i32>());
+    }
+
+    #[test]
+    fn test_permute_rows() {
+        let mut matrix = matrix![[0, 1], [2, 3], [4, 5]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.permute_rows(&[1, 2, 0]).unwrap();
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[2, 3], [4, 5], [0, 1]]);
+
+            let mut output = matrix.clone();
+            assert_eq!(output.permute_rows(&[0, 1]), Err(Error::InvalidPermutation));
+            assert_eq!(
+                output.permute_rows(&[0, 1, 2, 3]),
+                Err(Error::InvalidPermutation)
+            );
+            assert_eq!(
+                output.permute_rows(&[0, 1, 3]),
+                Err(Error::IndexOutOfBounds {
+                    index: Index::new(3, 0),
+                    shape: Shape::new(3, 1)
+                })
+            );
+            assert_eq!(
+                output.permute_rows(&[1, 1, 0]),
+                Err(Error::InvalidPermutation)
+            );
+            assert_eq!(output, matrix);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_permute_cols() {
+        let mut matrix = matrix![[0, 1, 2, 3], [4, 5, 6, 7]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.permute_cols(&[3, 0, 2, 1]).unwrap();
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[3, 0, 2, 1], [7, 4, 6, 5]]);
+
+            let mut output = matrix.clone();
+            assert_eq!(
+                output.permute_cols(&[0, 1, 2]),
+                Err(Error::InvalidPermutation)
+            );
+            assert_eq!(output, matrix);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_mul() {
+        let p = PermutationMatrix::new(vec![2, 0, 1]).unwrap();
+        let q = PermutationMatrix::new(vec![1, 2, 0]).unwrap();
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+
+        for _ in 0..2 {
+            let mut expected = p.to_dense::<i32>() * &matrix;
+            expected.set_order(matrix.order());
+            assert_eq!(&p * &matrix, expected);
+            assert_eq!(&p * matrix.clone(), expected);
+
+            let expected = &matrix * p.to_dense::<i32>();
+            assert_eq!(&matrix * &p, expected);
+            assert_eq!(matrix.clone() * &p, expected);
+
+            let expected = &p * (&q * &matrix);
+            assert_eq!(&(&p * &q) * &matrix, expected);
+
+            matrix.switch_order();
+        }
+
+        let composed = &p * &q;
+        assert_eq!(
+            composed.to_dense::<i32>(),
+            p.to_dense::<i32>() * q.to_dense::<i32>()
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "shape not conformable for permutation matrix multiplication: \
+                               2 columns against 1 rows"
+    )]
+    fn test_mul_fails() {
+        let p = PermutationMatrix::identity(2);
+        let _ = &p * matrix![[0, 1, 2]];
+    }
+}
@@ -0,0 +1,442 @@
+//! This module provides column-wise scaling transformers with fitted
+//! state, so the normalization learned on one matrix can be re-applied
+//! to others consistently, as well as one-off normalization.
+
+use super::axis::Axis;
+use super::real::Real;
+use super::shape::Shape;
+use super::statistics::{mean, variance};
+use super::Matrix;
+use crate::error::{Error, Result};
+
+/// The kind of scaling performed by a [`Scaler`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScalerKind {
+    /// Centers each column to zero mean and scales it to unit
+    /// (population) standard deviation.
+    Standard,
+
+    /// Maps each column onto the range `[0, 1]` using its minimum and
+    /// maximum.
+    MinMax,
+}
+
+/// The kind of normalization performed by [`Matrix::normalize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NormalizeKind {
+    /// Maps elements onto the range `[0, 1]` using their minimum and
+    /// maximum.
+    MinMax,
+
+    /// Centers elements to zero mean and scales them to unit
+    /// (population) standard deviation.
+    ZScore,
+}
+
+/// A column-wise scaler that maps each element `x` of column `j` to
+/// `(x - offsets[j]) / scales[j]`.
+///
+/// This struct is created by [`Scaler::fit`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scaler<T> {
+    kind: ScalerKind,
+    offsets: Vec<T>,
+    scales: Vec<T>,
+}
+
+impl<T> Scaler<T> {
+    /// Returns the kind of scaling performed.
+    pub fn kind(&self) -> ScalerKind {
+        self.kind
+    }
+
+    /// Returns the per-column offsets subtracted by [`Scaler::transform`].
+    pub fn offsets(&self) -> &[T] {
+        &self.offsets
+    }
+
+    /// Returns the per-column scales divided by [`Scaler::transform`].
+    pub fn scales(&self) -> &[T] {
+        &self.scales
+    }
+}
+
+macro_rules! impl_scaler {
+    ($($t:ty)*) => {
+        $(
+            impl Scaler<$t> {
+                /// Learns the per-column offsets and scales of `matrix`.
+                ///
+                /// # Notes
+                ///
+                /// Columns with zero spread, and all columns of a matrix
+                /// without rows, get a scale of one so that transforming
+                /// never divides by zero.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix, Scaler, ScalerKind};
+                ///
+                /// let train: Matrix<f64> = matrix![[0.0, 10.0], [2.0, 10.0], [4.0, 10.0]];
+                /// let scaler = Scaler::<f64>::fit(&train, ScalerKind::MinMax);
+                ///
+                /// assert_eq!(scaler.offsets(), &[0.0, 10.0]);
+                /// assert_eq!(scaler.scales(), &[4.0, 1.0]);
+                /// ```
+                pub fn fit(matrix: &Matrix<$t>, kind: ScalerKind) -> Self {
+                    fit(matrix, kind)
+                }
+
+                /// Applies the fitted scaling to `matrix`.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotConformable`] if the number of columns of
+                ///   `matrix` differs from that of the fitted matrix.
+                ///
+                /// # Notes
+                ///
+                /// The resulting matrix will always have the same order as
+                /// `matrix`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix, Scaler, ScalerKind};
+                ///
+                /// let train: Matrix<f64> = matrix![[1.0, 0.0], [3.0, 4.0]];
+                /// let scaler = Scaler::<f64>::fit(&train, ScalerKind::Standard);
+                ///
+                /// let test: Matrix<f64> = matrix![[2.0, 6.0]];
+                /// assert_eq!(scaler.transform(&test), Ok(matrix![[0.0, 2.0]]));
+                /// ```
+                ///
+                /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+                pub fn transform(&self, matrix: &Matrix<$t>) -> Result<Matrix<$t>> {
+                    apply(self, matrix, |x, offset, scale| (x - offset) / scale)
+                }
+
+                /// Reverts the fitted scaling on `matrix`.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::NotConformable`] if the number of columns of
+                ///   `matrix` differs from that of the fitted matrix.
+                ///
+                /// # Notes
+                ///
+                /// The resulting matrix will always have the same order as
+                /// `matrix`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix, Scaler, ScalerKind};
+                ///
+                /// let train: Matrix<f64> = matrix![[1.0, 0.0], [3.0, 4.0]];
+                /// let scaler = Scaler::<f64>::fit(&train, ScalerKind::MinMax);
+                ///
+                /// let scaled = scaler.transform(&train).unwrap();
+                /// assert_eq!(scaled, matrix![[0.0, 0.0], [1.0, 1.0]]);
+                /// assert_eq!(scaler.inverse_transform(&scaled), Ok(train));
+                /// ```
+                ///
+                /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+                pub fn inverse_transform(&self, matrix: &Matrix<$t>) -> Result<Matrix<$t>> {
+                    apply(self, matrix, |x, offset, scale| x * scale + offset)
+                }
+            }
+        )*
+    };
+}
+
+impl_scaler! {f32 f64}
+
+macro_rules! impl_normalize {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                /// Returns a copy of the matrix normalized as a whole.
+                ///
+                /// # Notes
+                ///
+                /// If all elements are equal, they are only shifted, so
+                /// the result is all zeros rather than NaN. The resulting
+                /// matrix will always have the same order as `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix, NormalizeKind};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 3.0], [3.0, 5.0]];
+                ///
+                /// let output = matrix.normalize(NormalizeKind::MinMax);
+                /// assert_eq!(output, matrix![[0.0, 0.5], [0.5, 1.0]]);
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 5.0], [1.0, 5.0]];
+                ///
+                /// let output = matrix.normalize(NormalizeKind::ZScore);
+                /// assert_eq!(output, matrix![[-1.0, 1.0], [-1.0, 1.0]]);
+                /// ```
+                pub fn normalize(&self, kind: NormalizeKind) -> Self {
+                    let (offset, scale) = offset_and_scale(kind, self.data.iter());
+                    let mut output = self.clone();
+                    output.data.iter_mut().for_each(|x| *x = (*x - offset) / scale);
+                    output
+                }
+
+                /// Returns a copy of the matrix with each vector along the
+                /// given axis normalized independently.
+                ///
+                /// # Notes
+                ///
+                /// Normalizing along [`Axis::Row`] normalizes each row,
+                /// matching the vectors reduced by [`Matrix::mean_axis`].
+                /// Vectors whose elements are all equal become zeros. The
+                /// resulting matrix will always have the same order as
+                /// `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Axis, Matrix, NormalizeKind};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[0.0, 5.0, 10.0], [2.0, 2.0, 2.0]];
+                ///
+                /// let output = matrix.normalize_axis(NormalizeKind::MinMax, Axis::Row);
+                /// assert_eq!(output, matrix![[0.0, 0.5, 1.0], [0.0, 0.0, 0.0]]);
+                ///
+                /// let output = matrix.normalize_axis(NormalizeKind::ZScore, Axis::Col);
+                /// assert_eq!(output, matrix![[-1.0, 1.0, 1.0], [1.0, -1.0, -1.0]]);
+                /// ```
+                pub fn normalize_axis(&self, kind: NormalizeKind, axis: Axis) -> Self {
+                    normalize_axis(self, kind, axis)
+                }
+            }
+        )*
+    };
+}
+
+impl_normalize! {f32 f64}
+
+fn apply<T, F>(scaler: &Scaler<T>, matrix: &Matrix<T>, op: F) -> Result<Matrix<T>>
+where
+    T: Real,
+    F: Fn(T, T, T) -> T,
+{
+    if matrix.ncols() != scaler.offsets.len() {
+        return Err(Error::not_conformable(
+            matrix.shape(),
+            Shape::new(1, scaler.offsets.len()),
+        ));
+    }
+    let mut output = matrix.clone();
+    let factors = scaler.offsets.iter().zip(&scaler.scales);
+    for (col, (&offset, &scale)) in output.iter_cols_mut().zip(factors) {
+        col.for_each(|x| *x = op(*x, offset, scale));
+    }
+    Ok(output)
+}
+
+fn fit<T: Real>(matrix: &Matrix<T>, kind: ScalerKind) -> Scaler<T> {
+    let ncols = matrix.ncols();
+    if matrix.nrows() == 0 {
+        return Scaler {
+            kind,
+            offsets: vec![T::ZERO; ncols],
+            scales: vec![T::ONE; ncols],
+        };
+    }
+    let normalize_kind = match kind {
+        ScalerKind::Standard => NormalizeKind::ZScore,
+        ScalerKind::MinMax => NormalizeKind::MinMax,
+    };
+    let (offsets, scales) = matrix
+        .iter_cols()
+        .map(|col| offset_and_scale(normalize_kind, col))
+        .unzip();
+    Scaler {
+        kind,
+        offsets,
+        scales,
+    }
+}
+
+fn normalize_axis<T: Real>(matrix: &Matrix<T>, kind: NormalizeKind, axis: Axis) -> Matrix<T> {
+    let factors: Vec<(T, T)> = matrix
+        .iter_axis(axis)
+        .map(|vector| offset_and_scale(kind, vector))
+        .collect();
+    let mut output = matrix.clone();
+    for (vector, &(offset, scale)) in output.iter_axis_mut(axis).zip(&factors) {
+        vector.for_each(|x| *x = (*x - offset) / scale);
+    }
+    output
+}
+
+/// Returns the offset to subtract from and the scale to divide the
+/// elements by, where a scale of one stands in for zero spread.
+fn offset_and_scale<'a, T, I>(kind: NormalizeKind, elements: I) -> (T, T)
+where
+    T: Real + 'a,
+    I: Iterator<Item = &'a T> + Clone,
+{
+    let (offset, spread) = match kind {
+        NormalizeKind::ZScore => (mean(elements.clone()), variance(elements).sqrt()),
+        NormalizeKind::MinMax => {
+            let mut elements = elements.copied();
+            let first = elements.next().unwrap_or(T::ZERO);
+            let (min, max) = elements.fold((first, first), |(min, max), x| {
+                (if x < min { x } else { min }, if x > max { x } else { max })
+            });
+            (min, max - min)
+        }
+    };
+    let scale = if spread > T::ZERO { spread } else { T::ONE };
+    (offset, scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::order::Order;
+
+    #[test]
+    fn test_standard_scaler() {
+        let mut train: Matrix<f64> = matrix![[1.0, 5.0, 2.0], [3.0, 5.0, 4.0], [5.0, 5.0, 9.0]];
+
+        for _ in 0..2 {
+            let scaler = Scaler::<f64>::fit(&train, ScalerKind::Standard);
+            assert_eq!(scaler.kind(), ScalerKind::Standard);
+            assert_eq!(scaler.offsets(), &[3.0, 5.0, 5.0]);
+            assert_eq!(scaler.scales()[1], 1.0);
+
+            let scaled = scaler.transform(&train).unwrap();
+            assert_eq!(scaled.order(), train.order());
+            for col in scaled.iter_cols() {
+                let mean: f64 = col.clone().sum::<f64>() / 3.0;
+                let variance: f64 = col.map(|x| (x - mean) * (x - mean)).sum::<f64>() / 3.0;
+                assert!(mean.abs() < 1e-12);
+                assert!(variance == 0.0 || (variance - 1.0).abs() < 1e-12);
+            }
+
+            let restored = scaler.inverse_transform(&scaled).unwrap();
+            for (x, y) in restored
+                .iter_rows()
+                .flatten()
+                .zip(train.iter_rows().flatten())
+            {
+                assert!((x - y).abs() < 1e-12);
+            }
+
+            train.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_min_max_scaler() {
+        let train: Matrix<f32> = matrix![[2.0, -1.0], [4.0, 1.0], [6.0, 0.0]];
+        let scaler = Scaler::<f32>::fit(&train, ScalerKind::MinMax);
+        assert_eq!(scaler.offsets(), &[2.0, -1.0]);
+        assert_eq!(scaler.scales(), &[4.0, 2.0]);
+
+        let mut test: Matrix<f32> = matrix![[0.0, 3.0], [4.0, 0.0]];
+        for _ in 0..2 {
+            let mut scaled = scaler.transform(&test).unwrap();
+            scaled.set_order(Order::RowMajor);
+            assert_eq!(scaled, matrix![[-0.5, 2.0], [0.5, 0.5]]);
+
+            test.switch_order();
+        }
+
+        assert_eq!(
+            scaler.transform(&matrix![[1.0, 2.0, 3.0]]),
+            Err(Error::NotConformable {
+                lhs: Shape::new(1, 3),
+                rhs: Shape::new(1, 2)
+            })
+        );
+        assert_eq!(
+            scaler.inverse_transform(&matrix![[1.0]]),
+            Err(Error::NotConformable {
+                lhs: Shape::new(1, 1),
+                rhs: Shape::new(1, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_fit_without_rows() {
+        let scaler = Scaler::<f64>::fit(&Matrix::<f64>::new((0, 2)), ScalerKind::Standard);
+        assert_eq!(scaler.offsets(), &[0.0, 0.0]);
+        assert_eq!(scaler.scales(), &[1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut matrix: Matrix<f64> = matrix![[2.0, 4.0], [6.0, 10.0]];
+
+        for _ in 0..2 {
+            let mut output = matrix.normalize(NormalizeKind::MinMax);
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0.0, 0.25], [0.5, 1.0]]);
+
+            let mut output = matrix.normalize(NormalizeKind::ZScore);
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            let expected = [-3.5, -1.5, 0.5, 4.5].map(|x: f64| x / 8.75_f64.sqrt());
+            for (x, y) in output.iter_elements().zip(expected) {
+                assert!((x - y).abs() < 1e-12);
+            }
+
+            matrix.switch_order();
+        }
+
+        let matrix: Matrix<f32> = matrix![[3.0, 3.0], [3.0, 3.0]];
+        assert_eq!(matrix.normalize(NormalizeKind::MinMax), Matrix::new((2, 2)));
+        assert_eq!(matrix.normalize(NormalizeKind::ZScore), Matrix::new((2, 2)));
+
+        let matrix = Matrix::<f64>::new((0, 3));
+        assert_eq!(matrix.normalize(NormalizeKind::ZScore), matrix);
+    }
+
+    #[test]
+    fn test_normalize_axis() {
+        let mut matrix: Matrix<f64> = matrix![[1.0, 3.0, 5.0], [4.0, 4.0, 0.0]];
+
+        for _ in 0..2 {
+            let mut output = matrix.normalize_axis(NormalizeKind::MinMax, Axis::Row);
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0.0, 0.5, 1.0], [1.0, 1.0, 0.0]]);
+
+            let mut output = matrix.normalize_axis(NormalizeKind::MinMax, Axis::Col);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0.0, 0.0, 1.0], [1.0, 1.0, 0.0]]);
+
+            let mut output = matrix.normalize_axis(NormalizeKind::ZScore, Axis::Col);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[-1.0, -1.0, 1.0], [1.0, 1.0, -1.0]]);
+
+            let output = matrix.normalize_axis(NormalizeKind::ZScore, Axis::Row);
+            for row in output.iter_rows() {
+                let row: Vec<f64> = row.copied().collect();
+                let center = row.iter().sum::<f64>() / 3.0;
+                let spread = row.iter().map(|x| x * x).sum::<f64>() / 3.0;
+                assert!(center.abs() < 1e-12);
+                assert!((spread - 1.0).abs() < 1e-12);
+            }
+
+            matrix.switch_order();
+        }
+
+        let matrix: Matrix<f32> = matrix![[1.0, 1.0], [2.0, 4.0]];
+        let mut output = matrix.normalize_axis(NormalizeKind::ZScore, Axis::Row);
+        output.set_order(Order::RowMajor);
+        assert_eq!(output, matrix![[0.0, 0.0], [-1.0, 1.0]]);
+    }
+}
@@ -0,0 +1,34 @@
+//! This module defines [`ProgressSink`], the hook through which
+//! long-running operations report their progress.
+
+/// A receiver of progress reports from long-running operations.
+///
+/// Any `FnMut(usize, usize)` closure is a [`ProgressSink`], so most
+/// callers never need to implement this trait by hand.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::matrix;
+///
+/// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+/// let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+///
+/// let mut reports = Vec::new();
+/// let result = lhs.mat_mul_with_progress(rhs, |done, total| reports.push((done, total)));
+/// assert_eq!(result, Ok(matrix![[10, 13], [28, 40]]));
+/// assert_eq!(reports, vec![(2, 4), (4, 4)]);
+/// ```
+pub trait ProgressSink {
+    /// Reports that `done` out of `total` units of work have completed.
+    fn report(&mut self, done: usize, total: usize);
+}
+
+impl<F> ProgressSink for F
+where
+    F: FnMut(usize, usize),
+{
+    fn report(&mut self, done: usize, total: usize) {
+        self(done, total)
+    }
+}
@@ -0,0 +1,147 @@
+use super::Matrix;
+use core::convert::Infallible;
+use ndarray::Array2;
+use numpy::{Element, PyArray2, PyArrayMethods};
+use pyo3::{Borrowed, Bound, CastError, FromPyObject, IntoPyObject, PyAny, Python};
+
+impl<'py, T: Element> IntoPyObject<'py> for Matrix<T> {
+    type Target = PyArray2<T>;
+    type Output = Bound<'py, PyArray2<T>>;
+    type Error = Infallible;
+
+    /// Converts a [`Matrix`] into a NumPy array, handing its storage
+    /// over to NumPy without copying.
+    ///
+    /// A row-major matrix becomes a C-contiguous array, and a
+    /// column-major matrix an F-contiguous one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if size exceeds [`isize::MAX`], which only matrices of
+    /// zero-sized types can reach.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use matreex::matrix;
+    /// use numpy::PyUntypedArrayMethods;
+    /// use pyo3::{IntoPyObject, Python};
+    ///
+    /// Python::attach(|py| {
+    ///     let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+    ///     let array = matrix.into_pyobject(py).unwrap();
+    ///     assert_eq!(array.shape(), [2, 3]);
+    ///     assert!(array.is_c_contiguous());
+    /// });
+    /// ```
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(PyArray2::from_owned_array(py, Array2::from(self)))
+    }
+}
+
+impl<'a, 'py, T: Element + Clone + 'a> FromPyObject<'a, 'py> for Matrix<T> {
+    type Error = CastError<'a, 'py>;
+
+    /// Extracts a [`Matrix`] from a two-dimensional NumPy array of
+    /// element type `T`.
+    ///
+    /// The elements are copied out of the Python heap, keeping the
+    /// memory order: an F-contiguous array becomes a column-major
+    /// matrix, and any other array a row-major one.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the object is not a `numpy.ndarray` with two dimensions
+    /// and element type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use matreex::{matrix, Matrix, Order};
+    /// use numpy::PyArray2;
+    /// use pyo3::prelude::*;
+    ///
+    /// Python::attach(|py| {
+    ///     let array = PyArray2::from_vec2(py, &[vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+    ///     let matrix: Matrix<i64> = array.extract().unwrap();
+    ///     assert_eq!(matrix.order(), Order::RowMajor);
+    ///     assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+    /// });
+    /// ```
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let array = obj.cast::<PyArray2<T>>()?;
+        Ok(Self::from(array.to_owned_array()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::order::Order;
+    use crate::matrix::shape::Shape;
+    use alloc::vec;
+    use numpy::{PyArray1, PyUntypedArrayMethods};
+    use pyo3::types::PyAnyMethods;
+
+    fn attach<R>(f: impl for<'py> FnOnce(Python<'py>) -> R) -> R {
+        Python::initialize();
+        Python::attach(f)
+    }
+
+    #[test]
+    fn test_into_pyarray2() {
+        attach(|py| {
+            let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+            matrix.set_order(Order::RowMajor);
+            let array = matrix.clone().into_pyobject(py).unwrap();
+            assert!(array.is_c_contiguous());
+            assert_eq!(array.readonly().as_array(), Array2::from(matrix.clone()));
+
+            matrix.set_order(Order::ColMajor);
+            let array = matrix.clone().into_pyobject(py).unwrap();
+            assert!(array.is_fortran_contiguous());
+            assert_eq!(array.readonly().as_array(), Array2::from(matrix.clone()));
+
+            let array = Matrix::<i32>::new((3, 0)).into_pyobject(py).unwrap();
+            assert_eq!(array.shape(), [3, 0]);
+        });
+    }
+
+    #[test]
+    fn test_from_pyarray2() {
+        attach(|py| {
+            let array = PyArray2::from_vec2(py, &[vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+            let matrix: Matrix<i32> = array.extract().unwrap();
+            assert_eq!(matrix.order(), Order::RowMajor);
+            assert_eq!(matrix, matrix![[0, 1, 2], [3, 4, 5]]);
+
+            let transposed = array.transpose().unwrap();
+            let matrix: Matrix<i32> = transposed.extract().unwrap();
+            assert_eq!(matrix.order(), Order::ColMajor);
+            assert_eq!(matrix[(0, 1)], 3);
+
+            let matrix: Matrix<i32> = PyArray2::<i32>::zeros(py, (0, 3), false).extract().unwrap();
+            assert_eq!(matrix.shape(), Shape::new(0, 3));
+
+            let mut expected = matrix![[0.0, 1.0], [2.0, 3.0]];
+            expected.set_order(Order::ColMajor);
+            let array = expected.clone().into_pyobject(py).unwrap();
+            assert_eq!(array.extract::<Matrix<f64>>().unwrap(), expected);
+        });
+    }
+
+    #[test]
+    fn test_from_pyarray2_fails() {
+        attach(|py| {
+            let array = PyArray2::from_vec2(py, &[vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+            assert!(array.extract::<Matrix<f64>>().is_err());
+
+            let array = PyArray1::from_vec(py, vec![0, 1, 2]);
+            assert!(array.extract::<Matrix<i32>>().is_err());
+
+            let list = vec![vec![0, 1, 2]].into_pyobject(py).unwrap();
+            assert!(list.extract::<Matrix<i32>>().is_err());
+        });
+    }
+}
@@ -0,0 +1,304 @@
+//! This module provides generators of structured random matrices for
+//! benchmarks and tests, available with the `rand` feature.
+
+use super::real::Real;
+use super::shape::ShapeLike;
+use super::Matrix;
+use crate::error::Result;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+impl<T> Matrix<T> {
+    /// Creates a new [`Matrix`] instance in which each element is
+    /// independently drawn from [`Standard`].
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Notes
+    ///
+    /// The elements are drawn in row-major order, so a seeded `rng`
+    /// always yields the same matrix, which makes this suitable for
+    /// reproducible benchmarks and tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::Matrix;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let lhs = Matrix::<f64>::random((3, 4), &mut StdRng::seed_from_u64(0)).unwrap();
+    /// let rhs = Matrix::<f64>::random((3, 4), &mut StdRng::seed_from_u64(0)).unwrap();
+    /// assert_eq!(lhs, rhs);
+    /// assert!(lhs.iter_elements().all(|&x| (0.0..1.0).contains(&x)));
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn random<S, R>(shape: S, rng: &mut R) -> Result<Self>
+    where
+        S: ShapeLike,
+        R: Rng + ?Sized,
+        T: Default,
+        Standard: Distribution<T>,
+    {
+        let mut matrix = Self::build(shape)?;
+        for element in matrix.iter_elements_mut() {
+            *element = rng.gen();
+        }
+        Ok(matrix)
+    }
+
+    /// Creates a new [`Matrix`] instance in which each element is
+    /// independently drawn from [`Standard`] with probability `density`,
+    /// and is `T::default()` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `density` is NaN. Other values outside `[0, 1]` are
+    /// clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::Matrix;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(0);
+    ///
+    /// let matrix = Matrix::<f64>::random_sparse((100, 100), 0.05, &mut rng).unwrap();
+    /// let nnz = matrix.iter_elements().filter(|&&x| x != 0.0).count();
+    /// assert!(nnz > 0 && nnz < 1000);
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn random_sparse<S, R>(shape: S, density: f64, rng: &mut R) -> Result<Self>
+    where
+        S: ShapeLike,
+        R: Rng + ?Sized,
+        T: Default,
+        Standard: Distribution<T>,
+    {
+        assert!(!density.is_nan(), "density is NaN");
+        let density = density.clamp(0.0, 1.0);
+        let mut matrix = Self::build(shape)?;
+        for element in matrix.iter_elements_mut() {
+            if rng.gen_bool(density) {
+                *element = rng.gen();
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Creates a new banded [`Matrix`] instance, in which elements with
+    /// at most `lower` subdiagonals below and `upper` superdiagonals
+    /// above the main diagonal are drawn from [`Standard`], and all
+    /// others are `T::default()`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::Matrix;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(0);
+    ///
+    /// // tridiagonal
+    /// let matrix = Matrix::<f64>::random_banded((4, 4), 1, 1, &mut rng).unwrap();
+    /// assert_eq!(matrix[(0, 2)], 0.0);
+    /// assert_eq!(matrix[(3, 1)], 0.0);
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn random_banded<S, R>(shape: S, lower: usize, upper: usize, rng: &mut R) -> Result<Self>
+    where
+        S: ShapeLike,
+        R: Rng + ?Sized,
+        T: Default,
+        Standard: Distribution<T>,
+    {
+        let mut matrix = Self::build(shape)?;
+        for (index, element) in matrix.iter_elements_mut_with_index() {
+            let (row, col) = (index.row, index.col);
+            if row <= col.saturating_add(lower) && col <= row.saturating_add(upper) {
+                *element = rng.gen();
+            }
+        }
+        Ok(matrix)
+    }
+}
+
+macro_rules! impl_random {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                /// Creates a new `size x size` strictly diagonally dominant
+                /// [`Matrix`] instance, which is guaranteed to be
+                /// nonsingular.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+                /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+                ///
+                /// # Notes
+                ///
+                /// Off-diagonal elements are drawn uniformly from `[-1, 1)`,
+                /// and each diagonal element exceeds the sum of absolute
+                /// values of the other elements in its row by one.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::Matrix;
+                /// use rand::rngs::StdRng;
+                /// use rand::SeedableRng;
+                ///
+                /// let mut rng = StdRng::seed_from_u64(0);
+                ///
+                /// let matrix = Matrix::<f64>::random_diagonally_dominant(3, &mut rng).unwrap();
+                /// assert!(matrix.inverse().is_ok());
+                /// ```
+                ///
+                /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+                /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+                pub fn random_diagonally_dominant<R>(size: usize, rng: &mut R) -> Result<Self>
+                where
+                    R: Rng + ?Sized,
+                {
+                    random_diagonally_dominant(size, rng)
+                }
+            }
+        )*
+    };
+}
+
+impl_random! {f32 f64}
+
+fn random_diagonally_dominant<T, R>(size: usize, rng: &mut R) -> Result<Matrix<T>>
+where
+    T: Real,
+    R: Rng + ?Sized,
+{
+    let mut matrix = Matrix::build((size, size))?;
+    for (index, element) in matrix.iter_elements_mut_with_index() {
+        if index.row != index.col {
+            *element = T::from_f64(rng.gen_range(-1.0..1.0));
+        }
+    }
+    for n in 0..size {
+        let sum = (0..size)
+            .filter(|&col| col != n)
+            .fold(T::ZERO, |acc, col| acc + matrix[(n, col)].abs());
+        matrix[(n, n)] = sum + T::ONE;
+    }
+    Ok(matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_random() {
+        let lhs = Matrix::<f64>::random((20, 30), &mut StdRng::seed_from_u64(1)).unwrap();
+        let mut rhs = Matrix::<f64>::random((20, 30), &mut StdRng::seed_from_u64(1)).unwrap();
+        assert_eq!(lhs, rhs);
+        assert!(lhs.iter_elements().all(|&x| (0.0..1.0).contains(&x)));
+
+        rhs = Matrix::<f64>::random((20, 30), &mut StdRng::seed_from_u64(2)).unwrap();
+        assert_ne!(lhs, rhs);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let result = Matrix::<u8>::random((usize::MAX, 2), &mut rng);
+        assert_eq!(result, Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    fn test_random_sparse() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let matrix = Matrix::<f64>::random_sparse((50, 40), 0.1, &mut rng).unwrap();
+        assert_eq!(matrix.shape().nrows, 50);
+        assert_eq!(matrix.shape().ncols, 40);
+        let nnz = matrix.iter_elements().filter(|&&x| x != 0.0).count();
+        assert!(nnz > 100 && nnz < 300, "nnz = {nnz}");
+
+        let matrix = Matrix::<f64>::random_sparse((5, 5), -1.0, &mut rng).unwrap();
+        assert_eq!(matrix, Matrix::new((5, 5)));
+
+        let matrix = Matrix::<u8>::random_sparse((5, 5), 2.0, &mut rng).unwrap();
+        assert_eq!(matrix.size(), 25);
+
+        let result = Matrix::<f64>::random_sparse((usize::MAX, 2), 0.1, &mut rng);
+        assert_eq!(result, Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_sparse_nan() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let _ = Matrix::<f64>::random_sparse((2, 2), f64::NAN, &mut rng);
+    }
+
+    #[test]
+    fn test_random_banded() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let matrix = Matrix::<f64>::random_banded((6, 8), 2, 1, &mut rng).unwrap();
+        for (index, &element) in matrix.iter_elements_with_index() {
+            let (row, col) = (index.row, index.col);
+            if row > col + 2 || col > row + 1 {
+                assert_eq!(element, 0.0);
+            } else {
+                assert!(element > 0.0 && element < 1.0);
+            }
+        }
+
+        let matrix = Matrix::<f64>::random_banded((3, 3), 0, 0, &mut rng).unwrap();
+        assert_eq!(matrix[(0, 1)], 0.0);
+        assert!(matrix[(1, 1)] > 0.0);
+
+        let matrix = Matrix::<f64>::random_banded((3, 3), usize::MAX, usize::MAX, &mut rng);
+        assert!(matrix.unwrap().iter_elements().all(|&x| x > 0.0));
+    }
+
+    #[test]
+    fn test_random_diagonally_dominant() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let matrix = Matrix::<f64>::random_diagonally_dominant(10, &mut rng).unwrap();
+        for row in 0..10 {
+            let off_diagonal: f64 = (0..10)
+                .filter(|&col| col != row)
+                .map(|col| matrix[(row, col)].abs())
+                .sum();
+            assert!(off_diagonal < 10.0);
+            assert!(matrix[(row, row)] > off_diagonal);
+        }
+        assert!(matrix.inverse().is_ok());
+
+        let matrix = Matrix::<f32>::random_diagonally_dominant(0, &mut rng).unwrap();
+        assert!(matrix.is_empty());
+    }
+}
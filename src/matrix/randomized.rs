@@ -0,0 +1,465 @@
+//! This module provides randomized algorithms for approximating large
+//! matrices, available with the `rand` feature.
+
+use super::order::Order;
+use super::real::Real;
+use super::shape::{AxisShape, Shape};
+use super::Matrix;
+use crate::error::Result;
+use rand::Rng;
+
+/// Maximum number of sweeps performed by the one-sided Jacobi method.
+const MAX_SWEEPS: usize = 64;
+
+/// A truncated singular value decomposition `U * diag(s) * Vt`.
+///
+/// This struct is created by [`Matrix::randomized_svd`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Svd<T> {
+    /// Left singular vectors, one per column.
+    pub u: Matrix<T>,
+
+    /// Singular values in descending order.
+    pub s: Vec<T>,
+
+    /// Right singular vectors, one per row.
+    pub vt: Matrix<T>,
+}
+
+macro_rules! impl_randomized {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                /// Computes an approximate truncated singular value
+                /// decomposition with the randomized range finder.
+                ///
+                /// # Notes
+                ///
+                /// The range of the matrix is sampled with
+                /// `rank + oversampling` Gaussian test vectors, refined by
+                /// `n_iter` power iterations, and the singular value
+                /// decomposition of the projected matrix is then computed
+                /// exactly. Larger `oversampling` and `n_iter` improve
+                /// accuracy at the cost of extra passes over the matrix.
+                ///
+                /// `rank` is clamped to the smaller dimension of the matrix.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                /// use rand::rngs::StdRng;
+                /// use rand::SeedableRng;
+                ///
+                /// let matrix: Matrix<f64> = matrix![[3.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 1.0]];
+                /// let mut rng = StdRng::seed_from_u64(0);
+                ///
+                /// let svd = matrix.randomized_svd(2, 1, 2, &mut rng);
+                /// assert_eq!(svd.u.shape().nrows, 3);
+                /// assert_eq!(svd.u.shape().ncols, 2);
+                /// assert!((svd.s[0] - 3.0).abs() < 1e-6);
+                /// assert!((svd.s[1] - 2.0).abs() < 1e-6);
+                /// assert_eq!(svd.vt.shape().nrows, 2);
+                /// assert_eq!(svd.vt.shape().ncols, 3);
+                /// ```
+                pub fn randomized_svd<R>(
+                    &self,
+                    rank: usize,
+                    oversampling: usize,
+                    n_iter: usize,
+                    rng: &mut R,
+                ) -> Svd<$t>
+                where
+                    R: Rng + ?Sized,
+                {
+                    randomized_svd(self, rank, oversampling, n_iter, rng)
+                }
+
+                /// Projects each row of the matrix onto `target_dim`
+                /// dimensions with a Johnson-Lindenstrauss random projection.
+                ///
+                /// # Errors
+                ///
+                /// - [`Error::SizeOverflow`] if the size of the result
+                ///   or of the projection matrix exceeds [`usize::MAX`].
+                /// - [`Error::CapacityExceeded`] if required capacity in bytes
+                ///   exceeds [`isize::MAX`].
+                ///
+                /// # Notes
+                ///
+                /// The matrix is multiplied by an `ncols x target_dim` matrix
+                /// of independent random signs scaled by `1 / sqrt(target_dim)`,
+                /// which approximately preserves pairwise distances between
+                /// rows. The result is an `nrows x target_dim` matrix.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                /// use rand::rngs::StdRng;
+                /// use rand::SeedableRng;
+                ///
+                /// let matrix: Matrix<f64> = matrix![[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0]];
+                /// let mut rng = StdRng::seed_from_u64(0);
+                ///
+                /// let sketch = matrix.sketch(2, &mut rng).unwrap();
+                /// assert_eq!(sketch.nrows(), 2);
+                /// assert_eq!(sketch.ncols(), 2);
+                /// ```
+                ///
+                /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+                /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+                pub fn sketch<R>(&self, target_dim: usize, rng: &mut R) -> Result<Matrix<$t>>
+                where
+                    R: Rng + ?Sized,
+                {
+                    sketch(self, target_dim, rng)
+                }
+            }
+        )*
+    };
+}
+
+impl_randomized! {f32 f64}
+
+fn randomized_svd<T, R>(
+    matrix: &Matrix<T>,
+    rank: usize,
+    oversampling: usize,
+    n_iter: usize,
+    rng: &mut R,
+) -> Svd<T>
+where
+    T: Real,
+    R: Rng + ?Sized,
+{
+    let nrows = matrix.nrows();
+    let ncols = matrix.ncols();
+    let limit = nrows.min(ncols);
+    let rank = rank.min(limit);
+    let nsamples = rank.saturating_add(oversampling).min(limit);
+
+    // The product of two dimensions of `matrix` is bounded by its size,
+    // so none of the following allocations can overflow.
+    let omega = random_matrix(Shape::new(ncols, nsamples), rng, gaussian);
+    let mut q = orthonormalize(multiply(matrix, &omega));
+    if n_iter > 0 {
+        let mut transposed = matrix.clone();
+        transposed.transpose();
+        for _ in 0..n_iter {
+            let z = orthonormalize(multiply(&transposed, &q));
+            q = orthonormalize(multiply(matrix, &z));
+        }
+    }
+
+    let mut qt = q.clone();
+    qt.transpose();
+    let mut b = multiply(&qt, matrix);
+    b.set_order(Order::RowMajor);
+
+    let (sigma, mut j) = one_sided_jacobi(&mut b.data, nsamples, ncols);
+
+    let mut indices: Vec<usize> = (0..nsamples).collect();
+    // Sort in descending order, with NaN, which only arises from
+    // non-finite input, placed last.
+    indices.sort_by(|&x, &y| {
+        let (x, y) = (sigma[x], sigma[y]);
+        y.partial_cmp(&x)
+            .unwrap_or_else(|| x.is_nan().cmp(&y.is_nan()))
+    });
+    indices.truncate(rank);
+
+    // Columns of `j` are left singular vectors of `b`.
+    j.set_order(Order::ColMajor);
+    let mut data = Vec::with_capacity(nsamples * rank);
+    for &index in &indices {
+        data.extend_from_slice(&j.data[index * nsamples..(index + 1) * nsamples]);
+    }
+    let order = Order::ColMajor;
+    let shape = AxisShape::from_shape_unchecked(Shape::new(nsamples, rank), order);
+    let u = multiply(&q, &Matrix { order, shape, data });
+
+    // Rows of `b` have become right singular vectors scaled by `sigma`.
+    let mut data = Vec::with_capacity(rank * ncols);
+    for &index in &indices {
+        let row = &b.data[index * ncols..(index + 1) * ncols];
+        let scale = sigma[index];
+        if scale > T::ZERO {
+            data.extend(row.iter().map(|&x| x / scale));
+        } else {
+            data.extend(row.iter().map(|_| T::ZERO));
+        }
+    }
+    let order = Order::RowMajor;
+    let shape = AxisShape::from_shape_unchecked(Shape::new(rank, ncols), order);
+    let vt = Matrix { order, shape, data };
+
+    let s = indices.iter().map(|&index| sigma[index]).collect();
+
+    Svd { u, s, vt }
+}
+
+fn sketch<T, R>(matrix: &Matrix<T>, target_dim: usize, rng: &mut R) -> Result<Matrix<T>>
+where
+    T: Real,
+    R: Rng + ?Sized,
+{
+    let output_shape = Shape::new(matrix.nrows(), target_dim);
+    Matrix::<T>::check_size(AxisShape::try_from_shape(output_shape, Order::default())?.size())?;
+    let shape = Shape::new(matrix.ncols(), target_dim);
+    Matrix::<T>::check_size(AxisShape::try_from_shape(shape, Order::default())?.size())?;
+
+    let scale = T::ONE / T::from_f64(target_dim.max(1) as f64).sqrt();
+    let projection = random_matrix(
+        shape,
+        rng,
+        |rng| {
+            if rng.gen::<bool>() {
+                scale
+            } else {
+                -scale
+            }
+        },
+    );
+    Ok(multiply(matrix, &projection))
+}
+
+fn multiply<T: Real>(lhs: &Matrix<T>, rhs: &Matrix<T>) -> Matrix<T> {
+    match lhs.checked_mul(rhs) {
+        Err(error) => panic!("{error}"),
+        Ok(output) => output,
+    }
+}
+
+fn random_matrix<T, R, F>(shape: Shape, rng: &mut R, mut sample: F) -> Matrix<T>
+where
+    R: Rng + ?Sized,
+    F: FnMut(&mut R) -> T,
+{
+    let order = Order::default();
+    let shape = AxisShape::from_shape_unchecked(shape, order);
+    let data = (0..shape.size()).map(|_| sample(rng)).collect();
+    Matrix { order, shape, data }
+}
+
+/// Samples from the standard normal distribution with the Box-Muller
+/// transform.
+fn gaussian<T: Real, R: Rng + ?Sized>(rng: &mut R) -> T {
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    let value = (-2.0 * u1.ln()).sqrt() * (core::f64::consts::TAU * u2).cos();
+    T::from_f64(value)
+}
+
+/// Orthonormalizes the columns of `matrix` with the modified Gram-Schmidt
+/// process, applied twice for numerical stability.
+///
+/// Columns that are linearly dependent on the previous ones are zeroed.
+fn orthonormalize<T: Real>(mut matrix: Matrix<T>) -> Matrix<T> {
+    matrix.set_order(Order::ColMajor);
+    let len = matrix.nrows();
+    let ncols = matrix.ncols();
+    if len == 0 {
+        return matrix;
+    }
+    let columns = &mut matrix.data;
+    for col in 0..ncols {
+        let original = norm(&columns[col * len..(col + 1) * len]);
+        for _ in 0..2 {
+            for prev in 0..col {
+                let (head, tail) = columns.split_at_mut(col * len);
+                let prev = &head[prev * len..(prev + 1) * len];
+                let current = &mut tail[..len];
+                let projection = dot(prev, current);
+                for (x, &p) in current.iter_mut().zip(prev) {
+                    *x = *x - projection * p;
+                }
+            }
+        }
+        let current = &mut columns[col * len..(col + 1) * len];
+        let remaining = norm(current);
+        let threshold = T::from_f64(len as f64) * T::EPSILON * original;
+        if remaining > threshold && remaining > T::ZERO {
+            current.iter_mut().for_each(|x| *x = *x / remaining);
+        } else {
+            current.iter_mut().for_each(|x| *x = T::ZERO);
+        }
+    }
+    matrix
+}
+
+/// Orthogonalizes the `count` rows of length `len` stored in `rows` with
+/// the one-sided Jacobi method.
+///
+/// Returns the norms of the resulting rows together with the accumulated
+/// rotation, so that the original rows equal `rotation * rows`.
+fn one_sided_jacobi<T: Real>(rows: &mut [T], count: usize, len: usize) -> (Vec<T>, Matrix<T>) {
+    let order = Order::RowMajor;
+    let shape = AxisShape::from_shape_unchecked(Shape::new(count, count), order);
+    let mut data = vec![T::ZERO; count * count];
+    for i in 0..count {
+        data[i * count + i] = T::ONE;
+    }
+    let mut rotation = Matrix { order, shape, data };
+
+    for _ in 0..MAX_SWEEPS {
+        let mut converged = true;
+        for p in 0..count {
+            for q in p + 1..count {
+                let (head, tail) = rows.split_at_mut(q * len);
+                let row_p = &mut head[p * len..(p + 1) * len];
+                let row_q = &mut tail[..len];
+                let alpha = dot(row_p, row_p);
+                let beta = dot(row_q, row_q);
+                let gamma = dot(row_p, row_q);
+                if gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                converged = false;
+
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let sign = if zeta < T::ZERO { -T::ONE } else { T::ONE };
+                let t = sign / (zeta.abs() + (T::ONE + zeta * zeta).sqrt());
+                let c = T::ONE / (T::ONE + t * t).sqrt();
+                let s = c * t;
+
+                for (x, y) in row_p.iter_mut().zip(row_q.iter_mut()) {
+                    let (xp, yq) = (*x, *y);
+                    *x = c * xp - s * yq;
+                    *y = s * xp + c * yq;
+                }
+                for i in 0..count {
+                    let xp = rotation.data[i * count + p];
+                    let yq = rotation.data[i * count + q];
+                    rotation.data[i * count + p] = c * xp - s * yq;
+                    rotation.data[i * count + q] = s * xp + c * yq;
+                }
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+
+    let sigma = (0..count)
+        .map(|i| norm(&rows[i * len..(i + 1) * len]))
+        .collect();
+    (sigma, rotation)
+}
+
+fn dot<T: Real>(lhs: &[T], rhs: &[T]) -> T {
+    lhs.iter()
+        .zip(rhs)
+        .fold(T::ZERO, |acc, (&x, &y)| acc + x * y)
+}
+
+fn norm<T: Real>(vector: &[T]) -> T {
+    dot(vector, vector).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn reconstruct(svd: &Svd<f64>) -> Matrix<f64> {
+        let mut us = svd.u.clone();
+        for (col, &s) in svd.s.iter().enumerate() {
+            for element in us.iter_nth_col_mut(col).unwrap() {
+                *element *= s;
+            }
+        }
+        us.checked_mul(&svd.vt).unwrap()
+    }
+
+    fn assert_close(lhs: &Matrix<f64>, rhs: &Matrix<f64>, tolerance: f64) {
+        assert_eq!(lhs.shape(), rhs.shape());
+        for (index, x) in lhs.iter_elements_with_index() {
+            let y = rhs[index];
+            assert!((x - y).abs() < tolerance, "{x} != {y} at {index}");
+        }
+    }
+
+    #[test]
+    fn test_randomized_svd() {
+        // rank 2, 4 x 3
+        let mut matrix: Matrix<f64> = matrix![
+            [1.0, 2.0, 3.0],
+            [2.0, 4.0, 6.0],
+            [1.0, 0.0, 1.0],
+            [3.0, 4.0, 7.0]
+        ];
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..2 {
+            let svd = matrix.randomized_svd(2, 1, 2, &mut rng);
+            assert_eq!(svd.u.shape(), Shape::new(4, 2));
+            assert_eq!(svd.s.len(), 2);
+            assert_eq!(svd.vt.shape(), Shape::new(2, 3));
+            assert!(svd.s[0] >= svd.s[1]);
+            assert_close(&reconstruct(&svd), &matrix, 1e-9);
+
+            let utu = {
+                let mut ut = svd.u.clone();
+                ut.transpose();
+                ut.checked_mul(&svd.u).unwrap()
+            };
+            assert_close(&utu, &matrix![[1.0, 0.0], [0.0, 1.0]], 1e-9);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_randomized_svd_clamps_rank() {
+        let matrix: Matrix<f64> = matrix![[2.0, 0.0], [0.0, 1.0]];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let svd = matrix.randomized_svd(5, 5, 0, &mut rng);
+        assert_eq!(svd.s.len(), 2);
+        assert!((svd.s[0] - 2.0).abs() < 1e-12);
+        assert!((svd.s[1] - 1.0).abs() < 1e-12);
+        assert_close(&reconstruct(&svd), &matrix, 1e-12);
+
+        let matrix = Matrix::<f64>::new((3, 0));
+        let svd = matrix.randomized_svd(2, 2, 1, &mut rng);
+        assert_eq!(svd.u.shape(), Shape::new(3, 0));
+        assert!(svd.s.is_empty());
+        assert_eq!(svd.vt.shape(), Shape::new(0, 0));
+    }
+
+    #[test]
+    fn test_randomized_svd_non_finite() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0], [4.0, value, 6.0], [7.0, 8.0, 9.0]];
+            let svd = matrix.randomized_svd(2, 1, 1, &mut rng);
+            assert_eq!(svd.u.shape(), Shape::new(3, 2));
+            assert_eq!(svd.s.len(), 2);
+            assert_eq!(svd.vt.shape(), Shape::new(2, 3));
+        }
+    }
+
+    #[test]
+    fn test_sketch() {
+        let matrix = Matrix::<f32>::new((3, 100));
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let sketch = matrix.sketch(10, &mut rng).unwrap();
+        assert_eq!(sketch.shape(), Shape::new(3, 10));
+
+        let row: Matrix<f32> = matrix![[1.0; 64]];
+        let sketch = row.sketch(4096, &mut rng).unwrap();
+        let original = 64.0f32;
+        let projected: f32 = sketch.iter_elements().map(|x| x * x).sum();
+        assert!((projected / original - 1.0).abs() < 0.1);
+
+        assert_eq!(
+            matrix.sketch(usize::MAX, &mut rng),
+            Err(crate::error::Error::SizeOverflow)
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Floating-point operations required by numeric algorithms.
+pub(super) trait Real:
+    Copy
+    + Default
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const EPSILON: Self;
+
+    fn sqrt(self) -> Self;
+
+    fn abs(self) -> Self;
+
+    fn is_finite(self) -> bool;
+
+    #[cfg(feature = "rand")]
+    fn is_nan(self) -> bool;
+
+    fn from_f64(value: f64) -> Self;
+
+    #[cfg(feature = "rand")]
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_real {
+    ($($t:ty)*) => {
+        $(
+            impl Real for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const EPSILON: Self = <$t>::EPSILON;
+
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+
+                #[cfg(feature = "rand")]
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                #[cfg(feature = "rand")]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_real! {f32 f64}
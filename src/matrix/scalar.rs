@@ -0,0 +1,67 @@
+/// Any type that implements this trait can be used as the `scalar`
+/// argument of [`Matrix::scalar_operation`] and its variants.
+///
+/// It is implemented for references to any type, and for primitive
+/// numeric types by value.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::matrix;
+///
+/// let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+/// let scalar = 2;
+///
+/// let foo = matrix.scalar_operation(&scalar, |x, y| x + y);
+/// let bar = matrix.scalar_operation(2, |x, y| x + y);
+/// assert_eq!(foo, bar);
+/// ```
+///
+/// [`Matrix::scalar_operation`]: crate::matrix::Matrix::scalar_operation
+pub trait ScalarLike {
+    /// The type of the scalar passed to the operation.
+    type Scalar;
+
+    /// Returns a reference to the scalar.
+    fn as_scalar(&self) -> &Self::Scalar;
+}
+
+impl<S> ScalarLike for &S {
+    type Scalar = S;
+
+    fn as_scalar(&self) -> &S {
+        self
+    }
+}
+
+macro_rules! impl_scalar_like {
+    ($($t:ty)*) => {
+        $(
+            impl ScalarLike for $t {
+                type Scalar = $t;
+
+                fn as_scalar(&self) -> &$t {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_like! {bool char u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn test_trait_scalar_like() {
+        let scalar = 2.5;
+        assert_eq!(<&f64>::as_scalar(&&scalar), &2.5);
+        assert_eq!(f64::as_scalar(&scalar), &2.5);
+
+        let scalar = String::from("foo");
+        assert_eq!(<&String>::as_scalar(&&scalar), "foo");
+    }
+}
@@ -12,6 +12,10 @@ use crate::error::{Error, Result};
 /// let foo = Matrix::<i32>::new(Shape::new(2, 3));
 /// let bar = Matrix::<i32>::new((2, 3));
 /// let baz = Matrix::<i32>::new([2, 3]);
+///
+/// // a single `usize` stands for a square shape
+/// let qux = Matrix::<i32>::new(3);
+/// assert_eq!(qux.shape(), Shape::new(3, 3));
 /// ```
 ///
 /// [`Matrix`]: crate::matrix::Matrix
@@ -64,10 +68,59 @@ impl Shape {
     pub fn new(nrows: usize, ncols: usize) -> Self {
         Self { nrows, ncols }
     }
+
+    /// Creates a new [`Shape`] instance of `n` rows and `n` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::Shape;
+    ///
+    /// assert_eq!(Shape::square(3), Shape::new(3, 3));
+    /// ```
+    pub fn square(n: usize) -> Self {
+        Self::new(n, n)
+    }
+
+    /// Returns the shape with the number of rows and columns swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::Shape;
+    ///
+    /// assert_eq!(Shape::new(2, 3).transposed(), Shape::new(3, 2));
+    /// ```
+    pub fn transposed(self) -> Self {
+        Self::new(self.ncols, self.nrows)
+    }
+
+    /// Returns the size of the shape.
+    ///
+    /// This is the same as [`ShapeLike::size`], without needing the
+    /// trait in scope.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{Error, Shape};
+    ///
+    /// assert_eq!(Shape::new(2, 3).size_checked(), Ok(6));
+    /// assert_eq!(Shape::new(2, usize::MAX).size_checked(), Err(Error::SizeOverflow));
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    pub fn size_checked(&self) -> Result<usize> {
+        ShapeLike::size(self)
+    }
 }
 
-impl std::fmt::Display for Shape {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Shape {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "({}, {})", self.nrows, self.ncols)
     }
 }
@@ -102,6 +155,39 @@ impl ShapeLike for [usize; 2] {
     }
 }
 
+/// A single `usize` stands for a square shape.
+impl ShapeLike for usize {
+    fn nrows(&self) -> usize {
+        *self
+    }
+
+    fn ncols(&self) -> usize {
+        *self
+    }
+}
+
+/// A single-element tuple stands for a square shape.
+impl ShapeLike for (usize,) {
+    fn nrows(&self) -> usize {
+        self.0
+    }
+
+    fn ncols(&self) -> usize {
+        self.0
+    }
+}
+
+/// A single-element array stands for a square shape.
+impl ShapeLike for [usize; 1] {
+    fn nrows(&self) -> usize {
+        self[0]
+    }
+
+    fn ncols(&self) -> usize {
+        self[0]
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub(super) struct AxisShape {
     major: usize,
@@ -173,6 +259,7 @@ impl AxisShape {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn test_trait_shape_like() {
@@ -190,6 +277,32 @@ mod tests {
         assert_eq!([2, 3].ncols(), 3);
         assert_eq!([2, 3].size(), Ok(6));
         assert_eq!([2, usize::MAX].size(), Err(Error::SizeOverflow));
+
+        assert_eq!(3.nrows(), 3);
+        assert_eq!(3.ncols(), 3);
+        assert_eq!(3.size(), Ok(9));
+        assert_eq!(usize::MAX.size(), Err(Error::SizeOverflow));
+
+        assert_eq!((3,).nrows(), 3);
+        assert_eq!((3,).ncols(), 3);
+        assert_eq!((3,).size(), Ok(9));
+
+        assert_eq!([3].nrows(), 3);
+        assert_eq!([3].ncols(), 3);
+        assert_eq!([3].size(), Ok(9));
+    }
+
+    #[test]
+    fn test_struct_shape_helpers() {
+        assert_eq!(Shape::square(3), Shape::new(3, 3));
+        assert_eq!(Shape::square(0), Shape::default());
+        assert_eq!(Shape::new(2, 3).transposed(), Shape::new(3, 2));
+        assert_eq!(Shape::new(2, 3).transposed().transposed(), Shape::new(2, 3));
+        assert_eq!(Shape::new(2, 3).size_checked(), Ok(6));
+        assert_eq!(
+            Shape::new(usize::MAX, 2).size_checked(),
+            Err(Error::SizeOverflow)
+        );
     }
 
     #[test]
@@ -0,0 +1,616 @@
+//! This module provides [`SharedMatrix`], a cheaply cloneable read-only
+//! handle that can be sent across threads.
+
+use super::axis::Axis;
+use super::iter::{StrideIter, VectorLayout};
+use super::Matrix;
+use crate::error::{Error, Result};
+use alloc::sync::Arc;
+use core::iter::FusedIterator;
+use core::ops::Deref;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// A thread-safe, read-only handle to a [`Matrix`].
+///
+/// Cloning a [`SharedMatrix`] only bumps a reference count, so a large
+/// matrix can be handed to many worker threads without copying it. All
+/// read-only methods of [`Matrix`] are available through [`Deref`].
+///
+/// # Examples
+///
+/// ```
+/// use matreex::matrix;
+/// use std::thread;
+///
+/// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+///
+/// let handles: Vec<_> = shared
+///     .rows()
+///     .map(|row| thread::spawn(move || row.iter().sum::<i32>()))
+///     .collect();
+/// let sums: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+/// assert_eq!(sums, vec![3, 12]);
+/// ```
+#[derive(Debug)]
+pub struct SharedMatrix<T>(Arc<Matrix<T>>);
+
+impl<T> SharedMatrix<T> {
+    /// Creates a new [`SharedMatrix`] instance from `matrix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, SharedMatrix};
+    ///
+    /// let shared = SharedMatrix::new(matrix![[0, 1, 2], [3, 4, 5]]);
+    /// assert_eq!(*shared, matrix![[0, 1, 2], [3, 4, 5]]);
+    /// ```
+    pub fn new(matrix: Matrix<T>) -> Self {
+        Self(Arc::new(matrix))
+    }
+
+    /// Returns `true` if both handles point to the same matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, SharedMatrix};
+    ///
+    /// let shared = SharedMatrix::new(matrix![[0, 1, 2], [3, 4, 5]]);
+    /// let other = SharedMatrix::new(matrix![[0, 1, 2], [3, 4, 5]]);
+    ///
+    /// assert!(SharedMatrix::ptr_eq(&shared, &shared.clone()));
+    /// assert!(!SharedMatrix::ptr_eq(&shared, &other));
+    /// ```
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+
+    /// Returns the inner matrix if this is the only handle to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the handle itself if other handles or views still exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, SharedMatrix};
+    ///
+    /// let shared = SharedMatrix::new(matrix![[0, 1, 2], [3, 4, 5]]);
+    /// let row = shared.row(0).unwrap();
+    ///
+    /// let shared = SharedMatrix::try_unwrap(shared).unwrap_err();
+    /// drop(row);
+    /// assert_eq!(
+    ///     SharedMatrix::try_unwrap(shared),
+    ///     Ok(matrix![[0, 1, 2], [3, 4, 5]])
+    /// );
+    /// ```
+    pub fn try_unwrap(this: Self) -> core::result::Result<Matrix<T>, Self> {
+        Arc::try_unwrap(this.0).map_err(Self)
+    }
+
+    /// Returns a view of the nth vector along the given axis.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is greater than or equal to
+    ///   the length of the matrix along `axis`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let row = shared.vector(Axis::Row, 1).unwrap();
+    /// assert!(row.iter().eq(&[3, 4, 5]));
+    ///
+    /// let col = shared.vector(Axis::Col, 1).unwrap();
+    /// assert!(col.iter().eq(&[1, 4]));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn vector(&self, axis: Axis, n: usize) -> Result<SharedVector<T>> {
+        let layout = self.0.vector_layout(axis);
+        if n >= layout.count {
+            return Err(Error::vector_out_of_bounds(axis, n, self.0.shape()));
+        }
+        Ok(SharedVector::new(self.0.clone(), layout, n))
+    }
+
+    /// Returns a view of the nth row.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is greater than or equal to
+    ///   the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let row = shared.row(1).unwrap();
+    /// assert!(row.iter().eq(&[3, 4, 5]));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn row(&self, n: usize) -> Result<SharedVector<T>> {
+        self.vector(Axis::Row, n)
+    }
+
+    /// Returns a view of the nth column.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `n` is greater than or equal to
+    ///   the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let col = shared.col(1).unwrap();
+    /// assert!(col.iter().eq(&[1, 4]));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn col(&self, n: usize) -> Result<SharedVector<T>> {
+        self.vector(Axis::Col, n)
+    }
+
+    /// Returns an iterator over views of the vectors along the given axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let sums: Vec<i32> = shared.vectors(Axis::Col).map(|col| col.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    pub fn vectors(&self, axis: Axis) -> SharedVectors<T> {
+        let layout = self.0.vector_layout(axis);
+        SharedVectors {
+            matrix: self.0.clone(),
+            layout,
+            front: 0,
+            back: layout.count,
+        }
+    }
+
+    /// Returns an iterator over views of the rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let sums: Vec<i32> = shared.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 12]);
+    /// ```
+    pub fn rows(&self) -> SharedVectors<T> {
+        self.vectors(Axis::Row)
+    }
+
+    /// Returns an iterator over views of the columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let sums: Vec<i32> = shared.cols().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    pub fn cols(&self) -> SharedVectors<T> {
+        self.vectors(Axis::Col)
+    }
+}
+
+impl<T: Clone> SharedMatrix<T> {
+    /// Returns the inner matrix, cloning it if other handles or views
+    /// still exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    /// let other = shared.clone();
+    ///
+    /// assert_eq!(shared.into_matrix(), matrix![[0, 1, 2], [3, 4, 5]]);
+    /// assert_eq!(other.into_matrix(), matrix![[0, 1, 2], [3, 4, 5]]);
+    /// ```
+    pub fn into_matrix(self) -> Matrix<T> {
+        Arc::unwrap_or_clone(self.0)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T> SharedMatrix<T>
+where
+    T: Sync + Send,
+{
+    /// Returns a parallel iterator over views of the vectors along the
+    /// given axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let sums: Vec<i32> = shared
+    ///     .par_vectors(Axis::Row)
+    ///     .map(|row| row.iter().sum())
+    ///     .collect();
+    /// assert_eq!(sums, vec![3, 12]);
+    /// ```
+    pub fn par_vectors(&self, axis: Axis) -> impl IndexedParallelIterator<Item = SharedVector<T>> {
+        let matrix = self.0.clone();
+        let layout = matrix.vector_layout(axis);
+        (0..layout.count)
+            .into_par_iter()
+            .map(move |n| SharedVector::new(matrix.clone(), layout, n))
+    }
+
+    /// Returns a parallel iterator over views of the rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let sums: Vec<i32> = shared.par_rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 12]);
+    /// ```
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = SharedVector<T>> {
+        self.par_vectors(Axis::Row)
+    }
+
+    /// Returns a parallel iterator over views of the columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let sums: Vec<i32> = shared.par_cols().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    pub fn par_cols(&self) -> impl IndexedParallelIterator<Item = SharedVector<T>> {
+        self.par_vectors(Axis::Col)
+    }
+}
+
+impl<T> Clone for SharedMatrix<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Deref for SharedMatrix<T> {
+    type Target = Matrix<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<Matrix<T>> for SharedMatrix<T> {
+    fn as_ref(&self) -> &Matrix<T> {
+        &self.0
+    }
+}
+
+impl<T> From<Matrix<T>> for SharedMatrix<T> {
+    fn from(value: Matrix<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: PartialEq> PartialEq for SharedMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq> Eq for SharedMatrix<T> {}
+
+impl<T> Matrix<T> {
+    /// Converts the matrix into a [`SharedMatrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    /// assert_eq!(shared.shape().nrows, 2);
+    /// ```
+    pub fn into_shared(self) -> SharedMatrix<T> {
+        SharedMatrix::new(self)
+    }
+}
+
+/// An owned, read-only view of a single row or column of a
+/// [`SharedMatrix`].
+///
+/// The view keeps the underlying matrix alive, so it can be moved into
+/// another thread on its own.
+///
+/// This struct is created by [`SharedMatrix::vector`] and its relatives.
+#[derive(Debug)]
+pub struct SharedVector<T> {
+    matrix: Arc<Matrix<T>>,
+    start: usize,
+    step: usize,
+    len: usize,
+}
+
+impl<T> SharedVector<T> {
+    fn new(matrix: Arc<Matrix<T>>, layout: VectorLayout, n: usize) -> Self {
+        Self {
+            matrix,
+            start: n * layout.offset,
+            step: layout.step,
+            len: layout.len,
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    /// assert_eq!(shared.row(0).unwrap().len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    /// assert!(!shared.row(0).unwrap().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at `index`, or [`None`] if
+    /// out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    /// let col = shared.col(2).unwrap();
+    ///
+    /// assert_eq!(col.get(1), Some(&5));
+    /// assert_eq!(col.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        Some(&self.matrix.data[self.start + index * self.step])
+    }
+
+    /// Returns an iterator over the elements of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    /// let col = shared.col(0).unwrap();
+    ///
+    /// assert!(col.iter().eq(&[0, 3]));
+    /// ```
+    pub fn iter(&self) -> StrideIter<'_, T> {
+        unsafe { StrideIter::new_unchecked(&self.matrix.data, self.start, self.step, self.len) }
+    }
+}
+
+impl<T> Clone for SharedVector<T> {
+    fn clone(&self) -> Self {
+        Self {
+            matrix: self.matrix.clone(),
+            start: self.start,
+            step: self.step,
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SharedVector<T> {
+    type Item = &'a T;
+    type IntoIter = StrideIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over views of the vectors of a [`SharedMatrix`].
+///
+/// This struct is created by [`SharedMatrix::vectors`] and its relatives.
+#[derive(Debug)]
+pub struct SharedVectors<T> {
+    matrix: Arc<Matrix<T>>,
+    layout: VectorLayout,
+    front: usize,
+    back: usize,
+}
+
+impl<T> Iterator for SharedVectors<T> {
+    type Item = SharedVector<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let vector = SharedVector::new(self.matrix.clone(), self.layout, self.front);
+        self.front += 1;
+        Some(vector)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for SharedVectors<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(SharedVector::new(
+            self.matrix.clone(),
+            self.layout,
+            self.back,
+        ))
+    }
+}
+
+impl<T> ExactSizeIterator for SharedVectors<T> {}
+
+impl<T> FusedIterator for SharedVectors<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::index::Index;
+    use crate::matrix::shape::Shape;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    use std::thread;
+
+    #[test]
+    fn test_shared_vector() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let shared = matrix.clone().into_shared();
+
+            let rows: Vec<Vec<i32>> = shared
+                .rows()
+                .map(|row| row.iter().copied().collect())
+                .collect();
+            assert_eq!(rows, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+
+            let cols: Vec<Vec<i32>> = shared
+                .cols()
+                .rev()
+                .map(|col| col.iter().copied().collect())
+                .collect();
+            assert_eq!(cols, vec![vec![2, 5], vec![1, 4], vec![0, 3]]);
+
+            let col = shared.col(1).unwrap();
+            assert_eq!(col.len(), 2);
+            assert_eq!(col.get(0), Some(&1));
+            assert_eq!(col.get(1), Some(&4));
+            assert_eq!(col.get(2), None);
+
+            assert_eq!(
+                shared.row(2).unwrap_err(),
+                Error::IndexOutOfBounds {
+                    index: Index::new(2, 0),
+                    shape: Shape::new(2, 3)
+                }
+            );
+            assert_eq!(
+                shared.col(3).unwrap_err(),
+                Error::IndexOutOfBounds {
+                    index: Index::new(0, 3),
+                    shape: Shape::new(2, 3)
+                }
+            );
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_shared_across_threads() {
+        let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    shared
+                        .cols()
+                        .map(|col| col.iter().sum::<i32>())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), vec![3, 5, 7]);
+        }
+
+        assert_eq!(shared.into_matrix(), matrix![[0, 1, 2], [3, 4, 5]]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_vectors() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let shared = matrix.clone().into_shared();
+
+            let sums: Vec<i32> = shared.par_rows().map(|row| row.iter().sum()).collect();
+            assert_eq!(sums, vec![3, 12]);
+
+            let sums: Vec<i32> = shared.par_cols().map(|col| col.iter().sum()).collect();
+            assert_eq!(sums, vec![3, 5, 7]);
+
+            matrix.switch_order();
+        }
+    }
+}
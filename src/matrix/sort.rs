@@ -0,0 +1,224 @@
+use super::axis::Axis;
+use super::iter::StrideIter;
+use super::Matrix;
+use alloc::vec::Vec;
+
+impl<T> Matrix<T> {
+    /// Sorts the rows of the matrix in place by the key extracted from
+    /// each row.
+    ///
+    /// # Notes
+    ///
+    /// The sort is stable, and `f` is called exactly once per row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[3, 0], [1, 2], [2, 1]];
+    ///
+    /// matrix.sort_rows_by_key(|mut row| *row.next().unwrap());
+    /// assert_eq!(matrix, matrix![[1, 2], [2, 1], [3, 0]]);
+    /// ```
+    pub fn sort_rows_by_key<K, F>(&mut self, f: F) -> &mut Self
+    where
+        K: Ord,
+        F: FnMut(StrideIter<'_, T>) -> K,
+    {
+        let permutation = self.argsort_rows_by_key(f);
+        self.permute_vectors(Axis::Row, &permutation);
+        self
+    }
+
+    /// Sorts the columns of the matrix in place by the key extracted
+    /// from each column.
+    ///
+    /// # Notes
+    ///
+    /// The sort is stable, and `f` is called exactly once per column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let mut matrix = matrix![[0, 1, 2], [5, 3, 1]];
+    ///
+    /// matrix.sort_cols_by_key(|col| col.sum::<i32>());
+    /// assert_eq!(matrix, matrix![[2, 1, 0], [1, 3, 5]]);
+    /// ```
+    pub fn sort_cols_by_key<K, F>(&mut self, f: F) -> &mut Self
+    where
+        K: Ord,
+        F: FnMut(StrideIter<'_, T>) -> K,
+    {
+        let permutation = self.argsort_cols_by_key(f);
+        self.permute_vectors(Axis::Col, &permutation);
+        self
+    }
+
+    /// Returns the permutation that stably sorts the rows of the matrix
+    /// by the key extracted from each row.
+    ///
+    /// Element `i` of the result is the index of the row that would be
+    /// moved to position `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[3, 0], [1, 2], [2, 1]];
+    ///
+    /// let permutation = matrix.argsort_rows_by_key(|mut row| *row.next().unwrap());
+    /// assert_eq!(permutation, vec![1, 2, 0]);
+    /// ```
+    pub fn argsort_rows_by_key<K, F>(&self, f: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: FnMut(StrideIter<'_, T>) -> K,
+    {
+        argsort(self.iter_rows().map(f).collect())
+    }
+
+    /// Returns the permutation that stably sorts the columns of the
+    /// matrix by the key extracted from each column.
+    ///
+    /// Element `i` of the result is the index of the column that would
+    /// be moved to position `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [5, 3, 1]];
+    ///
+    /// let permutation = matrix.argsort_cols_by_key(|col| col.sum::<i32>());
+    /// assert_eq!(permutation, vec![2, 1, 0]);
+    /// ```
+    pub fn argsort_cols_by_key<K, F>(&self, f: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: FnMut(StrideIter<'_, T>) -> K,
+    {
+        argsort(self.iter_cols().map(f).collect())
+    }
+
+    /// Moves the vector at index `permutation[i]` along `axis` to index
+    /// `i`, for every `i`.
+    pub(super) fn permute_vectors(&mut self, axis: Axis, permutation: &[usize]) {
+        // Swapping position `i` with wherever its source currently is.
+        // A source index below `i` has already been swapped away, so its
+        // chain of swaps is followed to find where it went.
+        for i in 0..permutation.len() {
+            let mut source = permutation[i];
+            while source < i {
+                source = permutation[source];
+            }
+            let _ = self.swap_vectors(axis, i, source);
+        }
+    }
+}
+
+fn argsort<K: Ord>(keys: Vec<K>) -> Vec<usize> {
+    let mut permutation: Vec<usize> = (0..keys.len()).collect();
+    permutation.sort_by(|&i, &j| keys[i].cmp(&keys[j]));
+    permutation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::order::Order;
+    use alloc::vec;
+
+    #[test]
+    fn test_sort_rows_by_key() {
+        let mut matrix = matrix![[2, 0], [0, 1], [1, 2], [0, 3], [2, 4]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.sort_rows_by_key(|mut row| *row.next().unwrap());
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 1], [0, 3], [1, 2], [2, 0], [2, 4]]);
+
+            let mut output = matrix.clone();
+            output.sort_rows_by_key(|row| core::cmp::Reverse(row.sum::<i32>()));
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[2, 4], [1, 2], [0, 3], [2, 0], [0, 1]]);
+
+            matrix.switch_order();
+        }
+
+        let mut matrix = Matrix::<i32>::new((0, 2));
+        matrix.sort_rows_by_key(|row| row.len());
+        assert_eq!(matrix, Matrix::new((0, 2)));
+    }
+
+    #[test]
+    fn test_sort_cols_by_key() {
+        let mut matrix = matrix![[3, 1, 2, 0], [0, 1, 2, 3]];
+
+        for _ in 0..2 {
+            let mut output = matrix.clone();
+            output.sort_cols_by_key(|col| col.copied().collect::<Vec<_>>());
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[0, 1, 2, 3], [3, 1, 2, 0]]);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_argsort_by_key() {
+        let matrix = matrix![[3, 1, 2, 0], [0, 1, 2, 3]];
+        assert_eq!(
+            matrix.argsort_cols_by_key(|mut col| *col.next().unwrap()),
+            [3, 1, 2, 0]
+        );
+        assert_eq!(matrix.argsort_rows_by_key(|row| row.max().copied()), [0, 1]);
+        assert_eq!(
+            matrix.argsort_rows_by_key(|mut row| *row.next().unwrap()),
+            [1, 0]
+        );
+    }
+
+    #[test]
+    fn test_permute_vectors() {
+        let permutations = [
+            vec![0, 1, 2, 3, 4],
+            vec![4, 3, 2, 1, 0],
+            vec![1, 2, 3, 4, 0],
+            vec![4, 0, 1, 2, 3],
+            vec![2, 0, 4, 1, 3],
+            vec![3, 4, 0, 2, 1],
+            vec![1, 0, 3, 2, 4],
+        ];
+        let mut matrix = matrix![[0, 10], [1, 11], [2, 12], [3, 13], [4, 14]];
+
+        for _ in 0..2 {
+            for permutation in &permutations {
+                let mut output = matrix.clone();
+                output.permute_vectors(Axis::Row, permutation);
+                for (i, &source) in permutation.iter().enumerate() {
+                    assert_eq!(output[(i, 0)], source as i32, "{permutation:?}");
+                    assert_eq!(output[(i, 1)], source as i32 + 10, "{permutation:?}");
+                }
+
+                let mut output = matrix.clone();
+                output.transpose();
+                output.permute_vectors(Axis::Col, permutation);
+                for (i, &source) in permutation.iter().enumerate() {
+                    assert_eq!(output[(0, i)], source as i32, "{permutation:?}");
+                }
+            }
+
+            matrix.switch_order();
+        }
+    }
+}
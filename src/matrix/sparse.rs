@@ -0,0 +1,878 @@
+//! This module provides sparse matrices in coordinate ([`CooMatrix`]) and
+//! compressed sparse row ([`CsrMatrix`]) formats.
+//!
+//! [`CooMatrix`] is convenient for assembling a matrix entry by entry,
+//! while [`CsrMatrix`] is the format to compute with. Only explicitly
+//! stored entries take up memory; all others are implicitly
+//! `T::default()`.
+
+use super::index::Index;
+use super::order::Order;
+use super::shape::{AxisShape, Shape, ShapeLike};
+use super::Matrix;
+use crate::error::{Error, Result};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+/// A sparse matrix in coordinate format, i.e., a list of
+/// `(row, col, value)` triplets.
+///
+/// Entries may be pushed in any order, and duplicate entries are summed
+/// when converting into other formats.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::{matrix, CooMatrix};
+///
+/// let mut coo = CooMatrix::new((2, 3));
+/// coo.push(1, 2, 5).unwrap();
+/// coo.push(0, 0, 1).unwrap();
+/// coo.push(1, 2, 1).unwrap();
+///
+/// assert_eq!(coo.to_dense(), Ok(matrix![[1, 0, 0], [0, 0, 6]]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CooMatrix<T> {
+    shape: Shape,
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CooMatrix<T> {
+    /// Creates a new [`CooMatrix`] instance without any stored entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::CooMatrix;
+    ///
+    /// let coo = CooMatrix::<i32>::new((2, 3));
+    /// assert_eq!(coo.nnz(), 0);
+    /// ```
+    pub fn new<S: ShapeLike>(shape: S) -> Self {
+        Self {
+            shape: Shape::new(shape.nrows(), shape.ncols()),
+            rows: Vec::new(),
+            cols: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Returns the shape of the matrix.
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Returns the number of stored entries, counting duplicates.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Stores `value` at `(row, col)`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if `(row, col)` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{CooMatrix, Error, Index, Shape};
+    ///
+    /// let mut coo = CooMatrix::new((2, 3));
+    /// assert_eq!(coo.push(1, 2, 5), Ok(()));
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(2, 0),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(coo.push(2, 0, 5), Err(error));
+    /// ```
+    ///
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn push(&mut self, row: usize, col: usize, value: T) -> Result<()> {
+        if row >= self.shape.nrows || col >= self.shape.ncols {
+            return Err(Error::IndexOutOfBounds {
+                index: Index::new(row, col),
+                shape: self.shape,
+            });
+        }
+        self.rows.push(row);
+        self.cols.push(col);
+        self.values.push(value);
+        Ok(())
+    }
+
+    /// Returns an iterator over the stored entries as `(row, col, value)`,
+    /// in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::CooMatrix;
+    ///
+    /// let mut coo = CooMatrix::new((2, 3));
+    /// coo.push(1, 2, 5).unwrap();
+    /// coo.push(0, 0, 1).unwrap();
+    ///
+    /// let entries: Vec<_> = coo.iter().collect();
+    /// assert_eq!(entries, vec![(1, 2, &5), (0, 0, &1)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.rows
+            .iter()
+            .zip(&self.cols)
+            .zip(&self.values)
+            .map(|((&row, &col), value)| (row, col, value))
+    }
+
+    /// Converts the matrix into compressed sparse row format, summing
+    /// duplicate entries.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if `nrows + 1` exceeds [`usize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::CooMatrix;
+    ///
+    /// let mut coo = CooMatrix::new((2, 3));
+    /// coo.push(1, 2, 5).unwrap();
+    /// coo.push(0, 1, 1).unwrap();
+    /// coo.push(1, 2, 1).unwrap();
+    ///
+    /// let csr = coo.to_csr().unwrap();
+    /// assert_eq!(csr.nnz(), 2);
+    /// assert_eq!(csr.get(1, 2), Some(&6));
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    pub fn to_csr(&self) -> Result<CsrMatrix<T>>
+    where
+        T: Add<Output = T> + Clone,
+    {
+        let len = self.shape.nrows.checked_add(1).ok_or(Error::SizeOverflow)?;
+        let mut entries: Vec<usize> = (0..self.nnz()).collect();
+        entries.sort_by_key(|&entry| (self.rows[entry], self.cols[entry]));
+
+        let mut indptr = vec![0; len];
+        let mut indices: Vec<usize> = Vec::new();
+        let mut values: Vec<T> = Vec::new();
+        let mut last = None;
+        for entry in entries {
+            let (row, col) = (self.rows[entry], self.cols[entry]);
+            let value = self.values[entry].clone();
+            match values.last_mut() {
+                Some(sum) if last == Some((row, col)) => *sum = sum.clone() + value,
+                _ => {
+                    indptr[row + 1] += 1;
+                    indices.push(col);
+                    values.push(value);
+                }
+            }
+            last = Some((row, col));
+        }
+        for row in 0..self.shape.nrows {
+            indptr[row + 1] += indptr[row];
+        }
+
+        Ok(CsrMatrix {
+            shape: self.shape,
+            indptr,
+            indices,
+            values,
+        })
+    }
+
+    /// Converts the matrix into a dense matrix, summing duplicate entries.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, CooMatrix};
+    ///
+    /// let mut coo = CooMatrix::new((2, 2));
+    /// coo.push(0, 1, 3).unwrap();
+    ///
+    /// assert_eq!(coo.to_dense(), Ok(matrix![[0, 3], [0, 0]]));
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn to_dense(&self) -> Result<Matrix<T>>
+    where
+        T: Add<Output = T> + Clone + Default,
+    {
+        let mut output = Matrix::build(self.shape)?;
+        for (row, col, value) in self.iter() {
+            let element: &mut T = &mut output[(row, col)];
+            *element = element.clone() + value.clone();
+        }
+        Ok(output)
+    }
+}
+
+/// A sparse matrix in compressed sparse row format.
+///
+/// The column indices and values of the stored entries in row `i` are
+/// `indices[indptr[i]..indptr[i + 1]]` and `values[indptr[i]..indptr[i + 1]]`,
+/// with column indices strictly increasing within each row.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::matrix;
+///
+/// let dense = matrix![[1, 0, 0], [0, 0, 2]];
+/// let csr = dense.to_csr();
+///
+/// assert_eq!(csr.nnz(), 2);
+/// assert_eq!(csr.indptr(), &[0, 1, 2]);
+/// assert_eq!(csr.indices(), &[0, 2]);
+/// assert_eq!(csr.values(), &[1, 2]);
+/// assert_eq!(csr.to_dense(), Ok(dense));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsrMatrix<T> {
+    shape: Shape,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CsrMatrix<T> {
+    /// Creates a new [`CsrMatrix`] instance from its raw parts.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if `nrows + 1` exceeds [`usize::MAX`].
+    /// - [`Error::LengthInconsistent`] if `indptr` does not have
+    ///   `nrows + 1` elements starting at zero and ending at the number of
+    ///   values, `indptr` decreases, or `indices` and `values` have
+    ///   different lengths.
+    /// - [`Error::IndexOutOfBounds`] if any column index is out of bounds,
+    ///   or column indices are not strictly increasing within a row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, CsrMatrix, Error, Index, Shape};
+    ///
+    /// let csr = CsrMatrix::from_raw_parts((2, 3), vec![0, 1, 2], vec![0, 2], vec![1, 2]);
+    /// assert_eq!(csr.unwrap().to_dense(), Ok(matrix![[1, 0, 0], [0, 0, 2]]));
+    ///
+    /// let csr = CsrMatrix::from_raw_parts((2, 3), vec![0, 1, 2], vec![0, 3], vec![1, 2]);
+    /// let error = Error::IndexOutOfBounds {
+    ///     index: Index::new(1, 3),
+    ///     shape: Shape::new(2, 3),
+    /// };
+    /// assert_eq!(csr, Err(error));
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::LengthInconsistent`]: crate::error::Error::LengthInconsistent
+    /// [`Error::IndexOutOfBounds`]: crate::error::Error::IndexOutOfBounds
+    pub fn from_raw_parts<S: ShapeLike>(
+        shape: S,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        let shape = Shape::new(shape.nrows(), shape.ncols());
+        let len = shape.nrows.checked_add(1).ok_or(Error::SizeOverflow)?;
+        if indptr.len() != len
+            || indptr[0] != 0
+            || indptr[shape.nrows] != values.len()
+            || indices.len() != values.len()
+            || indptr.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            return Err(Error::LengthInconsistent);
+        }
+        for (row, range) in indptr.windows(2).enumerate() {
+            let cols = &indices[range[0]..range[1]];
+            let offending = cols
+                .iter()
+                .enumerate()
+                .find(|&(k, &col)| col >= shape.ncols || k > 0 && cols[k - 1] >= col);
+            if let Some((_, &col)) = offending {
+                return Err(Error::IndexOutOfBounds {
+                    index: Index::new(row, col),
+                    shape,
+                });
+            }
+        }
+        Ok(Self {
+            shape,
+            indptr,
+            indices,
+            values,
+        })
+    }
+
+    /// Returns the shape of the matrix.
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Returns the number of rows.
+    pub fn nrows(&self) -> usize {
+        self.shape.nrows
+    }
+
+    /// Returns the number of columns.
+    pub fn ncols(&self) -> usize {
+        self.shape.ncols
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the row pointers.
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    /// Returns the column indices of the stored entries.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the values of the stored entries.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns a reference to the stored entry at `(row, col)`, or
+    /// [`None`] if it is not stored or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let csr = matrix![[1, 0, 0], [0, 0, 2]].to_csr();
+    ///
+    /// assert_eq!(csr.get(1, 2), Some(&2));
+    /// assert_eq!(csr.get(1, 1), None);
+    /// assert_eq!(csr.get(2, 0), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.nrows() {
+            return None;
+        }
+        let range = self.indptr[row]..self.indptr[row + 1];
+        let position = self.indices[range.clone()].binary_search(&col).ok()?;
+        self.values.get(range.start + position)
+    }
+
+    /// Returns an iterator over the stored entries as `(row, col, value)`,
+    /// in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let csr = matrix![[0, 3], [4, 0]].to_csr();
+    ///
+    /// let entries: Vec<_> = csr.iter().collect();
+    /// assert_eq!(entries, vec![(0, 1, &3), (1, 0, &4)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.indptr
+            .windows(2)
+            .enumerate()
+            .flat_map(move |(row, range)| {
+                let range = range[0]..range[1];
+                self.indices[range.clone()]
+                    .iter()
+                    .zip(&self.values[range])
+                    .map(move |(&col, value)| (row, col, value))
+            })
+    }
+
+    /// Converts the matrix into coordinate format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let csr = matrix![[0, 3], [4, 0]].to_csr();
+    /// let coo = csr.to_coo();
+    ///
+    /// assert_eq!(coo.nnz(), 2);
+    /// assert_eq!(coo.to_csr(), Ok(csr));
+    /// ```
+    pub fn to_coo(&self) -> CooMatrix<T>
+    where
+        T: Clone,
+    {
+        let mut coo = CooMatrix::new(self.shape);
+        for (row, col, value) in self.iter() {
+            coo.rows.push(row);
+            coo.cols.push(col);
+            coo.values.push(value.clone());
+        }
+        coo
+    }
+
+    /// Converts the matrix into a dense matrix.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SizeOverflow`] if size exceeds [`usize::MAX`].
+    /// - [`Error::CapacityExceeded`] if total bytes stored exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let dense = matrix![[0, 3], [4, 0]];
+    /// assert_eq!(dense.to_csr().to_dense(), Ok(dense));
+    /// ```
+    ///
+    /// [`Error::SizeOverflow`]: crate::error::Error::SizeOverflow
+    /// [`Error::CapacityExceeded`]: crate::error::Error::CapacityExceeded
+    pub fn to_dense(&self) -> Result<Matrix<T>>
+    where
+        T: Clone + Default,
+    {
+        let mut output = Matrix::build(self.shape)?;
+        for (row, col, value) in self.iter() {
+            output[(row, col)] = value.clone();
+        }
+        Ok(output)
+    }
+
+    /// Multiplies the sparse matrix by a dense matrix.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// The resulting matrix will always have the same order as `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[1, 0, 0], [0, 0, 2]].to_csr();
+    /// let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+    ///
+    /// assert_eq!(lhs.mul_dense(&rhs), Ok(matrix![[0, 1], [8, 10]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn mul_dense<R, U>(&self, rhs: &Matrix<R>) -> Result<Matrix<U>>
+    where
+        T: Mul<R, Output = U> + Clone,
+        R: Clone,
+        U: Add<Output = U> + Default,
+    {
+        if self.ncols() != rhs.nrows() {
+            return Err(Error::not_conformable(self.shape(), rhs.shape()));
+        }
+        let (nrows, ncols) = (self.nrows(), rhs.ncols());
+        let shape = AxisShape::try_from_shape(Shape::new(nrows, ncols), Order::RowMajor)?;
+        let size = Matrix::<U>::check_size(shape.size())?;
+        let mut data: Vec<U> = Vec::with_capacity(size);
+        for range in self.indptr.windows(2) {
+            let start = data.len();
+            data.resize_with(start + ncols, U::default);
+            let output = &mut data[start..];
+            for (&index, value) in self.indices[range[0]..range[1]]
+                .iter()
+                .zip(&self.values[range[0]..range[1]])
+            {
+                for (col, element) in output.iter_mut().enumerate() {
+                    let product = value.clone() * rhs[(index, col)].clone();
+                    *element = core::mem::take(element) + product;
+                }
+            }
+        }
+        let mut output = Matrix {
+            order: Order::RowMajor,
+            shape,
+            data,
+        };
+        output.set_order(rhs.order());
+        Ok(output)
+    }
+
+    /// Performs elementwise addition on two sparse matrices.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// An entry is stored in the result if it is stored in either operand,
+    /// even if the sum is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[1, 0], [0, 2]].to_csr();
+    /// let rhs = matrix![[0, 3], [0, -2]].to_csr();
+    ///
+    /// let sum = lhs.elementwise_add(&rhs).unwrap();
+    /// assert_eq!(sum.nnz(), 3);
+    /// assert_eq!(sum.to_dense(), Ok(matrix![[1, 3], [0, 0]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn elementwise_add(&self, rhs: &Self) -> Result<Self>
+    where
+        T: Add<Output = T> + Clone,
+    {
+        self.merge(rhs, |left, right| match (left, right) {
+            (Some(left), Some(right)) => Some(left.clone() + right.clone()),
+            (Some(value), None) | (None, Some(value)) => Some(value.clone()),
+            (None, None) => None,
+        })
+    }
+
+    /// Performs elementwise subtraction on two sparse matrices.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// An entry is stored in the result if it is stored in either operand,
+    /// even if the difference is zero. Entries missing from `self` are
+    /// taken to be `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[1, 0], [0, 2]].to_csr();
+    /// let rhs = matrix![[0, 3], [0, 2]].to_csr();
+    ///
+    /// let difference = lhs.elementwise_sub(&rhs).unwrap();
+    /// assert_eq!(difference.to_dense(), Ok(matrix![[1, -3], [0, 0]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn elementwise_sub(&self, rhs: &Self) -> Result<Self>
+    where
+        T: Sub<Output = T> + Clone + Default,
+    {
+        self.merge(rhs, |left, right| match (left, right) {
+            (Some(left), Some(right)) => Some(left.clone() - right.clone()),
+            (Some(left), None) => Some(left.clone()),
+            (None, Some(right)) => Some(T::default() - right.clone()),
+            (None, None) => None,
+        })
+    }
+
+    /// Performs elementwise multiplication on two sparse matrices.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the matrices are not conformable.
+    ///
+    /// # Notes
+    ///
+    /// An entry is stored in the result only if it is stored in both
+    /// operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[1, 0], [4, 2]].to_csr();
+    /// let rhs = matrix![[0, 3], [5, 2]].to_csr();
+    ///
+    /// let product = lhs.elementwise_mul(&rhs).unwrap();
+    /// assert_eq!(product.nnz(), 2);
+    /// assert_eq!(product.to_dense(), Ok(matrix![[0, 0], [20, 4]]));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn elementwise_mul(&self, rhs: &Self) -> Result<Self>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        self.merge(rhs, |left, right| match (left, right) {
+            (Some(left), Some(right)) => Some(left.clone() * right.clone()),
+            _ => None,
+        })
+    }
+
+    /// Merges the stored entries of two matrices row by row, keeping
+    /// whatever `op` returns for each column stored in either of them.
+    fn merge<F>(&self, rhs: &Self, mut op: F) -> Result<Self>
+    where
+        F: FnMut(Option<&T>, Option<&T>) -> Option<T>,
+    {
+        if self.shape != rhs.shape {
+            return Err(Error::not_conformable(self.shape, rhs.shape));
+        }
+        let mut indptr = Vec::with_capacity(self.nrows() + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        indptr.push(0);
+        for row in 0..self.nrows() {
+            let (mut i, lhs_end) = (self.indptr[row], self.indptr[row + 1]);
+            let (mut j, rhs_end) = (rhs.indptr[row], rhs.indptr[row + 1]);
+            while i < lhs_end || j < rhs_end {
+                let lhs_col = (i < lhs_end).then(|| self.indices[i]);
+                let rhs_col = (j < rhs_end).then(|| rhs.indices[j]);
+                let (col, left, right) = match (lhs_col, rhs_col) {
+                    (Some(l), Some(r)) if l == r => {
+                        i += 1;
+                        j += 1;
+                        (l, Some(&self.values[i - 1]), Some(&rhs.values[j - 1]))
+                    }
+                    (Some(l), Some(r)) if l < r => {
+                        i += 1;
+                        (l, Some(&self.values[i - 1]), None)
+                    }
+                    (Some(l), None) => {
+                        i += 1;
+                        (l, Some(&self.values[i - 1]), None)
+                    }
+                    (_, Some(r)) => {
+                        j += 1;
+                        (r, None, Some(&rhs.values[j - 1]))
+                    }
+                    (None, None) => unreachable!(),
+                };
+                if let Some(value) = op(left, right) {
+                    indices.push(col);
+                    values.push(value);
+                }
+            }
+            indptr.push(values.len());
+        }
+        Ok(Self {
+            shape: self.shape,
+            indptr,
+            indices,
+            values,
+        })
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Converts the matrix into compressed sparse row format, storing
+    /// only the elements not equal to `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let csr = matrix![[1, 0, 0], [0, 0, 2]].to_csr();
+    /// assert_eq!(csr.nnz(), 2);
+    /// ```
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where
+        T: Clone + Default + PartialEq,
+    {
+        let zero = T::default();
+        let mut indptr = Vec::with_capacity(self.nrows() + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        indptr.push(0);
+        for row in self.iter_rows() {
+            for (col, value) in row.enumerate() {
+                if *value != zero {
+                    indices.push(col);
+                    values.push(value.clone());
+                }
+            }
+            indptr.push(values.len());
+        }
+        CsrMatrix {
+            shape: self.shape(),
+            indptr,
+            indices,
+            values,
+        }
+    }
+
+    /// Converts the matrix into coordinate format, storing only the
+    /// elements not equal to `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let coo = matrix![[1, 0, 0], [0, 0, 2]].to_coo();
+    ///
+    /// let entries: Vec<_> = coo.iter().collect();
+    /// assert_eq!(entries, vec![(0, 0, &1), (1, 2, &2)]);
+    /// ```
+    pub fn to_coo(&self) -> CooMatrix<T>
+    where
+        T: Clone + Default + PartialEq,
+    {
+        self.to_csr().to_coo()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_to_csr() {
+        let mut dense = matrix![[0, 1, 0, 0], [0, 0, 0, 0], [2, 0, 0, 3]];
+
+        for _ in 0..2 {
+            let csr = dense.to_csr();
+            assert_eq!(csr.shape(), Shape::new(3, 4));
+            assert_eq!(csr.indptr(), &[0, 1, 1, 3]);
+            assert_eq!(csr.indices(), &[1, 0, 3]);
+            assert_eq!(csr.values(), &[1, 2, 3]);
+
+            let mut restored = csr.to_dense().unwrap();
+            restored.set_order(dense.order());
+            assert_eq!(restored, dense);
+
+            dense.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_coo_to_csr() {
+        let mut coo = CooMatrix::new((3, 3));
+        coo.push(2, 1, 1).unwrap();
+        coo.push(0, 2, 2).unwrap();
+        coo.push(2, 1, 3).unwrap();
+        coo.push(0, 0, 4).unwrap();
+        assert_eq!(
+            coo.push(0, 3, 0),
+            Err(Error::IndexOutOfBounds {
+                index: Index::new(0, 3),
+                shape: Shape::new(3, 3)
+            })
+        );
+        assert_eq!(coo.nnz(), 4);
+
+        let csr = coo.to_csr().unwrap();
+        assert_eq!(csr.indptr(), &[0, 2, 2, 3]);
+        assert_eq!(csr.indices(), &[0, 2, 1]);
+        assert_eq!(csr.values(), &[4, 2, 4]);
+        assert_eq!(csr.to_dense(), coo.to_dense());
+        assert_eq!(csr.to_coo().to_csr(), Ok(csr));
+
+        let coo = CooMatrix::<i32>::new((usize::MAX, 0));
+        assert_eq!(coo.to_csr(), Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    fn test_from_raw_parts() {
+        let result = CsrMatrix::from_raw_parts((2, 2), vec![0, 1, 2], vec![1, 0], vec![1, 2]);
+        assert_eq!(result.unwrap().to_dense(), Ok(matrix![[0, 1], [2, 0]]));
+
+        let result = CsrMatrix::from_raw_parts((2, 2), vec![0, 1], vec![1], vec![1]);
+        assert_eq!(result, Err(Error::LengthInconsistent));
+        let result = CsrMatrix::from_raw_parts((2, 2), vec![0, 2, 1], vec![0, 1], vec![1, 2]);
+        assert_eq!(result, Err(Error::LengthInconsistent));
+        let result = CsrMatrix::from_raw_parts((2, 2), vec![0, 1, 2], vec![0], vec![1, 2]);
+        assert_eq!(result, Err(Error::LengthInconsistent));
+        let result = CsrMatrix::from_raw_parts((1, 2), vec![0, 2], vec![1, 1], vec![1, 2]);
+        assert_eq!(
+            result,
+            Err(Error::IndexOutOfBounds {
+                index: Index::new(0, 1),
+                shape: Shape::new(1, 2)
+            })
+        );
+
+        let result = CsrMatrix::<i32>::from_raw_parts((usize::MAX, 0), vec![0], vec![], vec![]);
+        assert_eq!(result, Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    fn test_mul_dense() {
+        let lhs = matrix![[1, 0, 0], [0, 0, 0], [0, 2, -1]].to_csr();
+        let mut rhs = matrix![[1, 2], [3, 4], [5, 6]];
+        let expected = matrix![[1, 2], [0, 0], [1, 2]];
+
+        for _ in 0..2 {
+            let mut output = lhs.mul_dense(&rhs).unwrap();
+            assert_eq!(output.order(), rhs.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, expected);
+
+            rhs.switch_order();
+        }
+
+        assert_eq!(
+            lhs.mul_dense(&matrix![[1, 2], [3, 4]]),
+            Err(Error::NotConformable {
+                lhs: Shape::new(3, 3),
+                rhs: Shape::new(2, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_elementwise() {
+        let lhs = matrix![[1, 0, 2], [0, 0, 3]].to_csr();
+        let rhs = matrix![[0, 4, 5], [0, 0, -3]].to_csr();
+
+        let sum = lhs.elementwise_add(&rhs).unwrap();
+        assert_eq!(sum.indices(), &[0, 1, 2, 2]);
+        assert_eq!(sum.to_dense(), Ok(matrix![[1, 4, 7], [0, 0, 0]]));
+
+        let difference = lhs.elementwise_sub(&rhs).unwrap();
+        assert_eq!(difference.to_dense(), Ok(matrix![[1, -4, -3], [0, 0, 6]]));
+
+        let product = lhs.elementwise_mul(&rhs).unwrap();
+        assert_eq!(product.indptr(), &[0, 1, 2]);
+        assert_eq!(product.to_dense(), Ok(matrix![[0, 0, 10], [0, 0, -9]]));
+
+        let other = matrix![[1, 2]].to_csr();
+        assert_eq!(
+            lhs.elementwise_add(&other),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(1, 2)
+            })
+        );
+        assert_eq!(
+            lhs.elementwise_sub(&other),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(1, 2)
+            })
+        );
+        assert_eq!(
+            lhs.elementwise_mul(&other),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(1, 2)
+            })
+        );
+    }
+}
@@ -0,0 +1,764 @@
+use super::axis::Axis;
+use super::index::Index;
+#[cfg(feature = "std")]
+use super::real::Real;
+use super::Matrix;
+use crate::error::{Error, Result};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign};
+
+impl<T> Matrix<T> {
+    /// Returns the weighted sum of each vector along the given axis.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the length of `weights` does not
+    ///   match the length of the vectors along `axis`.
+    ///
+    /// # Notes
+    ///
+    /// Reducing along [`Axis::Row`] weights each row and yields an `m x 1`
+    /// matrix, so `weights` must have `n` elements. Reducing along
+    /// [`Axis::Col`] yields a `1 x n` matrix, and `weights` must have `m`
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis, Error, Shape};
+    ///
+    /// let matrix = matrix![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// let result = matrix.weighted_sum(Axis::Row, &[1, 0, 2]);
+    /// assert_eq!(result, Ok(matrix![[7], [16]]));
+    ///
+    /// let result = matrix.weighted_sum(Axis::Col, &[1, 2]);
+    /// assert_eq!(result, Ok(matrix![[9, 12, 15]]));
+    ///
+    /// let result = matrix.weighted_sum(Axis::Col, &[1, 2, 3]);
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(2, 3),
+    ///     rhs: Shape::new(3, 1),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    pub fn weighted_sum<W, U>(&self, axis: Axis, weights: &[W]) -> Result<Matrix<U>>
+    where
+        T: Mul<W, Output = U> + Clone,
+        W: Clone,
+        U: Add<Output = U> + Default,
+    {
+        self.ensure_weights_conformable(axis, weights)?;
+        let output = self.map_axis(axis, |vector| {
+            vector
+                .zip(weights)
+                .fold(U::default(), |acc, (x, w)| acc + x.clone() * w.clone())
+        });
+        Ok(output)
+    }
+
+    /// Returns the weighted mean of each vector along the given axis.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the length of `weights` does not
+    ///   match the length of the vectors along `axis`.
+    ///
+    /// # Notes
+    ///
+    /// The shape of the result and the expected length of `weights` follow
+    /// the same rules as [`Matrix::weighted_sum`].
+    ///
+    /// Each weighted sum is divided by the total of `weights`, which is
+    /// computed only once. Integer types panic when the total is zero,
+    /// while floating-point types yield NaN or infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    ///
+    /// let result = matrix.weighted_mean(Axis::Row, &[1.0, 0.0, 1.0]);
+    /// assert_eq!(result, Ok(matrix![[2.0], [5.0]]));
+    ///
+    /// let result = matrix.weighted_mean(Axis::Col, &[3.0, 1.0]);
+    /// assert_eq!(result, Ok(matrix![[1.75, 2.75, 3.75]]));
+    /// ```
+    pub fn weighted_mean<W, U, V>(&self, axis: Axis, weights: &[W]) -> Result<Matrix<V>>
+    where
+        T: Mul<W, Output = U> + Clone,
+        W: Add<Output = W> + Clone + Default,
+        U: Add<Output = U> + Div<W, Output = V> + Default,
+    {
+        self.ensure_weights_conformable(axis, weights)?;
+        let total = weights.iter().fold(W::default(), |acc, w| acc + w.clone());
+        let output = self.map_axis(axis, |vector| {
+            let sum = vector
+                .zip(weights)
+                .fold(U::default(), |acc, (x, w)| acc + x.clone() * w.clone());
+            sum / total.clone()
+        });
+        Ok(output)
+    }
+
+    /// Returns the cumulative sum of each vector along the given axis.
+    ///
+    /// # Notes
+    ///
+    /// Accumulating along [`Axis::Row`] sums each row from left to right,
+    /// and along [`Axis::Col`] each column from top to bottom. The
+    /// resulting matrix will always have the same shape and order as
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(matrix.cumsum(Axis::Row), matrix![[1, 3, 6], [4, 9, 15]]);
+    /// assert_eq!(matrix.cumsum(Axis::Col), matrix![[1, 2, 3], [5, 7, 9]]);
+    /// ```
+    pub fn cumsum(&self, axis: Axis) -> Self
+    where
+        T: AddAssign + Clone,
+    {
+        let mut output = self.clone();
+        output.cumsum_assign(axis);
+        output
+    }
+
+    /// Replaces each vector along the given axis with its cumulative sum
+    /// in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let mut matrix = matrix![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// matrix.cumsum_assign(Axis::Row);
+    /// assert_eq!(matrix, matrix![[1, 3, 6], [4, 9, 15]]);
+    /// ```
+    pub fn cumsum_assign(&mut self, axis: Axis) -> &mut Self
+    where
+        T: AddAssign + Clone,
+    {
+        self.accumulate_assign(axis, |x, prev| *x += prev.clone())
+    }
+
+    /// Returns the cumulative product of each vector along the given axis.
+    ///
+    /// # Notes
+    ///
+    /// Accumulating along [`Axis::Row`] multiplies each row from left to
+    /// right, and along [`Axis::Col`] each column from top to bottom. The
+    /// resulting matrix will always have the same shape and order as
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(matrix.cumprod(Axis::Row), matrix![[1, 2, 6], [4, 20, 120]]);
+    /// assert_eq!(matrix.cumprod(Axis::Col), matrix![[1, 2, 3], [4, 10, 18]]);
+    /// ```
+    pub fn cumprod(&self, axis: Axis) -> Self
+    where
+        T: MulAssign + Clone,
+    {
+        let mut output = self.clone();
+        output.cumprod_assign(axis);
+        output
+    }
+
+    /// Replaces each vector along the given axis with its cumulative
+    /// product in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let mut matrix = matrix![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// matrix.cumprod_assign(Axis::Col);
+    /// assert_eq!(matrix, matrix![[1, 2, 3], [4, 10, 18]]);
+    /// ```
+    pub fn cumprod_assign(&mut self, axis: Axis) -> &mut Self
+    where
+        T: MulAssign + Clone,
+    {
+        self.accumulate_assign(axis, |x, prev| *x *= prev.clone())
+    }
+
+    /// Returns the index of and a reference to the largest element of the
+    /// matrix, or [`None`] if it is empty.
+    ///
+    /// # Notes
+    ///
+    /// If several elements are equally the largest, the first one in
+    /// row-major order is returned, regardless of the internal order.
+    /// Incomparable elements such as NaN are skipped unless no element
+    /// can be compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Index, Matrix};
+    ///
+    /// let matrix = matrix![[3, 7, 1], [7, 0, 5]];
+    /// assert_eq!(matrix.argmax(), Some((Index::new(0, 1), &7)));
+    ///
+    /// let matrix = Matrix::<i32>::new((0, 3));
+    /// assert_eq!(matrix.argmax(), None);
+    /// ```
+    pub fn argmax(&self) -> Option<(Index, &T)>
+    where
+        T: PartialOrd,
+    {
+        self.arg_extremum(Ordering::Greater)
+    }
+
+    /// Returns the index of and a reference to the smallest element of the
+    /// matrix, or [`None`] if it is empty.
+    ///
+    /// # Notes
+    ///
+    /// If several elements are equally the smallest, the first one in
+    /// row-major order is returned, regardless of the internal order.
+    /// Incomparable elements such as NaN are skipped unless no element
+    /// can be compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Index};
+    ///
+    /// let matrix = matrix![[3, 0, 1], [7, 0, 5]];
+    /// assert_eq!(matrix.argmin(), Some((Index::new(0, 1), &0)));
+    /// ```
+    pub fn argmin(&self) -> Option<(Index, &T)>
+    where
+        T: PartialOrd,
+    {
+        self.arg_extremum(Ordering::Less)
+    }
+
+    /// Returns the position of the largest element within each vector
+    /// along the given axis, or [`None`] if those vectors are empty.
+    ///
+    /// # Notes
+    ///
+    /// Searching along [`Axis::Row`] yields the column index of the
+    /// largest element of each row, and along [`Axis::Col`] the row index
+    /// of the largest element of each column. Ties and incomparable
+    /// elements are handled as in [`Matrix::argmax`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis, Matrix};
+    ///
+    /// let matrix = matrix![[3, 7, 1], [7, 0, 5]];
+    ///
+    /// assert_eq!(matrix.argmax_axis(Axis::Row), Some(vec![1, 0]));
+    /// assert_eq!(matrix.argmax_axis(Axis::Col), Some(vec![1, 0, 1]));
+    ///
+    /// let matrix = Matrix::<i32>::new((2, 0));
+    /// assert_eq!(matrix.argmax_axis(Axis::Row), None);
+    /// ```
+    pub fn argmax_axis(&self, axis: Axis) -> Option<Vec<usize>>
+    where
+        T: PartialOrd,
+    {
+        self.arg_extremum_axis(axis, Ordering::Greater)
+    }
+
+    /// Returns the position of the smallest element within each vector
+    /// along the given axis, or [`None`] if those vectors are empty.
+    ///
+    /// # Notes
+    ///
+    /// Searching along [`Axis::Row`] yields the column index of the
+    /// smallest element of each row, and along [`Axis::Col`] the row
+    /// index of the smallest element of each column. Ties and
+    /// incomparable elements are handled as in [`Matrix::argmin`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Axis};
+    ///
+    /// let matrix = matrix![[3, 7, 1], [7, 0, 5]];
+    ///
+    /// assert_eq!(matrix.argmin_axis(Axis::Row), Some(vec![2, 1]));
+    /// assert_eq!(matrix.argmin_axis(Axis::Col), Some(vec![0, 1, 0]));
+    /// ```
+    pub fn argmin_axis(&self, axis: Axis) -> Option<Vec<usize>>
+    where
+        T: PartialOrd,
+    {
+        self.arg_extremum_axis(axis, Ordering::Less)
+    }
+
+    fn arg_extremum(&self, wanted: Ordering) -> Option<(Index, &T)>
+    where
+        T: PartialOrd,
+    {
+        let ncols = self.ncols();
+        let (position, element) = extremum(self.iter_rows().flatten(), wanted)?;
+        Some((Index::new(position / ncols, position % ncols), element))
+    }
+
+    fn arg_extremum_axis(&self, axis: Axis, wanted: Ordering) -> Option<Vec<usize>>
+    where
+        T: PartialOrd,
+    {
+        self.iter_axis(axis)
+            .map(|vector| extremum(vector, wanted).map(|(position, _)| position))
+            .collect()
+    }
+
+    /// Folds each element of every vector along `axis` with the already
+    /// accumulated element before it.
+    fn accumulate_assign<F>(&mut self, axis: Axis, mut op: F) -> &mut Self
+    where
+        F: FnMut(&mut T, &T),
+    {
+        for mut vector in self.iter_axis_mut(axis) {
+            let Some(mut prev) = vector.next() else {
+                continue;
+            };
+            for x in vector {
+                op(x, prev);
+                prev = x;
+            }
+        }
+        self
+    }
+
+    fn ensure_weights_conformable<W>(&self, axis: Axis, weights: &[W]) -> Result<()> {
+        if weights.len() != self.len_of(axis.switch()) {
+            Err(Error::not_conformable_with_slice(
+                self.shape(),
+                weights.len(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_statistics {
+    ($($t:ty)*) => {
+        $(
+            impl Matrix<$t> {
+                /// Returns the arithmetic mean of all elements.
+                ///
+                /// # Notes
+                ///
+                /// An empty matrix yields NaN.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0], [3.0, 6.0]];
+                /// assert_eq!(matrix.mean(), 3.0);
+                /// ```
+                pub fn mean(&self) -> $t {
+                    mean(self.data.iter())
+                }
+
+                /// Returns the population variance of all elements, i.e.,
+                /// the mean of squared deviations from [`Matrix::mean`].
+                ///
+                /// # Notes
+                ///
+                /// An empty matrix yields NaN.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0], [3.0, 6.0]];
+                /// assert_eq!(matrix.variance(), 3.5);
+                /// ```
+                pub fn variance(&self) -> $t {
+                    variance(self.data.iter())
+                }
+
+                /// Returns the population standard deviation of all
+                /// elements, i.e., the square root of
+                /// [`Matrix::variance`].
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 3.0], [1.0, 3.0]];
+                /// assert_eq!(matrix.std(), 1.0);
+                /// ```
+                pub fn std(&self) -> $t {
+                    self.variance().sqrt()
+                }
+
+                /// Returns the mean of each vector along the given axis.
+                ///
+                /// # Notes
+                ///
+                /// Reducing along [`Axis::Row`] yields an `m x 1` matrix,
+                /// and along [`Axis::Col`] a `1 x n` matrix, as with
+                /// [`Matrix::weighted_sum`]. The resulting matrix will
+                /// always have the same order as `self`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Axis, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+                ///
+                /// assert_eq!(matrix.mean_axis(Axis::Row), matrix![[2.0], [5.0]]);
+                /// assert_eq!(matrix.mean_axis(Axis::Col), matrix![[2.5, 3.5, 4.5]]);
+                /// ```
+                pub fn mean_axis(&self, axis: Axis) -> Self {
+                    self.map_axis(axis, mean)
+                }
+
+                /// Returns the population variance of each vector along
+                /// the given axis.
+                ///
+                /// # Notes
+                ///
+                /// See [`Matrix::mean_axis`] for the shape of the result.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Axis, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0], [4.0, 4.0, 4.0]];
+                ///
+                /// let output = matrix.variance_axis(Axis::Row);
+                /// assert_eq!(output, matrix![[2.0 / 3.0], [0.0]]);
+                ///
+                /// let output = matrix.variance_axis(Axis::Col);
+                /// assert_eq!(output, matrix![[2.25, 1.0, 0.25]]);
+                /// ```
+                pub fn variance_axis(&self, axis: Axis) -> Self {
+                    self.map_axis(axis, variance)
+                }
+
+                /// Returns the population standard deviation of each
+                /// vector along the given axis.
+                ///
+                /// # Notes
+                ///
+                /// See [`Matrix::mean_axis`] for the shape of the result.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use matreex::{matrix, Axis, Matrix};
+                ///
+                /// let matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0], [4.0, 4.0, 4.0]];
+                ///
+                /// let output = matrix.std_axis(Axis::Col);
+                /// assert_eq!(output, matrix![[1.5, 1.0, 0.5]]);
+                /// ```
+                pub fn std_axis(&self, axis: Axis) -> Self {
+                    self.map_axis(axis, |vector| variance(vector).sqrt())
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+impl_statistics! {f32 f64}
+
+/// Returns the position of the first element that no other element
+/// compares as `wanted` to, along with the element itself. Elements
+/// unordered with themselves, such as NaN, only win if nothing else can.
+fn extremum<'a, T, I>(elements: I, wanted: Ordering) -> Option<(usize, &'a T)>
+where
+    T: PartialOrd + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    elements.enumerate().reduce(|best, candidate| {
+        let unordered = best.1.partial_cmp(best.1).is_none();
+        if unordered || candidate.1.partial_cmp(best.1) == Some(wanted) {
+            candidate
+        } else {
+            best
+        }
+    })
+}
+
+#[cfg(feature = "std")]
+pub(super) fn mean<'a, T, I>(elements: I) -> T
+where
+    T: Real + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    let (sum, count) = elements.fold((T::ZERO, 0usize), |(sum, count), &x| (sum + x, count + 1));
+    sum / T::from_f64(count as f64)
+}
+
+#[cfg(feature = "std")]
+pub(super) fn variance<'a, T, I>(elements: I) -> T
+where
+    T: Real + 'a,
+    I: Iterator<Item = &'a T> + Clone,
+{
+    let center = mean(elements.clone());
+    let (sum, count) = elements.fold((T::ZERO, 0usize), |(sum, count), &x| {
+        (sum + (x - center) * (x - center), count + 1)
+    });
+    sum / T::from_f64(count as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::order::Order;
+    use crate::matrix::shape::Shape;
+    use alloc::vec;
+
+    #[test]
+    fn test_weighted_sum() {
+        let mut matrix = matrix![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(
+            matrix.weighted_sum(Axis::Row, &[1, 0, 2]),
+            Ok(matrix![[7], [16]])
+        );
+        assert_eq!(
+            matrix.weighted_sum(Axis::Col, &[1, 2]),
+            Ok(matrix![[9, 12, 15]])
+        );
+
+        matrix.switch_order();
+
+        let mut output = matrix.weighted_sum(Axis::Row, &[1, 0, 2]).unwrap();
+        output.switch_order();
+        assert_eq!(output, matrix![[7], [16]]);
+        let mut output = matrix.weighted_sum(Axis::Col, &[1, 2]).unwrap();
+        output.switch_order();
+        assert_eq!(output, matrix![[9, 12, 15]]);
+
+        assert_eq!(
+            matrix.weighted_sum::<i32, i32>(Axis::Row, &[1, 2]),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(2, 1)
+            })
+        );
+        assert_eq!(
+            matrix.weighted_sum::<i32, i32>(Axis::Col, &[1, 2, 3]),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 1)
+            })
+        );
+
+        let matrix = Matrix::<i32>::new((2, 0));
+        assert_eq!(
+            matrix.weighted_sum::<i32, i32>(Axis::Row, &[]),
+            Ok(matrix![[0], [0]])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_mean_variance_std() {
+        let mut matrix: Matrix<f64> = matrix![[1.0, 3.0, 1.0], [3.0, 1.0, 3.0]];
+
+        for _ in 0..2 {
+            assert_eq!(matrix.mean(), 2.0);
+            assert_eq!(matrix.variance(), 1.0);
+            assert_eq!(matrix.std(), 1.0);
+
+            matrix.switch_order();
+        }
+
+        let matrix: Matrix<f32> = matrix![[-2.0, 2.0]];
+        assert_eq!(matrix.mean(), 0.0);
+        assert_eq!(matrix.variance(), 4.0);
+        assert_eq!(matrix.std(), 2.0);
+
+        let matrix = Matrix::<f64>::new((2, 0));
+        assert!(matrix.mean().is_nan());
+        assert!(matrix.variance().is_nan());
+        assert!(matrix.std().is_nan());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_mean_variance_std_axis() {
+        let mut matrix: Matrix<f64> = matrix![[1.0, 2.0, 3.0], [4.0, 4.0, 4.0]];
+
+        for _ in 0..2 {
+            let order = matrix.order();
+
+            let output = matrix.mean_axis(Axis::Row);
+            assert_eq!(output.order(), order);
+            assert_eq!(output.shape(), Shape::new(2, 1));
+            assert_eq!(output.to_row_major_vec(), [2.0, 4.0]);
+
+            let output = matrix.mean_axis(Axis::Col);
+            assert_eq!(output.shape(), Shape::new(1, 3));
+            assert_eq!(output.to_row_major_vec(), [2.5, 3.0, 3.5]);
+
+            let output = matrix.variance_axis(Axis::Row);
+            assert_eq!(output.to_row_major_vec(), [2.0 / 3.0, 0.0]);
+
+            let output = matrix.variance_axis(Axis::Col);
+            assert_eq!(output.to_row_major_vec(), [2.25, 1.0, 0.25]);
+
+            let output = matrix.std_axis(Axis::Col);
+            assert_eq!(output.order(), order);
+            assert_eq!(output.to_row_major_vec(), [1.5, 1.0, 0.5]);
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<f32>::new((2, 0));
+        let output = matrix.mean_axis(Axis::Row);
+        assert!(output.iter_elements().all(|x| x.is_nan()));
+        assert_eq!(matrix.std_axis(Axis::Col).shape(), Shape::new(1, 0));
+    }
+
+    #[test]
+    fn test_cumsum() {
+        let mut matrix = matrix![[1, 2, 3], [4, 5, 6]];
+
+        for _ in 0..2 {
+            let mut output = matrix.cumsum(Axis::Row);
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1, 3, 6], [4, 9, 15]]);
+
+            let mut output = matrix.clone();
+            output.cumsum_assign(Axis::Col).cumsum_assign(Axis::Row);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1, 3, 6], [5, 12, 21]]);
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<i32>::new((0, 3));
+        assert_eq!(matrix.cumsum(Axis::Row), matrix);
+        assert_eq!(matrix.cumsum(Axis::Col), matrix);
+    }
+
+    #[test]
+    fn test_cumprod() {
+        let mut matrix = matrix![[1, 2, 3], [4, 5, 6]];
+
+        for _ in 0..2 {
+            let mut output = matrix.cumprod(Axis::Col);
+            assert_eq!(output.order(), matrix.order());
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1, 2, 3], [4, 10, 18]]);
+
+            let mut output = matrix.clone();
+            output.cumprod_assign(Axis::Row);
+            output.set_order(Order::RowMajor);
+            assert_eq!(output, matrix![[1, 2, 6], [4, 20, 120]]);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_argmax_argmin() {
+        let mut matrix = matrix![[3, 7, 1], [7, 0, 5], [2, 0, 7]];
+
+        for _ in 0..2 {
+            assert_eq!(matrix.argmax(), Some((Index::new(0, 1), &7)));
+            assert_eq!(matrix.argmin(), Some((Index::new(1, 1), &0)));
+
+            matrix.switch_order();
+        }
+
+        let matrix = matrix![[f64::NAN, 2.0], [-1.0, f64::NAN]];
+        assert_eq!(
+            matrix.argmax().map(|(index, _)| index),
+            Some(Index::new(0, 1))
+        );
+        assert_eq!(
+            matrix.argmin().map(|(index, _)| index),
+            Some(Index::new(1, 0))
+        );
+
+        let matrix = Matrix::<i32>::new((3, 0));
+        assert_eq!(matrix.argmax(), None);
+        assert_eq!(matrix.argmin(), None);
+    }
+
+    #[test]
+    fn test_argmax_argmin_axis() {
+        let mut matrix = matrix![[3, 7, 1], [7, 0, 5], [2, 0, 7]];
+
+        for _ in 0..2 {
+            assert_eq!(matrix.argmax_axis(Axis::Row), Some(vec![1, 0, 2]));
+            assert_eq!(matrix.argmax_axis(Axis::Col), Some(vec![1, 0, 2]));
+            assert_eq!(matrix.argmin_axis(Axis::Row), Some(vec![2, 1, 1]));
+            assert_eq!(matrix.argmin_axis(Axis::Col), Some(vec![2, 1, 0]));
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<i32>::new((0, 2));
+        assert_eq!(matrix.argmax_axis(Axis::Row), Some(vec![]));
+        assert_eq!(matrix.argmax_axis(Axis::Col), None);
+        assert_eq!(matrix.argmin_axis(Axis::Col), None);
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let mut matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+        assert_eq!(
+            matrix.weighted_mean(Axis::Row, &[1.0, 0.0, 1.0]),
+            Ok(matrix![[2.0], [5.0]])
+        );
+        assert_eq!(
+            matrix.weighted_mean(Axis::Col, &[3.0, 1.0]),
+            Ok(matrix![[1.75, 2.75, 3.75]])
+        );
+
+        matrix.switch_order();
+
+        let mut output = matrix.weighted_mean(Axis::Row, &[1.0, 0.0, 1.0]).unwrap();
+        output.switch_order();
+        assert_eq!(output, matrix![[2.0], [5.0]]);
+        let mut output = matrix.weighted_mean(Axis::Col, &[3.0, 1.0]).unwrap();
+        output.switch_order();
+        assert_eq!(output, matrix![[1.75, 2.75, 3.75]]);
+
+        assert_eq!(
+            matrix.weighted_mean::<f64, f64, f64>(Axis::Row, &[1.0]),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(1, 1)
+            })
+        );
+    }
+}
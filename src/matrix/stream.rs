@@ -0,0 +1,170 @@
+//! This module provides row-by-row streaming of matrix computations.
+//!
+//! Long-running jobs can report progress as each row completes, rather
+//! than blocking until the whole result exists.
+
+use super::iter::{AxisIter, StrideIter};
+use super::shared::SharedMatrix;
+use super::Matrix;
+use core::iter::{Enumerate, FusedIterator};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+impl<T> Matrix<T> {
+    /// Returns an iterator that lazily applies `op` to each row and
+    /// yields the row index together with the result.
+    ///
+    /// Nothing is computed until the iterator is advanced, so callers
+    /// can report progress between rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let lhs = matrix![[0, 1, 2], [3, 4, 5]];
+    /// let rhs = matrix![[0, 1], [2, 3], [4, 5]];
+    ///
+    /// let mut stream = lhs.compute_rows_stream(|row| {
+    ///     rhs.iter_cols()
+    ///         .map(|col| row.clone().zip(col).map(|(x, y)| x * y).sum())
+    ///         .collect::<Vec<i32>>()
+    /// });
+    /// assert_eq!(stream.len(), 2);
+    /// assert_eq!(stream.next(), Some((0, vec![10, 13])));
+    /// assert_eq!(stream.next(), Some((1, vec![28, 40])));
+    /// assert_eq!(stream.next(), None);
+    /// ```
+    pub fn compute_rows_stream<'a, U, F>(&'a self, op: F) -> RowStream<'a, T, F>
+    where
+        F: FnMut(StrideIter<'a, T>) -> U,
+    {
+        RowStream {
+            rows: self.iter_rows().enumerate(),
+            op,
+        }
+    }
+}
+
+impl<T> SharedMatrix<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Applies `op` to each row on a background thread and sends the
+    /// row index together with the result through a channel as soon as
+    /// each row completes.
+    ///
+    /// # Notes
+    ///
+    /// Rows are sent in order. The background thread stops early once
+    /// the receiver is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let shared = matrix![[0, 1, 2], [3, 4, 5]].into_shared();
+    ///
+    /// let receiver = shared.compute_rows_channel(|row| row.sum::<i32>());
+    /// let results: Vec<(usize, i32)> = receiver.iter().collect();
+    /// assert_eq!(results, vec![(0, 3), (1, 12)]);
+    /// ```
+    pub fn compute_rows_channel<U, F>(&self, mut op: F) -> Receiver<(usize, U)>
+    where
+        U: Send + 'static,
+        F: FnMut(StrideIter<'_, T>) -> U + Send + 'static,
+    {
+        let matrix = self.clone();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (n, row) in matrix.iter_rows().enumerate() {
+                if sender.send((n, op(row))).is_err() {
+                    break;
+                }
+            }
+        });
+        receiver
+    }
+}
+
+/// An iterator that lazily computes a result for each row of a matrix.
+///
+/// This struct is created by [`Matrix::compute_rows_stream`].
+pub struct RowStream<'a, T, F> {
+    rows: Enumerate<AxisIter<'a, T>>,
+    op: F,
+}
+
+impl<T: core::fmt::Debug, F> core::fmt::Debug for RowStream<'_, T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RowStream")
+            .field("rows", &self.rows)
+            .finish()
+    }
+}
+
+impl<'a, T, U, F> Iterator for RowStream<'a, T, F>
+where
+    F: FnMut(StrideIter<'a, T>) -> U,
+{
+    type Item = (usize, U);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (n, row) = self.rows.next()?;
+        Some((n, (self.op)(row)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<'a, T, U, F> ExactSizeIterator for RowStream<'a, T, F> where F: FnMut(StrideIter<'a, T>) -> U {}
+
+impl<'a, T, U, F> FusedIterator for RowStream<'a, T, F> where F: FnMut(StrideIter<'a, T>) -> U {}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix;
+
+    #[test]
+    fn test_compute_rows_stream() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let mut completed = Vec::new();
+            let mut stream = matrix.compute_rows_stream(|row| row.copied().collect::<Vec<_>>());
+            assert_eq!(stream.len(), 2);
+            for (n, row) in &mut stream {
+                completed.push(n);
+                assert_eq!(
+                    row,
+                    matrix.iter_nth_row(n).unwrap().copied().collect::<Vec<_>>()
+                );
+            }
+            assert_eq!(completed, vec![0, 1]);
+            assert_eq!(stream.next(), None);
+
+            matrix.switch_order();
+        }
+    }
+
+    #[test]
+    fn test_compute_rows_channel() {
+        let mut matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+
+        for _ in 0..2 {
+            let shared = matrix.clone().into_shared();
+            let receiver = shared.compute_rows_channel(|row| row.max().copied());
+            let results: Vec<_> = receiver.iter().collect();
+            assert_eq!(results, vec![(0, Some(2)), (1, Some(5)), (2, Some(8))]);
+
+            let receiver = shared.compute_rows_channel(|row| row.count());
+            assert_eq!(receiver.recv(), Ok((0, 3)));
+            drop(receiver);
+
+            matrix.switch_order();
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! This module provides [`Typed`], a layer that checks matrix shapes at
+//! compile time with const generics.
+
+use super::shape::Shape;
+use super::Matrix;
+use crate::error::{Error, Result};
+use core::ops::{Add, Deref, Mul, Sub};
+
+/// A matrix whose shape is part of its type.
+///
+/// Operators on [`Typed`] only accept operands of conformable shapes, so
+/// shape errors surface at compile time instead of as runtime panics.
+/// It dereferences to the underlying matrix for read-only access.
+///
+/// # Examples
+///
+/// ```
+/// use matreex::{matrix, Typed};
+///
+/// let lhs: Typed<_, 2, 3> = Typed::new(matrix![[0, 1, 2], [3, 4, 5]]).unwrap();
+/// let rhs: Typed<_, 3, 2> = Typed::new(matrix![[0, 1], [2, 3], [4, 5]]).unwrap();
+///
+/// let product: Typed<_, 2, 2> = lhs * rhs;
+/// assert_eq!(*product, matrix![[10, 13], [28, 40]]);
+/// ```
+///
+/// Operands of mismatched shapes do not compile:
+///
+/// ```compile_fail
+/// use matreex::{matrix, Typed};
+///
+/// let lhs: Typed<_, 2, 3> = Typed::new(matrix![[0, 1, 2], [3, 4, 5]]).unwrap();
+///
+/// let product = lhs.clone() * lhs;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Typed<M, const R: usize, const C: usize>(M);
+
+impl<T, const R: usize, const C: usize> Typed<Matrix<T>, R, C> {
+    /// Wraps `matrix`, checking that its shape is `R x C`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotConformable`] if the shape of `matrix` is not `R x C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Error, Shape, Typed};
+    ///
+    /// let result = Typed::<_, 2, 3>::new(matrix![[0, 1, 2], [3, 4, 5]]);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = Typed::<_, 3, 2>::new(matrix![[0, 1, 2], [3, 4, 5]]);
+    /// let error = Error::NotConformable {
+    ///     lhs: Shape::new(2, 3),
+    ///     rhs: Shape::new(3, 2),
+    /// };
+    /// assert_eq!(result, Err(error));
+    /// ```
+    ///
+    /// [`Error::NotConformable`]: crate::error::Error::NotConformable
+    pub fn new(matrix: Matrix<T>) -> Result<Self> {
+        if matrix.nrows() != R || matrix.ncols() != C {
+            return Err(Error::not_conformable(matrix.shape(), Shape::new(R, C)));
+        }
+        Ok(Self(matrix))
+    }
+
+    /// Unwraps the underlying matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Typed};
+    ///
+    /// let typed = Typed::<_, 1, 2>::new(matrix![[0, 1]]).unwrap();
+    /// assert_eq!(typed.into_inner(), matrix![[0, 1]]);
+    /// ```
+    pub fn into_inner(self) -> Matrix<T> {
+        self.0
+    }
+
+    /// Returns the transpose of the matrix, with its shape swapped in
+    /// the type as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::{matrix, Typed};
+    ///
+    /// let typed = Typed::<_, 1, 2>::new(matrix![[0, 1]]).unwrap();
+    ///
+    /// let transposed: Typed<_, 2, 1> = typed.transpose();
+    /// assert_eq!(transposed.shape().nrows, 2);
+    /// assert_eq!(transposed[(1, 0)], 1);
+    /// ```
+    pub fn transpose(mut self) -> Typed<Matrix<T>, C, R> {
+        self.0.transpose();
+        Typed(self.0)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Deref for Typed<Matrix<T>, R, C> {
+    type Target = Matrix<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const R: usize, const C: usize> AsRef<Matrix<T>> for Typed<Matrix<T>, R, C> {
+    fn as_ref(&self) -> &Matrix<T> {
+        &self.0
+    }
+}
+
+impl<T, const R: usize, const C: usize> TryFrom<Matrix<T>> for Typed<Matrix<T>, R, C> {
+    type Error = Error;
+
+    fn try_from(value: Matrix<T>) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<Typed<Matrix<T>, R, C>> for Matrix<T> {
+    fn from(value: Typed<Matrix<T>, R, C>) -> Self {
+        value.0
+    }
+}
+
+impl<L, Rhs, U, const R: usize, const C: usize> Add<Typed<Matrix<Rhs>, R, C>>
+    for Typed<Matrix<L>, R, C>
+where
+    L: Add<Rhs, Output = U>,
+    Rhs: Clone,
+{
+    type Output = Typed<Matrix<U>, R, C>;
+
+    fn add(self, rhs: Typed<Matrix<Rhs>, R, C>) -> Self::Output {
+        match self.0.elementwise_add_consume_self(&rhs.0) {
+            Err(_) => unreachable!(),
+            Ok(output) => Typed(output),
+        }
+    }
+}
+
+impl<L, Rhs, U, const R: usize, const C: usize> Sub<Typed<Matrix<Rhs>, R, C>>
+    for Typed<Matrix<L>, R, C>
+where
+    L: Sub<Rhs, Output = U>,
+    Rhs: Clone,
+{
+    type Output = Typed<Matrix<U>, R, C>;
+
+    fn sub(self, rhs: Typed<Matrix<Rhs>, R, C>) -> Self::Output {
+        match self.0.elementwise_sub_consume_self(&rhs.0) {
+            Err(_) => unreachable!(),
+            Ok(output) => Typed(output),
+        }
+    }
+}
+
+impl<L, Rhs, U, const R: usize, const K: usize, const C: usize> Mul<Typed<Matrix<Rhs>, K, C>>
+    for Typed<Matrix<L>, R, K>
+where
+    L: Mul<Rhs, Output = U> + Clone,
+    Rhs: Clone,
+    U: Add<Output = U> + Default,
+{
+    type Output = Typed<Matrix<U>, R, C>;
+
+    fn mul(self, rhs: Typed<Matrix<Rhs>, K, C>) -> Self::Output {
+        match self.0.mat_mul(rhs.0) {
+            Err(_) => unreachable!(),
+            Ok(output) => Typed(output),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_typed_new() {
+        let matrix = matrix![[0, 1, 2], [3, 4, 5]];
+
+        let typed = Typed::<_, 2, 3>::new(matrix.clone()).unwrap();
+        assert_eq!(*typed, matrix);
+        assert_eq!(typed.nrows(), 2);
+        assert_eq!(Matrix::from(typed), matrix);
+
+        assert_eq!(
+            Typed::<_, 2, 2>::try_from(matrix.clone()),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(2, 2)
+            })
+        );
+        assert_eq!(
+            Typed::<_, 3, 3>::new(matrix),
+            Err(Error::NotConformable {
+                lhs: Shape::new(2, 3),
+                rhs: Shape::new(3, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_typed_ops() {
+        let mut lhs = matrix![[0, 1, 2], [3, 4, 5]];
+
+        for _ in 0..2 {
+            let typed = Typed::<_, 2, 3>::new(lhs.clone()).unwrap();
+            let ones = Typed::<_, 2, 3>::new(matrix![[1; 3]; 2]).unwrap();
+
+            let mut sum = (typed.clone() + ones.clone()).into_inner();
+            sum.set_order(crate::Order::RowMajor);
+            assert_eq!(sum, matrix![[1, 2, 3], [4, 5, 6]]);
+
+            let mut difference = (typed.clone() - ones).into_inner();
+            difference.set_order(crate::Order::RowMajor);
+            assert_eq!(difference, matrix![[-1, 0, 1], [2, 3, 4]]);
+
+            let transposed: Typed<_, 3, 2> = typed.clone().transpose();
+            let mut product: Matrix<i32> = (typed * transposed).into();
+            product.set_order(crate::Order::RowMajor);
+            assert_eq!(product, matrix![[5, 14], [14, 50]]);
+
+            lhs.switch_order();
+        }
+    }
+}
@@ -0,0 +1,354 @@
+//! This module provides iterators over submatrices.
+
+use super::order::Order;
+use super::shape::{AxisShape, Shape, ShapeLike};
+use super::Matrix;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// An iterator over overlapping submatrices of a fixed shape.
+///
+/// This struct is created by [`Matrix::windows`].
+#[derive(Clone, Debug)]
+pub struct Windows<'a, T> {
+    tiles: Tiles<'a, T>,
+}
+
+/// An iterator over non-overlapping submatrices tiling a matrix.
+///
+/// This struct is created by [`Matrix::chunks`].
+#[derive(Clone, Debug)]
+pub struct Chunks<'a, T> {
+    tiles: Tiles<'a, T>,
+}
+
+/// Submatrices at regularly spaced positions, in row-major order of
+/// their top-left corners.
+#[derive(Clone, Debug)]
+struct Tiles<'a, T> {
+    matrix: &'a Matrix<T>,
+    shape: Shape,
+    step: Shape,
+    ncols: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Tiles<'a, T> {
+    fn new(matrix: &'a Matrix<T>, shape: Shape, step: Shape, count: Shape) -> Self {
+        Self {
+            matrix,
+            shape,
+            step,
+            ncols: count.ncols,
+            front: 0,
+            back: count.nrows * count.ncols,
+        }
+    }
+
+    fn tile(&self, n: usize) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let row = n / self.ncols * self.step.nrows;
+        let col = n % self.ncols * self.step.ncols;
+        let nrows = self.shape.nrows.min(self.matrix.nrows() - row);
+        let ncols = self.shape.ncols.min(self.matrix.ncols() - col);
+        self.matrix.submatrix(row, col, Shape::new(nrows, ncols))
+    }
+
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<T: Clone> Iterator for Tiles<'_, T> {
+    type Item = Matrix<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let tile = self.tile(self.front);
+        self.front += 1;
+        Some(tile)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for Tiles<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.tile(self.back))
+    }
+}
+
+macro_rules! impl_tiles_iterator {
+    ($($name:ident)*) => {
+        $(
+            impl<T: Clone> Iterator for $name<'_, T> {
+                type Item = Matrix<T>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    self.tiles.next()
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    self.tiles.size_hint()
+                }
+
+                fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                    self.tiles.nth(n)
+                }
+            }
+
+            impl<T: Clone> DoubleEndedIterator for $name<'_, T> {
+                fn next_back(&mut self) -> Option<Self::Item> {
+                    self.tiles.next_back()
+                }
+            }
+
+            impl<T: Clone> ExactSizeIterator for $name<'_, T> {}
+
+            impl<T: Clone> FusedIterator for $name<'_, T> {}
+        )*
+    };
+}
+
+impl_tiles_iterator! {Windows Chunks}
+
+impl<T> Matrix<T> {
+    /// Returns an iterator over all contiguous submatrices of the given
+    /// shape, analogous to [`slice::windows`].
+    ///
+    /// Submatrices are yielded in row-major order of their top-left
+    /// corners. If the shape is larger than the matrix along either
+    /// axis, no submatrix is yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension of `shape` is zero.
+    ///
+    /// # Notes
+    ///
+    /// Each submatrix is cloned from the matrix and has the same order
+    /// as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+    /// let mut windows = matrix.windows((2, 2));
+    ///
+    /// assert_eq!(windows.len(), 4);
+    /// assert_eq!(windows.next(), Some(matrix![[0, 1], [3, 4]]));
+    /// assert_eq!(windows.next(), Some(matrix![[1, 2], [4, 5]]));
+    /// assert_eq!(windows.next(), Some(matrix![[3, 4], [6, 7]]));
+    /// assert_eq!(windows.next(), Some(matrix![[4, 5], [7, 8]]));
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    pub fn windows<S: ShapeLike>(&self, shape: S) -> Windows<'_, T>
+    where
+        T: Clone,
+    {
+        let shape = Shape::new(shape.nrows(), shape.ncols());
+        assert!(
+            shape.nrows != 0 && shape.ncols != 0,
+            "window shape must be non-zero"
+        );
+        let count = if shape.nrows <= self.nrows() && shape.ncols <= self.ncols() {
+            Shape::new(
+                self.nrows() - shape.nrows + 1,
+                self.ncols() - shape.ncols + 1,
+            )
+        } else {
+            Shape::new(0, 0)
+        };
+        Windows {
+            tiles: Tiles::new(self, shape, Shape::new(1, 1), count),
+        }
+    }
+
+    /// Returns an iterator over non-overlapping submatrices of the given
+    /// shape tiling the matrix, analogous to [`slice::chunks`].
+    ///
+    /// Submatrices are yielded in row-major order of their top-left
+    /// corners. Those along the bottom and right edges are smaller if
+    /// the shape does not evenly divide the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension of `shape` is zero.
+    ///
+    /// # Notes
+    ///
+    /// Each submatrix is cloned from the matrix and has the same order
+    /// as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use matreex::matrix;
+    ///
+    /// let matrix = matrix![[0, 1, 2], [3, 4, 5], [6, 7, 8]];
+    /// let mut chunks = matrix.chunks((2, 2));
+    ///
+    /// assert_eq!(chunks.len(), 4);
+    /// assert_eq!(chunks.next(), Some(matrix![[0, 1], [3, 4]]));
+    /// assert_eq!(chunks.next(), Some(matrix![[2], [5]]));
+    /// assert_eq!(chunks.next(), Some(matrix![[6, 7]]));
+    /// assert_eq!(chunks.next(), Some(matrix![[8]]));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn chunks<S: ShapeLike>(&self, shape: S) -> Chunks<'_, T>
+    where
+        T: Clone,
+    {
+        let shape = Shape::new(shape.nrows(), shape.ncols());
+        assert!(
+            shape.nrows != 0 && shape.ncols != 0,
+            "chunk shape must be non-zero"
+        );
+        let count = Shape::new(
+            self.nrows().div_ceil(shape.nrows),
+            self.ncols().div_ceil(shape.ncols),
+        );
+        Chunks {
+            tiles: Tiles::new(self, shape, shape, count),
+        }
+    }
+
+    /// Clones the submatrix of the given shape whose top-left corner is
+    /// at `(row, col)`, which must lie within the matrix.
+    fn submatrix(&self, row: usize, col: usize, shape: Shape) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let order = self.order;
+        let (major, minor) = match order {
+            Order::RowMajor => ((row, shape.nrows), (col, shape.ncols)),
+            Order::ColMajor => ((col, shape.ncols), (row, shape.nrows)),
+        };
+        let stride = self.major_stride();
+        let mut data = Vec::with_capacity(major.1 * minor.1);
+        for i in major.0..major.0 + major.1 {
+            let start = i * stride + minor.0;
+            data.extend_from_slice(&self.data[start..start + minor.1]);
+        }
+        let shape = AxisShape::from_shape_unchecked(shape, order);
+        Matrix { order, shape, data }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_windows() {
+        let mut matrix = matrix![[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]];
+
+        for _ in 0..2 {
+            let windows = matrix.windows((2, 3));
+            assert_eq!(windows.len(), 4);
+            let expected = [
+                matrix![[0, 1, 2], [4, 5, 6]],
+                matrix![[1, 2, 3], [5, 6, 7]],
+                matrix![[4, 5, 6], [8, 9, 10]],
+                matrix![[5, 6, 7], [9, 10, 11]],
+            ];
+            for (window, mut expected) in windows.zip(expected) {
+                assert_eq!(window.order(), matrix.order());
+                expected.set_order(matrix.order());
+                assert_eq!(window, expected);
+            }
+
+            let mut windows = matrix.windows((3, 4));
+            assert_eq!(windows.len(), 1);
+            let mut expected = matrix.clone();
+            expected.set_order(matrix.order());
+            assert_eq!(windows.next_back(), Some(expected));
+            assert_eq!(windows.next(), None);
+
+            let mut windows = matrix.windows((1, 1));
+            assert_eq!(windows.len(), 12);
+            assert_eq!(windows.nth(6).unwrap()[(0, 0)], 6);
+            assert_eq!(windows.next_back().unwrap()[(0, 0)], 11);
+            assert_eq!(windows.len(), 4);
+
+            assert_eq!(matrix.windows((4, 1)).len(), 0);
+            assert_eq!(matrix.windows((1, 5)).next(), None);
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<i32>::new((0, 3));
+        assert_eq!(matrix.windows((1, 1)).len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_windows_fails() {
+        let matrix = matrix![[0, 1], [2, 3]];
+        matrix.windows((0, 1));
+    }
+
+    #[test]
+    fn test_chunks() {
+        let mut matrix = matrix![[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]];
+
+        for _ in 0..2 {
+            let chunks = matrix.chunks((2, 3));
+            assert_eq!(chunks.len(), 4);
+            let expected = [
+                matrix![[0, 1, 2], [4, 5, 6]],
+                matrix![[3], [7]],
+                matrix![[8, 9, 10]],
+                matrix![[11]],
+            ];
+            for (chunk, mut expected) in chunks.zip(expected) {
+                assert_eq!(chunk.order(), matrix.order());
+                expected.set_order(matrix.order());
+                assert_eq!(chunk, expected);
+            }
+
+            let chunks = matrix.chunks((1, 2));
+            assert_eq!(chunks.len(), 6);
+            let sum: i32 = chunks
+                .rev()
+                .map(|chunk| chunk.iter_elements().sum::<i32>())
+                .sum();
+            assert_eq!(sum, 66);
+
+            let mut chunks = matrix.chunks((5, 5));
+            assert_eq!(chunks.len(), 1);
+            assert_eq!(chunks.next().unwrap().shape(), matrix.shape());
+
+            matrix.switch_order();
+        }
+
+        let matrix = Matrix::<i32>::new((0, 3));
+        assert_eq!(matrix.chunks((1, 1)).len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunks_fails() {
+        let matrix = matrix![[0, 1], [2, 3]];
+        matrix.chunks((1, 0));
+    }
+}